@@ -21,6 +21,7 @@ use anyhow::{anyhow, Context as _};
 use fj_export::export;
 use fj_host::{Model, Parameters};
 use fj_interop::status_report::StatusReport;
+use fj_kernel::validation::ValidationConfig;
 use fj_operations::shape_processor::ShapeProcessor;
 use fj_window::run::run;
 use tracing_subscriber::fmt::format;
@@ -61,6 +62,13 @@ fn main() -> anyhow::Result<()> {
 
     let shape_processor = ShapeProcessor {
         tolerance: args.tolerance,
+
+        // Report all validation errors, so they can be highlighted in the
+        // model at once.
+        validation_config: ValidationConfig {
+            collect_all: true,
+            ..ValidationConfig::default()
+        },
     };
 
     if let Some(path) = args.export {
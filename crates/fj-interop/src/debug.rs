@@ -11,6 +11,9 @@ use fj_math::{Point, Segment};
 pub struct DebugInfo {
     /// Rays being used during face triangulation
     pub triangle_edge_checks: Vec<TriangleEdgeCheck>,
+
+    /// Positions of objects that failed validation
+    pub validation_issues: Vec<Point<3>>,
 }
 
 impl DebugInfo {
@@ -26,6 +29,7 @@ impl DebugInfo {
     /// allocations.
     pub fn clear(&mut self) {
         self.triangle_edge_checks.clear();
+        self.validation_issues.clear();
     }
 }
 
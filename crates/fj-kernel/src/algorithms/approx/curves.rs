@@ -1,6 +1,6 @@
 use std::cmp::max;
 
-use fj_math::{Circle, Point, Scalar};
+use fj_math::{Circle, NurbsCurve, Point, Scalar};
 
 use crate::objects::{CurveKind, GlobalCurve};
 
@@ -11,32 +11,32 @@ use super::{Local, Tolerance};
 /// `tolerance` defines how far the approximation is allowed to deviate from the
 /// actual edge.
 ///
-/// # Implementation Note
-///
-/// This only works as it is, because edges are severely limited and don't
-/// define which section of the curve they inhabit. Once they do that, we need
-/// an `approximate_between(a, b)` method instead, where `a` and `b` are the
-/// vertices that bound the edge on the curve.
-///
-/// The `approximate_between` methods of the curves then need to make sure to
-/// only return points in between those vertices, not the vertices themselves.
+/// If `range` is provided, only the section of the curve between the two
+/// points (in curve coordinates) is approximated, and the returned points
+/// don't include the boundaries of that range. Otherwise, the whole curve is
+/// approximated, which only makes sense for closed curves.
 pub fn approx_curve(
     curve: &GlobalCurve,
+    range: Option<[Point<1>; 2]>,
     tolerance: Tolerance,
     out: &mut Vec<Local<Point<1>>>,
 ) {
     match curve.kind() {
-        CurveKind::Circle(curve) => approx_circle(curve, tolerance, out),
+        CurveKind::Circle(curve) => approx_circle(curve, range, tolerance, out),
         CurveKind::Line(_) => {}
+        CurveKind::Nurbs(curve) => {
+            approx_nurbs_curve(curve, range, tolerance, out)
+        }
     }
 }
 
 /// Approximate the circle
 ///
 /// `tolerance` specifies how much the approximation is allowed to deviate
-/// from the circle.
+/// from the circle. See [`approx_curve`] for the meaning of `range`.
 pub fn approx_circle(
     circle: &Circle<3>,
+    range: Option<[Point<1>; 2]>,
     tolerance: Tolerance,
     out: &mut Vec<Local<Point<1>>>,
 ) {
@@ -50,14 +50,150 @@ pub fn approx_circle(
 
     let n = number_of_vertices_for_circle(tolerance, radius);
 
-    for i in 0..n {
-        let angle = Scalar::PI * 2. / n as f64 * i as f64;
+    let (start, end, segments) = match range {
+        Some([start, end]) => {
+            // Use the same density of points as for the full circle, but
+            // spread them evenly over the range. The boundaries are left out.
+            let full_turn = Scalar::PI * 2.;
+            let segments = (Scalar::from_u64(n) * (end.t - start.t).abs()
+                / full_turn)
+                .ceil()
+                .into_u64()
+                .max(1);
+
+            (start.t, end.t, segments)
+        }
+        None => {
+            let start = circle.point_from_circle_coords([0.]);
+            out.push(Local::new([0.], start));
+
+            (Scalar::ZERO, Scalar::PI * 2., n)
+        }
+    };
+
+    for i in 1..segments {
+        let angle = start + (end - start) / segments as f64 * i as f64;
         let point = circle.point_from_circle_coords([angle]);
         out.push(Local::new([angle], point));
     }
 }
 
-fn number_of_vertices_for_circle(tolerance: Tolerance, radius: Scalar) -> u64 {
+/// Approximate the NURBS curve
+///
+/// Starts out with a few points per span of the curve, then keeps refining the
+/// approximation, wherever it deviates from the curve by more than `tolerance`.
+/// See [`approx_curve`] for the meaning of `range`.
+pub fn approx_nurbs_curve(
+    curve: &NurbsCurve<3>,
+    range: Option<[Point<1>; 2]>,
+    tolerance: Tolerance,
+    out: &mut Vec<Local<Point<1>>>,
+) {
+    let [start, end] = match range {
+        Some(range) => range.map(|point| point.t),
+        None => curve.domain(),
+    };
+
+    // The range might go against the direction of the curve. Each span between
+    // two breakpoints is a single polynomial piece of the curve, and a few
+    // points per piece make sure that we don't miss any of its features.
+    let mut coords = vec![start];
+    for breakpoint in curve.breakpoints() {
+        if (breakpoint - start) * (end - breakpoint) > Scalar::ZERO {
+            coords.push(breakpoint);
+        }
+    }
+    coords.push(end);
+    coords.sort_by_key(|&t| (t - start).abs());
+
+    let point = |t: Scalar| (t, curve.point_from_curve_coords([t]));
+
+    let mut points = vec![point(start)];
+    for span in coords.windows(2) {
+        let [a, b] = [span[0], span[1]];
+
+        let pieces = curve.degree();
+        for i in 1..=pieces {
+            let t = a + (b - a) * (i as f64 / pieces as f64);
+            let next = point(t);
+
+            // Can't panic, as `points` always contains the start point.
+            let previous = *points.last().unwrap();
+            refine_nurbs_curve_approx(
+                curve,
+                previous,
+                next,
+                tolerance,
+                0,
+                &mut points,
+            );
+            points.push(next);
+        }
+    }
+
+    // The end point is never part of the approximation. The start point is
+    // only, if the whole curve is approximated.
+    points.pop();
+    if range.is_some() {
+        points.remove(0);
+    }
+
+    out.extend(points.into_iter().map(|(t, point)| Local::new([t], point)));
+}
+
+/// Add points between `a` and `b`, until the approximation is within tolerance
+fn refine_nurbs_curve_approx(
+    curve: &NurbsCurve<3>,
+    a: (Scalar, Point<3>),
+    b: (Scalar, Point<3>),
+    tolerance: Tolerance,
+    depth: u32,
+    out: &mut Vec<(Scalar, Point<3>)>,
+) {
+    // Limits the refinement in case of numerical trouble, which could otherwise
+    // lead to endless recursion.
+    const MAX_DEPTH: u32 = 16;
+
+    let middle = (a.0 + b.0) / 2.;
+    let middle = (middle, curve.point_from_curve_coords([middle]));
+
+    // Checking the quarter points in addition to the middle makes it less
+    // likely that an S-shaped section passes as flat.
+    let deviation = [0.25, 0.5, 0.75]
+        .map(|s| {
+            let t = a.0 + (b.0 - a.0) * s;
+            distance_to_segment(curve.point_from_curve_coords([t]), [a.1, b.1])
+        })
+        .into_iter()
+        .max()
+        .unwrap_or(Scalar::ZERO);
+
+    if deviation <= tolerance.inner() || depth >= MAX_DEPTH {
+        return;
+    }
+
+    refine_nurbs_curve_approx(curve, a, middle, tolerance, depth + 1, out);
+    out.push(middle);
+    refine_nurbs_curve_approx(curve, middle, b, tolerance, depth + 1, out);
+}
+
+fn distance_to_segment(point: Point<3>, [a, b]: [Point<3>; 2]) -> Scalar {
+    let ab = b - a;
+    let length_squared = ab.dot(&ab);
+    if length_squared == Scalar::ZERO {
+        return (point - a).magnitude();
+    }
+
+    let s = ((point - a).dot(&ab) / length_squared)
+        .max(Scalar::ZERO)
+        .min(Scalar::ONE);
+    (point - (a + ab * s)).magnitude()
+}
+
+pub(crate) fn number_of_vertices_for_circle(
+    tolerance: Tolerance,
+    radius: Scalar,
+) -> u64 {
     let n = (Scalar::PI / (Scalar::ONE - (tolerance.inner() / radius)).acos())
         .ceil()
         .into_u64();
@@ -67,10 +203,57 @@ fn number_of_vertices_for_circle(tolerance: Tolerance, radius: Scalar) -> u64 {
 
 #[cfg(test)]
 mod tests {
-    use fj_math::Scalar;
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use fj_math::{NurbsCurve, Point, Scalar};
 
     use crate::algorithms::Tolerance;
 
+    #[test]
+    fn approx_nurbs_curve() -> anyhow::Result<()> {
+        // A quarter of the unit circle, which makes it easy to check how far
+        // the approximation deviates from the curve.
+        let curve = NurbsCurve::new(
+            2,
+            [[1., 0., 0.], [1., 1., 0.], [0., 1., 0.]],
+            [1., FRAC_1_SQRT_2, 1.],
+            [0., 0., 0., 1., 1., 1.],
+        );
+        let range = Some([Point::from([0.]), Point::from([1.])]);
+
+        let approx = |tolerance: Tolerance| {
+            let mut points = Vec::new();
+            super::approx_nurbs_curve(&curve, range, tolerance, &mut points);
+            points
+        };
+
+        let tolerance = Tolerance::from_scalar(0.001)?;
+        let points = approx(tolerance);
+
+        let mut polyline = vec![Point::from([1., 0., 0.])];
+        for point in &points {
+            assert_eq!(
+                curve.point_from_curve_coords(*point.local_form()),
+                *point.global_form(),
+            );
+            polyline.push(*point.global_form());
+        }
+        polyline.push(Point::from([0., 1., 0.]));
+
+        for segment in polyline.windows(2) {
+            let middle = segment[0] + (segment[1] - segment[0]) / 2.;
+            assert!(
+                Scalar::ONE - middle.coords.magnitude() <= tolerance.inner()
+            );
+        }
+
+        // A finer tolerance requires more points.
+        let tolerance = Tolerance::from_scalar(0.0001)?;
+        assert!(approx(tolerance).len() > points.len());
+
+        Ok(())
+    }
+
     #[test]
     fn number_of_vertices_for_circle() {
         verify_result(50., 100., 3);
@@ -17,25 +17,57 @@ impl CycleApprox {
     /// `tolerance` defines how far the approximation is allowed to deviate from
     /// the actual face.
     pub fn new(cycle: &Cycle, tolerance: Tolerance) -> Self {
-        let mut points = Vec::new();
+        let mut points: Vec<Local<Point<2>>> = Vec::new();
 
         for edge in cycle.edges() {
+            let range = edge
+                .vertices()
+                .get()
+                .map(|vertices| vertices.map(|vertex| vertex.position()));
+
             let mut edge_points = Vec::new();
-            approx_curve(edge.curve().global(), tolerance, &mut edge_points);
+            approx_curve(
+                edge.curve().global(),
+                range,
+                tolerance,
+                &mut edge_points,
+            );
             approx_edge(*edge.vertices(), &mut edge_points);
 
-            points.extend(edge_points.into_iter().map(|point| {
+            let mut edge_points = edge_points.into_iter().map(|point| {
                 let local = edge
                     .curve()
                     .kind()
                     .point_from_curve_coords(*point.local_form());
                 Local::new(local, *point.global_form())
-            }));
+            });
+
+            // Each edge starts where the previous one ended. Can't just rely
+            // on `dedup` to remove that duplicate point, as the conversion from
+            // curve coordinates could lead to subtly different surface
+            // coordinates.
+            //
+            // Only the points where edges meet are deduplicated. Within an
+            // edge, different surface coordinates can share the same global
+            // point, like along the pole of a sphere.
+            if let Some(first) = edge_points.next() {
+                let previous = points.last().map(|last| *last.global_form());
+                if previous != Some(*first.global_form()) {
+                    points.push(first);
+                }
+            }
+            points.extend(edge_points);
         }
 
-        // Can't just rely on `dedup`, as the conversion from curve coordinates
-        // could lead to subtly different surface coordinates.
-        points.dedup_by(|a, b| a.global_form() == b.global_form());
+        // For the same reason, the point that closes the cycle might not be
+        // identical to the one it starts with.
+        if let Some(&first) = points.first() {
+            if let Some(last) = points.last_mut() {
+                if last.global_form() == first.global_form() {
+                    *last = first;
+                }
+            }
+        }
 
         Self { points }
     }
@@ -1,10 +1,15 @@
 use std::collections::HashSet;
 
-use fj_math::Point;
+use fj_math::{Aabb, NurbsSurface, Point, Scalar};
 
-use crate::objects::Face;
+use crate::{
+    algorithms::intersect::Intersect,
+    objects::{Face, Surface},
+};
 
-use super::{CycleApprox, Local, Tolerance};
+use super::{
+    curves::number_of_vertices_for_circle, CycleApprox, Local, Tolerance,
+};
 
 /// An approximation of a [`Face`]
 #[derive(Debug, PartialEq)]
@@ -28,18 +33,14 @@ impl FaceApprox {
     /// `tolerance` defines how far the approximation is allowed to deviate from
     /// the actual face.
     pub fn new(face: &Face, tolerance: Tolerance) -> Self {
-        // Curved faces whose curvature is not fully defined by their edges
-        // are not supported yet. For that reason, we can fully ignore `face`'s
-        // `surface` field and just pass the edges to `Self::for_edges`.
+        // The curvature of some faces is fully defined by the edges that
+        // bound them. An example of that is the cylinder, whose curvature is
+        // defined by the circles that border it. The circle approximations are
+        // sufficient to triangulate the surface.
         //
-        // An example of a curved face that is supported, is the cylinder. Its
-        // curvature is fully defined be the edges (circles) that border it. The
-        // circle approximations are sufficient to triangulate the surface.
-        //
-        // An example of a curved face that is currently not supported, and thus
-        // doesn't need to be handled here, is a sphere. A spherical face would
-        // would need to provide its own approximation, as the edges that bound
-        // it have nothing to do with its curvature.
+        // Other faces, like those of a sphere, curve in between their edges.
+        // For those, we need to add more points, to approximate the surface
+        // itself.
 
         let mut points = HashSet::new();
         let mut exteriors = Vec::new();
@@ -70,6 +71,8 @@ impl FaceApprox {
             "Approximation only supports faces with one exterior cycle",
         );
 
+        points.extend(approx_surface(face, &exterior, tolerance));
+
         Self {
             points,
             exterior,
@@ -78,6 +81,222 @@ impl FaceApprox {
     }
 }
 
+/// Approximate the surface of a face that curves in between its edges
+///
+/// Returns points within the face, that approximate the curvature of its
+/// surface. For spheres and tori, those are located in a regular grid of
+/// surface coordinates, spaced according to the radii. For NURBS surfaces, the
+/// grid is refined adaptively, wherever the surface deviates from it by more
+/// than `tolerance`.
+fn approx_surface(
+    face: &Face,
+    exterior: &CycleApprox,
+    tolerance: Tolerance,
+) -> Vec<Local<Point<2>>> {
+    let surface = face.surface();
+
+    let bounds = Aabb::<2>::from_points(
+        exterior.points.iter().map(|point| *point.local_form()),
+    );
+
+    let points = match surface {
+        Surface::Nurbs(surface) => {
+            nurbs_surface_points(surface, bounds, tolerance)
+        }
+        Surface::Sphere(sphere) => {
+            grid_points(bounds, [sphere.radius(), sphere.radius()], tolerance)
+        }
+        Surface::SweptCurve(_) => return Vec::new(),
+        Surface::Torus(torus) => grid_points(
+            bounds,
+            [
+                torus.major_radius() + torus.minor_radius(),
+                torus.minor_radius(),
+            ],
+            tolerance,
+        ),
+    };
+
+    points
+        .into_iter()
+        .filter(|point| (face, point).intersect().is_some())
+        .map(|point| {
+            let global = surface.point_from_surface_coords(point);
+            Local::new(point, global)
+        })
+        .collect()
+}
+
+/// Points in a regular grid, strictly within the bounds
+///
+/// The spacing along each surface coordinate is chosen such that it
+/// approximates a circle of the respective radius within tolerance.
+fn grid_points(
+    bounds: Aabb<2>,
+    radii: [Scalar; 2],
+    tolerance: Tolerance,
+) -> Vec<Point<2>> {
+    let [min, max] = [bounds.min, bounds.max];
+
+    // Grid coordinates strictly between the boundaries, along one of the
+    // surface coordinates.
+    let coords = |min: Scalar, max: Scalar, radius: Scalar| {
+        let n = number_of_vertices_for_circle(tolerance, radius);
+        let step = Scalar::PI * 2. / n as f64;
+
+        let mut coords = Vec::new();
+        let mut coord = min + step;
+        while coord < max {
+            coords.push(coord);
+            coord += step;
+        }
+
+        coords
+    };
+
+    let us = coords(min.u, max.u, radii[0]);
+    let vs = coords(min.v, max.v, radii[1]);
+
+    let mut points = Vec::new();
+    for &u in &us {
+        for &v in &vs {
+            points.push(Point::from([u, v]));
+        }
+    }
+
+    points
+}
+
+/// Points that approximate a NURBS surface, strictly within the bounds
+///
+/// Starts with one cell per span of the surface, and keeps subdividing the
+/// cells that deviate from the surface by more than `tolerance`. Returns the
+/// corners of the resulting cells.
+fn nurbs_surface_points(
+    surface: &NurbsSurface<3>,
+    bounds: Aabb<2>,
+    tolerance: Tolerance,
+) -> Vec<Point<2>> {
+    let [min, max] = [bounds.min, bounds.max];
+    let [[min_u, _], [min_v, _]] = surface.domain();
+
+    let coords = |breakpoints: Vec<Scalar>, min: Scalar, max: Scalar| {
+        let mut coords = vec![min];
+        coords.extend(
+            breakpoints
+                .into_iter()
+                .filter(|&breakpoint| min < breakpoint && breakpoint < max),
+        );
+        coords.push(max);
+
+        coords
+    };
+
+    let us = coords(surface.curve_along_u(min_v).breakpoints(), min.u, max.u);
+    let vs = coords(surface.curve_along_v(min_u).breakpoints(), min.v, max.v);
+
+    let mut points = HashSet::new();
+    for u in us.windows(2) {
+        for v in vs.windows(2) {
+            // Can't panic, as we passed `2` to `windows`.
+            let cell = [Point::from([u[0], v[0]]), Point::from([u[1], v[1]])];
+            refine_nurbs_surface_approx(
+                surface,
+                cell,
+                tolerance,
+                0,
+                &mut points,
+            );
+        }
+    }
+
+    points
+        .into_iter()
+        .filter(|point| {
+            min.u < point.u
+                && point.u < max.u
+                && min.v < point.v
+                && point.v < max.v
+        })
+        .collect()
+}
+
+/// Subdivide a cell, until the surface is flat within it
+fn refine_nurbs_surface_approx(
+    surface: &NurbsSurface<3>,
+    [min, max]: [Point<2>; 2],
+    tolerance: Tolerance,
+    depth: u32,
+    points: &mut HashSet<Point<2>>,
+) {
+    // Limits the refinement in case of numerical trouble, which could otherwise
+    // lead to endless recursion.
+    const MAX_DEPTH: u32 = 8;
+
+    let corners = [
+        [min.u, min.v],
+        [max.u, min.v],
+        [max.u, max.v],
+        [min.u, max.v],
+    ]
+    .map(Point::from);
+    let global = corners.map(|point| surface.point_from_surface_coords(point));
+
+    // Compare the surface to the triangles that the cell ends up as, in the
+    // middle of the cell and in the middle of each of its sides. The cell can
+    // be split along either diagonal, and if its corners don't lie in a plane,
+    // the middle of those diagonals differs.
+    let middle = min + (max - min) / 2.;
+    let mut deviation = Scalar::ZERO;
+    for [i, j] in [[0, 2], [1, 3]] {
+        let interpolated = global[i] + (global[j] - global[i]) / 2.;
+
+        deviation = deviation.max(
+            (surface.point_from_surface_coords(middle) - interpolated)
+                .magnitude(),
+        );
+    }
+
+    for i in 0..corners.len() {
+        let j = (i + 1) % corners.len();
+
+        let side_middle = corners[i] + (corners[j] - corners[i]) / 2.;
+        let interpolated = global[i] + (global[j] - global[i]) / 2.;
+
+        deviation = deviation.max(
+            (surface.point_from_surface_coords(side_middle) - interpolated)
+                .magnitude(),
+        );
+    }
+
+    if deviation <= tolerance.inner() || depth >= MAX_DEPTH {
+        points.extend(corners);
+        return;
+    }
+
+    let cells = [
+        [min, middle],
+        [
+            Point::from([middle.u, min.v]),
+            Point::from([max.u, middle.v]),
+        ],
+        [middle, max],
+        [
+            Point::from([min.u, middle.v]),
+            Point::from([middle.u, max.v]),
+        ],
+    ];
+    for cell in cells {
+        refine_nurbs_surface_approx(
+            surface,
+            cell,
+            tolerance,
+            depth + 1,
+            points,
+        );
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use fj_math::{Point, Scalar};
@@ -11,3 +11,5 @@ pub use self::{
     local::{Local, LocalForm},
     tolerance::{InvalidTolerance, Tolerance},
 };
+
+pub(crate) use self::curves::approx_curve;
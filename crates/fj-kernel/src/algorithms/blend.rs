@@ -0,0 +1,694 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_interop::mesh::Color;
+use fj_math::{Line, NurbsCurve, Point, Scalar, Vector};
+
+use crate::{
+    objects::{
+        Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, GlobalVertex, Solid,
+        Surface, SweptCurve, Vertex, VerticesOfEdge,
+    },
+    validation::edge_positions,
+};
+
+use super::loft::ruled_face;
+
+/// Round the selected edges of a solid
+///
+/// Every edge for which `select` returns `true` is replaced by a blend face,
+/// which is a section of a cylinder with the given radius. The blend face is
+/// tangent to both faces that are adjacent to the edge, and those faces are
+/// trimmed accordingly.
+///
+/// # Limitations
+///
+/// Only straight, convex edges between two planar faces can be blended.
+/// Exactly three straight edges must meet at each end of a selected edge, and
+/// no two selected edges may meet at a vertex. Unsupported cases are reported
+/// as a [`BlendError`].
+pub fn fillet(
+    solid: &Solid,
+    radius: impl Into<Scalar>,
+    select: impl Fn(&Edge) -> bool,
+) -> Result<Solid, BlendError> {
+    blend(solid, Blend::Fillet(radius.into()), select)
+}
+
+/// Bevel the selected edges of a solid
+///
+/// Every edge for which `select` returns `true` is replaced by a planar blend
+/// face, which cuts `distance` off both faces that are adjacent to the edge.
+///
+/// The same limitations as for [`fillet`] apply.
+pub fn chamfer(
+    solid: &Solid,
+    distance: impl Into<Scalar>,
+    select: impl Fn(&Edge) -> bool,
+) -> Result<Solid, BlendError> {
+    blend(solid, Blend::Chamfer(distance.into()), select)
+}
+
+/// An error that can occur when blending the edges of a solid
+///
+/// Returned by [`fillet`] and [`chamfer`].
+#[derive(Debug, thiserror::Error)]
+pub enum BlendError {
+    /// The radius or distance of the blend is not a positive number
+    #[error("Blend size must be positive, but is {0}")]
+    InvalidSize(Scalar),
+
+    /// A selected edge is curved
+    #[error("Blending curved edges is not supported yet")]
+    CurvedEdge(Vec<Point<3>>),
+
+    /// A selected edge doesn't end in exactly three straight edges
+    #[error(
+        "Blending edges that don't end in exactly three straight edges is not \
+        supported yet"
+    )]
+    UnsupportedVertex(Point<3>),
+
+    /// Two selected edges meet at a vertex
+    #[error("Blending edges that meet at a vertex is not supported yet")]
+    AdjacentEdges(Point<3>),
+
+    /// A selected edge doesn't bound exactly two faces
+    #[error("Blended edge must bound exactly two faces")]
+    NotTwoFaces(Vec<Point<3>>),
+
+    /// A face adjacent to a selected edge is not planar
+    #[error("Blending edges of curved faces is not supported yet")]
+    CurvedFace(Vec<Point<3>>),
+
+    /// A selected edge is concave
+    #[error("Blending concave edges is not supported yet")]
+    ConcaveEdge(Vec<Point<3>>),
+
+    /// The blend doesn't fit within the faces adjacent to a selected edge
+    #[error("Blend is too large for the edges adjacent to the blended edge")]
+    TooLarge(Vec<Point<3>>),
+}
+
+impl BlendError {
+    /// Access the positions of the edge or vertex that caused the error
+    ///
+    /// Can be used to highlight them in a model.
+    pub fn positions(&self) -> Vec<Point<3>> {
+        match self {
+            Self::InvalidSize(_) => Vec::new(),
+            Self::UnsupportedVertex(position)
+            | Self::AdjacentEdges(position) => vec![*position],
+            Self::CurvedEdge(positions)
+            | Self::NotTwoFaces(positions)
+            | Self::CurvedFace(positions)
+            | Self::ConcaveEdge(positions)
+            | Self::TooLarge(positions) => positions.clone(),
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
+enum Blend {
+    Fillet(Scalar),
+    Chamfer(Scalar),
+}
+
+/// Identifies a straight edge by its (sorted) vertices
+type EdgeKey = [GlobalVertex; 2];
+
+fn blend(
+    solid: &Solid,
+    blend: Blend,
+    select: impl Fn(&Edge) -> bool,
+) -> Result<Solid, BlendError> {
+    let size = match blend {
+        Blend::Fillet(size) | Blend::Chamfer(size) => size,
+    };
+    if !(size > Scalar::ZERO && size.into_f64().is_finite()) {
+        return Err(BlendError::InvalidSize(size));
+    }
+
+    let faces: Vec<_> = solid.faces().cloned().collect();
+
+    let mut face_edges = Vec::new();
+    let mut vertex_edges = BTreeMap::<_, BTreeSet<_>>::new();
+    let mut curved_vertices = BTreeSet::new();
+    let mut selected = BTreeSet::new();
+
+    for face in &faces {
+        let mut edges = BTreeSet::new();
+
+        let cycles = face
+            .triangles()
+            .is_none()
+            .then(|| face.all_cycles())
+            .into_iter()
+            .flatten();
+
+        for edge in cycles.flat_map(|cycle| cycle.edges()) {
+            let key = edge_key(edge);
+
+            if select(edge) {
+                match key {
+                    Some(key) => {
+                        selected.insert(key);
+                    }
+                    None => {
+                        return Err(BlendError::CurvedEdge(edge_positions(
+                            edge,
+                        )));
+                    }
+                }
+            }
+
+            match key {
+                Some(key) => {
+                    edges.insert(key);
+                    for vertex in key {
+                        vertex_edges.entry(vertex).or_default().insert(key);
+                    }
+                }
+                None => {
+                    curved_vertices.extend(
+                        edge.vertices().iter().map(|vertex| *vertex.global()),
+                    );
+                }
+            }
+        }
+
+        face_edges.push(edges);
+    }
+
+    let mut trims = BTreeMap::new();
+    let mut cross_sections = BTreeMap::new();
+    let mut blend_faces = Vec::new();
+
+    for &edge in &selected {
+        let [start, end] = edge;
+        let direction = (end.position() - start.position()).normalize();
+        let positions = || edge.map(|vertex| vertex.position()).to_vec();
+
+        for vertex in edge {
+            let edges = &vertex_edges[&vertex];
+
+            if edges.len() != 3 || curved_vertices.contains(&vertex) {
+                return Err(BlendError::UnsupportedVertex(vertex.position()));
+            }
+            if edges.iter().filter(|&key| selected.contains(key)).count() > 1 {
+                return Err(BlendError::AdjacentEdges(vertex.position()));
+            }
+        }
+
+        let adjacent_faces: Vec<_> = (0..faces.len())
+            .filter(|&i| face_edges[i].contains(&edge))
+            .collect();
+        let mut sides = match adjacent_faces.as_slice() {
+            &[a, b] => [a, b],
+            _ => return Err(BlendError::NotTwoFaces(positions())),
+        };
+
+        // Direction within the face, perpendicular to the edge and pointing
+        // away from it.
+        let inward = |face: usize| {
+            let other = other_edge(&face_edges[face], edge, start)?;
+            let along = (other_vertex(other, start).position()
+                - start.position())
+            .normalize();
+            Ok(perpendicular(along, direction))
+        };
+
+        let normal_a =
+            match sides.map(|face| plane_normal(faces[face].surface())) {
+                [Some(normal_a), Some(_)] => normal_a,
+                _ => return Err(BlendError::CurvedFace(positions())),
+            };
+        if normal_a.dot(&inward(sides[1])?) >= Scalar::ZERO {
+            return Err(BlendError::ConcaveEdge(positions()));
+        }
+
+        // Make sure that the blend face, which goes from the first side to the
+        // second one, points outward.
+        if direction.cross(&inward(sides[0])?).dot(&normal_a) < Scalar::ZERO {
+            sides.swap(0, 1);
+        }
+
+        let angle = inward(sides[0])?.dot(&inward(sides[1])?).acos();
+        let (sin, cos) = (angle / 2.).sin_cos();
+        let (distance, weight) = match blend {
+            Blend::Fillet(radius) => (radius * cos / sin, Some(sin)),
+            Blend::Chamfer(distance) => (distance, None),
+        };
+
+        let [start_section, end_section] = edge.map(|vertex| {
+            let [a, b] = sides.map(|face| {
+                let other = other_edge(&face_edges[face], edge, vertex)?;
+                let along =
+                    other_vertex(other, vertex).position() - vertex.position();
+
+                let direction_along = along.normalize();
+                let offset = distance
+                    / direction_along
+                        .dot(&perpendicular(direction_along, direction));
+                if offset >= along.magnitude() {
+                    return Err(BlendError::TooLarge(positions()));
+                }
+
+                let trimmed = GlobalVertex::from_position(
+                    vertex.position() + direction_along * offset,
+                );
+                trims.insert((vertex, other), trimmed);
+
+                Ok(trimmed)
+            });
+
+            let section = CrossSection {
+                vertices: [a?, b?],
+                corner: vertex.position(),
+                weight,
+            };
+            cross_sections.insert(vertex, section);
+
+            Ok(section)
+        });
+        let sections = [start_section?, end_section?];
+
+        let color = faces[sides[0]].color();
+        blend_faces.push(match blend {
+            Blend::Fillet(_) => fillet_face(sections, color),
+            Blend::Chamfer(_) => chamfer_face(sections, color),
+        });
+    }
+
+    let trimmer = Trimmer {
+        face_edges: &face_edges,
+        selected: &selected,
+        trims: &trims,
+        cross_sections: &cross_sections,
+    };
+
+    let faces = faces
+        .iter()
+        .enumerate()
+        .map(|(i, face)| {
+            if face.triangles().is_some() {
+                return face.clone();
+            }
+
+            let exteriors =
+                face.exteriors().map(|cycle| trimmer.trim(cycle, i));
+            let interiors =
+                face.interiors().map(|cycle| trimmer.trim(cycle, i));
+
+            Face::new(face.surface().clone())
+                .with_exteriors(exteriors)
+                .with_interiors(interiors)
+                .with_color(face.color())
+        })
+        .collect::<Vec<_>>();
+
+    Ok(Solid::new().with_faces(faces).with_faces(blend_faces))
+}
+
+/// The cross-section of a blend face, at one end of the blended edge
+#[derive(Clone, Copy)]
+struct CrossSection {
+    /// The vertices where the cross-section meets the adjacent faces
+    vertices: [GlobalVertex; 2],
+
+    /// The position of the vertex that the cross-section replaces
+    corner: Point<3>,
+
+    /// The weight of the corner, if the cross-section is a circular arc
+    weight: Option<Scalar>,
+}
+
+impl CrossSection {
+    /// Create the curve of the cross-section from its points
+    ///
+    /// Expects the points of the vertices, with the corner between them. The
+    /// curve runs from the first vertex, at `0`, to the second one, at `1`.
+    fn curve<const D: usize>(&self, points: [Point<D>; 3]) -> CurveKind<D> {
+        let [a, corner, b] = points;
+
+        match self.weight {
+            // A rational quadratic curve is an exact circular arc, if the
+            // weight of its middle control point is the cosine of half the
+            // arc's angle. Any affine image of that, like the section of a
+            // cylinder in an oblique plane, keeps the weights.
+            Some(weight) => CurveKind::Nurbs(NurbsCurve::new(
+                2,
+                [a, corner, b],
+                [Scalar::ONE, weight, Scalar::ONE],
+                [0., 0., 0., 1., 1., 1.],
+            )),
+            None => CurveKind::Line(Line::from_points([a, b])),
+        }
+    }
+
+    fn points(&self) -> [Point<3>; 3] {
+        let [a, b] = self.vertices.map(|vertex| vertex.position());
+        [a, self.corner, b]
+    }
+
+    /// Create an edge along the cross-section, in the given planar surface
+    fn edge(&self, surface: &Surface, vertices: [GlobalVertex; 2]) -> Edge {
+        let local = self
+            .points()
+            .map(|point| surface.point_to_surface_coords(point));
+
+        let range = if vertices[0] == self.vertices[0] {
+            [0., 1.]
+        } else {
+            [1., 0.]
+        };
+
+        Edge::new(
+            Curve::new(
+                self.curve(local),
+                GlobalCurve::from_kind(self.curve(self.points())),
+            ),
+            vertices_of_edge(range, vertices),
+        )
+    }
+}
+
+/// Trims the faces adjacent to the blended edges
+struct Trimmer<'r> {
+    face_edges: &'r [BTreeSet<EdgeKey>],
+    selected: &'r BTreeSet<EdgeKey>,
+    trims: &'r BTreeMap<(GlobalVertex, EdgeKey), GlobalVertex>,
+    cross_sections: &'r BTreeMap<GlobalVertex, CrossSection>,
+}
+
+impl Trimmer<'_> {
+    fn trim(&self, cycle: &Cycle, face: usize) -> Cycle {
+        let surface = cycle.surface();
+        let edges: Vec<_> = cycle.edges().collect();
+
+        let mut trimmed = Vec::new();
+
+        for (i, &edge) in edges.iter().enumerate() {
+            let vertices = match edge.vertices().get() {
+                Some(vertices) => vertices.map(|vertex| *vertex.global()),
+                None => {
+                    trimmed.push(edge.clone());
+                    continue;
+                }
+            };
+
+            let trimmed_vertices =
+                vertices.map(|vertex| self.vertex(edge, vertex, face));
+            if trimmed_vertices == vertices {
+                trimmed.push(edge.clone());
+            } else {
+                trimmed.push(line_segment(surface, trimmed_vertices));
+            }
+
+            // If the edges were connected by a vertex that has been replaced
+            // by a cross-section, the gap between them needs to be closed.
+            let next = edges[(i + 1) % edges.len()];
+            let shared = next.vertices().get().and_then(|next_vertices| {
+                vertices.into_iter().find(|vertex| {
+                    next_vertices.iter().any(|next| next.global() == vertex)
+                })
+            });
+            if let Some(vertex) = shared {
+                let from = self.vertex(edge, vertex, face);
+                let to = self.vertex(next, vertex, face);
+
+                if from != to {
+                    trimmed.push(
+                        self.cross_sections[&vertex].edge(surface, [from, to]),
+                    );
+                }
+            }
+        }
+
+        Cycle::new(surface.clone()).with_edges(trimmed)
+    }
+
+    /// Determine what a vertex of an edge of the given face is replaced with
+    fn vertex(
+        &self,
+        edge: &Edge,
+        vertex: GlobalVertex,
+        face: usize,
+    ) -> GlobalVertex {
+        let mut key = match edge_key(edge) {
+            Some(key) => key,
+            None => return vertex,
+        };
+
+        if self.selected.contains(&key) {
+            // The blended edge is replaced by an edge that is parallel to it.
+            // Its vertices lie on the other edges of the face.
+            key = other_edge(&self.face_edges[face], key, vertex)
+                .expect("Vertices of blended edges have been checked");
+        }
+
+        self.trims.get(&(vertex, key)).copied().unwrap_or(vertex)
+    }
+}
+
+fn fillet_face(sections: [CrossSection; 2], color: Color) -> Face {
+    // The blend face is ruled between the cross-sections, which are arcs of
+    // the same circle, translated along the blended edge. That makes it a
+    // section of a cylinder.
+    let [start, end] =
+        sections.map(|section| match section.curve(section.points()) {
+            CurveKind::Nurbs(curve) => curve,
+            _ => unreachable!("Cross-sections of fillets are circular arcs"),
+        });
+
+    ruled_face(
+        [&start, &end],
+        sections.map(|section| section.vertices),
+        color,
+    )
+}
+
+fn chamfer_face(sections: [CrossSection; 2], color: Color) -> Face {
+    let [start, end] = sections;
+
+    let [a, b] = start.vertices;
+    let [d, c] = end.vertices;
+
+    let surface = Surface::plane_from_points([a, b, d].map(|v| v.position()));
+    let edges = [
+        start.edge(&surface, [a, b]),
+        line_segment(&surface, [b, c]),
+        end.edge(&surface, [c, d]),
+        line_segment(&surface, [d, a]),
+    ];
+
+    Face::new(surface.clone())
+        .with_exteriors([Cycle::new(surface).with_edges(edges)])
+        .with_color(color)
+}
+
+fn edge_key(edge: &Edge) -> Option<EdgeKey> {
+    if let CurveKind::Line(_) = edge.curve().global().kind() {
+        let mut vertices =
+            edge.vertices().get()?.map(|vertex| *vertex.global());
+        vertices.sort();
+        return Some(vertices);
+    }
+
+    None
+}
+
+fn other_edge(
+    face_edges: &BTreeSet<EdgeKey>,
+    edge: EdgeKey,
+    vertex: GlobalVertex,
+) -> Result<EdgeKey, BlendError> {
+    let mut others = face_edges
+        .iter()
+        .filter(|&&key| key != edge && key.contains(&vertex));
+
+    match (others.next(), others.next()) {
+        (Some(&other), None) => Ok(other),
+        _ => Err(BlendError::UnsupportedVertex(vertex.position())),
+    }
+}
+
+fn other_vertex(edge: EdgeKey, vertex: GlobalVertex) -> GlobalVertex {
+    let [a, b] = edge;
+    if a == vertex {
+        b
+    } else {
+        a
+    }
+}
+
+fn perpendicular(vector: Vector<3>, direction: Vector<3>) -> Vector<3> {
+    (vector - direction * vector.dot(&direction)).normalize()
+}
+
+fn plane_normal(surface: &Surface) -> Option<Vector<3>> {
+    match surface {
+        Surface::SweptCurve(SweptCurve {
+            curve: CurveKind::Line(line),
+            path,
+        }) => Some(line.direction().cross(path).normalize()),
+        _ => None,
+    }
+}
+
+fn line_segment(surface: &Surface, vertices: [GlobalVertex; 2]) -> Edge {
+    let points = vertices.map(|vertex| vertex.position());
+    let local = points.map(|point| surface.point_to_surface_coords(point));
+
+    Edge::new(
+        Curve::new(
+            CurveKind::Line(Line::from_points(local)),
+            GlobalCurve::from_kind(CurveKind::Line(Line::from_points(points))),
+        ),
+        vertices_of_edge([0., 1.], vertices),
+    )
+}
+
+fn vertices_of_edge(
+    range: [f64; 2],
+    vertices: [GlobalVertex; 2],
+) -> VerticesOfEdge {
+    let [a, b] = range;
+    let [vertex_a, vertex_b] = vertices;
+
+    VerticesOfEdge::from_vertices([
+        Vertex::new(Point::from([a]), vertex_a),
+        Vertex::new(Point::from([b]), vertex_b),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Color;
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::{sweep, BlendError},
+        objects::{Edge, Face, Sketch, Solid, Surface},
+        test_utils::volume,
+    };
+
+    #[test]
+    fn fillet_vertical_edges() -> anyhow::Result<()> {
+        let cube = Solid::build().cube_from_edge_length(1.);
+
+        let solid = super::fillet(&cube, 0.25, is_vertical)?;
+
+        // Each corner of the square loses the part of a square with side length
+        // 0.25 that isn't covered by a quarter circle.
+        let corner = Scalar::from(0.0625) * (Scalar::ONE - Scalar::PI / 4.);
+        let expected = Scalar::ONE - corner * 4.;
+        assert!((volume(solid)? - expected).abs() < Scalar::from(0.001));
+
+        Ok(())
+    }
+
+    #[test]
+    fn fillet_horizontal_edge() -> anyhow::Result<()> {
+        let cube = Solid::build().cube_from_edge_length(1.);
+
+        let solid = super::fillet(&cube, 0.25, |edge| {
+            is_at(edge, |[_, y, z]| y == -0.5 && z == 0.5)
+        })?;
+
+        let corner = Scalar::from(0.0625) * (Scalar::ONE - Scalar::PI / 4.);
+        let expected = Scalar::ONE - corner;
+        assert!((volume(solid)? - expected).abs() < Scalar::from(0.001));
+
+        Ok(())
+    }
+
+    #[test]
+    fn chamfer_vertical_edges() -> anyhow::Result<()> {
+        let cube = Solid::build().cube_from_edge_length(1.);
+
+        let solid = super::chamfer(&cube, 0.25, is_vertical)?;
+
+        let expected = Scalar::ONE - Scalar::from(0.03125) * 4.;
+        assert!((volume(solid)? - expected).abs() < Scalar::from(0.001));
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_size() {
+        let cube = Solid::build().cube_from_edge_length(1.);
+
+        for radius in [0., -0.25, f64::INFINITY] {
+            let result = super::fillet(&cube, radius, is_vertical);
+            assert!(matches!(result, Err(BlendError::InvalidSize(_))));
+        }
+    }
+
+    #[test]
+    fn adjacent_edges() {
+        let cube = Solid::build().cube_from_edge_length(1.);
+
+        let result = super::fillet(&cube, 0.25, |_| true);
+        assert!(matches!(result, Err(BlendError::AdjacentEdges(_))));
+    }
+
+    #[test]
+    fn too_large() {
+        let cube = Solid::build().cube_from_edge_length(1.);
+
+        let result = super::chamfer(&cube, 1.5, is_vertical);
+        assert!(matches!(result, Err(BlendError::TooLarge(_))));
+    }
+
+    #[test]
+    fn concave_edge() -> anyhow::Result<()> {
+        // An L-shaped prism, whose inner vertical edge is concave.
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([
+                [0., 0.],
+                [2., 0.],
+                [2., 1.],
+                [1., 1.],
+                [1., 2.],
+                [0., 2.],
+            ])
+            .into_face();
+        let sketch = Sketch::new().with_faces([face]);
+        let solid = sweep(sketch, [0., 0., 1.], Color([255, 0, 0, 255]));
+
+        let result = super::fillet(&solid, 0.25, |edge| {
+            is_vertical(edge) && is_at(edge, |[x, y, _]| x == 1. && y == 1.)
+        });
+        match result {
+            Err(BlendError::ConcaveEdge(positions)) => {
+                assert_eq!(positions.len(), 2);
+            }
+            result => panic!("Expected concave edge, got {result:?}"),
+        }
+
+        // The convex edges of the same solid can still be blended.
+        let solid = super::fillet(&solid, 0.25, |edge| {
+            is_vertical(edge) && is_at(edge, |[x, y, _]| x == 2. && y == 0.)
+        })?;
+        assert!(volume(solid)? < Scalar::from(3.));
+
+        Ok(())
+    }
+
+    fn is_vertical(edge: &Edge) -> bool {
+        let mut z = edge.vertices().iter().map(|v| v.global().position().z);
+        matches!((z.next(), z.next()), (Some(a), Some(b)) if a != b)
+    }
+
+    fn is_at(edge: &Edge, f: impl Fn([f64; 3]) -> bool) -> bool {
+        edge.vertices().get().is_some()
+            && edge.vertices().iter().all(|vertex| {
+                f(vertex
+                    .global()
+                    .position()
+                    .coords
+                    .components
+                    .map(|component| component.into_f64()))
+            })
+    }
+}
@@ -0,0 +1,566 @@
+//! Boolean operations
+
+mod region;
+mod sketch;
+mod solid;
+
+use fj_math::Point;
+
+use crate::{
+    objects::{Face, Sketch, Solid},
+    validation::edge_positions,
+};
+
+use self::region::Operation;
+
+pub use self::region::signed_area;
+
+pub(super) use self::region::{approx_nurbs_edges, fill, is_mirrored};
+
+pub(crate) use self::region::oriented_cycles;
+
+use super::Tolerance;
+
+/// Compute boolean operations between objects
+///
+/// # Sketches
+///
+/// Boolean operations on [`Sketch`]es are computed on the exact geometry of
+/// lines and circles. NURBS edges, like those of splines, are approximated by
+/// polylines first.
+///
+/// All faces of both sketches must be defined in the same surface. The
+/// operations return [`BooleanError::DifferentSurfaces`], if that is not the
+/// case.
+///
+/// # Solids
+///
+/// Boolean operations on [`Solid`]s only support planar faces bounded by line
+/// segments. Other faces are approximated by planar facets first, and facets
+/// that end up in the same plane are merged back together. This means curved
+/// faces of the operands end up faceted in the result.
+pub trait BooleanOps: Sized {
+    /// Compute the union of two objects
+    ///
+    /// The result contains everything that is in either object.
+    ///
+    /// `tolerance` defines how far approximations of curved geometry are
+    /// allowed to deviate from the actual geometry, where such approximations
+    /// are required.
+    fn union(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+    ) -> Result<Self, BooleanError>;
+
+    /// Compute the difference of two objects
+    ///
+    /// The result contains everything that is in `self`, but not in `other`.
+    ///
+    /// See [`BooleanOps::union`] for the meaning of `tolerance`.
+    fn difference(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+    ) -> Result<Self, BooleanError>;
+
+    /// Compute the intersection of two objects
+    ///
+    /// The result contains everything that is in both objects.
+    ///
+    /// See [`BooleanOps::union`] for the meaning of `tolerance`.
+    fn intersection(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+    ) -> Result<Self, BooleanError>;
+}
+
+impl BooleanOps for Solid {
+    fn union(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+    ) -> Result<Self, BooleanError> {
+        solid::compute(self, other, Operation::Union, tolerance)
+    }
+
+    fn difference(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+    ) -> Result<Self, BooleanError> {
+        solid::compute(self, other, Operation::Difference, tolerance)
+    }
+
+    fn intersection(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+    ) -> Result<Self, BooleanError> {
+        solid::compute(self, other, Operation::Intersection, tolerance)
+    }
+}
+
+impl BooleanOps for Sketch {
+    fn union(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+    ) -> Result<Self, BooleanError> {
+        sketch::compute(self, other, Operation::Union, tolerance)
+    }
+
+    fn difference(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+    ) -> Result<Self, BooleanError> {
+        sketch::compute(self, other, Operation::Difference, tolerance)
+    }
+
+    fn intersection(
+        &self,
+        other: &Self,
+        tolerance: Tolerance,
+    ) -> Result<Self, BooleanError> {
+        sketch::compute(self, other, Operation::Intersection, tolerance)
+    }
+}
+
+/// An error that can occur during a boolean operation
+///
+/// Returned by the methods of [`BooleanOps`].
+#[derive(Debug, thiserror::Error)]
+pub enum BooleanError {
+    /// The faces of the sketches are not all defined in the same surface
+    #[error(
+        "Boolean operations on sketches require all faces to be in the same \
+        surface"
+    )]
+    DifferentSurfaces(Vec<Point<3>>),
+
+    /// A face of a solid is not planar, after it has been approximated
+    #[error("Boolean operations on solids only support planar faces")]
+    NonPlanarFace(Vec<Point<3>>),
+}
+
+impl BooleanError {
+    /// Access the positions of the face that caused the error
+    ///
+    /// Can be used to highlight it in a model.
+    pub fn positions(&self) -> Vec<Point<3>> {
+        match self {
+            Self::DifferentSurfaces(positions)
+            | Self::NonPlanarFace(positions) => positions.clone(),
+        }
+    }
+}
+
+fn face_positions(face: &Face) -> Vec<Point<3>> {
+    face.all_cycles()
+        .flat_map(|cycle| cycle.edges())
+        .flat_map(edge_positions)
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use fj_math::{Circle, Point, Scalar};
+
+    use crate::{
+        algorithms::{Tolerance, TransformObject},
+        iter::ObjectIters,
+        objects::{Cycle, Edge, Face, Sketch, Solid, Surface},
+        test_utils::{circle, cuboid, rectangle, volume},
+        validation::{validate, ValidationConfig},
+    };
+
+    use super::{oriented_cycles, signed_area, BooleanError, BooleanOps};
+
+    #[test]
+    fn union_of_overlapping_solids() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let a = cuboid([0., 0., 0.], [2.; 3]);
+        let b = cuboid([1., 1., 1.], [2.; 3]);
+
+        let union = a.union(&b, tolerance)?;
+
+        // Each cube contributes three unchanged faces, and three faces that
+        // were partially covered by the other cube.
+        assert_eq!(union.face_iter().count(), 12);
+
+        // The two corners that lie within the other cube are gone. Six new
+        // vertices were created, where the surfaces of the cubes intersect.
+        let vertices: Vec<_> = union
+            .global_vertex_iter()
+            .map(|vertex| vertex.position())
+            .collect();
+        assert_eq!(vertices.len(), 20);
+        assert!(!vertices.contains(&Point::from([2., 2., 2.])));
+        assert!(!vertices.contains(&Point::from([1., 1., 1.])));
+        assert!(vertices.contains(&Point::from([2., 1., 2.])));
+
+        validate(union, &ValidationConfig::default())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_of_disjoint_solids() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let a = cuboid([0., 0., 0.], [1.; 3]);
+        let b = cuboid([2., 0., 0.], [1.; 3]);
+
+        let union = a.union(&b, tolerance)?;
+
+        assert_eq!(union.face_iter().count(), 12);
+        assert_eq!(union.global_vertex_iter().count(), 16);
+
+        validate(union, &ValidationConfig::default())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn union_with_contained_solid() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let a = cuboid([0., 0., 0.], [3.; 3]);
+        let b = cuboid([1., 1., 1.], [1.; 3]);
+
+        let union = a.union(&b, tolerance)?;
+
+        let mut vertices: Vec<_> = union
+            .global_vertex_iter()
+            .map(|vertex| vertex.position())
+            .collect();
+        let mut expected: Vec<_> = a
+            .global_vertex_iter()
+            .map(|vertex| vertex.position())
+            .collect();
+        vertices.sort();
+        expected.sort();
+
+        assert_eq!(union.face_iter().count(), 6);
+        assert_eq!(vertices, expected);
+
+        validate(union, &ValidationConfig::default())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_of_overlapping_solids() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let a = cuboid([0., 0., 0.], [2.; 3]);
+        let b = cuboid([1., 1., 1.], [2.; 3]);
+
+        let difference = a.difference(&b, tolerance)?;
+
+        // The corner of the first cube has been cut away, which leaves three
+        // partially covered faces of the first cube, and three faces of the
+        // second cube that bound the pocket.
+        assert_eq!(difference.face_iter().count(), 9);
+
+        let vertices: Vec<_> = difference
+            .global_vertex_iter()
+            .map(|vertex| vertex.position())
+            .collect();
+        assert_eq!(vertices.len(), 14);
+        assert!(!vertices.contains(&Point::from([2., 2., 2.])));
+        assert!(vertices.contains(&Point::from([1., 1., 1.])));
+
+        validate(difference, &ValidationConfig::default())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_hole() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let a = cuboid([0., 0., 0.], [3.; 3]);
+        let b = cuboid([1., 1., -1.], [1., 1., 5.]);
+
+        let difference = a.difference(&b, tolerance)?;
+
+        // The top and bottom faces now have a hole. The hole is bounded by four
+        // additional faces.
+        assert_eq!(difference.face_iter().count(), 10);
+        assert_eq!(
+            difference
+                .face_iter()
+                .filter(|face| face.interiors().count() == 1)
+                .count(),
+            2
+        );
+
+        validate(difference, &ValidationConfig::default())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersection_of_overlapping_solids() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let a = cuboid([0., 0., 0.], [2.; 3]);
+        let b = cuboid([1., 1., 1.], [2.; 3]);
+
+        let intersection = a.intersection(&b, tolerance)?;
+
+        let mut vertices: Vec<_> = intersection
+            .global_vertex_iter()
+            .map(|vertex| vertex.position())
+            .collect();
+        let mut expected: Vec<_> = cuboid([1., 1., 1.], [1.; 3])
+            .global_vertex_iter()
+            .map(|vertex| vertex.position())
+            .collect();
+        vertices.sort();
+        expected.sort();
+
+        assert_eq!(intersection.face_iter().count(), 6);
+        assert_eq!(vertices, expected);
+
+        validate(intersection, &ValidationConfig::default())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn intersection_of_disjoint_solids() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let a = cuboid([0., 0., 0.], [1.; 3]);
+        let b = cuboid([2., 0., 0.], [1.; 3]);
+
+        let intersection = a.intersection(&b, tolerance)?;
+        assert_eq!(intersection.face_iter().count(), 0);
+
+        validate(intersection, &ValidationConfig::default())?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn difference_with_cylinder() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let a = cuboid([-2., -2., 0.], [4., 4., 2.]);
+        let b = Solid::build()
+            .cylinder_from_radius_and_height(1., 4.)
+            .translate([0., 0., -1.]);
+
+        let difference = a.difference(&b, tolerance)?;
+
+        // The top and bottom faces now have a hole, which approximates the
+        // cylinder. The facets of the cylinder are merged back into one face
+        // per edge of the hole.
+        let holes: Vec<_> = difference
+            .face_iter()
+            .flat_map(|face| face.interiors())
+            .map(|cycle| cycle.edges().count())
+            .collect();
+        assert_eq!(holes.len(), 2);
+        assert_eq!(difference.face_iter().count(), 6 + holes[0]);
+
+        let volume = volume(difference)?;
+        let expected = Scalar::from(32. - PI * 2.);
+        assert!((volume - expected).abs() < Scalar::from(0.1));
+
+        Ok(())
+    }
+
+    #[test]
+    fn sketch_union() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let a = rectangle([0., 0.], [2., 2.]);
+        let b = rectangle([1., 1.], [2., 2.]);
+
+        let union = a.union(&b, tolerance)?;
+
+        assert_eq!(union.face_iter().count(), 1);
+        assert_eq!(union.global_vertex_iter().count(), 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sketch_difference() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        // Overlapping
+        let a = rectangle([0., 0.], [2., 2.]);
+        let b = rectangle([1., 1.], [2., 2.]);
+
+        let difference = a.difference(&b, tolerance)?;
+
+        assert_eq!(difference.face_iter().count(), 1);
+        assert_eq!(difference.global_vertex_iter().count(), 6);
+
+        // Contained
+        let a = rectangle([0., 0.], [3., 3.]);
+        let b = rectangle([1., 1.], [1., 1.]);
+
+        let difference = a.difference(&b, tolerance)?;
+
+        let faces: Vec<_> = difference.face_iter().collect();
+        assert_eq!(faces.len(), 1);
+        assert_eq!(faces[0].interiors().count(), 1);
+
+        // Splitting
+        let a = rectangle([0., 0.], [3., 1.]);
+        let b = rectangle([1., -1.], [1., 3.]);
+
+        let difference = a.difference(&b, tolerance)?;
+
+        assert_eq!(difference.face_iter().count(), 2);
+        assert_eq!(difference.global_vertex_iter().count(), 8);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sketch_intersection() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let a = rectangle([0., 0.], [2., 2.]);
+        let b = rectangle([1., 1.], [2., 2.]);
+
+        let intersection = a.intersection(&b, tolerance)?;
+
+        let mut vertices: Vec<_> = intersection
+            .global_vertex_iter()
+            .map(|vertex| vertex.position())
+            .collect();
+        vertices.sort();
+
+        assert_eq!(intersection.face_iter().count(), 1);
+        assert_eq!(
+            vertices,
+            [[1., 1., 0.], [1., 2., 0.], [2., 1., 0.], [2., 2., 0.]]
+                .map(Point::from)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn sketch_circle_and_rectangle() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        // The rectangle covers a quarter of the circle.
+        let circle = circle([0., 0.], 1.);
+        let rectangle = rectangle([0., 0.], [2., 2.]);
+
+        let quarter = PI / 4.;
+
+        let union = circle.union(&rectangle, tolerance)?;
+        assert_eq!(union.face_iter().count(), 1);
+        assert_area(&union, PI + 4. - quarter);
+
+        let difference = circle.difference(&rectangle, tolerance)?;
+        assert_eq!(difference.face_iter().count(), 1);
+        assert_area(&difference, PI - quarter);
+
+        let difference = rectangle.difference(&circle, tolerance)?;
+        assert_eq!(difference.face_iter().count(), 1);
+        assert_area(&difference, 4. - quarter);
+
+        let intersection = circle.intersection(&rectangle, tolerance)?;
+        assert_eq!(intersection.face_iter().count(), 1);
+        assert_area(&intersection, quarter);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sketch_circles() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let a = circle([0., 0.], 1.);
+        let b = circle([1., 0.], 1.);
+
+        // The area that two unit circles, at a distance of 1, have in common.
+        let lens = PI * 2. / 3. - 3_f64.sqrt() / 2.;
+
+        let union = a.union(&b, tolerance)?;
+        assert_eq!(union.face_iter().count(), 1);
+        assert_eq!(union.global_vertex_iter().count(), 2);
+        assert_area(&union, PI * 2. - lens);
+
+        let difference = a.difference(&b, tolerance)?;
+        assert_eq!(difference.face_iter().count(), 1);
+        assert_area(&difference, PI - lens);
+
+        let intersection = a.intersection(&b, tolerance)?;
+        assert_eq!(intersection.face_iter().count(), 1);
+        assert_area(&intersection, lens);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sketch_nurbs() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        // A circle, defined as a NURBS curve. The rectangle covers a quarter
+        // of it.
+        let surface = Surface::xy_plane();
+        let curve = Circle::new([0., 0.], [1., 0.], [0., 1.])
+            .to_nurbs([Scalar::ZERO, Scalar::PI * 2.]);
+        let edge = Edge::build().nurbs_from_curve(&surface, curve);
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges([edge])]);
+        let circle = Sketch::new().with_faces([face]);
+        let rectangle = rectangle([0., 0.], [2., 2.]);
+
+        let quarter = PI / 4.;
+
+        let difference = circle.difference(&rectangle, tolerance)?;
+        assert_eq!(difference.face_iter().count(), 1);
+        assert_area(&difference, PI - quarter);
+
+        let intersection = circle.intersection(&rectangle, tolerance)?;
+        assert_eq!(intersection.face_iter().count(), 1);
+        assert_area(&intersection, quarter);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sketch_different_surfaces() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
+
+        let a = rectangle([0., 0.], [1., 1.]);
+        let b = Sketch::new().with_faces([Face::build(Surface::xz_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])]);
+
+        let union = a.union(&b, tolerance);
+        assert!(matches!(union, Err(BooleanError::DifferentSurfaces(_))));
+
+        Ok(())
+    }
+
+    /// Assert the area of a sketch, within the accuracy of [`signed_area`]
+    fn assert_area(sketch: &Sketch, expected: f64) {
+        validate(sketch.clone(), &ValidationConfig::default())
+            .expect("Result of boolean operation should be valid");
+
+        let area: f64 = sketch
+            .face_iter()
+            .flat_map(oriented_cycles)
+            .map(|cycle| signed_area(&cycle))
+            .sum();
+
+        assert!((area - expected).abs() < 0.05, "{area} != {expected}");
+    }
+}
@@ -0,0 +1,1122 @@
+//! Boolean operations on faces that are defined in the same surface
+//!
+//! This is the two-dimensional core of the boolean operations. It works
+//! exclusively in surface coordinates, and is used both for sketches and as a
+//! building block for the boolean operations on solids.
+//!
+//! The algorithm works by splitting the edges of both operands at all points
+//! where they intersect, classifying the resulting fragments against the other
+//! operand, then linking the fragments that make up the boundary of the result
+//! into new cycles.
+//!
+//! The same approach is used by [`fill`], to resolve cycles that intersect
+//! themselves or each other, as offsetting a sketch creates them.
+//!
+//! Lines and circles are handled exactly. NURBS edges are approximated by
+//! polylines first.
+
+use fj_math::{Circle, Line, Point, Scalar, Vector};
+
+use crate::{
+    algorithms::{approx::approx_curve, Tolerance},
+    objects::{
+        Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, GlobalVertex,
+        Surface, Vertex, VerticesOfEdge,
+    },
+};
+
+/// A boolean operation
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum Operation {
+    /// Keep everything that is in either operand
+    Union,
+
+    /// Keep everything that is in the first operand, but not in the second
+    Difference,
+
+    /// Keep everything that is in both operands
+    Intersection,
+}
+
+/// Compute a boolean operation between two sets of faces
+///
+/// All faces must be defined in the same surface. Within each set, the faces
+/// must not overlap.
+///
+/// NURBS edges are approximated by polylines, which deviate from them by no
+/// more than `tolerance`.
+pub fn compute(
+    a: &[Face],
+    b: &[Face],
+    operation: Operation,
+    tolerance: Tolerance,
+) -> Vec<Face> {
+    let first = match a.iter().chain(b).next() {
+        Some(face) => face,
+        None => return Vec::new(),
+    };
+    let surface = first.surface().clone();
+    let color = first.color();
+
+    let mirrored = is_mirrored(first);
+
+    let edges_a = approx_nurbs_edges(&surface, oriented_edges(a), tolerance);
+    let edges_b = approx_nurbs_edges(&surface, oriented_edges(b), tolerance);
+
+    let precision = Precision::new(edges_a.iter().chain(&edges_b));
+
+    let mut pool = VertexPool::new(surface.clone(), precision);
+    for edge in edges_a.iter().chain(&edges_b) {
+        for vertex in edge.vertices().iter() {
+            let position = point_on_edge(edge, vertex.position().t);
+            pool.insert(position, *vertex.global());
+        }
+    }
+
+    let mut splits_a = vec![Vec::new(); edges_a.len()];
+    let mut splits_b = vec![Vec::new(); edges_b.len()];
+
+    for (i, edge_a) in edges_a.iter().enumerate() {
+        for (j, edge_b) in edges_b.iter().enumerate() {
+            for point in intersections(edge_a, edge_b, precision) {
+                let split = pool.get_or_insert(point);
+
+                splits_a[i].push(split);
+                splits_b[j].push(split);
+            }
+        }
+    }
+
+    let fragments_a = fragments(&edges_a, &splits_a, &pool);
+    let fragments_b = fragments(&edges_b, &splits_b, &pool);
+
+    let mut kept = Vec::new();
+
+    for fragment in fragments_a {
+        let [left, right] = sides(&fragment, &edges_b, precision);
+
+        let keep = match operation {
+            Operation::Union => !right,
+            Operation::Difference => !left,
+            Operation::Intersection => left,
+        };
+
+        if keep {
+            kept.push(fragment);
+        }
+    }
+    for fragment in fragments_b {
+        let [left, right] = sides(&fragment, &edges_a, precision);
+
+        // Fragments that coincide with a fragment of the first operand are
+        // never kept here. Where required, the fragment of the first operand
+        // has already been kept.
+        match operation {
+            Operation::Union => {
+                if !left && !right {
+                    kept.push(fragment);
+                }
+            }
+            Operation::Difference => {
+                if left && right {
+                    kept.push(reverse_edge(&fragment));
+                }
+            }
+            Operation::Intersection => {
+                if left && right {
+                    kept.push(fragment);
+                }
+            }
+        }
+    }
+
+    let cycles = link(kept, precision).into_iter().map(|cycle| {
+        cycle.iter().map(|edge| pool.rebuild_edge(edge)).collect()
+    });
+
+    build_faces(surface, cycles, mirrored, precision)
+        .into_iter()
+        .map(|face| face.with_color(color))
+        .collect()
+}
+
+/// Compute the faces bounded by edges that might intersect each other
+///
+/// The edges must form closed cycles in surface coordinates, but those cycles
+/// may intersect themselves and each other. The result covers everything the
+/// cycles wind around counter-clockwise, at least once. Regions that they wind
+/// around clockwise, or not at all, are not part of the result.
+///
+/// If `mirrored` is `true`, the cycles of the resulting faces follow the
+/// convention of a reversed surface (see [`is_mirrored`]).
+///
+/// Like with [`compute`], NURBS edges are approximated by polylines.
+pub fn fill(
+    surface: Surface,
+    edges: Vec<Edge>,
+    mirrored: bool,
+    tolerance: Tolerance,
+) -> Vec<Face> {
+    let edges = approx_nurbs_edges(&surface, edges, tolerance);
+
+    let precision = Precision::new(&edges);
+
+    let mut pool = VertexPool::new(surface.clone(), precision);
+    for edge in &edges {
+        for vertex in edge.vertices().iter() {
+            let position = point_on_edge(edge, vertex.position().t);
+            pool.insert(position, *vertex.global());
+        }
+    }
+
+    let mut splits = vec![Vec::new(); edges.len()];
+
+    for (i, a) in edges.iter().enumerate() {
+        for (j, b) in edges.iter().enumerate().skip(i + 1) {
+            for point in intersections(a, b, precision) {
+                let split = pool.get_or_insert(point);
+
+                splits[i].push(split);
+                splits[j].push(split);
+            }
+        }
+    }
+
+    let mut kept: Vec<Edge> = Vec::new();
+
+    for fragment in fragments(&edges, &splits, &pool) {
+        let [left, right] = side_points(&fragment, precision)
+            .map(|point| winding_number(&edges, point));
+
+        // Coincident fragments have the same winding numbers on both sides,
+        // so either all of them pass this check, or none do. Only one of them
+        // can end up in the boundary of the result.
+        let is_boundary = left > 0 && right <= 0;
+        let is_duplicate = kept
+            .iter()
+            .any(|other| is_coincident(other, &fragment, precision));
+
+        if is_boundary && !is_duplicate {
+            kept.push(fragment);
+        }
+    }
+
+    let cycles = link(kept, precision).into_iter().map(|cycle| {
+        cycle.iter().map(|edge| pool.rebuild_edge(edge)).collect()
+    });
+
+    build_faces(surface, cycles, mirrored, precision)
+}
+
+/// Determine whether the cycles of a face go the opposite way in its surface
+///
+/// The faces of a surface that has been reversed have their exterior cycles
+/// going clockwise in surface coordinates. Internally, we normalize everything
+/// to counter-clockwise exteriors, but want results to follow the same
+/// convention as the input.
+pub fn is_mirrored(face: &Face) -> bool {
+    face.exteriors()
+        .next()
+        .map(|cycle| signed_area(cycle.edges()) < 0.)
+        .unwrap_or(false)
+}
+
+/// Numerical precision of the operation, derived from the size of the input
+#[derive(Clone, Copy)]
+struct Precision {
+    /// Distance below which points are considered identical
+    epsilon: f64,
+
+    /// Distance used to sample points on either side of an edge
+    offset: f64,
+}
+
+impl Precision {
+    fn new<'r>(edges: impl IntoIterator<Item = &'r Edge>) -> Self {
+        let mut min = [f64::INFINITY; 2];
+        let mut max = [f64::NEG_INFINITY; 2];
+
+        for edge in edges {
+            for point in sample_edge(edge) {
+                let point = [point.u.into_f64(), point.v.into_f64()];
+
+                for i in 0..2 {
+                    min[i] = min[i].min(point[i]);
+                    max[i] = max[i].max(point[i]);
+                }
+            }
+        }
+
+        let extent = (max[0] - min[0]).max(max[1] - min[1]);
+        let extent = if extent.is_finite() && extent > 0. {
+            extent
+        } else {
+            1.
+        };
+
+        Self {
+            epsilon: extent * 1e-9,
+            offset: extent * 1e-6,
+        }
+    }
+}
+
+/// The global vertices that have been used so far, by position
+///
+/// Makes sure that all edges that meet at a point refer to the same global
+/// vertex.
+struct VertexPool {
+    surface: Surface,
+    precision: Precision,
+    vertices: Vec<(Point<2>, GlobalVertex)>,
+}
+
+impl VertexPool {
+    fn new(surface: Surface, precision: Precision) -> Self {
+        Self {
+            surface,
+            precision,
+            vertices: Vec::new(),
+        }
+    }
+
+    fn get(&self, point: Point<2>) -> Option<(Point<2>, GlobalVertex)> {
+        self.vertices.iter().copied().find(|(position, _)| {
+            distance(*position, point) < self.precision.epsilon
+        })
+    }
+
+    fn insert(&mut self, point: Point<2>, vertex: GlobalVertex) {
+        if self.get(point).is_none() {
+            self.vertices.push((point, vertex));
+        }
+    }
+
+    fn get_or_insert(&mut self, point: Point<2>) -> (Point<2>, GlobalVertex) {
+        if let Some(vertex) = self.get(point) {
+            return vertex;
+        }
+
+        let vertex = GlobalVertex::from_position(
+            self.surface.point_from_surface_coords(point),
+        );
+        self.vertices.push((point, vertex));
+
+        (point, vertex)
+    }
+
+    /// Rebuild a line segment from the positions of its vertices in the pool
+    ///
+    /// The positions of the end points of fragments are computed from different
+    /// curves, which means the end points of adjacent fragments can differ
+    /// slightly in surface coordinates. Rebuilding the edges makes sure that all
+    /// edges that share a vertex also agree on its position.
+    fn rebuild_edge(&self, edge: &Edge) -> Edge {
+        let vertices = match (edge.curve().kind(), edge.vertices().get()) {
+            (CurveKind::Line(_), Some(vertices)) => {
+                vertices.map(|vertex| *vertex.global())
+            }
+            _ => return edge.clone(),
+        };
+
+        let local = vertices.map(|global| {
+            self.vertices
+                .iter()
+                .find(|(_, vertex)| *vertex == global)
+                .map(|&(point, _)| point)
+                .unwrap_or_else(|| {
+                    self.surface.point_to_surface_coords(global.position())
+                })
+        });
+
+        line_segment(local, vertices)
+    }
+}
+
+/// Replace NURBS edges with polylines that approximate them
+///
+/// `tolerance` defines how far the polylines are allowed to deviate from the
+/// NURBS curves. Other edges are returned unchanged.
+pub fn approx_nurbs_edges(
+    surface: &Surface,
+    edges: impl IntoIterator<Item = Edge>,
+    tolerance: Tolerance,
+) -> Vec<Edge> {
+    let mut approximated = Vec::new();
+
+    for edge in edges {
+        if !matches!(edge.curve().kind(), CurveKind::Nurbs(_)) {
+            approximated.push(edge);
+            continue;
+        }
+
+        let range = edge
+            .vertices()
+            .get()
+            .map(|vertices| vertices.map(|vertex| vertex.position()));
+
+        let mut points = Vec::new();
+        approx_curve(edge.curve().global(), range, tolerance, &mut points);
+
+        let mut polyline: Vec<_> = points
+            .into_iter()
+            .map(|point| {
+                let point = point_on_edge(&edge, point.local_form().t);
+                let vertex = GlobalVertex::from_position(
+                    surface.point_from_surface_coords(point),
+                );
+
+                (point, vertex)
+            })
+            .collect();
+
+        // The approximation doesn't include the vertices of the edge. An edge
+        // without vertices is closed, and the approximation of it starts at
+        // its start point, but doesn't come back to it.
+        match edge.vertices().get() {
+            Some(vertices) => {
+                let [start, end] = vertices.map(|vertex| {
+                    (
+                        point_on_edge(&edge, vertex.position().t),
+                        *vertex.global(),
+                    )
+                });
+                polyline.insert(0, start);
+                polyline.push(end);
+            }
+            None => {
+                if let Some(&start) = polyline.first() {
+                    polyline.push(start);
+                }
+            }
+        }
+
+        approximated.extend(polyline.windows(2).map(|segment| {
+            let [(a, vertex_a), (b, vertex_b)] = [segment[0], segment[1]];
+            line_segment([a, b], [vertex_a, vertex_b])
+        }));
+    }
+
+    approximated
+}
+
+/// Create a line segment between the given vertices
+fn line_segment(local: [Point<2>; 2], vertices: [GlobalVertex; 2]) -> Edge {
+    let curve = Curve::new(
+        CurveKind::line_from_points(local),
+        GlobalCurve::from_kind(CurveKind::line_from_points(
+            vertices.map(|vertex| vertex.position()),
+        )),
+    );
+    let [a, b] = vertices;
+
+    Edge::new(
+        curve,
+        VerticesOfEdge::from_vertices([
+            Vertex::new([0.], a),
+            Vertex::new([1.], b),
+        ]),
+    )
+}
+
+/// Collect the edges of all faces, oriented such that the face is on the left
+fn oriented_edges(faces: &[Face]) -> Vec<Edge> {
+    faces.iter().flat_map(oriented_cycles).flatten().collect()
+}
+
+/// Collect the cycles of a face, oriented such that the face is on the left
+///
+/// This means exterior cycles go counter-clockwise in surface coordinates, and
+/// interior cycles go clockwise, regardless of the orientation of the surface.
+pub fn oriented_cycles(face: &Face) -> Vec<Vec<Edge>> {
+    if face.triangles().is_some() {
+        panic!("Boolean operations on tri-rep faces are not supported");
+    }
+
+    let exteriors = face.exteriors().map(|cycle| {
+        let reverse = signed_area(cycle.edges()) < 0.;
+        orient_cycle(cycle, reverse)
+    });
+    let interiors = face.interiors().map(|cycle| {
+        let reverse = signed_area(cycle.edges()) > 0.;
+        orient_cycle(cycle, reverse)
+    });
+
+    exteriors.chain(interiors).collect()
+}
+
+fn orient_cycle(cycle: &Cycle, reverse: bool) -> Vec<Edge> {
+    let edges: Vec<_> = cycle.edges().cloned().collect();
+
+    if reverse {
+        edges.iter().rev().map(reverse_edge).collect()
+    } else {
+        edges
+    }
+}
+
+/// Reverse the direction of an edge
+fn reverse_edge(edge: &Edge) -> Edge {
+    let curve = Curve::new(
+        edge.curve().kind().clone().reverse(),
+        GlobalCurve::from_kind(edge.curve().global().kind().clone().reverse()),
+    );
+
+    Edge::new(curve, edge.vertices().reverse())
+}
+
+/// Compute the points where two edges intersect
+fn intersections(a: &Edge, b: &Edge, precision: Precision) -> Vec<Point<2>> {
+    let mut candidates = Vec::new();
+
+    // We don't use `CurveEdgeIntersection` here, as it requires the
+    // intersection point to be exactly on the edge, which fails for many
+    // intersections due to floating point inaccuracy. Whether the point is
+    // close enough to both edges is checked below.
+    match (a.curve().kind(), b.curve().kind()) {
+        (CurveKind::Line(_), CurveKind::Line(_)) => {
+            candidates.extend(line_line_intersection(a, b));
+        }
+        (CurveKind::Line(line), CurveKind::Circle(circle))
+        | (CurveKind::Circle(circle), CurveKind::Line(line)) => {
+            candidates.extend(line_circle_intersections(line, circle));
+        }
+        (CurveKind::Circle(a), CurveKind::Circle(b)) => {
+            candidates.extend(circle_circle_intersections(a, b, precision));
+        }
+        _ => {
+            unreachable!("NURBS edges are approximated by `compute` and `fill`")
+        }
+    }
+
+    // The end points of each edge need to be considered separately. This
+    // covers coincident edges, as well as edges touching each other, which
+    // the intersection tests above might not detect due to floating point
+    // inaccuracy.
+    for edge in [a, b] {
+        if edge.vertices().get().is_some() {
+            candidates.extend(end_points(edge));
+        }
+    }
+
+    candidates
+        .into_iter()
+        .filter(|&point| {
+            distance_to_edge(point, a) < precision.epsilon
+                && distance_to_edge(point, b) < precision.epsilon
+        })
+        .collect()
+}
+
+/// Compute the intersection point of the lines that two line edges lie on
+///
+/// Returns `None`, if the lines are parallel.
+fn line_line_intersection(a: &Edge, b: &Edge) -> Option<Point<2>> {
+    let [a_start, a_end] = end_points(a);
+    let [b_start, b_end] = end_points(b);
+
+    let a_dir = a_end - a_start;
+    let b_dir = b_end - b_start;
+
+    let denom = a_dir.u * b_dir.v - a_dir.v * b_dir.u;
+    if denom == Scalar::ZERO {
+        return None;
+    }
+
+    let offset = b_start - a_start;
+    let t = (offset.u * b_dir.v - offset.v * b_dir.u) / denom;
+
+    Some(a_start + a_dir * t)
+}
+
+/// Compute the points where a line intersects a circle
+///
+/// If the line misses the circle, returns the point of the line that is
+/// closest to the circle, as the line might still touch it, within the limits
+/// of floating point accuracy.
+fn line_circle_intersections(
+    line: &Line<2>,
+    circle: &Circle<2>,
+) -> Vec<Point<2>> {
+    let direction = line.direction();
+    let offset = line.origin() - circle.center();
+    let radius = circle.a().magnitude();
+
+    let a = direction.dot(&direction);
+    let b = direction.dot(&offset) * 2.;
+    let c = offset.dot(&offset) - radius * radius;
+
+    let discriminant = b * b - a * c * 4.;
+    if discriminant < Scalar::ZERO {
+        let t = -b / (a * 2.);
+        return vec![line.point_from_line_coords([t])];
+    }
+
+    let root = discriminant.sqrt();
+    [-b - root, -b + root]
+        .into_iter()
+        .map(|t| line.point_from_line_coords([t / (a * 2.)]))
+        .collect()
+}
+
+/// Compute the points where two circles intersect
+///
+/// Like [`line_circle_intersections`], returns the closest point, if the
+/// circles seem to miss each other. Concentric circles are not considered to
+/// intersect.
+fn circle_circle_intersections(
+    a: &Circle<2>,
+    b: &Circle<2>,
+    precision: Precision,
+) -> Vec<Point<2>> {
+    let between = b.center() - a.center();
+    let d = between.magnitude();
+
+    if d.into_f64() < precision.epsilon {
+        return Vec::new();
+    }
+
+    let [r_a, r_b] = [a, b].map(|circle| circle.a().magnitude());
+
+    let along = (d * d + r_a * r_a - r_b * r_b) / (d * 2.);
+    let across = (r_a * r_a - along * along).max(Scalar::ZERO).sqrt();
+
+    let direction = between / d;
+    let normal = Vector::from([-direction.v, direction.u]);
+
+    let base = a.center() + direction * along;
+    vec![base + normal * across, base - normal * across]
+}
+
+/// Split the edges at the provided points
+fn fragments(
+    edges: &[Edge],
+    splits: &[Vec<(Point<2>, GlobalVertex)>],
+    pool: &VertexPool,
+) -> Vec<Edge> {
+    let mut fragments = Vec::new();
+
+    for (edge, splits) in edges.iter().zip(splits) {
+        let [start, end] = match (edge.vertices().get(), splits.first()) {
+            (Some(_), _) => edge_range(edge),
+            (None, Some(&(point, _))) => {
+                // A continuous edge that is split starts and ends at one of
+                // the points it is split at.
+                let t = curve_coords(edge, point, Scalar::ZERO);
+                [t, t + Scalar::PI * 2.]
+            }
+            (None, None) => {
+                fragments.push(edge.clone());
+                continue;
+            }
+        };
+
+        // Make sure that the end points of all fragments refer to the global
+        // vertices from the pool.
+        let vertex = |t: Scalar| {
+            let point = point_on_edge(edge, t);
+            pool.get(point)
+                .map(|(_, vertex)| vertex)
+                .unwrap_or_else(|| {
+                    GlobalVertex::from_position(
+                        pool.surface.point_from_surface_coords(point),
+                    )
+                })
+        };
+
+        let mut points = vec![(start, vertex(start))];
+        for &(point, global) in splits {
+            let t = curve_coords(edge, point, start.min(end));
+
+            let is_within_edge = (t - start) * (end - t) > Scalar::ZERO;
+            let is_new = global != points[0].1 && global != vertex(end);
+
+            if is_within_edge && is_new {
+                points.push((t, global));
+            }
+        }
+        points[1..].sort_by_key(|&(t, _)| (t - start).abs());
+        points.dedup_by_key(|&mut (_, global)| global);
+        points.push((end, vertex(end)));
+
+        for pair in points.windows(2) {
+            // Can't panic, as we passed `2` to `windows`.
+            //
+            // Can be cleaned up, once `array_windows` is stable.
+            let [(a, vertex_a), (b, vertex_b)] = [pair[0], pair[1]];
+
+            fragments.push(Edge::new(
+                edge.curve().clone(),
+                VerticesOfEdge::from_vertices([
+                    Vertex::new([a], vertex_a),
+                    Vertex::new([b], vertex_b),
+                ]),
+            ));
+        }
+    }
+
+    fragments
+}
+
+/// Determine whether the sides of a fragment are inside the other operand
+///
+/// Returns whether the left and the right side of the fragment, with regards
+/// to its direction, are within the region bounded by `edges`.
+fn sides(fragment: &Edge, edges: &[Edge], precision: Precision) -> [bool; 2] {
+    side_points(fragment, precision).map(|point| contains(edges, point))
+}
+
+/// Compute points just to the left and the right of the middle of a fragment
+fn side_points(fragment: &Edge, precision: Precision) -> [Point<2>; 2] {
+    let [start, end] = edge_range(fragment);
+    let middle = (start + end) / 2.;
+
+    let point = point_on_edge(fragment, middle);
+    let normal = {
+        let tangent = tangent(fragment, middle).normalize();
+        Vector::from([-tangent.v, tangent.u]) * precision.offset
+    };
+
+    [point + normal, point - normal]
+}
+
+/// Determine whether two fragments lie on top of each other
+fn is_coincident(a: &Edge, b: &Edge, precision: Precision) -> bool {
+    let vertices = [a, b].map(|edge| {
+        edge.vertices()
+            .get()
+            .map(|vertices| vertices.map(|vertex| *vertex.global()))
+    });
+    let middles = [a, b].map(|edge| {
+        let [start, end] = edge_range(edge);
+        point_on_edge(edge, (start + end) / 2.)
+    });
+
+    vertices[0] == vertices[1]
+        && distance(middles[0], middles[1]) < precision.epsilon
+}
+
+/// Determine whether a point is inside the region bounded by some edges
+///
+/// Uses the even-odd rule, so the orientation of the edges doesn't matter.
+fn contains(edges: &[Edge], point: Point<2>) -> bool {
+    winding_number(edges, point) % 2 != 0
+}
+
+/// Compute how often the edges wind around a point counter-clockwise
+///
+/// Clockwise windings are counted negatively.
+fn winding_number(edges: &[Edge], point: Point<2>) -> i32 {
+    let [u, v] = [point.u.into_f64(), point.v.into_f64()];
+    let mut winding = 0;
+
+    for edge in edges {
+        match edge.curve().kind() {
+            CurveKind::Line(_) => {
+                let [a, b] = end_points(edge)
+                    .map(|point| [point.u.into_f64(), point.v.into_f64()]);
+
+                if (a[1] > v) != (b[1] > v) {
+                    let u_hit =
+                        a[0] + (v - a[1]) * (b[0] - a[0]) / (b[1] - a[1]);
+                    if u_hit > u {
+                        winding += if b[1] > a[1] { 1 } else { -1 };
+                    }
+                }
+            }
+            CurveKind::Circle(circle) => {
+                let center = circle.center();
+                let radius = circle.a().magnitude().into_f64();
+
+                let dv = v - center.v.into_f64();
+                if dv.abs() < radius {
+                    let du = (radius * radius - dv * dv).sqrt();
+
+                    for u_hit in
+                        [center.u.into_f64() - du, center.u.into_f64() + du]
+                    {
+                        if u_hit <= u {
+                            continue;
+                        }
+
+                        // Arcs only cover part of their circle.
+                        let [start, end] = edge_range(edge);
+                        let hit = Point::from([u_hit, v]);
+                        let t = curve_coords(edge, hit, start.min(end));
+                        if t > start.max(end) {
+                            continue;
+                        }
+
+                        winding += if tangent(edge, t).v > Scalar::ZERO {
+                            1
+                        } else {
+                            -1
+                        };
+                    }
+                }
+            }
+            CurveKind::Nurbs(_) => {
+                unreachable!(
+                    "NURBS edges are approximated by `compute` and `fill`"
+                )
+            }
+        }
+    }
+
+    winding
+}
+
+/// Link fragments into cycles
+fn link(mut fragments: Vec<Edge>, precision: Precision) -> Vec<Vec<Edge>> {
+    let mut cycles = Vec::new();
+
+    // Continuous edges form a cycle on their own.
+    fragments.retain(|fragment| {
+        if fragment.vertices().get().is_none() {
+            cycles.push(vec![fragment.clone()]);
+            return false;
+        }
+
+        true
+    });
+
+    let mut used = vec![false; fragments.len()];
+
+    for i in 0..fragments.len() {
+        if used[i] {
+            continue;
+        }
+        used[i] = true;
+
+        let mut cycle = vec![fragments[i].clone()];
+        let [start, _] = global_vertices(&fragments[i]);
+
+        loop {
+            // Can't panic. We checked that all remaining fragments have
+            // vertices.
+            let current = &cycle[cycle.len() - 1];
+            let [_, end] = global_vertices(current);
+
+            if end == start {
+                break;
+            }
+
+            let incoming = {
+                let [_, t] = edge_range(current);
+                tangent(current, t)
+            };
+
+            // If multiple fragments continue from the current vertex, the
+            // region touches itself there. Picking the fragment that turns
+            // left the most keeps the cycle tight around the region.
+            let next = (0..fragments.len())
+                .filter(|&j| {
+                    !used[j] && global_vertices(&fragments[j])[0] == end
+                })
+                .max_by_key(|&j| {
+                    let [t, _] = edge_range(&fragments[j]);
+                    let outgoing = tangent(&fragments[j], t);
+
+                    let cross =
+                        incoming.u * outgoing.v - incoming.v * outgoing.u;
+                    let dot = incoming.dot(&outgoing);
+
+                    Scalar::atan2(cross, dot)
+                });
+
+            match next {
+                Some(j) => {
+                    used[j] = true;
+                    cycle.push(fragments[j].clone());
+                }
+                None => {
+                    // The fragments don't form a closed cycle. This can only
+                    // happen due to numerical problems, and there's nothing
+                    // sensible we can do with the incomplete cycle.
+                    cycle.clear();
+                    break;
+                }
+            }
+        }
+
+        if !cycle.is_empty() {
+            cycles.push(merge_fragments(cycle, precision));
+        }
+    }
+
+    cycles
+}
+
+/// Merge consecutive fragments that continue in a straight line
+///
+/// Such fragments are the result of splitting an edge at a point that doesn't
+/// end up being a corner of the result.
+fn merge_fragments(fragments: Vec<Edge>, precision: Precision) -> Vec<Edge> {
+    let mut merged: Vec<Edge> = Vec::new();
+
+    for fragment in fragments {
+        if let Some(last) = merged.last_mut() {
+            if let Some(edge) = join(last, &fragment, precision) {
+                *last = edge;
+                continue;
+            }
+        }
+
+        merged.push(fragment);
+    }
+
+    while merged.len() > 1 {
+        // Can't panic, as we just checked the length.
+        let last = merged[merged.len() - 1].clone();
+
+        match join(&last, &merged[0], precision) {
+            Some(edge) => {
+                merged.pop();
+                merged[0] = edge;
+            }
+            None => break,
+        }
+    }
+
+    merged
+}
+
+fn join(a: &Edge, b: &Edge, precision: Precision) -> Option<Edge> {
+    let line = match (a.curve().kind(), b.curve().kind()) {
+        (CurveKind::Line(line), CurveKind::Line(_)) => line,
+        _ => return None,
+    };
+
+    let [start, _] = a.vertices().get()?;
+    let [_, end] = b.vertices().get()?;
+
+    if start.global() == end.global() {
+        return None;
+    }
+
+    let [p0, p1] = end_points(a);
+    let [_, p2] = end_points(b);
+
+    // The fragments only continue in a straight line, if the point between
+    // them is on the segment between their outer end points.
+    if distance_to_segment(p1, [p0, p2]) >= precision.epsilon {
+        return None;
+    }
+
+    let end = Vertex::new(line.point_to_line_coords(p2), *end.global());
+
+    Some(Edge::new(
+        a.curve().clone(),
+        VerticesOfEdge::from_vertices([*start, end]),
+    ))
+}
+
+/// Build faces from cycles
+///
+/// Cycles that go counter-clockwise become exteriors. Cycles that go clockwise
+/// become interiors of the smallest exterior that contains them.
+fn build_faces(
+    surface: Surface,
+    cycles: impl IntoIterator<Item = Vec<Edge>>,
+    mirrored: bool,
+    precision: Precision,
+) -> Vec<Face> {
+    let mut exteriors = Vec::new();
+    let mut interiors = Vec::new();
+
+    for cycle in cycles {
+        let area = signed_area(cycle.iter());
+
+        if area > 0. {
+            exteriors.push((area, cycle, Vec::new()));
+        } else if area < 0. {
+            interiors.push(cycle);
+        }
+    }
+
+    for interior in interiors {
+        // The face is to the left of the interior cycle. A point just to the
+        // left of it must be within the exterior that the cycle belongs to.
+        let point = {
+            let edge = &interior[0];
+            let [start, end] = edge_range(edge);
+            let middle = (start + end) / 2.;
+
+            let tangent = tangent(edge, middle).normalize();
+            point_on_edge(edge, middle)
+                + Vector::from([-tangent.v, tangent.u]) * precision.offset
+        };
+
+        let exterior = exteriors
+            .iter_mut()
+            .filter(|(_, exterior, _)| contains(exterior, point))
+            .min_by(|(a, _, _), (b, _, _)| a.total_cmp(b));
+
+        if let Some((_, _, holes)) = exterior {
+            holes.push(interior);
+        }
+    }
+
+    let to_cycle = |edges: Vec<Edge>| {
+        let edges = if mirrored {
+            edges.iter().rev().map(reverse_edge).collect()
+        } else {
+            edges
+        };
+
+        Cycle::new(surface.clone()).with_edges(edges)
+    };
+
+    exteriors
+        .into_iter()
+        .map(|(_, exterior, interiors)| {
+            Face::new(surface.clone())
+                .with_exteriors([to_cycle(exterior)])
+                .with_interiors(interiors.into_iter().map(to_cycle))
+        })
+        .collect()
+}
+
+/// The range of the edge, in curve coordinates, in the direction of the edge
+fn edge_range(edge: &Edge) -> [Scalar; 2] {
+    match (edge.vertices().get(), edge.curve().kind()) {
+        (Some(vertices), _) => vertices.map(|vertex| vertex.position().t),
+        (None, CurveKind::Nurbs(curve)) => curve.domain(),
+        (None, _) => [Scalar::ZERO, Scalar::PI * 2.],
+    }
+}
+
+/// Convert a point on the edge into curve coordinates
+///
+/// For circles, of all the coordinates that correspond to the point, the
+/// smallest one that is not below `min` is returned.
+fn curve_coords(edge: &Edge, point: Point<2>, min: Scalar) -> Scalar {
+    match edge.curve().kind() {
+        CurveKind::Circle(circle) => {
+            let full_turn = Scalar::PI * 2.;
+
+            let t = circle.point_to_circle_coords(point).t;
+            t + full_turn * ((min - t) / full_turn).ceil()
+        }
+        CurveKind::Line(line) => line.point_to_line_coords(point).t,
+        CurveKind::Nurbs(_) => {
+            unreachable!("NURBS edges are approximated by `compute` and `fill`")
+        }
+    }
+}
+
+fn global_vertices(edge: &Edge) -> [GlobalVertex; 2] {
+    edge.vertices()
+        .expect_vertices()
+        .map(|vertex| *vertex.global())
+}
+
+fn point_on_edge(edge: &Edge, t: Scalar) -> Point<2> {
+    edge.curve().kind().point_from_curve_coords([t])
+}
+
+fn end_points(edge: &Edge) -> [Point<2>; 2] {
+    edge_range(edge).map(|t| point_on_edge(edge, t))
+}
+
+/// The tangent of the edge at the given point, in the direction of the edge
+fn tangent(edge: &Edge, t: Scalar) -> Vector<2> {
+    let tangent = match edge.curve().kind() {
+        CurveKind::Circle(circle) => {
+            circle.vector_from_circle_coords([t + Scalar::PI / 2.])
+        }
+        CurveKind::Line(line) => line.direction(),
+        CurveKind::Nurbs(curve) => {
+            let [_, tangent] = curve.derivatives::<2>([t]);
+            tangent
+        }
+    };
+
+    let [start, end] = edge_range(edge);
+    if end < start {
+        -tangent
+    } else {
+        tangent
+    }
+}
+
+/// Sample points along the edge, excluding the end point
+fn sample_edge(edge: &Edge) -> Vec<Point<2>> {
+    const CURVE_SAMPLES: u64 = 32;
+
+    match edge.curve().kind() {
+        CurveKind::Circle(_) | CurveKind::Nurbs(_) => {
+            let [start, end] = edge_range(edge);
+
+            (0..CURVE_SAMPLES)
+                .map(|i| {
+                    let t = start
+                        + (end - start) * Scalar::from_u64(i)
+                            / Scalar::from_u64(CURVE_SAMPLES);
+                    point_on_edge(edge, t)
+                })
+                .collect()
+        }
+        CurveKind::Line(_) => vec![end_points(edge)[0]],
+    }
+}
+
+/// Compute the signed area enclosed by a cycle
+///
+/// The area is positive, if the cycle is counter-clockwise.
+pub fn signed_area<'r>(edges: impl IntoIterator<Item = &'r Edge>) -> f64 {
+    let points: Vec<_> = edges.into_iter().flat_map(sample_edge).collect();
+
+    let mut area = 0.;
+    for (i, a) in points.iter().enumerate() {
+        let b = points[(i + 1) % points.len()];
+        area +=
+            a.u.into_f64() * b.v.into_f64() - b.u.into_f64() * a.v.into_f64();
+    }
+
+    area / 2.
+}
+
+fn distance(a: Point<2>, b: Point<2>) -> f64 {
+    (a - b).magnitude().into_f64()
+}
+
+fn distance_to_edge(point: Point<2>, edge: &Edge) -> f64 {
+    let circle = match edge.curve().kind() {
+        CurveKind::Circle(circle) => circle,
+        _ => return distance_to_segment(point, end_points(edge)),
+    };
+
+    let distance_to_circle = (distance(point, circle.center())
+        - circle.a().magnitude().into_f64())
+    .abs();
+
+    if edge.vertices().get().is_some() {
+        let [start, end] = edge_range(edge);
+        let t = curve_coords(edge, point, start.min(end));
+
+        if t > start.max(end) {
+            let [a, b] = end_points(edge);
+            return distance(point, a).min(distance(point, b));
+        }
+    }
+
+    distance_to_circle
+}
+
+fn distance_to_segment(point: Point<2>, segment: [Point<2>; 2]) -> f64 {
+    let [a, b] = segment;
+
+    let ab = b - a;
+    let length_squared = ab.dot(&ab);
+    if length_squared == Scalar::ZERO {
+        return distance(point, a);
+    }
+
+    let t = ((point - a).dot(&ab) / length_squared)
+        .max(Scalar::ZERO)
+        .into_f64()
+        .min(1.);
+
+    distance(point, a + ab * t)
+}
@@ -0,0 +1,40 @@
+//! Boolean operations on sketches
+
+use crate::{algorithms::Tolerance, objects::Sketch};
+
+use super::{
+    face_positions,
+    region::{self, Operation},
+    BooleanError,
+};
+
+/// Compute a boolean operation between two sketches
+///
+/// Returns [`BooleanError::DifferentSurfaces`], if the faces of the sketches
+/// are not all defined in the same surface.
+///
+/// See [`region::compute`] for further details.
+pub fn compute(
+    a: &Sketch,
+    b: &Sketch,
+    operation: Operation,
+    tolerance: Tolerance,
+) -> Result<Sketch, BooleanError> {
+    let faces_a: Vec<_> = a.faces().cloned().collect();
+    let faces_b: Vec<_> = b.faces().cloned().collect();
+
+    if let Some(face) = faces_a.first().or_else(|| faces_b.first()) {
+        let surface = face.surface();
+
+        for face in faces_a.iter().chain(&faces_b) {
+            if face.surface() != surface {
+                return Err(BooleanError::DifferentSurfaces(face_positions(
+                    face,
+                )));
+            }
+        }
+    }
+
+    let faces = region::compute(&faces_a, &faces_b, operation, tolerance);
+    Ok(Sketch::new().with_faces(faces))
+}
@@ -0,0 +1,940 @@
+//! Boolean operations on solids
+//!
+//! Each face of either operand is split along its intersections with the
+//! faces of the other operand. The resulting pieces are classified as being
+//! inside, outside, or on the boundary of the other operand, and the pieces
+//! that make up the boundary of the result are kept.
+
+use std::collections::HashMap;
+
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_math::{Aabb, Line, Point, Scalar, Triangle, Vector};
+
+use crate::{
+    algorithms::{
+        intersect::{
+            face_point::FacePointIntersection, CurveFaceIntersection,
+            FaceFaceIntersection, Intersect,
+        },
+        reverse_face, triangulate, CycleApprox, Tolerance,
+    },
+    objects::{
+        Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, GlobalVertex, Solid,
+        Surface, SweptCurve, Vertex, VerticesOfEdge,
+    },
+};
+
+use super::{
+    face_positions,
+    region::{self, Operation},
+    BooleanError,
+};
+
+/// Compute a boolean operation between two solids
+///
+/// # Limitations
+///
+/// Only planar faces are supported. Curved faces and circles are converted into
+/// a polygonal approximation, according to `tolerance`. The facets of a curved
+/// face that end up in the same plane are merged back together, but the result
+/// still only consists of planar faces bounded by line segments.
+pub fn compute(
+    a: &Solid,
+    b: &Solid,
+    operation: Operation,
+    tolerance: Tolerance,
+) -> Result<Solid, BooleanError> {
+    let facets_a = polygonal_faces(a.faces(), tolerance);
+    let facets_b = polygonal_faces(b.faces(), tolerance);
+
+    let precision = Precision::new(facets_a.iter().chain(&facets_b).flatten());
+
+    let merge_facets = |facets: Vec<Vec<Face>>| {
+        let mut faces = Vec::new();
+        for facets in facets {
+            faces.extend(merge_coplanar_faces(facets, precision, tolerance)?);
+        }
+        Ok::<_, BooleanError>(faces)
+    };
+    let faces_a = merge_facets(facets_a)?;
+    let faces_b = merge_facets(facets_b)?;
+
+    let mut faces = Vec::new();
+
+    for face in &faces_a {
+        let pieces = classified_pieces(face, &faces_b, precision, tolerance)?;
+
+        let pieces = pieces.into_iter().filter(|(_, class)| {
+            matches!(
+                (operation, class),
+                (Operation::Union, Class::Outside | Class::OnSame)
+                    | (
+                        Operation::Difference,
+                        Class::Outside | Class::OnOpposite
+                    )
+                    | (Operation::Intersection, Class::Inside | Class::OnSame)
+            )
+        });
+
+        faces.extend(merge_pieces(
+            pieces.map(|(piece, _)| vec![piece]).collect(),
+            tolerance,
+        ));
+    }
+
+    for face in &faces_b {
+        let pieces = classified_pieces(face, &faces_a, precision, tolerance)?;
+
+        // Pieces that are on the boundary of the first operand have already
+        // been taken care of above, if required.
+        let pieces = pieces.into_iter().filter(|(_, class)| {
+            matches!(
+                (operation, class),
+                (Operation::Union, Class::Outside)
+                    | (
+                        Operation::Difference | Operation::Intersection,
+                        Class::Inside
+                    )
+            )
+        });
+
+        let merged = merge_pieces(
+            pieces.map(|(piece, _)| vec![piece]).collect(),
+            tolerance,
+        );
+
+        if operation == Operation::Difference {
+            faces.extend(merged.iter().map(reverse_face));
+        } else {
+            faces.extend(merged);
+        }
+    }
+
+    let faces = merge_coplanar_faces(faces, precision, tolerance)?;
+
+    let mut pool = VertexPool::new(precision);
+    let faces = faces
+        .iter()
+        .filter_map(|face| rebuild_face(face, &mut pool));
+
+    Ok(Solid::new().with_faces(faces))
+}
+
+/// The location of a piece of a face, relative to the other solid
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+enum Class {
+    Inside,
+    Outside,
+
+    /// On the boundary, with the boundary facing in the same direction
+    OnSame,
+
+    /// On the boundary, with the boundary facing in the opposite direction
+    OnOpposite,
+}
+
+/// Numerical precision of the operation, derived from the size of the input
+#[derive(Clone, Copy)]
+struct Precision {
+    /// Distance below which points are considered to be on a plane
+    epsilon: f64,
+
+    /// Distance below which vertices of the result are merged
+    snap: f64,
+}
+
+impl Precision {
+    fn new<'r>(faces: impl IntoIterator<Item = &'r Face>) -> Self {
+        let points = faces.into_iter().flat_map(|face| {
+            face.all_cycles()
+                .flat_map(|cycle| cycle.edges())
+                .flat_map(|edge| edge.vertices().iter())
+                .map(|vertex| vertex.global().position())
+                .collect::<Vec<_>>()
+        });
+
+        let aabb = Aabb::<3>::from_points(points);
+        let size = aabb.size();
+        let extent = size.x.max(size.y).max(size.z).into_f64();
+        let extent = if extent > 0. { extent } else { 1. };
+
+        // The validation code considers vertices that are closer than 5e-7
+        // to each other as duplicates, so we need to merge at least those.
+        Self {
+            epsilon: extent * 1e-9,
+            snap: (extent * 1e-7).max(1e-6),
+        }
+    }
+}
+
+/// Convert faces into faces that are bounded by line segments
+///
+/// Returns the resulting faces for each of the input faces. Curved faces, and
+/// faces in triangle representation, are converted into one planar face per
+/// triangle.
+fn polygonal_faces<'r>(
+    faces: impl IntoIterator<Item = &'r Face>,
+    tolerance: Tolerance,
+) -> Vec<Vec<Face>> {
+    let mut polygonal = Vec::new();
+
+    for face in faces {
+        if let Some(triangles) = face.triangles() {
+            polygonal.push(
+                triangles
+                    .iter()
+                    .filter_map(|&(triangle, color)| {
+                        triangle_face(triangle, color)
+                    })
+                    .collect(),
+            );
+            continue;
+        }
+
+        let is_planar = matches!(
+            face.surface(),
+            Surface::SweptCurve(SweptCurve {
+                curve: CurveKind::Line(_),
+                ..
+            })
+        );
+        if !is_planar {
+            let mesh = triangulate(
+                vec![face.clone()],
+                tolerance,
+                &mut DebugInfo::new(),
+            );
+            polygonal.push(
+                mesh.triangles()
+                    .filter_map(|triangle| {
+                        triangle_face(triangle.inner, triangle.color)
+                    })
+                    .collect(),
+            );
+            continue;
+        }
+
+        let is_polygonal = face
+            .all_cycles()
+            .flat_map(|cycle| cycle.edges())
+            .all(|edge| matches!(edge.curve().kind(), CurveKind::Line(_)));
+        if is_polygonal {
+            polygonal.push(vec![face.clone()]);
+            continue;
+        }
+
+        let surface = face.surface().clone();
+        let polygon = |cycle: &Cycle| {
+            let mut points: Vec<_> = CycleApprox::new(cycle, tolerance)
+                .points
+                .into_iter()
+                .map(|point| *point.local_form())
+                .collect();
+
+            // The approximation is closed, which means the first point is
+            // repeated at the end.
+            if points.len() > 1 && points.first() == points.last() {
+                points.pop();
+            }
+
+            Cycle::build(surface.clone()).polygon_from_points(points)
+        };
+
+        polygonal.push(vec![Face::new(surface.clone())
+            .with_exteriors(face.exteriors().map(polygon))
+            .with_interiors(face.interiors().map(polygon))
+            .with_color(face.color())]);
+    }
+
+    polygonal
+}
+
+fn triangle_face(triangle: Triangle<3>, color: Color) -> Option<Face> {
+    if triangle.normal().magnitude() == Scalar::ZERO {
+        return None;
+    }
+
+    let surface = Surface::plane_from_points(triangle.points());
+    let face = Face::build(surface)
+        .polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
+        .into_face()
+        .with_color(color);
+
+    Some(face)
+}
+
+/// Split a face along its intersections with other faces and classify the
+/// resulting pieces
+fn classified_pieces(
+    face: &Face,
+    others: &[Face],
+    precision: Precision,
+    tolerance: Tolerance,
+) -> Result<Vec<(Face, Class)>, BooleanError> {
+    let lines = cut_lines(face, others, precision)?;
+
+    let mut pieces = vec![face.clone()];
+    for line in lines {
+        let half_plane = half_plane(face, &line);
+
+        pieces = pieces
+            .into_iter()
+            .flat_map(|piece| {
+                // Most pieces aren't touched by most lines. Don't waste any
+                // effort on those.
+                if !passes_through(&piece, &line, precision) {
+                    return vec![piece];
+                }
+
+                let piece = [piece];
+                let half_plane = [half_plane.clone()];
+
+                let mut pieces = region::compute(
+                    &piece,
+                    &half_plane,
+                    Operation::Intersection,
+                    tolerance,
+                );
+                pieces.extend(region::compute(
+                    &piece,
+                    &half_plane,
+                    Operation::Difference,
+                    tolerance,
+                ));
+
+                pieces
+            })
+            .collect();
+    }
+
+    let mut classified = Vec::new();
+    for piece in pieces {
+        if let Some(class) = classify(&piece, others, precision)? {
+            classified.push((piece, class));
+        }
+    }
+
+    Ok(classified)
+}
+
+/// Compute the lines along which a face needs to be split
+///
+/// The lines are in the surface coordinates of the face.
+fn cut_lines(
+    face: &Face,
+    others: &[Face],
+    precision: Precision,
+) -> Result<Vec<Line<2>>, BooleanError> {
+    let mut lines: Vec<Line<2>> = Vec::new();
+
+    let mut add_line = |line: Line<2>| {
+        let is_duplicate = lines.iter().any(|other| {
+            let points =
+                [line.origin(), line.origin() + line.direction().normalize()];
+
+            points.into_iter().all(|point| {
+                let on_other = other
+                    .point_from_line_coords(other.point_to_line_coords(point));
+                (point - on_other).magnitude().into_f64() < precision.epsilon
+            })
+        });
+
+        if !is_duplicate {
+            lines.push(line);
+        }
+    };
+
+    let aabb = face_aabb(face);
+
+    for other in others {
+        if !overlaps(&aabb, &face_aabb(other), precision) {
+            continue;
+        }
+
+        if is_parallel(face, other)? {
+            if is_coplanar(face, other, precision)? {
+                // The faces lie in the same plane. We need to split along the
+                // edges of the other face that cross this one, so each piece
+                // is either completely within the other face, or completely
+                // outside of it.
+                for edge in other.all_cycles().flat_map(|cycle| cycle.edges()) {
+                    if let Some(vertices) = edge.vertices().get() {
+                        let points = vertices.map(|vertex| {
+                            face.surface().point_to_surface_coords(
+                                vertex.global().position(),
+                            )
+                        });
+                        if points[0] == points[1] {
+                            continue;
+                        }
+
+                        let curve = Curve::build(face.surface().clone())
+                            .line_from_points(points);
+                        if let CurveKind::Line(line) = curve.kind() {
+                            if !CurveFaceIntersection::compute(&curve, face)
+                                .is_empty()
+                            {
+                                add_line(*line);
+                            }
+                        }
+                    }
+                }
+            }
+
+            continue;
+        }
+
+        // The intersection line might just touch the faces, or run along their
+        // boundary. Splitting a face where it's not strictly necessary doesn't
+        // hurt, as the pieces are merged again later.
+        if let Some(intersection) = FaceFaceIntersection::compute([face, other])
+        {
+            let [curve, _] = intersection.intersection_curves;
+
+            if let CurveKind::Line(line) = curve.kind() {
+                add_line(*line);
+            }
+        }
+    }
+
+    Ok(lines)
+}
+
+/// Determine whether a line passes through the interior of a face
+///
+/// This is the case, if there are vertices of the face on both sides of the
+/// line.
+fn passes_through(face: &Face, line: &Line<2>, precision: Precision) -> bool {
+    let direction = line.direction().normalize();
+
+    let mut min = 0.;
+    let mut max = 0.;
+
+    for edge in face.all_cycles().flat_map(|cycle| cycle.edges()) {
+        for vertex in edge.vertices().iter() {
+            let point = edge
+                .curve()
+                .kind()
+                .point_from_curve_coords(vertex.position());
+            let offset = point - line.origin();
+
+            let side =
+                (direction.u * offset.v - direction.v * offset.u).into_f64();
+
+            min = side.min(min);
+            max = side.max(max);
+        }
+    }
+
+    min < -precision.epsilon && max > precision.epsilon
+}
+
+/// Create a face covering the half-plane to the left of a line
+///
+/// The face is large enough to cover the whole of `face`, which means it can
+/// be used to split `face` along the line.
+fn half_plane(face: &Face, line: &Line<2>) -> Face {
+    let points: Vec<_> = face
+        .all_cycles()
+        .flat_map(|cycle| cycle.edges())
+        .flat_map(|edge| {
+            edge.vertices()
+                .iter()
+                .map(|vertex| {
+                    edge.curve()
+                        .kind()
+                        .point_from_curve_coords(vertex.position())
+                })
+                .collect::<Vec<_>>()
+        })
+        .collect();
+    let aabb = Aabb::<2>::from_points(points);
+
+    let center = aabb.min + (aabb.max - aabb.min) / 2.;
+    let size = (aabb.max - aabb.min).magnitude() * 2. + Scalar::ONE;
+
+    let origin = line.point_from_line_coords(line.point_to_line_coords(center));
+    let direction = line.direction().normalize() * size;
+    let normal = Vector::from([-direction.v, direction.u]);
+
+    Face::build(face.surface().clone())
+        .polygon_from_points([
+            origin - direction,
+            origin + direction,
+            origin + direction + normal,
+            origin - direction + normal,
+        ])
+        .into_face()
+}
+
+/// Find a point within a piece, away from its boundary
+///
+/// Scans the piece along lines halfway between its vertices, and picks the
+/// middle of the widest span that is found within the piece.
+///
+/// Returns `None`, if the piece doesn't have any area.
+fn interior_point(piece: &Face, precision: Precision) -> Option<Point<3>> {
+    let segments: Vec<[[f64; 2]; 2]> = piece
+        .all_cycles()
+        .flat_map(|cycle| cycle.edges())
+        .filter_map(|edge| {
+            let vertices = edge.vertices().get()?;
+            Some(vertices.map(|vertex| {
+                let point = edge
+                    .curve()
+                    .kind()
+                    .point_from_curve_coords(vertex.position());
+                [point.u.into_f64(), point.v.into_f64()]
+            }))
+        })
+        .collect();
+
+    let mut vs: Vec<_> =
+        segments.iter().flatten().map(|point| point[1]).collect();
+    vs.sort_by(f64::total_cmp);
+    vs.dedup_by(|a, b| (*a - *b).abs() < precision.epsilon);
+
+    let mut best: Option<(f64, [f64; 2])> = None;
+
+    for pair in vs.windows(2) {
+        let v = (pair[0] + pair[1]) / 2.;
+
+        let mut us: Vec<_> = segments
+            .iter()
+            .filter(|[a, b]| (a[1] > v) != (b[1] > v))
+            .map(|[a, b]| a[0] + (v - a[1]) * (b[0] - a[0]) / (b[1] - a[1]))
+            .collect();
+        us.sort_by(f64::total_cmp);
+
+        // The piece is bounded by closed cycles, so spans between pairs of
+        // crossings are within the piece.
+        for span in us.chunks_exact(2) {
+            let width = (span[1] - span[0]).min(pair[1] - pair[0]);
+
+            let is_better = match best {
+                Some((best, _)) => width > best,
+                None => true,
+            };
+
+            if is_better {
+                best = Some((width, [(span[0] + span[1]) / 2., v]));
+            }
+        }
+    }
+
+    let (width, point) = best?;
+    if width < precision.epsilon {
+        return None;
+    }
+
+    Some(piece.surface().point_from_surface_coords(point))
+}
+
+/// Classify a piece of a face against a solid, represented by its faces
+///
+/// Returns `None`, if the piece is degenerate.
+fn classify(
+    piece: &Face,
+    faces: &[Face],
+    precision: Precision,
+) -> Result<Option<Class>, BooleanError> {
+    let point = match interior_point(piece, precision) {
+        Some(point) => point,
+        None => return Ok(None),
+    };
+    let normal = plane_normal(piece)?;
+
+    let face_normals = faces
+        .iter()
+        .map(plane_normal)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for (face, &face_normal) in faces.iter().zip(&face_normals) {
+        let origin = face.surface().point_from_surface_coords([0., 0.]);
+
+        let distance = (point - origin).dot(&face_normal).into_f64();
+        if distance.abs() >= precision.epsilon {
+            continue;
+        }
+
+        if contains_point(face, point) {
+            let class = if normal.dot(&face_normal) > Scalar::ZERO {
+                Class::OnSame
+            } else {
+                Class::OnOpposite
+            };
+
+            return Ok(Some(class));
+        }
+    }
+
+    // The point is not on the boundary of the solid. Cast rays to find out
+    // whether it is inside. A single ray might hit an edge or vertex, which
+    // would throw off the count, so we use multiple rays and let them vote.
+    let directions = [
+        [0.5377, 0.3127, 0.7832],
+        [-0.6124, 0.7013, 0.3652],
+        [0.2673, -0.5345, -0.8018],
+    ];
+
+    let votes = directions
+        .into_iter()
+        .filter(|&direction| {
+            let direction = Vector::from(direction);
+
+            let hits = faces
+                .iter()
+                .zip(&face_normals)
+                .filter(|(face, &face_normal)| {
+                    let origin =
+                        face.surface().point_from_surface_coords([0., 0.]);
+
+                    let denominator = direction.dot(&face_normal).into_f64();
+                    if denominator.abs() < 1e-12 {
+                        return false;
+                    }
+
+                    let t = (origin - point).dot(&face_normal).into_f64()
+                        / denominator;
+                    if t <= precision.epsilon {
+                        return false;
+                    }
+
+                    contains_point(face, point + direction * t)
+                })
+                .count();
+
+            hits % 2 == 1
+        })
+        .count();
+
+    let class = if votes * 2 > directions.len() {
+        Class::Inside
+    } else {
+        Class::Outside
+    };
+
+    Ok(Some(class))
+}
+
+/// Determine whether a face contains a point that lies in its surface
+fn contains_point(face: &Face, point: Point<3>) -> bool {
+    let point = face.surface().point_to_surface_coords(point);
+
+    matches!(
+        (face, &point).intersect(),
+        Some(FacePointIntersection::FaceContainsPoint)
+    )
+}
+
+/// Compute the normal of a planar face
+///
+/// All faces are converted into planar ones by `compute`, before this is
+/// called. Returns an error, if that didn't work out for any reason.
+fn plane_normal(face: &Face) -> Result<Vector<3>, BooleanError> {
+    match face.surface() {
+        Surface::SweptCurve(SweptCurve {
+            curve: CurveKind::Line(line),
+            path,
+        }) => Ok(line.direction().cross(path).normalize()),
+        _ => Err(BooleanError::NonPlanarFace(face_positions(face))),
+    }
+}
+
+fn is_parallel(a: &Face, b: &Face) -> Result<bool, BooleanError> {
+    let [normal_a, normal_b] = [plane_normal(a)?, plane_normal(b)?];
+    Ok(normal_a.cross(&normal_b).magnitude().into_f64() < 1e-9)
+}
+
+fn is_coplanar(
+    a: &Face,
+    b: &Face,
+    precision: Precision,
+) -> Result<bool, BooleanError> {
+    let [a_surface, b_surface] = [a.surface(), b.surface()];
+    let distance = (b_surface.point_from_surface_coords([0., 0.])
+        - a_surface.point_from_surface_coords([0., 0.]))
+    .dot(&plane_normal(a)?)
+    .into_f64();
+
+    Ok(is_parallel(a, b)? && distance.abs() < precision.epsilon)
+}
+
+fn face_aabb(face: &Face) -> Aabb<3> {
+    let points: Vec<_> = face
+        .all_cycles()
+        .flat_map(|cycle| cycle.edges())
+        .flat_map(|edge| edge.vertices().iter())
+        .map(|vertex| vertex.global().position())
+        .collect();
+
+    Aabb::<3>::from_points(points)
+}
+
+fn overlaps(a: &Aabb<3>, b: &Aabb<3>, precision: Precision) -> bool {
+    (0..3).all(|i| {
+        let [min_a, max_a, min_b, max_b] = [a.min, a.max, b.min, b.max]
+            .map(|point| point.coords.components[i].into_f64());
+
+        min_a <= max_b + precision.epsilon && min_b <= max_a + precision.epsilon
+    })
+}
+
+/// Merge the pieces of a face back together
+fn merge_pieces(mut pieces: Vec<Vec<Face>>, tolerance: Tolerance) -> Vec<Face> {
+    // Merging pairs of pieces, then pairs of the results, and so on, keeps the
+    // intermediate results small, which is much faster than merging all pieces
+    // into one result piece by piece.
+    while pieces.len() > 1 {
+        pieces = pieces
+            .chunks(2)
+            .map(|chunk| match chunk {
+                [a, b] => region::compute(a, b, Operation::Union, tolerance),
+                _ => chunk.concat(),
+            })
+            .collect();
+    }
+
+    pieces.pop().unwrap_or_default()
+}
+
+/// Merge faces that lie in the same plane and face the same direction
+///
+/// Where the faces of both operands were coplanar, the result would otherwise
+/// contain multiple adjacent faces, where a single one would do.
+fn merge_coplanar_faces(
+    faces: Vec<Face>,
+    precision: Precision,
+    tolerance: Tolerance,
+) -> Result<Vec<Face>, BooleanError> {
+    let mut groups: Vec<Vec<Face>> = Vec::new();
+
+    'faces: for face in faces {
+        for group in &mut groups {
+            let is_same_plane = is_coplanar(&group[0], &face, precision)?
+                && plane_normal(&group[0])?.dot(&plane_normal(&face)?)
+                    > Scalar::ZERO;
+
+            if is_same_plane {
+                group.push(face);
+                continue 'faces;
+            }
+        }
+
+        groups.push(vec![face]);
+    }
+
+    let faces = groups
+        .into_iter()
+        .flat_map(|group| {
+            if group.len() == 1 {
+                return group;
+            }
+
+            let surface = group[0].surface().clone();
+            let faces = group
+                .iter()
+                .map(|face| {
+                    let cycle = |cycle: &Cycle| {
+                        let edges = cycle.edges().filter_map(|edge| {
+                            let vertices = edge.vertices().get()?;
+
+                            let global =
+                                vertices.map(|vertex| *vertex.global());
+                            let local = global.map(|vertex| {
+                                surface
+                                    .point_to_surface_coords(vertex.position())
+                            });
+
+                            Some(line_segment(local, global))
+                        });
+
+                        Cycle::new(surface.clone()).with_edges(edges)
+                    };
+
+                    vec![Face::new(surface.clone())
+                        .with_exteriors(face.exteriors().map(cycle))
+                        .with_interiors(face.interiors().map(cycle))
+                        .with_color(face.color())]
+                })
+                .collect();
+
+            merge_pieces(faces, tolerance)
+        })
+        .collect();
+
+    Ok(faces)
+}
+
+/// The vertices of the result, by position
+///
+/// Used to merge vertices that are very close to each other, as the result of
+/// computations done for different faces.
+///
+/// The points are sorted into the cells of a grid, whose cells are as large as
+/// the snapping distance. Any point that a new point might snap to is then in
+/// the same cell, or one of the neighboring ones.
+struct VertexPool {
+    precision: Precision,
+    cells: HashMap<[i64; 3], Vec<(usize, Point<3>)>>,
+    num_points: usize,
+}
+
+impl VertexPool {
+    fn new(precision: Precision) -> Self {
+        Self {
+            precision,
+            cells: HashMap::new(),
+            num_points: 0,
+        }
+    }
+
+    fn snap(&mut self, point: Point<3>) -> Point<3> {
+        let cell = point
+            .coords
+            .components
+            .map(|c| (c.into_f64() / self.precision.snap).floor() as i64);
+
+        // If multiple points are within the snapping distance, prefer the one
+        // that was added first, regardless of the order of the cells.
+        let existing = neighbors(cell)
+            .filter_map(|cell| self.cells.get(&cell))
+            .flatten()
+            .filter(|(_, other)| {
+                (point - *other).magnitude().into_f64() < self.precision.snap
+            })
+            .min_by_key(|(index, _)| *index)
+            .map(|&(_, other)| other);
+
+        existing.unwrap_or_else(|| {
+            self.cells
+                .entry(cell)
+                .or_default()
+                .push((self.num_points, point));
+            self.num_points += 1;
+
+            point
+        })
+    }
+}
+
+/// The given cell of a grid, and all cells that neighbor it
+fn neighbors([x, y, z]: [i64; 3]) -> impl Iterator<Item = [i64; 3]> {
+    (-1..=1).flat_map(move |dx| {
+        (-1..=1).flat_map(move |dy| {
+            (-1..=1).map(move |dz| [x + dx, y + dy, z + dz])
+        })
+    })
+}
+
+/// Rebuild a face, using the vertex positions from the pool
+fn rebuild_face(face: &Face, pool: &mut VertexPool) -> Option<Face> {
+    let surface = face.surface().clone();
+
+    let exteriors: Vec<_> = face
+        .exteriors()
+        .filter_map(|cycle| rebuild_cycle(cycle, pool))
+        .collect();
+    if exteriors.is_empty() {
+        return None;
+    }
+
+    let interiors: Vec<_> = face
+        .interiors()
+        .filter_map(|cycle| rebuild_cycle(cycle, pool))
+        .collect();
+
+    Some(
+        Face::new(surface)
+            .with_exteriors(exteriors)
+            .with_interiors(interiors)
+            .with_color(face.color()),
+    )
+}
+
+fn rebuild_cycle(cycle: &Cycle, pool: &mut VertexPool) -> Option<Cycle> {
+    let mut edges = Vec::new();
+
+    for edge in cycle.edges() {
+        let vertices = match edge.vertices().get() {
+            Some(vertices) => vertices,
+            None => {
+                edges.push(edge.clone());
+                continue;
+            }
+        };
+
+        let local = vertices.map(|vertex| {
+            edge.curve()
+                .kind()
+                .point_from_curve_coords(vertex.position())
+        });
+        let global =
+            vertices.map(|vertex| pool.snap(vertex.global().position()));
+
+        if global[0] == global[1] {
+            // The edge has collapsed into a single point.
+            continue;
+        }
+
+        edges
+            .push(line_segment(local, global.map(GlobalVertex::from_position)));
+    }
+
+    let is_degenerate = edges.len() < 3
+        && edges.iter().all(|edge| edge.vertices().get().is_some());
+    if edges.is_empty() || is_degenerate {
+        return None;
+    }
+
+    Some(Cycle::new(cycle.surface().clone()).with_edges(edges))
+}
+
+/// Create a line segment from its vertices
+fn line_segment(local: [Point<2>; 2], global: [GlobalVertex; 2]) -> Edge {
+    let curve = Curve::new(
+        CurveKind::line_from_points(local),
+        GlobalCurve::from_kind(CurveKind::line_from_points(
+            global.map(|vertex| vertex.position()),
+        )),
+    );
+
+    let [a, b] = global;
+    let vertices = VerticesOfEdge::from_vertices([
+        Vertex::new([0.], a),
+        Vertex::new([1.], b),
+    ]);
+
+    Edge::new(curve, vertices)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Point;
+
+    use super::{Precision, VertexPool};
+
+    #[test]
+    fn vertex_pool_snap() {
+        let precision = Precision {
+            epsilon: 1e-9,
+            snap: 1e-6,
+        };
+        let mut pool = VertexPool::new(precision);
+
+        // The points are in different cells of the grid, but still within the
+        // snapping distance of each other.
+        let a = Point::from([0.9e-6, 0., 0.]);
+        let b = Point::from([1.1e-6, 0., 0.]);
+        assert_eq!(pool.snap(a), a);
+        assert_eq!(pool.snap(b), a);
+
+        // Points that are further away don't snap.
+        let c = Point::from([2.1e-6, 0., 0.]);
+        assert_eq!(pool.snap(c), c);
+
+        // If multiple points are in range, the first one added wins.
+        let d = Point::from([1.5e-6, 0., 0.]);
+        assert_eq!(pool.snap(d), a);
+    }
+}
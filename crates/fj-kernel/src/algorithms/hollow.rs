@@ -0,0 +1,778 @@
+use std::collections::{BTreeMap, BTreeSet};
+
+use fj_math::{Circle, Line, Point, Scalar, Transform, Vector};
+
+use crate::{
+    objects::{
+        Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, GlobalVertex, Shell,
+        Solid, Surface, SweptCurve, Vertex, VerticesOfEdge,
+    },
+    validation::edge_positions,
+};
+
+use super::{reverse_face, TransformObject};
+
+/// Hollow out a solid, leaving walls of the given thickness
+///
+/// Every face of the solid is offset inwards by `thickness`, and the offset
+/// faces are added to the solid, as the walls of the cavity inside of it. If
+/// the cavity is closed, the walls form an inner shell of the solid.
+///
+/// Every face for which `open` returns `true` is removed instead, opening the
+/// cavity towards it. What's left of the face is the rim of the opening, which
+/// covers the end of the walls.
+///
+/// # Limitations
+///
+/// Only solids whose faces are planes, or cylinders that are swept along their
+/// axis, are supported. Wherever more than three faces meet at a vertex, their
+/// offsets must meet at a single point too. The thickness must be small
+/// enough, that no offset face vanishes. Unsupported cases are reported as a
+/// [`HollowError`].
+pub fn hollow(
+    solid: &Solid,
+    thickness: impl Into<Scalar>,
+    open: impl Fn(&Face) -> bool,
+) -> Result<Solid, HollowError> {
+    let thickness = thickness.into();
+    if thickness <= Scalar::ZERO {
+        return Err(HollowError::InvalidThickness(thickness));
+    }
+
+    let faces: Vec<_> = solid.faces().cloned().collect();
+    let is_open: Vec<_> = faces.iter().map(&open).collect();
+
+    let geometry = faces
+        .iter()
+        .map(FaceGeometry::from_face)
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Faces that are opened aren't offset. Their offset boundary stays in the
+    // face, and becomes the inner boundary of the rim.
+    let offsets: Vec<_> = is_open
+        .iter()
+        .map(|&is_open| if is_open { Scalar::ZERO } else { thickness })
+        .collect();
+
+    let offset = Offset {
+        faces: &faces,
+        geometry: &geometry,
+        offsets: &offsets,
+        vertices: BTreeMap::new(),
+    };
+    let offset = offset.with_vertices()?;
+
+    let mut target = Vec::new();
+    let mut walls = Vec::new();
+
+    for (i, face) in faces.iter().enumerate() {
+        if is_open[i] {
+            let surface = face.surface();
+
+            for exterior in face.exteriors() {
+                let offset_exterior = offset.cycle(i, exterior, surface)?;
+                let rim = Face::new(surface.clone())
+                    .with_exteriors([exterior.clone()])
+                    .with_interiors([reverse_cycle(offset_exterior)])
+                    .with_color(face.color());
+                target.push(rim);
+            }
+            for interior in face.interiors() {
+                let offset_interior = offset.cycle(i, interior, surface)?;
+                let rim = Face::new(surface.clone())
+                    .with_exteriors([reverse_cycle(offset_interior)])
+                    .with_interiors([interior.clone()])
+                    .with_color(face.color());
+                target.push(rim);
+            }
+
+            continue;
+        }
+
+        let surface = geometry[i].offset_surface(face, thickness)?;
+
+        let exteriors = face
+            .exteriors()
+            .map(|cycle| offset.cycle(i, cycle, &surface))
+            .collect::<Result<Vec<_>, _>>()?;
+        let interiors = face
+            .interiors()
+            .map(|cycle| offset.cycle(i, cycle, &surface))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        // The offset face has the same orientation as the original one, but
+        // it bounds the cavity, so it must point the other way.
+        let wall = reverse_face(
+            &Face::new(surface)
+                .with_exteriors(exteriors)
+                .with_interiors(interiors)
+                .with_color(face.color()),
+        );
+
+        target.push(face.clone());
+        walls.push(wall);
+    }
+
+    let solid = if is_open.contains(&true) {
+        Solid::new().with_faces(target).with_faces(walls)
+    } else {
+        Solid::new()
+            .with_faces(target)
+            .with_inner_shells([Shell::new().with_faces(walls)])
+    };
+
+    Ok(solid)
+}
+
+/// An error that can occur when hollowing out a solid
+///
+/// Returned by [`hollow`].
+#[derive(Debug, thiserror::Error)]
+pub enum HollowError {
+    /// The thickness of the walls is not a positive number
+    #[error("Wall thickness must be positive, but is {0}")]
+    InvalidThickness(Scalar),
+
+    /// A face uses triangle representation
+    #[error("Hollowing tri-rep solids is not supported")]
+    TriRepFace,
+
+    /// A face is neither a plane nor a cylinder
+    #[error(
+        "Hollowing faces that aren't planes or cylinders is not supported"
+    )]
+    UnsupportedSurface(Vec<Point<3>>),
+
+    /// A face is a cylinder that isn't swept along its axis
+    #[error("Hollowing oblique cylinders is not supported yet")]
+    ObliqueCylinder(Vec<Point<3>>),
+
+    /// A cylindrical face vanishes, when it is offset
+    #[error("Wall thickness exceeds radius of cylindrical face")]
+    TooThick(Vec<Point<3>>),
+
+    /// An edge is a NURBS curve
+    #[error("Hollowing solids with NURBS edges is not supported yet")]
+    NurbsEdge(Vec<Point<3>>),
+
+    /// The offset faces around a vertex don't meet in a single point
+    #[error(
+        "Hollowing solids whose offset faces don't meet in a single point is \
+        not supported yet"
+    )]
+    UnsupportedVertex(Point<3>),
+
+    /// A circular edge bounds a face that isn't symmetric around the circle
+    #[error(
+        "Hollowing solids with circular edges between faces that aren't \
+        symmetric around the circle is not supported yet"
+    )]
+    AsymmetricCircle(Vec<Point<3>>),
+}
+
+impl HollowError {
+    /// Access the positions of the face, edge, or vertex that caused the error
+    ///
+    /// Can be used to highlight them in a model.
+    pub fn positions(&self) -> Vec<Point<3>> {
+        match self {
+            Self::InvalidThickness(_) | Self::TriRepFace => Vec::new(),
+            Self::UnsupportedVertex(position) => vec![*position],
+            Self::UnsupportedSurface(positions)
+            | Self::ObliqueCylinder(positions)
+            | Self::TooThick(positions)
+            | Self::NurbsEdge(positions)
+            | Self::AsymmetricCircle(positions) => positions.clone(),
+        }
+    }
+}
+
+/// The geometry of a face, as far as it's relevant for offsetting it
+enum FaceGeometry {
+    Plane {
+        normal: Vector<3>,
+    },
+    Cylinder {
+        center: Point<3>,
+        axis: Vector<3>,
+
+        /// Whether the surface normal points away from the axis
+        is_convex: bool,
+    },
+}
+
+impl FaceGeometry {
+    fn from_face(face: &Face) -> Result<Self, HollowError> {
+        if face.triangles().is_some() {
+            return Err(HollowError::TriRepFace);
+        }
+
+        let (curve, path) = match face.surface() {
+            Surface::SweptCurve(SweptCurve { curve, path }) => (curve, *path),
+            _ => {
+                return Err(HollowError::UnsupportedSurface(face_positions(
+                    face,
+                )))
+            }
+        };
+
+        let geometry = match curve {
+            CurveKind::Line(line) => Self::Plane {
+                normal: line.direction().cross(&path).normalize(),
+            },
+            CurveKind::Circle(circle) => {
+                let axis = circle.a().cross(&circle.b()).normalize();
+                if axis.cross(&path).magnitude()
+                    > path.magnitude() * Scalar::from(1e-9)
+                {
+                    return Err(HollowError::ObliqueCylinder(face_positions(
+                        face,
+                    )));
+                }
+
+                // At the start of the circle, the circle goes towards `b`, and
+                // `a` points away from the axis.
+                let normal = circle.b().cross(&path);
+
+                Self::Cylinder {
+                    center: circle.center(),
+                    axis,
+                    is_convex: normal.dot(&circle.a()) > Scalar::ZERO,
+                }
+            }
+            CurveKind::Nurbs(_) => {
+                return Err(HollowError::UnsupportedSurface(face_positions(
+                    face,
+                )))
+            }
+        };
+
+        Ok(geometry)
+    }
+
+    /// The normal of the surface at a point on it
+    fn normal_at(&self, point: Point<3>) -> Vector<3> {
+        match self {
+            Self::Plane { normal } => *normal,
+            Self::Cylinder {
+                center,
+                axis,
+                is_convex,
+            } => {
+                let radial = point - *center;
+                let radial = (radial - *axis * radial.dot(axis)).normalize();
+
+                if *is_convex {
+                    radial
+                } else {
+                    -radial
+                }
+            }
+        }
+    }
+
+    /// Offset the surface of a face against its normal
+    fn offset_surface(
+        &self,
+        face: &Face,
+        distance: Scalar,
+    ) -> Result<Surface, HollowError> {
+        let surface = face.surface();
+
+        let surface = match (self, surface) {
+            (Self::Plane { normal }, _) => surface
+                .clone()
+                .transform(&Transform::translation(-*normal * distance)),
+            (
+                Self::Cylinder { is_convex, .. },
+                Surface::SweptCurve(SweptCurve {
+                    curve: CurveKind::Circle(circle),
+                    path,
+                }),
+            ) => {
+                let radius = circle.a().magnitude();
+                let offset_radius = if *is_convex {
+                    radius - distance
+                } else {
+                    radius + distance
+                };
+                if offset_radius <= Scalar::ZERO {
+                    return Err(HollowError::TooThick(face_positions(face)));
+                }
+
+                let scale = offset_radius / radius;
+                let circle = Circle::new(
+                    circle.center(),
+                    circle.a() * scale,
+                    circle.b() * scale,
+                );
+
+                Surface::SweptCurve(SweptCurve {
+                    curve: CurveKind::Circle(circle),
+                    path: *path,
+                })
+            }
+            _ => unreachable!("Geometry was derived from surface"),
+        };
+
+        Ok(surface)
+    }
+
+    /// Convert a point on the offset surface into its surface coordinates
+    ///
+    /// `original` is the point on the original surface that was offset, in the
+    /// coordinates of that surface.
+    fn offset_surface_coords(
+        &self,
+        offset_surface: &Surface,
+        original: Point<2>,
+        point: Point<3>,
+    ) -> Point<2> {
+        let point = offset_surface.point_to_surface_coords(point);
+
+        match self {
+            Self::Plane { .. } => point,
+
+            // Offsetting a cylinder doesn't change the angle around its axis.
+            // Keeping the original angle avoids problems at the seam, where
+            // the angle wraps around.
+            Self::Cylinder { .. } => Point::from([original.u, point.v]),
+        }
+    }
+}
+
+struct Offset<'r> {
+    faces: &'r [Face],
+    geometry: &'r [FaceGeometry],
+    offsets: &'r [Scalar],
+    vertices: BTreeMap<GlobalVertex, GlobalVertex>,
+}
+
+impl Offset<'_> {
+    fn with_vertices(mut self) -> Result<Self, HollowError> {
+        let mut incident_faces = BTreeMap::<_, BTreeSet<_>>::new();
+
+        for edge in self.faces.iter().flat_map(face_edges) {
+            if let Some(vertices) = edge.vertices().get() {
+                let faces = self.faces_of_edge(edge);
+                for vertex in vertices {
+                    incident_faces
+                        .entry(*vertex.global())
+                        .or_default()
+                        .extend(faces.iter().copied());
+                }
+            }
+        }
+
+        for (vertex, faces) in incident_faces {
+            let position = vertex.position()
+                + self.displacement(&faces, vertex.position())?;
+            self.vertices
+                .insert(vertex, GlobalVertex::from_position(position));
+        }
+
+        Ok(self)
+    }
+
+    /// The indices of all faces that are bounded by the given edge
+    fn faces_of_edge(&self, edge: &Edge) -> BTreeSet<usize> {
+        self.faces
+            .iter()
+            .enumerate()
+            .filter(|(_, face)| {
+                face_edges(face).any(|other| is_same_edge(edge, other))
+            })
+            .map(|(i, _)| i)
+            .collect()
+    }
+
+    /// Compute how a point moves, if the faces it's on are offset
+    ///
+    /// The point must end up on all offset faces, and it must move as little
+    /// as possible. This is the case for the component of the displacement,
+    /// that is in the span of the face normals.
+    fn displacement(
+        &self,
+        faces: &BTreeSet<usize>,
+        point: Point<3>,
+    ) -> Result<Vector<3>, HollowError> {
+        let epsilon = Scalar::from(1e-9);
+
+        // Gram-Schmidt orthogonalization of the normals, while keeping track
+        // of the displacement along each basis vector.
+        let mut basis: Vec<(Vector<3>, Scalar)> = Vec::new();
+
+        for &i in faces {
+            let normal = self.geometry[i].normal_at(point);
+
+            let mut residual = normal;
+            let mut distance = -self.offsets[i];
+
+            for &(direction, displacement) in &basis {
+                let component = normal.dot(&direction);
+                residual = residual - direction * component;
+                distance = distance - component * displacement;
+            }
+
+            let magnitude = residual.magnitude();
+            if magnitude < Scalar::from(1e-6) {
+                // The normal is in the span of the previous ones. The offset
+                // face must still contain the point, or the offset faces don't
+                // meet in a single point.
+                if distance.abs() > epsilon {
+                    return Err(HollowError::UnsupportedVertex(point));
+                }
+                continue;
+            }
+
+            basis.push((residual / magnitude, distance / magnitude));
+        }
+
+        Ok(basis.into_iter().fold(
+            Vector::from([0., 0., 0.]),
+            |sum, (direction, displacement)| sum + direction * displacement,
+        ))
+    }
+
+    fn cycle(
+        &self,
+        face: usize,
+        cycle: &Cycle,
+        surface: &Surface,
+    ) -> Result<Cycle, HollowError> {
+        let edges = cycle
+            .edges()
+            .map(|edge| self.edge(face, edge, surface))
+            .collect::<Result<Vec<_>, _>>()?;
+
+        Ok(Cycle::new(surface.clone()).with_edges(edges))
+    }
+
+    fn edge(
+        &self,
+        face: usize,
+        edge: &Edge,
+        surface: &Surface,
+    ) -> Result<Edge, HollowError> {
+        let geometry = &self.geometry[face];
+
+        let edge = match edge.curve().global().kind() {
+            CurveKind::Line(_) => {
+                // Lines always have vertices.
+                let vertices =
+                    edge.vertices().get().expect("Line has vertices");
+
+                let global_vertices =
+                    vertices.map(|vertex| self.vertices[vertex.global()]);
+                let points = vertices.map(|vertex| {
+                    let original = edge
+                        .curve()
+                        .kind()
+                        .point_from_curve_coords(vertex.position());
+                    let position = self.vertices[vertex.global()].position();
+
+                    geometry.offset_surface_coords(surface, original, position)
+                });
+
+                let curve = Curve::new(
+                    CurveKind::Line(Line::from_points(points)),
+                    GlobalCurve::from_kind(CurveKind::Line(Line::from_points(
+                        global_vertices.map(|vertex| vertex.position()),
+                    ))),
+                );
+
+                let [a, b] = global_vertices;
+                Edge::new(
+                    curve,
+                    VerticesOfEdge::from_vertices([
+                        Vertex::new(Point::from([0.]), a),
+                        Vertex::new(Point::from([1.]), b),
+                    ]),
+                )
+            }
+            CurveKind::Circle(circle) => {
+                let circle = self.circle(edge, circle)?;
+
+                let local = match edge.curve().kind() {
+                    CurveKind::Circle(local) => {
+                        let center =
+                            surface.point_to_surface_coords(circle.center());
+                        let scale =
+                            circle.a().magnitude() / local.a().magnitude();
+
+                        CurveKind::Circle(Circle::new(
+                            center,
+                            local.a() * scale,
+                            local.b() * scale,
+                        ))
+                    }
+                    CurveKind::Line(local) => {
+                        // A circle on a cylinder, which runs along the angle
+                        // coordinate.
+                        let start = local.origin();
+                        let start = geometry.offset_surface_coords(
+                            surface,
+                            start,
+                            circle.point_from_circle_coords([0.]),
+                        );
+
+                        CurveKind::Line(Line::from_origin_and_direction(
+                            start,
+                            local.direction(),
+                        ))
+                    }
+                    CurveKind::Nurbs(_) => {
+                        return Err(HollowError::NurbsEdge(edge_positions(
+                            edge,
+                        )))
+                    }
+                };
+
+                // The offset circle has the same parametrization as the
+                // original one, so the vertices keep their curve coordinates.
+                let vertices = edge.vertices().map(|vertex| {
+                    Vertex::new(
+                        vertex.position(),
+                        self.vertices[vertex.global()],
+                    )
+                });
+
+                Edge::new(
+                    Curve::new(
+                        local,
+                        GlobalCurve::from_kind(CurveKind::Circle(circle)),
+                    ),
+                    vertices,
+                )
+            }
+            CurveKind::Nurbs(_) => {
+                return Err(HollowError::NurbsEdge(edge_positions(edge)))
+            }
+        };
+
+        Ok(edge)
+    }
+
+    /// Offset a circle, according to the faces it bounds
+    fn circle(
+        &self,
+        edge: &Edge,
+        circle: &Circle<3>,
+    ) -> Result<Circle<3>, HollowError> {
+        let faces = self.faces_of_edge(edge);
+
+        let center = circle.center();
+        let axis = circle.a().cross(&circle.b()).normalize();
+        let start = circle.point_from_circle_coords([0.]);
+
+        let displacement = self.displacement(&faces, start)?;
+
+        // The circle can only stay a circle, if all faces are rotationally
+        // symmetric around its axis.
+        let tangent = circle.b().normalize();
+        for &i in &faces {
+            let normal = self.geometry[i].normal_at(start);
+            if normal.dot(&tangent).abs() > Scalar::from(1e-9) {
+                return Err(HollowError::AsymmetricCircle(edge_positions(
+                    edge,
+                )));
+            }
+        }
+
+        let center = center + axis * displacement.dot(&axis);
+        let radius = (start + displacement - center).magnitude();
+        let scale = radius / circle.a().magnitude();
+
+        Ok(Circle::new(center, circle.a() * scale, circle.b() * scale))
+    }
+}
+
+fn face_positions(face: &Face) -> Vec<Point<3>> {
+    face_edges(face).flat_map(edge_positions).collect()
+}
+
+fn face_edges(face: &Face) -> impl Iterator<Item = &Edge> {
+    face.all_cycles().flat_map(|cycle| cycle.edges())
+}
+
+fn is_same_edge(a: &Edge, b: &Edge) -> bool {
+    match (a.curve().global().kind(), b.curve().global().kind()) {
+        (CurveKind::Circle(a), CurveKind::Circle(b)) => {
+            let epsilon = Scalar::from(1e-9);
+            let [axis_a, axis_b] =
+                [a, b].map(|circle| circle.a().cross(&circle.b()).normalize());
+
+            (a.center() - b.center()).magnitude() < epsilon
+                && (a.a().magnitude() - b.a().magnitude()).abs() < epsilon
+                && axis_a.cross(&axis_b).magnitude() < epsilon
+        }
+        (CurveKind::Circle(_), _) | (_, CurveKind::Circle(_)) => false,
+        _ => {
+            let vertices = |edge: &Edge| {
+                edge.vertices().get().map(|vertices| {
+                    let mut vertices = vertices.map(|vertex| *vertex.global());
+                    vertices.sort();
+                    vertices
+                })
+            };
+
+            vertices(a).is_some() && vertices(a) == vertices(b)
+        }
+    }
+}
+
+fn reverse_cycle(cycle: Cycle) -> Cycle {
+    let surface = cycle.surface().clone();
+
+    let mut edges: Vec<_> = cycle
+        .into_edges()
+        .map(|edge| {
+            let curve = Curve::new(
+                edge.curve().kind().clone().reverse(),
+                GlobalCurve::from_kind(
+                    edge.curve().global().kind().clone().reverse(),
+                ),
+            );
+            Edge::new(curve, edge.vertices().reverse())
+        })
+        .collect();
+    edges.reverse();
+
+    Cycle::new(surface).with_edges(edges)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Color;
+    use fj_math::{Line, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::{revolve, sweep, Tolerance},
+        objects::{
+            CurveKind, Cycle, Edge, Face, Sketch, Solid, Surface, SweptCurve,
+        },
+        test_utils::{circle, volume},
+    };
+
+    use super::HollowError;
+
+    #[test]
+    fn closed_box() -> anyhow::Result<()> {
+        let solid = Solid::build().cube_from_edge_length(2.);
+        let solid = super::hollow(&solid, 0.5, |_| false)?;
+        assert_eq!(solid.inner_shells().count(), 1);
+
+        let expected = Scalar::from(8. - 1.);
+        assert!((volume(solid)? - expected).abs() < Scalar::from(1e-9));
+        Ok(())
+    }
+
+    #[test]
+    fn open_box() -> anyhow::Result<()> {
+        let solid = Solid::build().cube_from_edge_length(2.);
+
+        // Open the top face.
+        let solid = super::hollow(&solid, 0.5, |face| {
+            let point = face.surface().point_from_surface_coords([0., 0.]);
+            point.z == Scalar::ONE
+        })?;
+
+        // The cavity is 1x1 wide and 1.5 deep.
+        let expected = Scalar::from(8. - 1.5);
+        assert!((volume(solid)? - expected).abs() < Scalar::from(1e-9));
+        Ok(())
+    }
+
+    #[test]
+    fn open_tube() -> anyhow::Result<()> {
+        let surface = Surface::xy_plane();
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges([
+                Edge::build().circle_from_radius(Scalar::from(2.))
+            ])]);
+        let solid = sweep(
+            Sketch::new().with_faces([face]),
+            [0., 0., 1.],
+            Color::default(),
+        );
+
+        // Open both ends.
+        let solid = super::hollow(&solid, 1., |face| {
+            matches!(
+                face.surface(),
+                Surface::SweptCurve(SweptCurve {
+                    curve: CurveKind::Line(_),
+                    ..
+                })
+            )
+        })?;
+
+        let expected = Scalar::PI * (4. - 1.);
+        assert!((volume(solid)? - expected).abs() < Scalar::from(0.01));
+        Ok(())
+    }
+
+    #[test]
+    fn open_plate_with_hole() -> anyhow::Result<()> {
+        let surface = Surface::xy_plane();
+        let hole = Cycle::new(surface.clone())
+            .with_edges([Edge::build().circle_from_radius(Scalar::ONE)]);
+        let face = Face::build(surface)
+            .polygon_from_points([[-2., -2.], [2., -2.], [2., 2.], [-2., 2.]])
+            .into_face()
+            .with_interiors([super::reverse_cycle(hole)]);
+        let solid = sweep(
+            Sketch::new().with_faces([face]),
+            [0., 0., 2.],
+            Color::default(),
+        );
+
+        // Open the top face, which has the hole in it.
+        let solid = super::hollow(&solid, 0.25, |face| {
+            let is_plane = matches!(
+                face.surface(),
+                Surface::SweptCurve(SweptCurve {
+                    curve: CurveKind::Line(_),
+                    ..
+                })
+            );
+            let point = face.surface().point_from_surface_coords([0., 0.]);
+
+            is_plane && point.z == Scalar::from(2.)
+        })?;
+
+        // The cavity is 3.5x3.5 wide, around a hole with a radius of 1.25, and
+        // it is 1.75 deep.
+        let material = (Scalar::from(16.) - Scalar::PI) * 2.;
+        let cavity = (Scalar::from(12.25) - Scalar::PI * 1.5625) * 1.75;
+        let expected = material - cavity;
+        assert!((volume(solid)? - expected).abs() < Scalar::from(0.01));
+        Ok(())
+    }
+
+    #[test]
+    fn revolved() -> anyhow::Result<()> {
+        // Revolving a circle around an axis next to it results in a torus.
+        let axis =
+            Line::from_origin_and_direction(Point::origin(), Vector::unit_y());
+        let solid = revolve(
+            circle([2., 0.], 1.),
+            axis,
+            Scalar::PI * 2.,
+            Tolerance::from_scalar(0.001)?,
+            Color::default(),
+        );
+
+        let result = super::hollow(&solid, 0.5, |_| false);
+        assert!(matches!(result, Err(HollowError::UnsupportedSurface(_))));
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_thickness() {
+        let solid = Solid::build().cube_from_edge_length(2.);
+
+        let result = super::hollow(&solid, 0., |_| false);
+        assert!(matches!(result, Err(HollowError::InvalidThickness(_))));
+    }
+}
@@ -83,7 +83,7 @@ mod tests {
     #[test]
     fn compute_edge_in_front_of_curve_origin() {
         let surface = Surface::xy_plane();
-        let curve = Curve::build(surface).u_axis();
+        let curve = Curve::build(surface.clone()).u_axis();
         let edge = Edge::build()
             .line_segment_from_points(&surface, [[1., -1.], [1., 1.]]);
 
@@ -100,7 +100,7 @@ mod tests {
     #[test]
     fn compute_edge_behind_curve_origin() {
         let surface = Surface::xy_plane();
-        let curve = Curve::build(surface).u_axis();
+        let curve = Curve::build(surface.clone()).u_axis();
         let edge = Edge::build()
             .line_segment_from_points(&surface, [[-1., -1.], [-1., 1.]]);
 
@@ -117,7 +117,7 @@ mod tests {
     #[test]
     fn compute_edge_parallel_to_curve() {
         let surface = Surface::xy_plane();
-        let curve = Curve::build(surface).u_axis();
+        let curve = Curve::build(surface.clone()).u_axis();
         let edge = Edge::build()
             .line_segment_from_points(&surface, [[-1., -1.], [1., -1.]]);
 
@@ -129,7 +129,7 @@ mod tests {
     #[test]
     fn compute_edge_on_curve() {
         let surface = Surface::xy_plane();
-        let curve = Curve::build(surface).u_axis();
+        let curve = Curve::build(surface.clone()).u_axis();
         let edge = Edge::build()
             .line_segment_from_points(&surface, [[-1., 0.], [1., 0.]]);
 
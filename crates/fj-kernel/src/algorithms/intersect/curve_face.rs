@@ -1,10 +1,10 @@
 use std::vec;
 
-use fj_math::Point;
+use fj_math::{Circle, Point, Scalar, Vector};
 
-use crate::objects::{Curve, Face};
+use crate::objects::{Curve, CurveKind, Face};
 
-use super::CurveEdgeIntersection;
+use super::{CurveEdgeIntersection, Intersect};
 
 /// The intersections between a [`Curve`] and a [`Face`], in curve coordinates
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -27,7 +27,31 @@ impl CurveFaceIntersection {
     }
 
     /// Compute the intersections between a [`Curve`] and a [`Face`]
+    ///
+    /// If the curve is a circle, the returned intervals are in the range from
+    /// `0.` to `PI * 2.`. An interval that wraps around the start of the circle
+    /// is split in two.
+    ///
+    /// Points where the curve only touches the face, without entering it, are
+    /// not part of the result.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the curve is a line and the face is bounded by anything other
+    /// than line segments, if the curve is a circle and the face is bounded by
+    /// arcs or NURBS curves, or if the curve is a NURBS curve. Those cases are
+    /// not supported yet.
     pub fn compute(curve: &Curve, face: &Face) -> Self {
+        match curve.kind() {
+            CurveKind::Circle(circle) => Self::compute_for_circle(circle, face),
+            CurveKind::Line(_) => Self::compute_for_line(curve, face),
+            CurveKind::Nurbs(_) => {
+                todo!("NURBS-face intersection is not supported yet")
+            }
+        }
+    }
+
+    fn compute_for_line(curve: &Curve, face: &Face) -> Self {
         let edges = face.all_cycles().flat_map(|cycle| {
             let edges: Vec<_> = cycle.edges().cloned().collect();
             edges
@@ -50,24 +74,77 @@ impl CurveFaceIntersection {
             }
         }
 
-        assert!(intersections.len() % 2 == 0);
+        let contains = |t: Scalar| {
+            let point = curve.kind().point_from_curve_coords([t]);
+            (face, &point).intersect().is_some()
+        };
 
-        intersections.sort();
+        let intervals = intervals_between(
+            intersections.into_iter().map(|point| point.t).collect(),
+            contains,
+        );
 
-        // Can be cleaned up, once `array_chunks` is stable:
-        // https://doc.rust-lang.org/std/primitive.slice.html#method.array_chunks
-        let intervals = intersections
-            .chunks(2)
-            .map(|chunk| {
-                // Can't panic, as we passed `2` to `chunks`.
-                CurveFaceIntersectionInterval {
-                    start: chunk[0],
-                    end: chunk[1],
+        Self::from_intervals(intervals)
+    }
+
+    fn compute_for_circle(circle: &Circle<2>, face: &Face) -> Self {
+        let full_turn = Scalar::PI * 2.;
+
+        let mut intersections = Vec::new();
+
+        for cycle in face.all_cycles() {
+            for edge in cycle.edges() {
+                match (edge.curve().kind(), edge.vertices().get()) {
+                    (CurveKind::Line(line), Some(vertices)) => {
+                        let segment = vertices.map(|vertex| {
+                            line.point_from_line_coords(vertex.position())
+                        });
+                        intersections.extend(circle_segment_intersections(
+                            circle, segment,
+                        ));
+                    }
+                    (CurveKind::Circle(other), None) => {
+                        if circle.center() == other.center()
+                            && circle.a().magnitude() == other.a().magnitude()
+                        {
+                            // The circle coincides with the boundary of the
+                            // face.
+                            return Self::from_intervals([[
+                                [Scalar::ZERO],
+                                [full_turn],
+                            ]]);
+                        }
+
+                        intersections
+                            .extend(circle_circle_intersections(circle, other));
+                    }
+                    (CurveKind::Circle(_), Some(_)) => {
+                        todo!("Circle-face intersection doesn't support arcs")
+                    }
+                    (CurveKind::Nurbs(_), _) => {
+                        todo!(
+                            "Circle-face intersection doesn't support NURBS \
+                            curves"
+                        )
+                    }
+                    (CurveKind::Line(_), None) => {
+                        unreachable!("Line segments are bounded by vertices")
+                    }
                 }
-            })
-            .collect();
+            }
+        }
+
+        let contains = |t: Scalar| {
+            let point = circle.point_from_circle_coords([t]);
+            (face, &point).intersect().is_some()
+        };
 
-        CurveFaceIntersection { intervals }
+        // Splitting the circle at the start and end of its coordinates makes
+        // sure none of the intervals wraps around.
+        intersections.extend([Scalar::ZERO, full_turn]);
+        let intervals = intervals_between(intersections, contains);
+
+        Self::from_intervals(intervals)
     }
 
     /// Merge this intersection list with another
@@ -146,6 +223,125 @@ pub struct CurveFaceIntersectionInterval {
     pub end: Point<1>,
 }
 
+/// Compute where a circle crosses a line segment, in circle coordinates
+fn circle_segment_intersections(
+    circle: &Circle<2>,
+    segment: [Point<2>; 2],
+) -> Vec<Scalar> {
+    // Express the segment in a coordinate system where the circle is the unit
+    // circle. Then all that's left to do, is finding the points on the segment
+    // that have a distance of one from the origin.
+    let radius_squared = circle.a().dot(&circle.a());
+    let to_unit_circle = |point: Point<2>| {
+        let vector = point - circle.center();
+        Vector::from([vector.dot(&circle.a()), vector.dot(&circle.b())])
+            / radius_squared
+    };
+
+    let [a, b] = segment.map(to_unit_circle);
+    let direction = b - a;
+
+    let qa = direction.dot(&direction);
+    let qb = a.dot(&direction) * 2.;
+    let qc = a.dot(&a) - Scalar::ONE;
+
+    let discriminant = qb * qb - qa * qc * 4.;
+    if discriminant <= Scalar::ZERO {
+        // The segment misses the circle, or just touches it.
+        return Vec::new();
+    }
+
+    let root = discriminant.sqrt();
+    [(-qb - root) / (qa * 2.), (-qb + root) / (qa * 2.)]
+        .into_iter()
+        // Intersections at the end of the segment are counted by the next one.
+        .filter(|&s| s >= Scalar::ZERO && s < Scalar::ONE)
+        .map(|s| {
+            let point = a + direction * s;
+            circle_coord(Scalar::atan2(point.v, point.u))
+        })
+        .collect()
+}
+
+/// Compute where a circle crosses another circle, in circle coordinates
+fn circle_circle_intersections(
+    circle: &Circle<2>,
+    other: &Circle<2>,
+) -> Vec<Scalar> {
+    let r_a = circle.a().magnitude();
+    let r_b = other.a().magnitude();
+
+    let between_centers = other.center() - circle.center();
+    let distance = between_centers.magnitude();
+
+    if distance >= r_a + r_b || distance <= (r_a - r_b).abs() {
+        // The circles are apart from each other, contain each other, or just
+        // touch.
+        return Vec::new();
+    }
+
+    // The intersection points lie on a line that is perpendicular to the line
+    // between the centers. Find where those lines meet, and how far the
+    // intersection points are from there.
+    let along = (distance * distance + r_a * r_a - r_b * r_b) / (distance * 2.);
+    let across = (r_a * r_a - along * along).sqrt();
+
+    let direction = between_centers / distance;
+    let perpendicular = Vector::from([-direction.v, direction.u]);
+
+    let base = circle.center() + direction * along;
+    [base + perpendicular * across, base - perpendicular * across]
+        .into_iter()
+        .map(|point| circle.point_to_circle_coords(point).t)
+        .collect()
+}
+
+/// Build the intervals between boundary points, in which a curve is in a face
+///
+/// The curve doesn't necessarily cross the boundary of the face at those
+/// points. It might just touch the boundary, or meet two edges at a vertex they
+/// share. So instead of assuming that the points alternate between entering and
+/// leaving the face, every section between two of them is checked separately.
+fn intervals_between(
+    mut points: Vec<Scalar>,
+    contains: impl Fn(Scalar) -> bool,
+) -> Vec<[[Scalar; 1]; 2]> {
+    // It's not clear yet, how large this epsilon should be. It just needs to
+    // catch the same point being computed from two adjacent edges.
+    let epsilon = Scalar::from_f64(1e-12);
+
+    points.sort();
+    points.dedup_by(|b, a| *b - *a < epsilon);
+
+    let mut intervals: Vec<[[Scalar; 1]; 2]> = Vec::new();
+
+    for section in points.windows(2) {
+        // Can't panic, as we passed `2` to `windows`.
+        let [start, end] = [section[0], section[1]];
+
+        if !contains((start + end) / 2.) {
+            continue;
+        }
+
+        // Sections that are adjacent become a single interval. Otherwise, the
+        // curve would just touch the boundary between them.
+        match intervals.last_mut() {
+            Some([_, [last_end]]) if *last_end == start => *last_end = end,
+            _ => intervals.push([[start], [end]]),
+        }
+    }
+
+    intervals
+}
+
+fn circle_coord(angle: Scalar) -> Scalar {
+    if angle < Scalar::ZERO {
+        angle + Scalar::PI * 2.
+    } else {
+        angle
+    }
+}
+
 impl<P> From<[P; 2]> for CurveFaceIntersectionInterval
 where
     P: Into<Point<1>>,
@@ -158,7 +354,13 @@ where
 
 #[cfg(test)]
 mod tests {
-    use crate::objects::{Curve, Face, Surface};
+    use std::f64::consts::{FRAC_PI_3, PI};
+
+    use fj_math::{Circle, Scalar};
+
+    use crate::objects::{
+        Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, Surface,
+    };
 
     use super::CurveFaceIntersection;
 
@@ -166,8 +368,8 @@ mod tests {
     fn compute() {
         let surface = Surface::xy_plane();
 
-        let curve =
-            Curve::build(surface).line_from_points([[-3., 0.], [-2., 0.]]);
+        let curve = Curve::build(surface.clone())
+            .line_from_points([[-3., 0.], [-2., 0.]]);
 
         #[rustfmt::skip]
         let exterior = [
@@ -193,6 +395,117 @@ mod tests {
         assert_eq!(CurveFaceIntersection::compute(&curve, &face), expected);
     }
 
+    #[test]
+    fn compute_circle() {
+        let surface = Surface::xy_plane();
+
+        let circle = Circle::new([0., 0.], [1., 0.], [0., 1.]);
+        let curve = Curve::new(
+            CurveKind::Circle(circle),
+            GlobalCurve::from_kind(CurveKind::Circle(Circle::new(
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [0., 1., 0.],
+            ))),
+        );
+
+        // The circle crosses the boundary of the face, and is inside of it
+        // where its coordinates wrap around.
+        let face = Face::build(surface.clone())
+            .polygon_from_points([[0., -2.], [2., -2.], [2., 2.], [0., 2.]])
+            .into_face();
+        assert_intervals(
+            CurveFaceIntersection::compute(&curve, &face),
+            [[0., PI / 2.], [PI * 3. / 2., PI * 2.]],
+        );
+
+        // The circle is completely inside of the face.
+        let face = Face::build(surface.clone())
+            .polygon_from_points([[-2., -2.], [2., -2.], [2., 2.], [-2., 2.]])
+            .into_face();
+        assert_intervals(
+            CurveFaceIntersection::compute(&curve, &face),
+            [[0., PI * 2.]],
+        );
+
+        // The circle crosses another circle that bounds the face.
+        let face = {
+            let edge = Edge::build().circle_from_radius(Scalar::ONE);
+            let edge = Edge::new(
+                Curve::new(
+                    CurveKind::Circle(Circle::new(
+                        [1., 0.],
+                        [1., 0.],
+                        [0., 1.],
+                    )),
+                    edge.curve().global().clone(),
+                ),
+                *edge.vertices(),
+            );
+
+            Face::new(surface.clone())
+                .with_exteriors([Cycle::new(surface).with_edges([edge])])
+        };
+        assert_intervals(
+            CurveFaceIntersection::compute(&curve, &face),
+            [[0., FRAC_PI_3], [FRAC_PI_3 * 5., PI * 2.]],
+        );
+    }
+
+    #[test]
+    fn compute_touching() {
+        let surface = Surface::xy_plane();
+
+        let circle = Circle::new([0., 0.], [1., 0.], [0., 1.]);
+        let curve = Curve::new(
+            CurveKind::Circle(circle),
+            GlobalCurve::from_kind(CurveKind::Circle(Circle::new(
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [0., 1., 0.],
+            ))),
+        );
+
+        // The circle touches a vertex of the face from the outside.
+        let face = Face::build(surface.clone())
+            .polygon_from_points([[1., 0.], [2., 0.], [2., 1.], [1., 1.]])
+            .into_face();
+        assert_intervals(CurveFaceIntersection::compute(&curve, &face), []);
+
+        // The circle touches every edge of the face from the inside.
+        let face = Face::build(surface.clone())
+            .polygon_from_points([[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]])
+            .into_face();
+        assert_intervals(
+            CurveFaceIntersection::compute(&curve, &face),
+            [[0., PI * 2.]],
+        );
+
+        // The line passes through two vertices of the face, each of which is
+        // shared by two edges.
+        let curve = Curve::build(surface.clone())
+            .line_from_points([[0., 0.], [1., 1.]]);
+        let face = Face::build(surface)
+            .polygon_from_points([[1., 1.], [2., 1.], [2., 2.], [1., 2.]])
+            .into_face();
+        assert_eq!(
+            CurveFaceIntersection::compute(&curve, &face),
+            CurveFaceIntersection::from_intervals([[[1.], [2.]]]),
+        );
+    }
+
+    fn assert_intervals<const N: usize>(
+        intersection: CurveFaceIntersection,
+        expected: [[f64; 2]; N],
+    ) {
+        assert_eq!(intersection.intervals.len(), N);
+
+        for (interval, [start, end]) in intersection.into_iter().zip(expected) {
+            assert!((interval.start.t.into_f64() - start).abs() < 1e-12);
+            assert!((interval.end.t.into_f64() - end).abs() < 1e-12);
+        }
+    }
+
     #[test]
     fn merge() {
         let a = CurveFaceIntersection::from_intervals([
@@ -30,11 +30,11 @@ impl FaceFaceIntersection {
         // Can be cleaned up, once `zip` is stable:
         // https://doc.rust-lang.org/std/primitive.array.html#method.zip
         let curve_face_intersections = {
-            let [curve_a, curve_b] = intersection_curves;
+            let [curve_a, curve_b] = &intersection_curves;
             let [face_a, face_b] = faces;
 
             [(curve_a, face_a), (curve_b, face_b)].map(|(curve, face)| {
-                CurveFaceIntersection::compute(&curve, face)
+                CurveFaceIntersection::compute(curve, face)
             })
         };
 
@@ -75,7 +75,7 @@ mod tests {
             [1., 2.],
         ];
         let surfaces = [Surface::xy_plane(), Surface::xz_plane()];
-        let [a, b] = surfaces.map(|surface| {
+        let [a, b] = surfaces.clone().map(|surface| {
             Face::build(surface).polygon_from_points(points).into_face()
         });
 
@@ -94,7 +94,7 @@ mod tests {
             [-1.,  1.],
         ];
         let surfaces = [Surface::xy_plane(), Surface::xz_plane()];
-        let [a, b] = surfaces.map(|surface| {
+        let [a, b] = surfaces.clone().map(|surface| {
             Face::build(surface).polygon_from_points(points).into_face()
         });
 
@@ -27,8 +27,7 @@ impl Intersect for (&Face, &Point<2>) {
             let mut previous_hit = cycle
                 .edges()
                 .last()
-                .copied()
-                .and_then(|edge| (&ray, &edge).intersect());
+                .and_then(|edge| (&ray, edge).intersect());
 
             for edge in cycle.edges() {
                 let hit = (&ray, edge).intersect();
@@ -112,11 +111,11 @@ pub enum FacePointIntersection {
 
 #[cfg(test)]
 mod tests {
-    use fj_math::Point;
+    use fj_math::{Point, Scalar};
 
     use crate::{
         algorithms::intersect::{face_point::FacePointIntersection, Intersect},
-        objects::{Face, Surface},
+        objects::{Cycle, Edge, Face, Surface},
     };
 
     #[test]
@@ -206,4 +205,26 @@ mod tests {
             Some(FacePointIntersection::FaceContainsPoint)
         );
     }
+
+    #[test]
+    fn face_bounded_by_circle() {
+        let surface = Surface::xy_plane();
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface)
+                .with_edges([Edge::build().circle_from_radius(Scalar::ONE)])]);
+
+        let inside = Point::from([0.5, 0.5]);
+        let on_boundary = Point::from([0., -1.]);
+        let outside = Point::from([-1., 1.]);
+
+        assert_eq!(
+            (&face, &inside).intersect(),
+            Some(FacePointIntersection::FaceContainsPoint)
+        );
+        assert_eq!(
+            (&face, &on_boundary).intersect(),
+            Some(FacePointIntersection::FaceContainsPoint)
+        );
+        assert_eq!((&face, &outside).intersect(), None);
+    }
 }
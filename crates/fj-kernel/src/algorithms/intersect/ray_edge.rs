@@ -1,6 +1,6 @@
 //! Intersection between a ray and an edge in 2D
 
-use fj_math::Segment;
+use fj_math::{Circle, Scalar, Segment};
 
 use crate::{
     algorithms::intersect::{HorizontalRayToTheRight, Intersect},
@@ -17,8 +17,15 @@ impl Intersect for (&HorizontalRayToTheRight<2>, &Edge) {
 
         let line = match edge.curve().kind() {
             CurveKind::Line(line) => line,
-            CurveKind::Circle(_) => {
-                todo!("Casting rays against circles is not supported yet")
+            CurveKind::Circle(circle) => {
+                if edge.vertices().get().is_some() {
+                    todo!("Casting rays against arcs is not supported yet")
+                }
+
+                return (ray, circle).intersect();
+            }
+            CurveKind::Nurbs(_) => {
+                todo!("Casting rays against NURBS curves is not supported yet")
             }
         };
 
@@ -31,3 +38,47 @@ impl Intersect for (&HorizontalRayToTheRight<2>, &Edge) {
         (ray, &segment).intersect()
     }
 }
+
+impl Intersect for (&HorizontalRayToTheRight<2>, &Circle<2>) {
+    type Intersection = RaySegmentIntersection;
+
+    fn intersect(self) -> Option<Self::Intersection> {
+        let (ray, circle) = self;
+
+        let radius = circle.a().magnitude();
+        let center = circle.center();
+
+        // How far above or below the center of the circle the ray passes.
+        let height = ray.origin.v - center.v;
+        if height.abs() > radius {
+            // ray passes the circle
+            return None;
+        }
+
+        // The offset from the center to where the ray's line crosses the
+        // circle, along the direction of the ray.
+        let offset = (radius * radius - height * height).sqrt();
+
+        let crossings = [center.u - offset, center.u + offset];
+        if crossings.contains(&ray.origin.u) {
+            return Some(RaySegmentIntersection::RayStartsOnSegment);
+        }
+        if offset == Scalar::ZERO {
+            // ray just touches the circle
+            return None;
+        }
+
+        // A full circle is crossed twice by any line that doesn't just touch
+        // it. Only if the ray starts inside the circle, does it cross it just
+        // once.
+        let hits = crossings
+            .into_iter()
+            .filter(|&crossing| crossing > ray.origin.u)
+            .count();
+        if hits == 1 {
+            return Some(RaySegmentIntersection::RayHitsSegment);
+        }
+
+        None
+    }
+}
@@ -1,6 +1,6 @@
-use fj_math::{Line, Point, Scalar, Vector};
+use fj_math::{Circle, Line, Point, Scalar, Vector};
 
-use crate::objects::{Curve, CurveKind, GlobalCurve, Surface};
+use crate::objects::{Curve, CurveKind, GlobalCurve, Surface, SweptCurve};
 
 /// The intersection between two surfaces
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
@@ -11,52 +11,225 @@ pub struct SurfaceSurfaceIntersection {
 
 impl SurfaceSurfaceIntersection {
     /// Compute the intersection between two surfaces
+    ///
+    /// # Panics
+    ///
+    /// Only intersections between two planes, and between planes and
+    /// cylinders, are supported. Of the latter, only those intersections that
+    /// consist of a single curve are supported, meaning the plane must be
+    /// either parallel to the circle that the cylinder was swept from, or touch
+    /// the cylinder along a line. Panics, if any other intersection is
+    /// requested.
     pub fn compute(surfaces: [&Surface; 2]) -> Option<Self> {
-        // Algorithm from Real-Time Collision Detection by Christer Ericson. See
-        // section 5.4.4, Intersection of Two Planes.
+        let [a, b] = surfaces.map(|surface| match surface {
+            Surface::SweptCurve(surface) => surface,
+            _ => {
+                todo!("Intersections are only supported between swept surfaces")
+            }
+        });
+
+        let intersection_curves = match (&a.curve, &b.curve) {
+            (CurveKind::Line(_), CurveKind::Line(_)) => plane_plane(surfaces)?,
+            (CurveKind::Line(_), CurveKind::Circle(circle)) => {
+                plane_cylinder(surfaces[0], *circle, b.path)?
+            }
+            (CurveKind::Circle(circle), CurveKind::Line(_)) => {
+                let [b, a] = plane_cylinder(surfaces[1], *circle, a.path)?;
+                [a, b]
+            }
+            (CurveKind::Circle(_), CurveKind::Circle(_)) => {
+                todo!("Cylinder-cylinder intersection is not supported yet")
+            }
+            (CurveKind::Nurbs(_), _) | (_, CurveKind::Nurbs(_)) => {
+                todo!(
+                    "Intersections of surfaces swept from NURBS curves are not \
+                    supported yet"
+                )
+            }
+        };
+
+        Some(Self {
+            intersection_curves,
+        })
+    }
+}
+
+fn plane_plane(surfaces: [&Surface; 2]) -> Option<[Curve; 2]> {
+    // Algorithm from Real-Time Collision Detection by Christer Ericson. See
+    // section 5.4.4, Intersection of Two Planes.
+    //
+    // Adaptations were made to get the intersection curves in local
+    // coordinates for each surface.
+
+    let planes_parametric = surfaces.map(PlaneParametric::extract_from_surface);
+    let [a, b] = planes_parametric
+        .map(|plane| PlaneConstantNormal::from_parametric_plane(&plane));
+
+    let direction = a.normal.cross(&b.normal);
+
+    let denom = direction.dot(&direction);
+    if denom == Scalar::ZERO {
+        // Comparing `denom` against zero looks fishy. It's probably better
+        // to compare it against an epsilon value, but I don't know how
+        // large that epsilon should be.
         //
-        // Adaptations were made to get the intersection curves in local
-        // coordinates for each surface.
-
-        let planes_parametric =
-            surfaces.map(PlaneParametric::extract_from_surface);
-        let [a, b] = planes_parametric
-            .map(|plane| PlaneConstantNormal::from_parametric_plane(&plane));
-
-        let direction = a.normal.cross(&b.normal);
-
-        let denom = direction.dot(&direction);
-        if denom == Scalar::ZERO {
-            // Comparing `denom` against zero looks fishy. It's probably better
-            // to compare it against an epsilon value, but I don't know how
-            // large that epsilon should be.
-            //
-            // I'll just leave it like that, until we had the opportunity to
-            // collect some experience with this code.
-            // - @hannobraun
+        // I'll just leave it like that, until we had the opportunity to
+        // collect some experience with this code.
+        // - @hannobraun
+        return None;
+    }
+
+    let origin = (b.normal * a.distance - a.normal * b.distance)
+        .cross(&direction)
+        / denom;
+    let origin = Point { coords: origin };
+
+    let line = Line::from_origin_and_direction(origin, direction);
+
+    let curves = surfaces.map(|surface| {
+        let local = project_line_into_plane(&line, surface);
+        let global =
+            CurveKind::Line(Line::from_origin_and_direction(origin, direction));
+
+        Curve::new(local, GlobalCurve::from_kind(global))
+    });
+
+    Some(curves)
+}
+
+/// Compute the intersection between a plane and a cylinder
+///
+/// The cylinder is defined by the circle it was swept from, and the path it was
+/// swept along. The returned curves are in the same order as the arguments.
+fn plane_cylinder(
+    plane: &Surface,
+    circle: Circle<3>,
+    path: Vector<3>,
+) -> Option<[Curve; 2]> {
+    // Like in the plane-plane case, it's not clear yet how large this epsilon
+    // value should be. This seems to work for now.
+    let epsilon = Scalar::from_f64(1e-9);
+
+    let PlaneConstantNormal { distance, normal } =
+        PlaneConstantNormal::from_parametric_plane(
+            &PlaneParametric::extract_from_surface(plane),
+        );
+
+    let circle_normal = circle.a().cross(&circle.b()).normalize();
+
+    if normal.cross(&circle_normal).magnitude() < epsilon {
+        // The plane is parallel to the circle. The intersection is a copy of
+        // the circle, moved along the path until it lies in the plane.
+        let v = (distance - normal.dot(&circle.center().coords))
+            / normal.dot(&path);
+        let center = circle.center() + path * v;
+
+        let global = GlobalCurve::from_kind(CurveKind::Circle(Circle::new(
+            center,
+            circle.a(),
+            circle.b(),
+        )));
+
+        let in_plane = {
+            let center_local = plane.point_to_surface_coords(center);
+            let [a, b] = [circle.a(), circle.b()].map(|vector| {
+                plane.point_to_surface_coords(center + vector) - center_local
+            });
+
+            if (a.magnitude() - b.magnitude()).abs() > epsilon
+                || a.dot(&b).abs() > epsilon
+            {
+                todo!(
+                    "Plane-cylinder intersection is only supported for planes \
+                    whose surface coordinates don't distort the circle"
+                )
+            }
+
+            // `Circle` requires `a` and `b` to be precisely of the same length,
+            // which the conversion into surface coordinates doesn't guarantee.
+            // Derive `b` from `a`, keeping the direction of the circle.
+            let perpendicular = Vector::from([-a.v, a.u]);
+            let b = if perpendicular.dot(&b) > Scalar::ZERO {
+                perpendicular
+            } else {
+                -perpendicular
+            };
+
+            Curve::new(
+                CurveKind::Circle(Circle::new(center_local, a, b)),
+                global.clone(),
+            )
+        };
+        let on_cylinder = Curve::new(
+            CurveKind::Line(Line::from_origin_and_direction(
+                Point::from([Scalar::ZERO, v]),
+                Vector::from([1., 0.]),
+            )),
+            global,
+        );
+
+        return Some([in_plane, on_cylinder]);
+    }
+
+    if normal.dot(&path).abs() < epsilon {
+        // The plane is parallel to the path. Any intersections are lines along
+        // the path, through the points where the plane intersects the circle.
+        //
+        // Those are the points where `n·(c + a·cos(t) + b·sin(t)) = d`. The
+        // left side of that equation oscillates with an amplitude of
+        // `sqrt((n·a)² + (n·b)²)`.
+        let n_a = normal.dot(&circle.a());
+        let n_b = normal.dot(&circle.b());
+        let offset = distance - normal.dot(&circle.center().coords);
+
+        let amplitude = (n_a * n_a + n_b * n_b).sqrt();
+        if offset.abs() > amplitude + epsilon {
             return None;
         }
+        if offset.abs() < amplitude - epsilon {
+            todo!(
+                "Plane-cylinder intersection is not supported, if the \
+                intersection consists of two lines"
+            )
+        }
 
-        let origin = (b.normal * a.distance - a.normal * b.distance)
-            .cross(&direction)
-            / denom;
-        let origin = Point { coords: origin };
-
-        let line = Line::from_origin_and_direction(origin, direction);
+        // The plane touches the cylinder along a single line, at the maximum
+        // or minimum of the oscillation.
+        let t = Scalar::atan2(n_b, n_a);
+        let t = if offset < Scalar::ZERO {
+            t + Scalar::PI
+        } else {
+            t
+        };
+        let t = if t < Scalar::ZERO {
+            t + Scalar::PI * 2.
+        } else {
+            t
+        };
 
-        let curves = planes_parametric.map(|plane| {
-            let local = project_line_into_plane(&line, &plane);
-            let global = CurveKind::Line(Line::from_origin_and_direction(
-                origin, direction,
-            ));
+        let line = Line::from_origin_and_direction(
+            circle.point_from_circle_coords([t]),
+            path,
+        );
+        let global = GlobalCurve::from_kind(CurveKind::Line(line));
 
-            Curve::new(local, GlobalCurve::from_kind(global))
-        });
+        let in_plane =
+            Curve::new(project_line_into_plane(&line, plane), global.clone());
+        let on_cylinder = Curve::new(
+            CurveKind::Line(Line::from_origin_and_direction(
+                Point::from([t, Scalar::ZERO]),
+                Vector::from([0., 1.]),
+            )),
+            global,
+        );
 
-        Some(Self {
-            intersection_curves: curves,
-        })
+        return Some([in_plane, on_cylinder]);
     }
+
+    todo!(
+        "Plane-cylinder intersection is not supported, if the plane is oblique \
+        to the cylinder"
+    )
 }
 
 /// A plane in parametric form
@@ -69,16 +242,16 @@ struct PlaneParametric {
 
 impl PlaneParametric {
     pub fn extract_from_surface(surface: &Surface) -> Self {
-        let Surface::SweptCurve(surface) = surface;
-        let line = match surface.curve {
-            CurveKind::Line(line) => line,
-            _ => todo!("Only plane-plane intersection is currently supported."),
-        };
-
-        Self {
-            origin: line.origin(),
-            u: line.direction(),
-            v: surface.path,
+        match surface {
+            Surface::SweptCurve(SweptCurve {
+                curve: CurveKind::Line(line),
+                path,
+            }) => Self {
+                origin: line.origin(),
+                u: line.direction(),
+                v: *path,
+            },
+            _ => unreachable!("Expected a plane"),
         }
     }
 }
@@ -109,42 +282,24 @@ impl PlaneConstantNormal {
     }
 }
 
-fn project_line_into_plane(
-    line: &Line<3>,
-    plane: &PlaneParametric,
-) -> CurveKind<2> {
-    let line_origin_relative_to_plane = line.origin() - plane.origin;
-    let line_origin_in_plane = Vector::from([
-        plane
-            .u
-            .scalar_projection_onto(&line_origin_relative_to_plane),
-        plane
-            .v
-            .scalar_projection_onto(&line_origin_relative_to_plane),
-    ]);
-
-    let line_direction_in_plane = Vector::from([
-        plane.u.scalar_projection_onto(&line.direction()),
-        plane.v.scalar_projection_onto(&line.direction()),
-    ]);
-
-    let line = Line::from_origin_and_direction(
-        Point {
-            coords: line_origin_in_plane,
-        },
-        line_direction_in_plane,
-    );
+fn project_line_into_plane(line: &Line<3>, surface: &Surface) -> CurveKind<2> {
+    let origin = surface.point_to_surface_coords(line.origin());
+    let direction = surface
+        .point_to_surface_coords(line.origin() + line.direction())
+        - origin;
+
+    let line = Line::from_origin_and_direction(origin, direction);
 
     CurveKind::Line(line)
 }
 
 #[cfg(test)]
 mod tests {
-    use fj_math::Transform;
+    use fj_math::{Circle, Line, Point, Transform, Vector};
 
     use crate::{
         algorithms::TransformObject,
-        objects::{Curve, Surface},
+        objects::{Curve, CurveKind, GlobalCurve, Surface, SweptCurve},
     };
 
     use super::SurfaceSurfaceIntersection;
@@ -159,13 +314,13 @@ mod tests {
         assert_eq!(
             SurfaceSurfaceIntersection::compute([
                 &xy,
-                &xy.transform(&Transform::translation([0., 0., 1.]))
+                &xy.clone().transform(&Transform::translation([0., 0., 1.]))
             ]),
             None,
         );
 
-        let expected_xy = Curve::build(xy).u_axis();
-        let expected_xz = Curve::build(xz).u_axis();
+        let expected_xy = Curve::build(xy.clone()).u_axis();
+        let expected_xz = Curve::build(xz.clone()).u_axis();
 
         assert_eq!(
             SurfaceSurfaceIntersection::compute([&xy, &xz]),
@@ -174,4 +329,94 @@ mod tests {
             })
         );
     }
+
+    #[test]
+    fn plane_cylinder() {
+        let cylinder = Surface::SweptCurve(SweptCurve {
+            curve: CurveKind::Circle(Circle::new(
+                [0., 0., 0.],
+                [1., 0., 0.],
+                [0., 1., 0.],
+            )),
+            path: Vector::from([0., 0., 2.]),
+        });
+
+        // A plane that is parallel to the circle intersects the cylinder in a
+        // circle.
+        let xy = Surface::xy_plane()
+            .transform(&Transform::translation([0., 0., 1.]));
+
+        let global = GlobalCurve::from_kind(CurveKind::Circle(Circle::new(
+            [0., 0., 1.],
+            [1., 0., 0.],
+            [0., 1., 0.],
+        )));
+        let expected_xy = Curve::new(
+            CurveKind::Circle(Circle::new([0., 0.], [1., 0.], [0., 1.])),
+            global.clone(),
+        );
+        let expected_cylinder = Curve::new(
+            CurveKind::Line(Line::from_origin_and_direction(
+                Point::from([0., 0.5]),
+                Vector::from([1., 0.]),
+            )),
+            global,
+        );
+
+        assert_eq!(
+            SurfaceSurfaceIntersection::compute([&xy, &cylinder]),
+            Some(SurfaceSurfaceIntersection {
+                intersection_curves: [
+                    expected_xy.clone(),
+                    expected_cylinder.clone(),
+                ],
+            })
+        );
+        assert_eq!(
+            SurfaceSurfaceIntersection::compute([&cylinder, &xy]),
+            Some(SurfaceSurfaceIntersection {
+                intersection_curves: [expected_cylinder, expected_xy],
+            })
+        );
+
+        // A plane that touches the cylinder intersects it in a line.
+        let yz = Surface::yz_plane()
+            .transform(&Transform::translation([1., 0., 0.]));
+
+        let global = GlobalCurve::from_kind(CurveKind::Line(
+            Line::from_origin_and_direction(
+                Point::from([1., 0., 0.]),
+                Vector::from([0., 0., 2.]),
+            ),
+        ));
+        let expected_yz = Curve::new(
+            CurveKind::Line(Line::from_origin_and_direction(
+                Point::from([0., 0.]),
+                Vector::from([0., 2.]),
+            )),
+            global.clone(),
+        );
+        let expected_cylinder = Curve::new(
+            CurveKind::Line(Line::from_origin_and_direction(
+                Point::from([0., 0.]),
+                Vector::from([0., 1.]),
+            )),
+            global,
+        );
+
+        assert_eq!(
+            SurfaceSurfaceIntersection::compute([&yz, &cylinder]),
+            Some(SurfaceSurfaceIntersection {
+                intersection_curves: [
+                    expected_yz.clone(),
+                    expected_cylinder.clone(),
+                ],
+            })
+        );
+
+        // A plane that passes the cylinder doesn't intersect it.
+        let yz = Surface::yz_plane()
+            .transform(&Transform::translation([2., 0., 0.]));
+        assert_eq!(SurfaceSurfaceIntersection::compute([&yz, &cylinder]), None);
+    }
 }
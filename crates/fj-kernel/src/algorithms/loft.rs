@@ -0,0 +1,543 @@
+use std::cmp::Ordering;
+
+use fj_interop::mesh::Color;
+use fj_math::{Line, NurbsCurve, NurbsSurface, Point, Scalar, Vector};
+
+use crate::objects::{
+    Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, GlobalVertex, Sketch,
+    Solid, Surface, Vertex, VerticesOfEdge,
+};
+
+use super::reverse_face;
+
+/// Create a solid by lofting between a number of sketches
+///
+/// The sketches, called profiles, must already be positioned in space. Each
+/// profile is connected to the next one by ruled faces. The first and the last
+/// profile become the faces that close the solid at either end.
+///
+/// The cycles of each profile are matched with the cycles of the next one in
+/// order, exteriors with exteriors, and interiors with interiors. Matching
+/// cycles don't need to have the same number of edges. Each cycle is divided
+/// into the same number of segments, by splitting each of its edges into equal
+/// parts, as required to match the edges of all other cycles. The start of each
+/// cycle is matched with the start of the others.
+///
+/// # Panics
+///
+/// Panics, if there are less than two profiles, or if the profiles don't have
+/// the same number of cycles. All cycles that are matched must have the same
+/// orientation.
+///
+/// # Limitations
+///
+/// Each profile must consist of exactly one face.
+pub fn loft(profiles: impl IntoIterator<Item = Sketch>, color: Color) -> Solid {
+    let profiles: Vec<_> = profiles
+        .into_iter()
+        .map(|sketch| {
+            let mut faces = sketch.into_faces();
+            match (faces.next(), faces.next()) {
+                (Some(face), None) => face,
+                _ => todo!(
+                    "Lofting profiles that don't consist of exactly one face \
+                    is not supported yet"
+                ),
+            }
+        })
+        .collect();
+
+    assert!(profiles.len() >= 2, "Loft needs at least two profiles");
+
+    let num_exteriors = profiles[0].exteriors().count();
+    let num_interiors = profiles[0].interiors().count();
+    assert!(
+        profiles.iter().all(|profile| {
+            profile.exteriors().count() == num_exteriors
+                && profile.interiors().count() == num_interiors
+        }),
+        "Profiles of a loft must have the same number of cycles"
+    );
+
+    let segments = profiles
+        .iter()
+        .map(|profile| profile.all_cycles().map(segments_of_cycle).collect())
+        .collect();
+
+    let last = profiles.len() - 1;
+    let caps = [&profiles[0], &profiles[last]]
+        .map(|profile| (profile.surface().clone(), profile.color()));
+    loft_segments(caps, num_exteriors, segments, color)
+}
+
+/// Create a solid by lofting between the segments of a number of profiles
+///
+/// `segments` contains, for each profile, the segments of each of its cycles,
+/// as returned by [`segments_of_cycle`]. The first `num_exteriors` cycles of
+/// each profile are exteriors, the rest are interiors. The first and the last
+/// profile must lie in the surfaces in `caps`. Those, and the colors that go
+/// with them, are used for the faces that close the solid.
+pub(super) fn loft_segments(
+    caps: [(Surface, Color); 2],
+    num_exteriors: usize,
+    segments: Vec<Vec<Vec<NurbsCurve<3>>>>,
+    color: Color,
+) -> Solid {
+    let num_cycles = segments[0].len();
+
+    // For each cycle, the loops that it has in all of the profiles.
+    let mut loops = Vec::new();
+    for i in 0..num_cycles {
+        let segments: Vec<_> =
+            segments.iter().map(|profile| profile[i].clone()).collect();
+
+        // Split every loop wherever any of them has a boundary between its
+        // segments. Positions along each loop are given as fractions of the
+        // number of its segments.
+        let mut fractions: Vec<_> = segments
+            .iter()
+            .flat_map(|segments| {
+                let n = segments.len() as u64;
+                (0..n).map(move |i| (i, n))
+            })
+            .collect();
+        fractions.sort_by(|&a, &b| compare_fractions(a, b));
+        fractions.dedup_by(|&mut a, &mut b| {
+            compare_fractions(a, b) == Ordering::Equal
+        });
+
+        loops.push(
+            segments
+                .into_iter()
+                .map(|segments| Loop::new(segments, &fractions))
+                .collect::<Vec<_>>(),
+        );
+    }
+
+    let is_loft_along_negative_direction = {
+        let (surface, _) = &caps[0];
+        let normal = surface
+            .vector_from_surface_coords([1., 0.])
+            .cross(&surface.vector_from_surface_coords([0., 1.]));
+
+        let [a, b] = [0, 1].map(|i| loops[0][i].vertices[0].position());
+        (b - a).dot(&normal) < Scalar::ZERO
+    };
+
+    let mut faces = Vec::new();
+
+    for loops in &loops {
+        for (bottom, top) in loops.iter().zip(&loops[1..]) {
+            for i in 0..bottom.segments.len() {
+                let face = ruled_face(
+                    [&bottom.segments[i], &top.segments[i]],
+                    [bottom.vertices_of_segment(i), top.vertices_of_segment(i)],
+                    color,
+                );
+
+                faces.push(if is_loft_along_negative_direction {
+                    reverse_face(&face)
+                } else {
+                    face
+                });
+            }
+        }
+    }
+
+    let last = segments.len() - 1;
+    let [bottom, top] = caps;
+    for (i, (surface, cap_color), is_bottom) in
+        [(0, bottom, true), (last, top, false)]
+    {
+        let cycles: Vec<_> =
+            loops.iter().map(|loops| loops[i].cycle(&surface)).collect();
+        let (exteriors, interiors) = cycles.split_at(num_exteriors);
+
+        let face = Face::new(surface)
+            .with_exteriors(exteriors.iter().cloned())
+            .with_interiors(interiors.iter().cloned())
+            .with_color(cap_color);
+
+        faces.push(if is_bottom != is_loft_along_negative_direction {
+            reverse_face(&face)
+        } else {
+            face
+        });
+    }
+
+    Solid::new().with_faces(faces)
+}
+
+/// Create a ruled face between two curves
+///
+/// Both curves must be defined over the domain `0..1`. The face is bounded by
+/// the curves and the straight lines that connect their ends. Its normal is the
+/// cross product of the direction of the curves and the direction from the
+/// first curve towards the second one.
+pub(super) fn ruled_face(
+    curves: [&NurbsCurve<3>; 2],
+    vertices: [[GlobalVertex; 2]; 2],
+    color: Color,
+) -> Face {
+    let [bottom, top] = make_compatible(curves);
+
+    // A ruled surface is linear between the two curves. Since the weights of
+    // the curves are interpolated too, its rulings are straight lines.
+    let surface = NurbsSurface::new(
+        [bottom.degree(), 1],
+        bottom
+            .control_points()
+            .iter()
+            .zip(top.control_points())
+            .map(|(&a, &b)| [a, b]),
+        bottom
+            .weights()
+            .iter()
+            .zip(top.weights())
+            .map(|(&a, &b)| [a, b]),
+        [
+            bottom.knots().to_vec(),
+            [0., 0., 1., 1.].map(Scalar::from).to_vec(),
+        ],
+    );
+
+    let [[a, b], [d, c]] = vertices;
+    let [curve_bottom, curve_top] = curves.map(curve_kind);
+
+    // The edges follow the iso-curves of the surface, so their curve
+    // coordinates are the same locally and globally. The top and left edges
+    // run backwards along those curves.
+    let edges = [
+        ([0., 0.], [1., 0.], curve_bottom, [0., 1.], [a, b]),
+        (
+            [1., 0.],
+            [0., 1.],
+            CurveKind::line_from_points([b, c].map(|v| v.position())),
+            [0., 1.],
+            [b, c],
+        ),
+        ([0., 1.], [1., 0.], curve_top, [1., 0.], [c, d]),
+        (
+            [0., 0.],
+            [0., 1.],
+            CurveKind::line_from_points([a, d].map(|v| v.position())),
+            [1., 0.],
+            [d, a],
+        ),
+    ]
+    .map(|(origin, direction, global, range, vertices)| {
+        Edge::new(
+            Curve::new(
+                CurveKind::Line(Line::from_origin_and_direction(
+                    Point::from(origin),
+                    Vector::from(direction),
+                )),
+                GlobalCurve::from_kind(global),
+            ),
+            vertices_of_edge(range, vertices),
+        )
+    });
+
+    let surface = Surface::Nurbs(surface);
+    Face::new(surface.clone())
+        .with_exteriors([Cycle::new(surface).with_edges(edges)])
+        .with_color(color)
+}
+
+/// A cycle of one profile, divided into the segments that are lofted
+struct Loop {
+    segments: Vec<NurbsCurve<3>>,
+    vertices: Vec<GlobalVertex>,
+}
+
+impl Loop {
+    /// Split the segments of a cycle at the given fractions
+    fn new(segments: Vec<NurbsCurve<3>>, fractions: &[(u64, u64)]) -> Self {
+        let n = segments.len() as u64;
+
+        let mut split = Vec::new();
+        for (i, segment) in (0..).zip(segments) {
+            let mut rest = segment;
+
+            for &(a, b) in fractions {
+                // The fraction `a / b`, relative to the segment's domain.
+                let start = i * b;
+                let position = a * n;
+
+                if start < position && position < start + b {
+                    let t = Scalar::from_u64(position - start)
+                        / Scalar::from_u64(b);
+
+                    let [segment, next] = rest.split([t]);
+                    split.push(segment);
+                    rest = next;
+                }
+            }
+
+            split.push(rest);
+        }
+
+        let segments: Vec<_> = split
+            .into_iter()
+            .map(|segment| segment.reparametrize([0., 1.]))
+            .collect();
+        let vertices = segments
+            .iter()
+            .map(|segment| {
+                GlobalVertex::from_position(segment.control_points()[0])
+            })
+            .collect();
+
+        Self { segments, vertices }
+    }
+
+    fn vertices_of_segment(&self, i: usize) -> [GlobalVertex; 2] {
+        [i, (i + 1) % self.vertices.len()].map(|i| self.vertices[i])
+    }
+
+    /// Create a cycle from the segments, in the given surface
+    fn cycle(&self, surface: &Surface) -> Cycle {
+        let edges = self.segments.iter().enumerate().map(|(i, segment)| {
+            let local = NurbsCurve::new(
+                segment.degree(),
+                segment
+                    .control_points()
+                    .iter()
+                    .map(|&point| surface.point_to_surface_coords(point)),
+                segment.weights().iter().copied(),
+                segment.knots().iter().copied(),
+            );
+
+            Edge::new(
+                Curve::new(
+                    curve_kind(&local),
+                    GlobalCurve::from_kind(curve_kind(segment)),
+                ),
+                vertices_of_edge([0., 1.], self.vertices_of_segment(i)),
+            )
+        });
+
+        Cycle::new(surface.clone()).with_edges(edges)
+    }
+}
+
+/// Convert the edges of a cycle into NURBS curves
+///
+/// Each curve is defined over the domain `0..1`, and starts exactly where the
+/// previous one ends.
+pub(super) fn segments_of_cycle(cycle: &Cycle) -> Vec<NurbsCurve<3>> {
+    let mut segments = Vec::new();
+
+    for edge in cycle.edges() {
+        let range = edge
+            .vertices()
+            .get()
+            .map(|vertices| vertices.map(|vertex| vertex.position().t))
+            .unwrap_or([Scalar::ZERO, Scalar::PI * 2.]);
+
+        let segment = match edge.curve().global().kind() {
+            CurveKind::Circle(circle) => circle.to_nurbs(range),
+            CurveKind::Line(line) => NurbsCurve::from_control_points(
+                1,
+                range.map(|t| line.point_from_line_coords([t])),
+            ),
+            CurveKind::Nurbs(curve) => {
+                let (curve, [start, end]) = if range[0] > range[1] {
+                    (curve.clone().reverse(), range.map(|t| -t))
+                } else {
+                    (curve.clone(), range)
+                };
+
+                let [domain_start, domain_end] = curve.domain();
+                let curve = if start > domain_start {
+                    let [_, curve] = curve.split([start]);
+                    curve
+                } else {
+                    curve
+                };
+                let curve = if end < domain_end {
+                    let [curve, _] = curve.split([end]);
+                    curve
+                } else {
+                    curve
+                };
+
+                curve.reparametrize([0., 1.])
+            }
+        };
+
+        // Snap the ends of the segment to the vertices, so they are shared
+        // exactly with the neighboring segments. An edge without vertices is a
+        // full circle, which needs to end where it starts.
+        let ends = match edge.vertices().get() {
+            Some(vertices) => vertices.map(|vertex| vertex.global().position()),
+            None => [segment.control_points()[0]; 2],
+        };
+
+        let mut control_points = segment.control_points().to_vec();
+        let last = control_points.len() - 1;
+        [control_points[0], control_points[last]] = ends;
+
+        segments.push(NurbsCurve::new(
+            segment.degree(),
+            control_points,
+            segment.weights().iter().copied(),
+            segment.knots().iter().copied(),
+        ));
+    }
+
+    segments
+}
+
+/// Bring two curves to the same degree and knot vector
+fn make_compatible(curves: [&NurbsCurve<3>; 2]) -> [NurbsCurve<3>; 2] {
+    let mut curves = curves.map(Clone::clone);
+
+    for [i, j] in [[0, 1], [1, 0]] {
+        while curves[i].degree() < curves[j].degree() {
+            curves[i] = curves[i].elevate_degree();
+        }
+    }
+
+    for [i, j] in [[0, 1], [1, 0]] {
+        let [start, end] = curves[j].domain();
+        let knots: Vec<_> = curves[j]
+            .knots()
+            .iter()
+            .copied()
+            .filter(|&knot| start < knot && knot < end)
+            .collect();
+
+        for knot in knots {
+            let multiplicity = |curve: &NurbsCurve<3>| {
+                curve.knots().iter().filter(|&&k| k == knot).count()
+            };
+
+            while multiplicity(&curves[i]) < multiplicity(&curves[j]) {
+                curves[i] = curves[i].insert_knot([knot]);
+            }
+        }
+    }
+
+    curves
+}
+
+fn compare_fractions((a, b): (u64, u64), (c, d): (u64, u64)) -> Ordering {
+    (a * d).cmp(&(c * b))
+}
+
+/// Use a line, if the curve is one, as that is easier to work with
+fn curve_kind<const D: usize>(curve: &NurbsCurve<D>) -> CurveKind<D> {
+    match (curve.control_points(), curve.weights()) {
+        (&[a, b], &[weight_a, weight_b]) if weight_a == weight_b => {
+            CurveKind::line_from_points([a, b])
+        }
+        _ => CurveKind::Nurbs(curve.clone()),
+    }
+}
+
+fn vertices_of_edge(
+    range: [f64; 2],
+    vertices: [GlobalVertex; 2],
+) -> VerticesOfEdge {
+    let [a, b] = range;
+    let [vertex_a, vertex_b] = vertices;
+
+    VerticesOfEdge::from_vertices([
+        Vertex::new(Point::from([a]), vertex_a),
+        Vertex::new(Point::from([b]), vertex_b),
+    ])
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Color;
+    use fj_math::Scalar;
+
+    use crate::{
+        algorithms::TransformObject,
+        objects::{Face, Sketch, Surface},
+        test_utils::{circle, volume},
+    };
+
+    #[test]
+    fn frustum() -> anyhow::Result<()> {
+        let solid = super::loft(
+            [
+                circle([0., 0.], 1.),
+                circle([0., 0.], 0.5).translate([0., 0., 2.]),
+            ],
+            Color::default(),
+        );
+
+        // The volume of a frustum of a cone.
+        let expected = Scalar::PI * 2. / 3. * (1. + 0.5 + 0.25);
+        assert!((volume(solid)? - expected).abs() < Scalar::from(0.01));
+
+        Ok(())
+    }
+
+    #[test]
+    fn circle_to_polygon() -> anyhow::Result<()> {
+        let diamond = [[1., 0.], [0., 1.], [-1., 0.], [0., -1.]];
+
+        for heights in [[0., 1.], [0., -1.]] {
+            let [bottom, top] = heights;
+            let solid = super::loft(
+                [
+                    circle([0., 0.], 1.).translate([0., 0., bottom]),
+                    polygon(diamond, top),
+                ],
+                Color::default(),
+            );
+
+            // Each cross-section lies between the diamond and the circle.
+            let volume = volume(solid)?;
+            assert!(volume > Scalar::from(2.));
+            assert!(volume < Scalar::PI);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn with_hole() -> anyhow::Result<()> {
+        let square = |size: f64, height: f64| {
+            let face = Face::build(Surface::xy_plane())
+                .polygon_from_points(
+                    [[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]]
+                        .map(|[x, y]| [x * size, y * size]),
+                )
+                .with_hole(
+                    [[-1., -1.], [-1., 1.], [1., 1.], [1., -1.]]
+                        .map(|[x, y]| [x * size / 2., y * size / 2.]),
+                )
+                .into_face();
+            Sketch::new().with_faces([face.translate([0., 0., height])])
+        };
+
+        let solid = super::loft(
+            [square(1., 0.), square(1., 1.), square(2., 2.)],
+            Color::default(),
+        );
+
+        // A prism, followed by a frustum of a pyramid, minus the same shapes
+        // at half the size.
+        let frustum = |a: f64, b: f64| (a * a + a * b + b * b) / 3.;
+        let expected = 4. + frustum(2., 4.) - 1. - frustum(1., 2.);
+        assert!(
+            (volume(solid)? - Scalar::from(expected)).abs()
+                < Scalar::from(0.001)
+        );
+
+        Ok(())
+    }
+
+    fn polygon(points: [[f64; 2]; 4], height: f64) -> Sketch {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points(points)
+            .into_face();
+
+        Sketch::new().with_faces([face.translate([0., 0., height])])
+    }
+}
@@ -4,7 +4,14 @@
 //! on their respective purpose.
 
 mod approx;
+mod blend;
+mod boolean;
+mod hollow;
+mod loft;
+mod offset;
+mod path_sweep;
 mod reverse;
+mod revolve;
 mod sweep;
 mod transform;
 mod triangulate;
@@ -13,8 +20,17 @@ pub mod intersect;
 
 pub use self::{
     approx::{CycleApprox, FaceApprox, InvalidTolerance, Tolerance},
+    blend::{chamfer, fillet, BlendError},
+    boolean::{signed_area, BooleanError, BooleanOps},
+    hollow::{hollow, HollowError},
+    loft::loft,
+    offset::{offset, OffsetJoin},
+    path_sweep::{sweep_along_path, PathFrame},
     reverse::reverse_face,
-    sweep::sweep,
+    revolve::revolve,
+    sweep::{sweep, sweep_with_draft, SweepError},
     transform::{transform_faces, TransformObject},
     triangulate::triangulate,
 };
+
+pub(crate) use self::boolean::oriented_cycles;
@@ -0,0 +1,593 @@
+use fj_math::{Circle, Point, Scalar, Vector};
+
+use crate::objects::{
+    Curve, CurveKind, Edge, GlobalCurve, GlobalVertex, Sketch, Surface, Vertex,
+    VerticesOfEdge,
+};
+
+use super::{
+    boolean::{approx_nurbs_edges, fill, is_mirrored, oriented_cycles},
+    Tolerance,
+};
+
+/// How the offsets of two edges are joined at a convex corner
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum OffsetJoin {
+    /// Join the offset edges with an arc around the corner
+    Round,
+
+    /// Extend the offset edges, until they meet
+    ///
+    /// Where this would result in a spike that reaches further than twice the
+    /// offset distance from the corner, the corner is squared off instead, as
+    /// with [`OffsetJoin::Square`].
+    Miter,
+
+    /// Extend the offset edges by the offset distance, then connect them
+    Square,
+}
+
+/// Grow or shrink the faces of a sketch by a distance
+///
+/// Every edge is moved by `distance`, perpendicular to itself. Positive
+/// distances move edges away from the face, growing it. Negative distances
+/// move them into the face, shrinking it. Where this opens up a gap at a
+/// convex corner, the gap is closed as defined by `join`.
+///
+/// Offsetting can make edges intersect each other. Where faces end up
+/// overlapping, they are merged. Parts that end up inside out vanish. This
+/// includes holes that are closed by growing a face, as well as parts of a
+/// face that are too narrow to survive shrinking it.
+///
+/// NURBS edges are approximated by polylines, before they are offset.
+/// `tolerance` defines how far those are allowed to deviate from them.
+///
+/// # Panics
+///
+/// Panics, if the faces of the sketch are not all defined in the same
+/// surface.
+pub fn offset(
+    sketch: &Sketch,
+    distance: impl Into<Scalar>,
+    join: OffsetJoin,
+    tolerance: Tolerance,
+) -> Sketch {
+    let distance = distance.into();
+
+    let faces: Vec<_> = sketch.faces().collect();
+    let first = match faces.first() {
+        Some(face) => *face,
+        None => return Sketch::new(),
+    };
+    let surface = first.surface();
+
+    let mut edges = Vec::new();
+
+    for face in &faces {
+        assert_eq!(
+            surface,
+            face.surface(),
+            "Offsetting a sketch requires all faces to be in the same surface",
+        );
+
+        for cycle in oriented_cycles(face) {
+            edges.extend(offset_cycle(
+                surface, &cycle, distance, join, tolerance,
+            ));
+        }
+    }
+
+    let faces = fill(surface.clone(), edges, is_mirrored(first), tolerance)
+        .into_iter()
+        .map(|face| face.with_color(first.color()));
+
+    Sketch::new().with_faces(faces)
+}
+
+/// Offset a cycle whose face is on its left
+///
+/// The offset edges form a closed cycle again, which might intersect itself.
+fn offset_cycle(
+    surface: &Surface,
+    cycle: &[Edge],
+    distance: Scalar,
+    join: OffsetJoin,
+    tolerance: Tolerance,
+) -> Vec<Edge> {
+    // NURBS edges are replaced by the line segments that approximate them.
+    // The corners between those segments are only there because of the
+    // approximation, and are barely noticeable. Mitering them keeps the
+    // result as simple as possible, whatever `join` is.
+    let mut segments = Vec::new();
+    for edge in cycle {
+        let approx = approx_nurbs_edges(surface, [edge.clone()], tolerance);
+        let last = approx.len() - 1;
+
+        segments.extend(approx.into_iter().enumerate().map(|(i, segment)| {
+            let join = if i < last { OffsetJoin::Miter } else { join };
+            (segment, join)
+        }));
+    }
+
+    let mut edges = Vec::new();
+
+    for (i, (edge, join)) in segments.iter().enumerate() {
+        edges.extend(offset_edge(surface, edge, distance));
+
+        // Continuous edges form a cycle on their own, without any corners.
+        if edge.vertices().get().is_some() {
+            let (next, _) = &segments[(i + 1) % segments.len()];
+            edges.extend(corner(surface, edge, next, distance, *join));
+        }
+    }
+
+    edges
+}
+
+/// Move an edge to its right by `distance`
+///
+/// Returns `None`, if the edge is a circle that shrinks to nothing.
+fn offset_edge(
+    surface: &Surface,
+    edge: &Edge,
+    distance: Scalar,
+) -> Option<Edge> {
+    let [start, end] = edge_range(edge);
+
+    match edge.curve().kind() {
+        CurveKind::Circle(circle) => {
+            let scale = offset_scale(edge, circle, distance);
+
+            // If an arc shrinks past its center, it ends up on the opposite
+            // side of it, which a negative scale takes care of. Full circles
+            // just vanish, which is the right result for them.
+            let vanishes = match edge.vertices().get() {
+                Some(_) => scale == Scalar::ZERO,
+                None => scale <= Scalar::ZERO,
+            };
+            if vanishes {
+                return None;
+            }
+
+            let circle = Circle::new(
+                circle.center(),
+                circle.a() * scale,
+                circle.b() * scale,
+            );
+
+            Some(circle_edge(
+                surface,
+                circle,
+                edge.vertices().get().map(|_| [start, end]),
+            ))
+        }
+        CurveKind::Line(_) => {
+            let points = [start, end]
+                .map(|t| point_on_edge(edge, t) + normal(edge, t) * distance);
+
+            Some(Edge::build().line_segment_from_points(surface, points))
+        }
+        CurveKind::Nurbs(_) => {
+            unreachable!("NURBS edges are approximated by `offset`")
+        }
+    }
+}
+
+/// The factor by which to scale a circle, to move its edge to its right
+///
+/// Moving the edge to its right either grows or shrinks the circle, depending
+/// on the direction of the edge. The factor is negative, if the circle shrinks
+/// past its center.
+pub(super) fn offset_scale(
+    edge: &Edge,
+    circle: &Circle<2>,
+    distance: Scalar,
+) -> Scalar {
+    let [start, _] = edge_range(edge);
+
+    let radius = circle.a().magnitude();
+    let grows = (point_on_edge(edge, start) - circle.center())
+        .dot(&normal(edge, start))
+        > Scalar::ZERO;
+    let change = if grows { distance } else { -distance };
+
+    (radius + change) / radius
+}
+
+/// Close the gap between the offsets of two consecutive edges
+fn corner(
+    surface: &Surface,
+    incoming: &Edge,
+    outgoing: &Edge,
+    distance: Scalar,
+    join: OffsetJoin,
+) -> Vec<Edge> {
+    let [_, t_in] = edge_range(incoming);
+    let [t_out, _] = edge_range(outgoing);
+
+    let corner = point_on_edge(incoming, t_in);
+    let from = corner + normal(incoming, t_in) * distance;
+    let to =
+        point_on_edge(outgoing, t_out) + normal(outgoing, t_out) * distance;
+
+    // Points closer than this are considered identical.
+    let epsilon = distance.abs() * Scalar::from_f64(1e-9);
+
+    // The edges continue smoothly, so there's no gap to close.
+    if (to - from).magnitude() <= epsilon {
+        return Vec::new();
+    }
+
+    let dir_in = tangent(incoming, t_in).normalize();
+    let dir_out = tangent(outgoing, t_out).normalize();
+
+    let cross = dir_in.u * dir_out.v - dir_in.v * dir_out.u;
+    let dot = dir_in.dot(&dir_out);
+
+    // If the edges are moved towards the inside of the corner, their offsets
+    // overlap. Connecting them through the corner creates a small loop that
+    // winds around its area the wrong way, which means it's removed later.
+    let is_convex = cross * distance > Scalar::ZERO;
+
+    let square = || {
+        let a = from + dir_in * distance.abs();
+        let b = to - dir_out * distance.abs();
+
+        vec![from, a, b, to]
+    };
+
+    let points = if !is_convex {
+        vec![from, corner, to]
+    } else {
+        match join {
+            OffsetJoin::Round => {
+                let a = from - corner;
+                let b = if cross > Scalar::ZERO {
+                    Vector::from([-a.v, a.u])
+                } else {
+                    Vector::from([a.v, -a.u])
+                };
+                let angle = Scalar::atan2(cross.abs(), dot);
+
+                let circle = Circle::new(corner, a, b);
+                return vec![circle_edge(
+                    surface,
+                    circle,
+                    Some([Scalar::ZERO, angle]),
+                )];
+            }
+            OffsetJoin::Miter => {
+                let between = to - from;
+                let along =
+                    (between.u * dir_out.v - between.v * dir_out.u) / cross;
+                let tip = from + dir_in * along;
+
+                if (tip - corner).magnitude() <= distance.abs() * 2. {
+                    vec![from, tip, to]
+                } else {
+                    square()
+                }
+            }
+            OffsetJoin::Square => square(),
+        }
+    };
+
+    // Depending on the angle of the corner, some of the points can coincide.
+    // Square joins of right angles are the same as miter joins, for example.
+    let mut points = points.into_iter();
+    let mut edges = Vec::new();
+
+    if let Some(mut start) = points.next() {
+        for end in points {
+            if (end - start).magnitude() > epsilon {
+                edges.push(
+                    Edge::build()
+                        .line_segment_from_points(surface, [start, end]),
+                );
+                start = end;
+            }
+        }
+    }
+
+    edges
+}
+
+/// Create an edge from a circle in surface coordinates
+///
+/// If `range` is `None`, the edge is the full circle. Otherwise, it is the arc
+/// between the provided circle coordinates, in that order.
+pub(super) fn circle_edge(
+    surface: &Surface,
+    circle: Circle<2>,
+    range: Option<[Scalar; 2]>,
+) -> Edge {
+    let global = {
+        let center = surface.point_from_surface_coords(circle.center());
+        let [a, b] = [circle.a(), circle.b()]
+            .map(|vector| surface.vector_from_surface_coords(vector));
+        GlobalCurve::from_kind(CurveKind::Circle(Circle::new(center, a, b)))
+    };
+    let curve = Curve::new(CurveKind::Circle(circle), global);
+
+    let vertices = match range {
+        Some(range) => VerticesOfEdge::from_vertices(range.map(|t| {
+            let position = surface.point_from_surface_coords(
+                circle.point_from_circle_coords([t]),
+            );
+            Vertex::new([t], GlobalVertex::from_position(position))
+        })),
+        None => VerticesOfEdge::none(),
+    };
+
+    Edge::new(curve, vertices)
+}
+
+/// The range of the edge, in curve coordinates, in the direction of the edge
+pub(super) fn edge_range(edge: &Edge) -> [Scalar; 2] {
+    match edge.vertices().get() {
+        Some(vertices) => vertices.map(|vertex| vertex.position().t),
+        None => [Scalar::ZERO, Scalar::PI * 2.],
+    }
+}
+
+pub(super) fn point_on_edge(edge: &Edge, t: Scalar) -> Point<2> {
+    edge.curve().kind().point_from_curve_coords([t])
+}
+
+/// The tangent of the edge at the given point, in the direction of the edge
+pub(super) fn tangent(edge: &Edge, t: Scalar) -> Vector<2> {
+    let tangent = match edge.curve().kind() {
+        CurveKind::Circle(circle) => {
+            circle.vector_from_circle_coords([t + Scalar::PI / 2.])
+        }
+        CurveKind::Line(line) => line.direction(),
+        CurveKind::Nurbs(_) => {
+            unreachable!("NURBS edges are approximated by `offset`")
+        }
+    };
+
+    let [start, end] = edge_range(edge);
+    if end < start {
+        -tangent
+    } else {
+        tangent
+    }
+}
+
+/// The unit normal of the edge that points to its right
+pub(super) fn normal(edge: &Edge, t: Scalar) -> Vector<2> {
+    let tangent = tangent(edge, t).normalize();
+    Vector::from([tangent.v, -tangent.u])
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_math::{Circle, Scalar};
+
+    use crate::{
+        algorithms::{triangulate, Tolerance},
+        objects::{Cycle, Edge, Face, Sketch, Surface},
+        test_utils::rectangle,
+        validation::{validate, ValidationConfig},
+    };
+
+    use super::OffsetJoin;
+
+    #[test]
+    fn grow_square() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let sketch = rectangle([0., 0.], [2., 2.]);
+
+        let round = super::offset(&sketch, 0.5, OffsetJoin::Round, tolerance);
+        let expected = Scalar::from(4. + 4.) + Scalar::PI * 0.25;
+        assert!((area(round)? - expected).abs() < Scalar::from(0.01));
+
+        let miter = super::offset(&sketch, 0.5, OffsetJoin::Miter, tolerance);
+        let expected = Scalar::from(9.);
+        assert!((area(miter)? - expected).abs() < Scalar::from(1e-9));
+
+        let square = super::offset(&sketch, 0.5, OffsetJoin::Square, tolerance);
+        let expected = Scalar::from(9.);
+        assert!((area(square)? - expected).abs() < Scalar::from(1e-9));
+
+        Ok(())
+    }
+
+    #[test]
+    fn miter_limit() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        // The corner at the right is sharp enough to be squared off.
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [10., 1.], [0., 2.]])
+            .into_face();
+        let sketch = Sketch::new().with_faces([face]);
+
+        let sketch = super::offset(&sketch, 0.5, OffsetJoin::Miter, tolerance);
+
+        let max_x = sketch
+            .faces()
+            .flat_map(|face| face.all_cycles())
+            .flat_map(|cycle| cycle.edges())
+            .flat_map(|edge| edge.vertices().iter().copied())
+            .map(|vertex| vertex.global().position().x)
+            .max()
+            .unwrap();
+        assert!(max_x <= Scalar::from(10. + 0.5 * 2.));
+
+        Ok(())
+    }
+
+    #[test]
+    fn shrink_square() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let sketch = super::offset(
+            &rectangle([0., 0.], [2., 2.]),
+            -0.5,
+            OffsetJoin::Round,
+            tolerance,
+        );
+
+        let expected = Scalar::ONE;
+        assert!((area(sketch)? - expected).abs() < Scalar::from(1e-9));
+        Ok(())
+    }
+
+    #[test]
+    fn shrink_narrow_rectangle() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [4., 0.], [4., 1.], [0., 1.]])
+            .into_face();
+        let sketch = Sketch::new().with_faces([face]);
+
+        let sketch = super::offset(&sketch, -0.6, OffsetJoin::Round, tolerance);
+        assert_eq!(sketch.faces().count(), 0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn grow_concave_outline() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        // An L-shape. Growing it with miter joins results in another L-shape,
+        // after the self-intersection at the concave corner is resolved.
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([
+                [0., 0.],
+                [3., 0.],
+                [3., 1.],
+                [1., 1.],
+                [1., 3.],
+                [0., 3.],
+            ])
+            .into_face();
+        let sketch = Sketch::new().with_faces([face]);
+
+        let miter = super::offset(&sketch, 0.5, OffsetJoin::Miter, tolerance);
+        let expected = Scalar::from(4. * 2. + 2. * 2.);
+        assert!((area(miter)? - expected).abs() < Scalar::from(1e-9));
+
+        // Five of the corners are convex, and are rounded off.
+        let round = super::offset(&sketch, 0.5, OffsetJoin::Round, tolerance);
+        let expected = expected - (Scalar::ONE - Scalar::PI / 4.) * 0.25 * 5.;
+        assert!((area(round)? - expected).abs() < Scalar::from(0.01));
+
+        Ok(())
+    }
+
+    #[test]
+    fn grow_merges_faces() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let surface = Surface::xy_plane();
+        let a = Face::build(surface.clone())
+            .polygon_from_points([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])
+            .into_face();
+        let b = Face::build(surface)
+            .polygon_from_points([
+                [1.5, 0.5],
+                [2.5, 0.5],
+                [2.5, 1.5],
+                [1.5, 1.5],
+            ])
+            .into_face();
+        let sketch = Sketch::new().with_faces([a, b]);
+
+        // The rounded corners of each face intersect the edges of the other.
+        let sketch = super::offset(&sketch, 0.5, OffsetJoin::Round, tolerance);
+        assert_eq!(sketch.faces().count(), 1);
+
+        let separate = (Scalar::from(3.) + Scalar::PI * 0.25) * 2.;
+        assert!(area(sketch)? < separate);
+        Ok(())
+    }
+
+    #[test]
+    fn grow_closes_hole() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let surface = Surface::xy_plane();
+        let hole = Cycle::build(surface.clone()).polygon_from_points([
+            [-1., -1.],
+            [-1., 1.],
+            [1., 1.],
+            [1., -1.],
+        ]);
+        let face = Face::build(surface)
+            .polygon_from_points([[-2., -2.], [2., -2.], [2., 2.], [-2., 2.]])
+            .into_face()
+            .with_interiors([hole]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        let sketch = super::offset(&sketch, 1.5, OffsetJoin::Miter, tolerance);
+
+        let expected = Scalar::from(49.);
+        assert!((area(sketch)? - expected).abs() < Scalar::from(1e-9));
+        Ok(())
+    }
+
+    #[test]
+    fn grow_circle() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let surface = Surface::xy_plane();
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface)
+                .with_edges([Edge::build().circle_from_radius(Scalar::ONE)])]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        let sketch = super::offset(&sketch, 1., OffsetJoin::Round, tolerance);
+
+        let expected = Scalar::PI * 4.;
+        assert!((area(sketch)? - expected).abs() < Scalar::from(0.01));
+        Ok(())
+    }
+
+    #[test]
+    fn grow_nurbs_circle() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.01)?;
+
+        let surface = Surface::xy_plane();
+        let curve = Circle::new([0., 0.], [1., 0.], [0., 1.])
+            .to_nurbs([Scalar::ZERO, Scalar::PI * 2.]);
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface.clone()).with_edges([
+                Edge::build().nurbs_from_curve(&surface, curve),
+            ])]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        let grown = super::offset(&sketch, 1., OffsetJoin::Round, tolerance);
+        let expected = Scalar::PI * 4.;
+        assert!((area(grown)? - expected).abs() < Scalar::from(0.05));
+
+        let shrunk = super::offset(&sketch, -0.5, OffsetJoin::Round, tolerance);
+        let expected = Scalar::PI / 4.;
+        assert!((area(shrunk)? - expected).abs() < Scalar::from(0.05));
+
+        Ok(())
+    }
+
+    fn area(sketch: Sketch) -> anyhow::Result<Scalar> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let sketch = validate(sketch, &ValidationConfig::default())?;
+
+        let faces = sketch.into_inner().into_faces().collect();
+        let mesh = triangulate(faces, tolerance, &mut DebugInfo::new());
+        let area = mesh
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                (b - a).cross(&(c - a)).magnitude() / 2.
+            })
+            .fold(Scalar::ZERO, |area, triangle| area + triangle);
+
+        Ok(area)
+    }
+}
@@ -0,0 +1,585 @@
+use fj_interop::mesh::Color;
+use fj_math::{NurbsCurve, Point, Scalar, Transform, Vector};
+
+use crate::objects::{CurveKind, GlobalCurve, Sketch, Solid};
+
+use super::{
+    approx::approx_curve,
+    loft::{loft_segments, segments_of_cycle},
+    Tolerance, TransformObject,
+};
+
+/// The orientation of a sketch, as it is swept along a path
+#[derive(Clone, Copy, Debug)]
+pub enum PathFrame {
+    /// Rotate the sketch as little as possible, while it follows the path
+    ///
+    /// This is well-defined for any path, and keeps the sketch from twisting
+    /// around the path, where the path is not planar.
+    RotationMinimizing,
+
+    /// Align the x-axis of the sketch with the principal normal of the path
+    ///
+    /// The principal normal points towards the center of the path's curvature.
+    /// Where the path is straight, it is undefined, and the sketch is rotated
+    /// as little as possible instead.
+    Frenet,
+
+    /// Keep the y-axis of the sketch pointing in the given direction
+    ///
+    /// The y-axis is the projection of this direction onto the plane that is
+    /// perpendicular to the path. The path must never be parallel to it.
+    ///
+    /// At a corner of the path that doesn't lie in a plane with the up
+    /// direction, the sketch can't follow it on both sides of the corner.
+    /// There, it turns with the path, and twists back into alignment along the
+    /// following section.
+    Up(Vector<3>),
+}
+
+/// Create a solid by sweeping a sketch along a path
+///
+/// The path is a chain of curve sections, each given as a curve and a range in
+/// the curve's coordinates. Each section must start where the previous one
+/// ends. The sketch is placed at each point of the path, with its origin on the
+/// path and its z-axis pointing along it. Its x- and y-axis are oriented as
+/// defined by `frame`, then rotated around the path by `twist`, an angle in
+/// radians that is distributed evenly over the length of the path.
+///
+/// Curved sections of the path are approximated within `tolerance`, and so is
+/// the rotation of the sketch. The sketch is lofted between its copies at the
+/// points of that approximation. At a corner between two sections, the sketch
+/// is stretched into the plane that bisects the corner, which makes sweeps
+/// along straight sections exact.
+///
+/// # Panics
+///
+/// Panics, if the path is empty, or if it reverses its direction at a corner.
+///
+/// # Limitations
+///
+/// The sketch must consist of exactly one face. Closed paths, and paths that
+/// are curved so tightly that the swept sketch intersects itself, result in an
+/// invalid solid.
+pub fn sweep_along_path(
+    source: Sketch,
+    path: impl IntoIterator<Item = (GlobalCurve, [Point<1>; 2])>,
+    frame: PathFrame,
+    twist: Scalar,
+    tolerance: Tolerance,
+    color: Color,
+) -> Solid {
+    let mut faces = source.into_faces();
+    let face = match (faces.next(), faces.next()) {
+        (Some(face), None) => face,
+        _ => todo!(
+            "Sweeping sketches that don't consist of exactly one face along a \
+            path is not supported yet"
+        ),
+    };
+
+    let segments: Vec<Vec<_>> =
+        face.all_cycles().map(segments_of_cycle).collect();
+
+    let stations = stations(path, &segments, twist, tolerance);
+    let placements = placements(&stations, frame, twist);
+
+    let sections = stations
+        .iter()
+        .zip(&placements)
+        .map(|(station, placement)| {
+            let [before, after] = station.tangents;
+            let miter = before + after;
+
+            // At a corner, the copy of the sketch is projected along the
+            // direction of the path into the bisecting plane. That is where the
+            // sections on both sides of the corner meet.
+            let project = move |point: Point<3>| {
+                if station.is_corner() {
+                    point
+                        - before
+                            * ((point - station.position).dot(&miter)
+                                / before.dot(&miter))
+                } else {
+                    point
+                }
+            };
+
+            segments
+                .iter()
+                .map(|cycle| {
+                    cycle
+                        .iter()
+                        .map(|segment| {
+                            map_control_points(segment, |point| {
+                                project(placement.transform_point(&point))
+                            })
+                        })
+                        .collect()
+                })
+                .collect()
+        })
+        .collect();
+
+    let last = placements.len() - 1;
+    let caps = [placements[0], placements[last]].map(|placement| {
+        (face.surface().clone().transform(&placement), face.color())
+    });
+
+    loft_segments(caps, face.exteriors().count(), sections, color)
+}
+
+/// A point on the path, at which a copy of the sketch is placed
+struct Station {
+    position: Point<3>,
+
+    /// The direction of the path before and after the station
+    ///
+    /// Both are the same, unless the station is at a corner.
+    tangents: [Vector<3>; 2],
+
+    /// The principal normal of the path, if it is curved at the station
+    normal: Option<Vector<3>>,
+
+    /// The distance from the start of the path, measured along its
+    /// approximation
+    distance: Scalar,
+}
+
+impl Station {
+    fn is_corner(&self) -> bool {
+        let [before, after] = self.tangents;
+        (after - before).magnitude() > Scalar::from(1e-9)
+    }
+}
+
+/// Approximate the path by the stations, at which the sketch is placed
+fn stations(
+    path: impl IntoIterator<Item = (GlobalCurve, [Point<1>; 2])>,
+    segments: &[Vec<NurbsCurve<3>>],
+    twist: Scalar,
+    tolerance: Tolerance,
+) -> Vec<Station> {
+    let mut sections = Vec::new();
+    for (curve, range) in path {
+        let [start, end] = range.map(|point| point.t);
+
+        let mut approx = Vec::new();
+        approx_curve(&curve, Some(range), tolerance, &mut approx);
+
+        let mut params = vec![start];
+        params.extend(approx.iter().map(|point| point.local_form().t));
+        params.push(end);
+
+        sections.push((curve, params, end < start));
+    }
+
+    assert!(!sections.is_empty(), "Path of a sweep must not be empty");
+
+    let length = |curve: &GlobalCurve, params: &[Scalar]| {
+        params.windows(2).fold(Scalar::ZERO, |length, params| {
+            let [a, b] = [params[0], params[1]]
+                .map(|t| curve.kind().point_from_curve_coords([t]));
+            length + (b - a).magnitude()
+        })
+    };
+    let total_length = sections
+        .iter()
+        .fold(Scalar::ZERO, |total, (curve, params, _)| {
+            total + length(curve, params)
+        });
+
+    // Rotating the sketch between two stations moves its points away from the
+    // straight lines that connect them. The rotation per step is limited by
+    // how far the sketch extends from the path.
+    let radius = segments
+        .iter()
+        .flatten()
+        .flat_map(|segment| segment.control_points())
+        .fold(Scalar::ZERO, |radius, point| {
+            radius.max(point.coords.magnitude())
+        });
+    let max_rotation = if tolerance.inner() < radius {
+        (Scalar::ONE - tolerance.inner() / radius).acos() * 2.
+    } else {
+        Scalar::PI / 2.
+    };
+
+    let mut stations: Vec<Station> = Vec::new();
+    for (curve, params, is_reversed) in sections {
+        let mut refined = vec![params[0]];
+        for params in params.windows(2) {
+            let [a, b] = [params[0], params[1]];
+
+            let steps = if twist == Scalar::ZERO {
+                1
+            } else {
+                let rotation =
+                    twist.abs() * length(&curve, &[a, b]) / total_length;
+                (rotation / max_rotation).ceil().into_u64().max(1)
+            };
+            for i in 1..=steps {
+                refined.push(
+                    a + (b - a)
+                        * (Scalar::from_u64(i) / Scalar::from_u64(steps)),
+                );
+            }
+        }
+
+        for (i, t) in refined.into_iter().enumerate() {
+            let (position, [tangent, acceleration]) =
+                derivatives(curve.kind(), t);
+
+            let tangent = if is_reversed { -tangent } else { tangent };
+            let normal = acceleration
+                - tangent
+                    * (acceleration.dot(&tangent) / tangent.dot(&tangent));
+            let normal = (normal.magnitude()
+                > tangent.dot(&tangent) * Scalar::from(1e-9))
+            .then(|| normal.normalize());
+            let tangent = tangent.normalize();
+
+            let distance = match stations.last() {
+                Some(previous) => {
+                    previous.distance
+                        + (position - previous.position).magnitude()
+                }
+                None => Scalar::ZERO,
+            };
+
+            // The first point of a section is the last point of the previous
+            // one. If the direction of the path changes there, it's a corner.
+            if let (0, Some(previous)) = (i, stations.last_mut()) {
+                previous.tangents[1] = tangent;
+                if previous.is_corner() {
+                    previous.normal = None;
+                }
+                continue;
+            }
+
+            stations.push(Station {
+                position,
+                tangents: [tangent; 2],
+                normal,
+                distance,
+            });
+        }
+    }
+
+    stations
+}
+
+/// Compute the placement of the sketch at each station
+fn placements(
+    stations: &[Station],
+    frame: PathFrame,
+    twist: Scalar,
+) -> Vec<Transform> {
+    let total_length = stations[stations.len() - 1].distance;
+
+    // Any direction that isn't parallel to the path would do here. Preferring
+    // the x-axis keeps the sketch as it is, if the path starts along the
+    // z-axis.
+    let [tangent, _] = stations[0].tangents;
+    let mut x_axis = if tangent.dot(&Vector::unit_x()).abs() < Scalar::from(0.9)
+    {
+        Vector::unit_x()
+    } else {
+        Vector::unit_y()
+    };
+
+    let mut placements: Vec<Transform> = Vec::new();
+    let mut previous: Option<&Station> = None;
+
+    for station in stations {
+        let [before, after] = station.tangents;
+
+        if let Some(previous) = previous {
+            x_axis = transport(previous, station, x_axis);
+        }
+        let x_axis_before = orient(frame, before, x_axis, station.normal);
+
+        let angle = if total_length > Scalar::ZERO {
+            twist * station.distance / total_length
+        } else {
+            Scalar::ZERO
+        };
+        placements.push(placement(
+            station.position,
+            before,
+            x_axis_before,
+            angle,
+        ));
+
+        // At a corner, the sketch turns with the path.
+        let turn = before.cross(&after);
+        let x_axis_after = if turn.magnitude() > Scalar::ZERO {
+            let angle = turn.magnitude().atan2(before.dot(&after));
+            Transform::rotation(turn.normalize() * angle)
+                .transform_vector(&x_axis_before)
+        } else {
+            x_axis_before
+        };
+        x_axis = orient(frame, after, x_axis_after, station.normal);
+
+        previous = Some(station);
+    }
+
+    placements
+}
+
+/// Carry the x-axis of the sketch from one station to the next
+///
+/// Uses the double reflection method, which approximates a rotation-minimizing
+/// frame along the path. See Wang et al., "Computation of Rotation Minimizing
+/// Frames", ACM Transactions on Graphics, 2008.
+fn transport(from: &Station, to: &Station, x_axis: Vector<3>) -> Vector<3> {
+    let reflect = |vector: Vector<3>, normal: Vector<3>| {
+        let length = normal.dot(&normal);
+        if length > Scalar::ZERO {
+            vector - normal * (normal.dot(&vector) * 2. / length)
+        } else {
+            vector
+        }
+    };
+
+    let [_, tangent_from] = from.tangents;
+    let [tangent_to, _] = to.tangents;
+
+    let chord = to.position - from.position;
+    let x_axis = reflect(x_axis, chord);
+    let tangent = reflect(tangent_from, chord);
+
+    reflect(x_axis, tangent_to - tangent)
+}
+
+/// Determine the x-axis of the sketch, according to the frame
+fn orient(
+    frame: PathFrame,
+    tangent: Vector<3>,
+    carried: Vector<3>,
+    normal: Option<Vector<3>>,
+) -> Vector<3> {
+    let x_axis = match frame {
+        PathFrame::RotationMinimizing => carried,
+        PathFrame::Frenet => normal.unwrap_or(carried),
+        PathFrame::Up(up) => up.cross(&tangent),
+    };
+
+    // Remove any numerical drift that has accumulated along the path.
+    let x_axis = x_axis - tangent * x_axis.dot(&tangent);
+    assert!(
+        x_axis.magnitude() > Scalar::from(1e-9),
+        "Path of a sweep must not be parallel to its up direction"
+    );
+
+    x_axis.normalize()
+}
+
+/// Place the sketch on the path
+///
+/// The z-axis of the sketch is rotated onto the tangent of the path, then the
+/// sketch is rotated around it, to align its x-axis, and by the twist angle.
+fn placement(
+    position: Point<3>,
+    tangent: Vector<3>,
+    x_axis: Vector<3>,
+    angle: Scalar,
+) -> Transform {
+    let z_axis = Vector::unit_z();
+
+    let axis = z_axis.cross(&tangent);
+    let tilt = if axis.magnitude() > Scalar::from(1e-9) {
+        axis.normalize() * axis.magnitude().atan2(z_axis.dot(&tangent))
+    } else if z_axis.dot(&tangent) < Scalar::ZERO {
+        Vector::unit_x() * Scalar::PI
+    } else {
+        Vector::from([0., 0., 0.])
+    };
+    let tilt = Transform::rotation(tilt);
+
+    let tilted = tilt.transform_vector(&Vector::unit_x());
+    let spin = tilted
+        .cross(&x_axis)
+        .dot(&tangent)
+        .atan2(tilted.dot(&x_axis))
+        + angle;
+
+    Transform::translation(position.coords)
+        * Transform::rotation(tangent * spin)
+        * tilt
+}
+
+/// Compute position, first, and second derivative of the curve
+fn derivatives(curve: &CurveKind<3>, t: Scalar) -> (Point<3>, [Vector<3>; 2]) {
+    match curve {
+        CurveKind::Circle(circle) => {
+            let (sin, cos) = t.sin_cos();
+            (
+                circle.point_from_circle_coords([t]),
+                [
+                    circle.b() * cos - circle.a() * sin,
+                    -(circle.a() * cos + circle.b() * sin),
+                ],
+            )
+        }
+        CurveKind::Line(line) => (
+            line.point_from_line_coords([t]),
+            [line.direction(), Vector::from([0., 0., 0.])],
+        ),
+        CurveKind::Nurbs(curve) => {
+            let [position, tangent, acceleration] = curve.derivatives::<3>([t]);
+            (Point::origin() + position, [tangent, acceleration])
+        }
+    }
+}
+
+fn map_control_points(
+    curve: &NurbsCurve<3>,
+    f: impl Fn(Point<3>) -> Point<3>,
+) -> NurbsCurve<3> {
+    NurbsCurve::new(
+        curve.degree(),
+        curve.control_points().iter().map(|&point| f(point)),
+        curve.weights().iter().copied(),
+        curve.knots().iter().copied(),
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Color;
+    use fj_math::{Circle, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::Tolerance,
+        objects::{CurveKind, Face, GlobalCurve, Sketch, Solid, Surface},
+        test_utils::volume,
+    };
+
+    use super::PathFrame;
+
+    #[test]
+    fn straight() -> anyhow::Result<()> {
+        let solid = sweep(
+            [line([0., 0., 0.], [0., 0., 2.])],
+            PathFrame::RotationMinimizing,
+            0.,
+        )?;
+
+        assert!((volume(solid)? - Scalar::from(2.)).abs() < Scalar::from(1e-6));
+        Ok(())
+    }
+
+    #[test]
+    fn polyline() -> anyhow::Result<()> {
+        for frame in [PathFrame::RotationMinimizing, PathFrame::Frenet] {
+            let solid = sweep(
+                [
+                    line([0., 0., 0.], [0., 0., 2.]),
+                    line([0., 0., 2.], [2., 0., 2.]),
+                    line([2., 0., 2.], [2., 2., 3.]),
+                ],
+                frame,
+                0.,
+            )?;
+
+            // A mitered corner doesn't change the volume of a prism, as long
+            // as the path goes through the centroid of its cross-section.
+            let length = 4. + 5_f64.sqrt();
+            assert!(
+                (volume(solid)? - Scalar::from(length)).abs()
+                    < Scalar::from(1e-6)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn polyline_with_up_direction() -> anyhow::Result<()> {
+        let solid = sweep(
+            [
+                line([0., 0., 0.], [0., 0., 2.]),
+                line([0., 0., 2.], [2., 0., 3.]),
+            ],
+            PathFrame::Up(Vector::unit_y()),
+            0.,
+        )?;
+
+        let length = 2. + 5_f64.sqrt();
+        assert!(
+            (volume(solid)? - Scalar::from(length)).abs() < Scalar::from(1e-6)
+        );
+
+        Ok(())
+    }
+
+    #[test]
+    fn arc() -> anyhow::Result<()> {
+        // A quarter of a torus with a square cross-section.
+        let circle = Circle::new(
+            [2., 0., 0.],
+            Vector::from([-2., 0., 0.]),
+            Vector::from([0., 0., 2.]),
+        );
+        let path = (
+            GlobalCurve::from_kind(CurveKind::Circle(circle)),
+            [Point::from([Scalar::ZERO]), Point::from([Scalar::PI / 2.])],
+        );
+
+        for frame in [PathFrame::RotationMinimizing, PathFrame::Frenet] {
+            let solid = sweep([path.clone()], frame, 0.)?;
+
+            // Pappus's centroid theorem.
+            let expected = Scalar::PI;
+            assert!((volume(solid)? - expected).abs() < Scalar::from(0.01));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn twist() -> anyhow::Result<()> {
+        let solid = sweep(
+            [line([0., 0., 0.], [0., 0., 2.])],
+            PathFrame::RotationMinimizing,
+            Scalar::PI / 2.,
+        )?;
+
+        // Every cross-section is the same square, just rotated.
+        assert!((volume(solid)? - Scalar::from(2.)).abs() < Scalar::from(0.01));
+        Ok(())
+    }
+
+    fn sweep(
+        path: impl IntoIterator<Item = (GlobalCurve, [Point<1>; 2])>,
+        frame: PathFrame,
+        twist: impl Into<Scalar>,
+    ) -> anyhow::Result<Solid> {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([
+                [-0.5, -0.5],
+                [0.5, -0.5],
+                [0.5, 0.5],
+                [-0.5, 0.5],
+            ])
+            .into_face();
+
+        Ok(super::sweep_along_path(
+            Sketch::new().with_faces([face]),
+            path,
+            frame,
+            twist.into(),
+            Tolerance::from_scalar(0.001)?,
+            Color::default(),
+        ))
+    }
+
+    fn line(start: [f64; 3], end: [f64; 3]) -> (GlobalCurve, [Point<1>; 2]) {
+        (
+            GlobalCurve::from_kind(CurveKind::line_from_points([start, end])),
+            [[0.], [1.]].map(Point::from),
+        )
+    }
+}
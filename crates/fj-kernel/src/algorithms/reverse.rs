@@ -1,4 +1,4 @@
-use fj_math::{Circle, Line, Point, Vector};
+use fj_math::{Circle, Line, NurbsCurve, Point, Vector};
 
 use crate::objects::{Curve, CurveKind, Cycle, Edge, Face};
 
@@ -8,7 +8,7 @@ pub fn reverse_face(face: &Face) -> Face {
         panic!("Reversing tri-rep faces is not supported");
     }
 
-    let surface = face.surface().reverse();
+    let surface = face.surface().clone().reverse();
 
     let exteriors = reverse_local_coordinates_in_cycle(face.exteriors());
     let interiors = reverse_local_coordinates_in_cycle(face.interiors());
@@ -23,7 +23,7 @@ fn reverse_local_coordinates_in_cycle<'r>(
     cycles: impl IntoIterator<Item = &'r Cycle> + 'r,
 ) -> impl Iterator<Item = Cycle> + 'r {
     cycles.into_iter().map(|cycle| {
-        let surface = cycle.surface().reverse();
+        let surface = cycle.surface().clone().reverse();
 
         let edges = cycle.edges().map(|edge| {
             let curve = {
@@ -51,9 +51,22 @@ fn reverse_local_coordinates_in_cycle<'r>(
                             origin, direction,
                         ))
                     }
+                    CurveKind::Nurbs(curve) => {
+                        let control_points = curve
+                            .control_points()
+                            .iter()
+                            .map(|point| Point::from([point.u, -point.v]));
+
+                        CurveKind::Nurbs(NurbsCurve::new(
+                            curve.degree(),
+                            control_points,
+                            curve.weights().iter().copied(),
+                            curve.knots().iter().copied(),
+                        ))
+                    }
                 };
 
-                Curve::new(local, *edge.curve().global())
+                Curve::new(local, edge.curve().global().clone())
             };
 
             Edge::new(curve, *edge.vertices())
@@ -0,0 +1,882 @@
+use fj_interop::mesh::Color;
+use fj_math::{
+    Circle, Line, NurbsCurve, NurbsSurface, Point, Scalar, Transform, Vector,
+};
+
+use crate::{
+    builder::face_bounded_by_iso_curves,
+    iter::ObjectIters,
+    objects::{
+        Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, GlobalVertex, Sketch,
+        Solid, Surface, SweptCurve, Torus, Vertex, VerticesOfEdge,
+    },
+};
+
+use super::{
+    loft::segments_of_cycle, reverse_face, CycleApprox, Tolerance,
+    TransformObject,
+};
+
+/// Create a solid by revolving a sketch around an axis
+///
+/// The sketch is rotated around `axis` by `angle`, following the right-hand
+/// rule. If `angle` is a full turn or more, the sketch is revolved all the way
+/// around, and the resulting solid has no faces at the start and the end of the
+/// revolution.
+///
+/// The sketch must lie on one side of the axis. It may touch the axis, but not
+/// cross it.
+///
+/// Each edge of the sketch is revolved into a face. Lines that are parallel to
+/// the axis result in cylinders, lines that are perpendicular to it in planes,
+/// and circles in a plane through the axis in tori. Any other edge, like a line
+/// that results in a cone, is revolved into a NURBS surface of revolution,
+/// which represents it exactly. `tolerance` is only used to decide which side
+/// of the axis the sketch is on.
+pub fn revolve(
+    source: Sketch,
+    axis: Line<3>,
+    angle: Scalar,
+    tolerance: Tolerance,
+    color: Color,
+) -> Solid {
+    // Revolving in the negative direction is the same as revolving around the
+    // reversed axis. Making sure the angle is positive keeps the surface
+    // coordinates of the side faces positive too.
+    let (direction, angle) = if angle < Scalar::ZERO {
+        (-axis.direction(), -angle)
+    } else {
+        (axis.direction(), angle)
+    };
+    let axis =
+        Line::from_origin_and_direction(axis.origin(), direction.normalize());
+
+    let is_full_turn = angle >= Scalar::PI * 2.;
+
+    let radius = source
+        .cycle_iter()
+        .flat_map(|cycle| CycleApprox::new(cycle, tolerance).points)
+        .map(|point| distance_from_axis(&axis, *point.global_form()))
+        .max()
+        .unwrap_or(Scalar::ZERO);
+    if radius == Scalar::ZERO {
+        // The sketch is empty, or lies completely on the axis. Either way,
+        // there's nothing to revolve.
+        return Solid::new();
+    }
+
+    let angle = if is_full_turn { Scalar::PI * 2. } else { angle };
+    let revolution = Revolution::new(axis, angle, is_full_turn, tolerance);
+
+    let mut target = Vec::new();
+
+    for face in source.face_iter() {
+        let is_revolve_against_normal =
+            is_revolve_against_normal(face, &axis, angle, tolerance);
+
+        if !is_full_turn {
+            create_start_face(face, is_revolve_against_normal, &mut target);
+            create_end_face(
+                face.clone(),
+                &revolution.rotation,
+                is_revolve_against_normal,
+                &mut target,
+            );
+        }
+
+        for cycle in face.all_cycles() {
+            for (edge, segment) in cycle.edges().zip(segments_of_cycle(cycle)) {
+                create_side_face(
+                    edge,
+                    &segment,
+                    &revolution,
+                    is_revolve_against_normal,
+                    color,
+                    &mut target,
+                );
+            }
+        }
+    }
+
+    Solid::new().with_faces(target)
+}
+
+fn create_start_face(
+    face: &Face,
+    is_revolve_against_normal: bool,
+    target: &mut Vec<Face>,
+) {
+    let face = if is_revolve_against_normal {
+        face.clone()
+    } else {
+        reverse_face(face)
+    };
+
+    target.push(face);
+}
+
+fn create_end_face(
+    face: Face,
+    rotation: &Transform,
+    is_revolve_against_normal: bool,
+    target: &mut Vec<Face>,
+) {
+    let mut face = face.transform(rotation);
+
+    if is_revolve_against_normal {
+        face = reverse_face(&face);
+    }
+
+    target.push(face);
+}
+
+/// Create the face that results from revolving an edge of a cycle
+///
+/// `segment` is the same edge, converted into a NURBS curve over the domain
+/// `0..1`, as returned by [`segments_of_cycle`].
+fn create_side_face(
+    edge: &Edge,
+    segment: &NurbsCurve<3>,
+    revolution: &Revolution,
+    is_revolve_against_normal: bool,
+    color: Color,
+    target: &mut Vec<Face>,
+) {
+    let profile = segment.control_points();
+    if profile.iter().all(|&point| revolution.is_on_axis(point)) {
+        // A segment on the axis doesn't sweep out any area.
+        return;
+    }
+
+    let last = profile.len() - 1;
+    let start = [profile[0], profile[last]].map(GlobalVertex::from_position);
+    let end = start.map(|vertex| revolution.rotate(vertex));
+
+    let face = match edge.curve().global().kind() {
+        CurveKind::Circle(circle) => {
+            let range = edge
+                .vertices()
+                .get()
+                .map(|vertices| vertices.map(|vertex| vertex.position().t))
+                .unwrap_or([Scalar::ZERO, Scalar::PI * 2.]);
+
+            revolution.torus(circle, range, start, end)
+        }
+        CurveKind::Line(_) => revolution
+            .cylinder(start, end)
+            .or_else(|| revolution.plane(start, end)),
+        CurveKind::Nurbs(_) => None,
+    };
+    let face = face
+        .unwrap_or_else(|| {
+            revolution.surface_of_revolution(segment, start, end)
+        })
+        .with_color(color);
+
+    // The normal of the face is the cross product of the direction of the
+    // revolution and the direction of the segment. That is the opposite of
+    // what `is_revolve_against_normal` is relative to.
+    target.push(if is_revolve_against_normal {
+        face
+    } else {
+        reverse_face(&face)
+    });
+}
+
+/// The rotation that the sketch undergoes
+struct Revolution {
+    axis: Line<3>,
+    angle: Scalar,
+    is_full_turn: bool,
+
+    /// The rotation from the start to the end of the revolution
+    rotation: Transform,
+
+    /// The revolution of a unit vector, as a NURBS curve
+    ///
+    /// The curve starts at `[1, 0]` and rotates towards `[0, 1]`.
+    arc: NurbsCurve<2>,
+
+    /// Points that are closer to the axis than this stay where they are
+    min_distance: Scalar,
+}
+
+impl Revolution {
+    fn new(
+        axis: Line<3>,
+        angle: Scalar,
+        is_full_turn: bool,
+        tolerance: Tolerance,
+    ) -> Self {
+        let rotation = if is_full_turn {
+            // Rotating by a full turn might not get us exactly back to where
+            // we started, which would leave a seam.
+            Transform::identity()
+        } else {
+            rotation_around(&axis, angle)
+        };
+
+        let unit_circle = Circle::new(
+            Point::origin(),
+            Vector::from([1., 0.]),
+            Vector::from([0., 1.]),
+        );
+        let mut arc = unit_circle.to_nurbs([Scalar::ZERO, angle]);
+        if is_full_turn {
+            let mut control_points = arc.control_points().to_vec();
+            let last = control_points.len() - 1;
+            control_points[last] = control_points[0];
+
+            arc = NurbsCurve::new(
+                arc.degree(),
+                control_points,
+                arc.weights().iter().copied(),
+                arc.knots().iter().copied(),
+            );
+        }
+
+        Self {
+            axis,
+            angle,
+            is_full_turn,
+            rotation,
+            arc,
+            min_distance: tolerance.inner() * 1e-3,
+        }
+    }
+
+    fn is_on_axis(&self, point: Point<3>) -> bool {
+        distance_from_axis(&self.axis, point) < self.min_distance
+    }
+
+    fn rotate(&self, vertex: GlobalVertex) -> GlobalVertex {
+        // Vertices on the axis stay where they are, exactly. Otherwise, the
+        // edges there wouldn't collapse into a single point.
+        if self.is_full_turn || self.is_on_axis(vertex.position()) {
+            return vertex;
+        }
+
+        vertex.transform(&self.rotation)
+    }
+
+    /// The point on the axis that is closest to the given point
+    fn center_of(&self, point: Point<3>) -> Point<3> {
+        let direction = self.axis.direction();
+        self.axis.origin()
+            + direction * (point - self.axis.origin()).dot(&direction)
+    }
+
+    /// The circle that the given point is revolved along
+    ///
+    /// Its curve coordinates are the angle of the revolution. Returns `None`,
+    /// if the point is on the axis.
+    fn circle_through(&self, point: Point<3>) -> Option<Circle<3>> {
+        if self.is_on_axis(point) {
+            return None;
+        }
+
+        let center = self.center_of(point);
+        let a = point - center;
+        let b = self.axis.direction().cross(&a);
+
+        Some(Circle::new(center, a, b))
+    }
+
+    /// Revolve a line that is parallel to the axis into a cylinder
+    ///
+    /// Returns `None`, if the line isn't parallel to the axis.
+    fn cylinder(
+        &self,
+        start: [GlobalVertex; 2],
+        end: [GlobalVertex; 2],
+    ) -> Option<Face> {
+        let [from, to] = start.map(|vertex| vertex.position());
+        let path = to - from;
+        if path.cross(&self.axis.direction()).magnitude() >= self.min_distance {
+            return None;
+        }
+
+        // The line is parallel to the axis, and not on it, so neither of its
+        // ends is on the axis either.
+        let [bottom, top] = [from, to].map(|point| self.circle_through(point));
+        let (bottom, top) = (bottom?, top?);
+
+        // The revolution goes along the first surface coordinate, the line
+        // along the second one, like for a surface of revolution.
+        let surface = Surface::SweptCurve(SweptCurve {
+            curve: CurveKind::Circle(bottom),
+            path,
+        });
+
+        let angle = self.angle.into_f64();
+        let [a, b, c, d] =
+            [[0., 0.], [angle, 0.], [angle, 1.], [0., 1.]].map(Point::from);
+        let circle = |circle| GlobalCurve::from_kind(CurveKind::Circle(circle));
+        let line = |vertices: [GlobalVertex; 2]| {
+            GlobalCurve::from_kind(CurveKind::Line(Line::from_points(
+                vertices.map(|vertex| vertex.position()),
+            )))
+        };
+
+        Some(face_bounded_by_iso_curves(
+            surface,
+            [
+                ([a, b], circle(bottom), [0., angle], [start[0], end[0]]),
+                ([b, c], line(end), [0., 1.], end),
+                ([c, d], circle(top), [angle, 0.], [end[1], start[1]]),
+                ([d, a], line(start), [1., 0.], [start[1], start[0]]),
+            ],
+        ))
+    }
+
+    /// Revolve a line that is perpendicular to the axis into a plane
+    ///
+    /// Returns `None`, if the line isn't perpendicular to the axis.
+    fn plane(
+        &self,
+        start: [GlobalVertex; 2],
+        end: [GlobalVertex; 2],
+    ) -> Option<Face> {
+        let direction = self.axis.direction();
+
+        let [from, to] = start.map(|vertex| vertex.position());
+        let path = to - from;
+        if path.dot(&direction).abs() >= self.min_distance {
+            return None;
+        }
+
+        let outer = if distance_from_axis(&self.axis, from)
+            > distance_from_axis(&self.axis, to)
+        {
+            from
+        } else {
+            to
+        };
+        let center = self.center_of(outer);
+        let radial = (outer - center).normalize();
+
+        // Like for the other surfaces, the normal is the cross product of the
+        // direction of the revolution and the direction of the line. A line
+        // that doesn't get any closer to the axis would cover the same area
+        // twice, and is left to the surface of revolution.
+        let normal = direction.cross(&radial).cross(&path);
+        if normal.magnitude() < self.min_distance {
+            return None;
+        }
+        let surface = Surface::SweptCurve(SweptCurve {
+            curve: CurveKind::Line(Line::from_origin_and_direction(
+                center, radial,
+            )),
+            path: normal.normalize().cross(&radial),
+        });
+
+        // Unlike on the other surfaces, the circles that the ends of the line
+        // are revolved along are circles in surface coordinates too.
+        let edge = |global: CurveKind<3>, range: [Scalar; 2], vertices| {
+            let to_surface = |point| surface.point_to_surface_coords(point);
+            let local = match global {
+                CurveKind::Circle(circle) => {
+                    let center = to_surface(circle.center());
+                    CurveKind::Circle(Circle::new(
+                        center,
+                        to_surface(circle.center() + circle.a()) - center,
+                        to_surface(circle.center() + circle.b()) - center,
+                    ))
+                }
+                CurveKind::Line(line) => CurveKind::Line(Line::from_points(
+                    [0., 1.]
+                        .map(|t| to_surface(line.point_from_line_coords([t]))),
+                )),
+                CurveKind::Nurbs(_) => {
+                    unreachable!(
+                        "Revolving a line only results in lines and circles"
+                    )
+                }
+            };
+
+            let [a, b] = vertices;
+            Edge::new(
+                Curve::new(local, GlobalCurve::from_kind(global)),
+                VerticesOfEdge::from_vertices([
+                    Vertex::new(Point::from([range[0]]), a),
+                    Vertex::new(Point::from([range[1]]), b),
+                ]),
+            )
+        };
+        let arc = |vertex: GlobalVertex, range, vertices| {
+            self.circle_through(vertex.position())
+                .map(|circle| edge(CurveKind::Circle(circle), range, vertices))
+        };
+        let line = |vertices: [GlobalVertex; 2]| {
+            CurveKind::Line(Line::from_points(
+                vertices.map(|vertex| vertex.position()),
+            ))
+        };
+
+        let zero = Scalar::ZERO;
+        let [bottom, top] = [
+            arc(start[0], [zero, self.angle], [start[0], end[0]]),
+            arc(start[1], [self.angle, zero], [end[1], start[1]]),
+        ];
+
+        let face = Face::new(surface.clone());
+        let cycle =
+            |edges: Vec<Edge>| Cycle::new(surface.clone()).with_edges(edges);
+
+        let face = if self.is_full_turn {
+            // The line would be a seam between the start and end of the
+            // revolution, that runs through the middle of the face. Leave it
+            // out, and bound the face by the circles only.
+            let (exterior, interior) = if outer == from {
+                (bottom, top)
+            } else {
+                (top, bottom)
+            };
+
+            face.with_exteriors(exterior.map(|edge| cycle(vec![edge])))
+                .with_interiors(interior.map(|edge| cycle(vec![edge])))
+        } else {
+            let edges = [
+                bottom,
+                Some(edge(line(end), [zero, Scalar::ONE], end)),
+                top,
+                Some(edge(
+                    line(start),
+                    [Scalar::ONE, zero],
+                    [start[1], start[0]],
+                )),
+            ];
+
+            face.with_exteriors([cycle(edges.into_iter().flatten().collect())])
+        };
+
+        Some(face)
+    }
+
+    /// Revolve a circle into a torus
+    ///
+    /// Returns `None`, if the circle isn't in a plane through the axis, or
+    /// touches it.
+    fn torus(
+        &self,
+        circle: &Circle<3>,
+        range: [Scalar; 2],
+        start: [GlobalVertex; 2],
+        end: [GlobalVertex; 2],
+    ) -> Option<Face> {
+        let direction = self.axis.direction();
+
+        let radius = circle.a().magnitude();
+        let normal = circle.a().cross(&circle.b()).normalize();
+        let center = self.center_of(circle.center());
+        let a = circle.center() - center;
+
+        let is_in_plane_through_axis = (direction.dot(&normal) * radius).abs()
+            < self.min_distance
+            && a.dot(&normal).abs() < self.min_distance;
+        if !is_in_plane_through_axis
+            || a.magnitude() - radius < self.min_distance
+        {
+            return None;
+        }
+
+        // The second surface coordinate of the torus needs to increase along
+        // the edge, like the one of a surface of revolution does.
+        let is_counter_clockwise =
+            normal.dot(&a.cross(&direction)) > Scalar::ZERO;
+        let is_forward = range[0] < range[1];
+        let c = if is_counter_clockwise == is_forward {
+            direction * radius
+        } else {
+            -direction * radius
+        };
+
+        let torus = Torus {
+            center,
+            a,
+            b: direction.cross(&a),
+            c,
+        };
+        let surface = Surface::Torus(torus);
+
+        let v_start = surface
+            .point_to_surface_coords(
+                circle.point_from_circle_coords([range[0]]),
+            )
+            .v;
+        let v_end = v_start + (range[1] - range[0]).abs();
+
+        let [angle, v_start, v_end] =
+            [self.angle, v_start, v_end].map(Scalar::into_f64);
+        let [a, b, c, d] =
+            [[0., v_start], [angle, v_start], [angle, v_end], [0., v_end]]
+                .map(Point::from);
+
+        let [bottom, top] = start.map(|vertex| {
+            self.circle_through(vertex.position())
+                .expect("Torus doesn't touch the axis")
+        });
+        let curve = |kind| GlobalCurve::from_kind(kind);
+        let rotated = CurveKind::Circle(*circle).transform(&self.rotation);
+        let range = range.map(Scalar::into_f64);
+
+        Some(face_bounded_by_iso_curves(
+            surface,
+            [
+                (
+                    [a, b],
+                    curve(CurveKind::Circle(bottom)),
+                    [0., angle],
+                    [start[0], end[0]],
+                ),
+                ([b, c], curve(rotated), range, end),
+                (
+                    [c, d],
+                    curve(CurveKind::Circle(top)),
+                    [angle, 0.],
+                    [end[1], start[1]],
+                ),
+                (
+                    [d, a],
+                    curve(CurveKind::Circle(*circle)),
+                    [range[1], range[0]],
+                    [start[1], start[0]],
+                ),
+            ],
+        ))
+    }
+
+    /// Revolve a segment into a NURBS surface of revolution
+    ///
+    /// The segment is defined over the domain `0..1`, as returned by
+    /// [`segments_of_cycle`].
+    fn surface_of_revolution(
+        &self,
+        segment: &NurbsCurve<3>,
+        start: [GlobalVertex; 2],
+        end: [GlobalVertex; 2],
+    ) -> Face {
+        let profile = segment.control_points();
+        let last = profile.len() - 1;
+
+        // Every control point of the segment is swept along the arc, which
+        // makes the surface exact for any segment that can be represented as a
+        // NURBS curve.
+        let arcs: Vec<_> = profile
+            .iter()
+            .map(|&point| self.arc_through(point))
+            .collect();
+
+        // The revolution goes along the first surface coordinate, the segment
+        // along the second one. That way, the edges that collapse into a single
+        // point, where the segment touches the axis, run along the first
+        // surface coordinate, like the poles of a sphere.
+        let num_arc_points = self.arc.control_points().len();
+        let mut control_points: Vec<Vec<_>> = (0..num_arc_points)
+            .map(|j| arcs.iter().map(|arc| arc[j]).collect())
+            .collect();
+
+        // Make sure the surface ends exactly where the rotated vertices are, so
+        // the face is connected to its neighbors. It already starts at the
+        // original ones.
+        let end_of_arcs = &mut control_points[num_arc_points - 1];
+        end_of_arcs[0] = end[0].position();
+        end_of_arcs[last] = end[1].position();
+
+        let surface = NurbsSurface::new(
+            [self.arc.degree(), segment.degree()],
+            control_points,
+            self.arc.weights().iter().map(|&arc_weight| {
+                segment
+                    .weights()
+                    .iter()
+                    .map(move |&weight| weight * arc_weight)
+            }),
+            [self.arc.knots().to_vec(), segment.knots().to_vec()],
+        );
+
+        let [a, b, c, d] =
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]].map(Point::from);
+        let nurbs = |curve| GlobalCurve::from_kind(CurveKind::Nurbs(curve));
+        let rotated = if self.is_full_turn {
+            segment.clone()
+        } else {
+            surface.curve_along_v(1.)
+        };
+
+        face_bounded_by_iso_curves(
+            Surface::Nurbs(surface.clone()),
+            [
+                (
+                    [a, b],
+                    nurbs(surface.curve_along_u(0.)),
+                    [0., 1.],
+                    [start[0], end[0]],
+                ),
+                ([b, c], nurbs(rotated), [0., 1.], end),
+                (
+                    [c, d],
+                    nurbs(surface.curve_along_u(1.)),
+                    [1., 0.],
+                    [end[1], start[1]],
+                ),
+                (
+                    [d, a],
+                    nurbs(segment.clone()),
+                    [1., 0.],
+                    [start[1], start[0]],
+                ),
+            ],
+        )
+    }
+
+    /// The control points of the arc that the given point is swept along
+    fn arc_through(&self, point: Point<3>) -> Vec<Point<3>> {
+        // Points on the axis stay where they are. Making sure of that, instead
+        // of relying on the rotation, means that the edges there collapse into
+        // a single point exactly.
+        if self.is_on_axis(point) {
+            return vec![point; self.arc.control_points().len()];
+        }
+
+        let center = self.center_of(point);
+        let a = point - center;
+        let b = self.axis.direction().cross(&a);
+
+        let mut control_points: Vec<_> = self
+            .arc
+            .control_points()
+            .iter()
+            .map(|arc_point| {
+                let [u, v] = arc_point.coords.components;
+                center + a * u + b * v
+            })
+            .collect();
+
+        // The arc needs to start exactly at the point, to connect the face to
+        // the original sketch.
+        control_points[0] = point;
+
+        control_points
+    }
+}
+
+/// Determine whether the face moves against its normal, when it is revolved
+fn is_revolve_against_normal(
+    face: &Face,
+    axis: &Line<3>,
+    angle: Scalar,
+    tolerance: Tolerance,
+) -> bool {
+    let normal = {
+        let surface = face.surface();
+
+        let origin = surface.point_from_surface_coords([0., 0.]);
+        let u = surface.point_from_surface_coords([1., 0.]) - origin;
+        let v = surface.point_from_surface_coords([0., 1.]) - origin;
+
+        u.cross(&v)
+    };
+
+    // The sketch lies on one side of the axis, so any point of it that isn't
+    // on the axis tells us which way it moves.
+    let point = face
+        .all_cycles()
+        .flat_map(|cycle| CycleApprox::new(cycle, tolerance).points)
+        .map(|point| *point.global_form())
+        .max_by_key(|&point| distance_from_axis(axis, point));
+
+    let direction = match point {
+        Some(point) => axis.direction().cross(&(point - axis.origin())),
+        None => return false,
+    };
+
+    (direction.dot(&normal) * angle) < Scalar::ZERO
+}
+
+fn rotation_around(axis: &Line<3>, angle: Scalar) -> Transform {
+    let offset = axis.origin().coords;
+
+    Transform::translation(offset)
+        * Transform::rotation(axis.direction() * angle)
+        * Transform::translation(-offset)
+}
+
+fn distance_from_axis(axis: &Line<3>, point: Point<3>) -> Scalar {
+    (point - axis.origin()).cross(&axis.direction()).magnitude()
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Color;
+    use fj_math::{Line, Point, Scalar, Vector};
+
+    use crate::{
+        algorithms::Tolerance,
+        objects::{Cycle, Edge, Face, Sketch, Solid, Surface},
+        test_utils::volume,
+    };
+
+    #[test]
+    fn full_turn() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let solid = revolve(Scalar::PI * 2., tolerance);
+
+        // Revolving the rectangle creates a tube. Each of the rectangle's edges
+        // results in one side face, a cylinder or a plane. No start and end
+        // faces are required.
+        assert_eq!(solid.faces().count(), 4);
+        assert!(solid
+            .faces()
+            .all(|face| matches!(face.surface(), Surface::SweptCurve(_))));
+
+        let volume = volume(solid)?;
+        let expected = Scalar::PI * 3.;
+        assert!((volume - expected).abs() < Scalar::from_f64(0.01));
+
+        Ok(())
+    }
+
+    #[test]
+    fn partial_turn() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        for angle in [Scalar::PI / 2., -Scalar::PI / 2.] {
+            let solid = revolve(angle, tolerance);
+            assert_eq!(solid.faces().count(), 6);
+
+            let volume = volume(solid)?;
+            let expected = Scalar::PI * 3. / 4.;
+            assert!((volume - expected).abs() < Scalar::from_f64(0.01));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn touching_axis() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let face = Face::build(Surface::xy_plane()).polygon_from_points([
+            [0., 0.],
+            [1., 0.],
+            [0., 1.],
+        ]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        let axis = Line::from_origin_and_direction(
+            Point::origin(),
+            Vector::from([0., 1., 0.]),
+        );
+        let solid = super::revolve(
+            sketch,
+            axis,
+            Scalar::PI * 2.,
+            tolerance,
+            Color::default(),
+        );
+
+        // The edge on the axis doesn't contribute a face. The other two create
+        // a cone and a disc.
+        assert_eq!(solid.faces().count(), 2);
+        assert_eq!(
+            solid
+                .faces()
+                .filter(|face| matches!(face.surface(), Surface::Nurbs(_)))
+                .count(),
+            1
+        );
+
+        let volume = volume(solid)?;
+        let expected = Scalar::PI / 3.;
+        assert!((volume - expected).abs() < Scalar::from_f64(0.01));
+
+        Ok(())
+    }
+
+    #[test]
+    fn circle() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let surface = Surface::xy_plane();
+        let edge = Edge::build().circle_from_radius(Scalar::from(0.25));
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges([edge])]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        let axis = Line::from_origin_and_direction(
+            Point::from([-0.5, 0., 0.]),
+            Vector::from([0., 1., 0.]),
+        );
+        let solid = super::revolve(
+            sketch,
+            axis,
+            Scalar::PI * 2.,
+            tolerance,
+            Color::default(),
+        );
+
+        // Revolving the circle creates a torus, which consists of a single
+        // face.
+        assert_eq!(solid.faces().count(), 1);
+        assert!(solid
+            .faces()
+            .all(|face| matches!(face.surface(), Surface::Torus(_))));
+
+        let volume = volume(solid)?;
+        let expected = Scalar::PI * Scalar::PI / 16.;
+        assert!((volume - expected).abs() < expected * 0.01);
+
+        Ok(())
+    }
+
+    #[test]
+    fn partial_circle() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let surface = Surface::xy_plane();
+        let edge = Edge::build().circle_from_radius(Scalar::from(0.25));
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges([edge])]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        let axis = Line::from_origin_and_direction(
+            Point::from([-0.5, 0., 0.]),
+            Vector::from([0., 1., 0.]),
+        );
+        let solid = super::revolve(
+            sketch,
+            axis,
+            -Scalar::PI / 2.,
+            tolerance,
+            Color::default(),
+        );
+
+        // A quarter of the torus, closed off by the circle at either end.
+        assert_eq!(solid.faces().count(), 3);
+
+        let volume = volume(solid)?;
+        let expected = Scalar::PI * Scalar::PI / 64.;
+        assert!((volume - expected).abs() < expected * 0.01);
+
+        Ok(())
+    }
+
+    fn revolve(angle: Scalar, tolerance: Tolerance) -> Solid {
+        let face = Face::build(Surface::xy_plane()).polygon_from_points([
+            [1., 0.],
+            [2., 0.],
+            [2., 1.],
+            [1., 1.],
+        ]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        let axis = Line::from_origin_and_direction(
+            Point::origin(),
+            Vector::from([0., 1., 0.]),
+        );
+
+        super::revolve(sketch, axis, angle, tolerance, Color::default())
+    }
+}
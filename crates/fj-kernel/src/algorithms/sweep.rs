@@ -1,21 +1,25 @@
 use fj_interop::mesh::Color;
-use fj_math::{Point, Scalar, Transform, Triangle, Vector};
+use fj_math::{Circle, Line, NurbsSurface, Point, Scalar, Transform, Vector};
 
 use crate::{
     iter::ObjectIters,
     objects::{
         Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, GlobalVertex, Sketch,
-        Solid, Surface, Vertex, VerticesOfEdge,
+        Solid, Surface, SweptCurve, Vertex, VerticesOfEdge,
     },
+    validation::edge_positions,
 };
 
-use super::{reverse_face, CycleApprox, Tolerance, TransformObject};
+use super::{
+    boolean::signed_area,
+    offset::{circle_edge, edge_range, normal, offset_scale},
+    reverse_face, TransformObject,
+};
 
 /// Create a solid by sweeping a sketch
 pub fn sweep(
     source: Sketch,
     path: impl Into<Vector<3>>,
-    tolerance: Tolerance,
     color: Color,
 ) -> Solid {
     let path = path.into();
@@ -40,21 +44,31 @@ pub fn sweep(
 
         for cycle in face.all_cycles() {
             for edge in cycle.edges() {
-                if let Some(vertices) = edge.vertices().get() {
+                if let CurveKind::Line(_) = edge.curve().global().kind() {
+                    // Can't panic. Only circles can form edges without
+                    // vertices.
+                    let vertices_bottom = edge
+                        .vertices()
+                        .expect_vertices()
+                        .map(|vertex| *vertex.global());
+                    let vertices_top = vertices_bottom.map(|vertex| {
+                        GlobalVertex::from_position(vertex.position() + path)
+                    });
+
                     create_non_continuous_side_face(
-                        path,
                         is_sweep_along_negative_direction,
-                        vertices.map(|vertex| *vertex.global()),
+                        vertices_bottom,
+                        vertices_top,
                         color,
                         &mut target,
                     );
                     continue;
                 }
 
-                create_continuous_side_face(
-                    *edge,
+                create_curved_side_face(
+                    edge,
                     path,
-                    tolerance,
+                    is_sweep_along_negative_direction,
                     color,
                     &mut target,
                 );
@@ -65,6 +79,147 @@ pub fn sweep(
     Solid::new().with_faces(target)
 }
 
+/// Create a solid by sweeping a sketch, tapering its walls by a draft angle
+///
+/// The draft angle is in radians, measured against the path. A positive angle
+/// tapers the walls inwards, which makes the top face a smaller offset of the
+/// bottom face. A negative angle tapers them outwards. Line edges are swept
+/// into inclined planes, circles and arcs into cones. A draft angle of zero
+/// results in the same solid as [`sweep`].
+///
+/// # Errors
+///
+/// Returns an error, if the draft angle is a right angle or more, or if it
+/// shrinks a circle, arc, or line segment to nothing or past it.
+///
+/// Drafting NURBS edges is not supported yet, and neither are corners between
+/// an arc and another edge, unless they are tangent-continuous. Returns an
+/// error, if the sketch contains any of those.
+pub fn sweep_with_draft(
+    source: Sketch,
+    path: impl Into<Vector<3>>,
+    draft: impl Into<Scalar>,
+    color: Color,
+) -> Result<Solid, SweepError> {
+    let path = path.into();
+    let draft = draft.into();
+
+    if draft.abs() >= Scalar::PI / 2. {
+        return Err(SweepError::InvalidDraft(draft));
+    }
+    if draft == Scalar::ZERO {
+        return Ok(sweep(source, path, color));
+    }
+
+    let is_sweep_along_negative_direction =
+        path.dot(&Vector::from([0., 0., 1.])) < Scalar::ZERO;
+
+    // The distance by which the top face is moved towards the inside of the
+    // bottom face.
+    let (sin, cos) = draft.sin_cos();
+    let inset = path.magnitude() * sin / cos;
+
+    let mut target = Vec::new();
+
+    for face in source.face_iter() {
+        create_bottom_faces(
+            face,
+            is_sweep_along_negative_direction,
+            &mut target,
+        );
+
+        let top = draft_face(face, inset)?;
+        let top_translated = top.clone().translate(path);
+        create_top_face(
+            top,
+            path,
+            is_sweep_along_negative_direction,
+            &mut target,
+        );
+
+        // The drafted face has the same cycles and edges as the original one,
+        // in the same order.
+        for (cycle_bottom, cycle_top) in
+            face.all_cycles().zip(top_translated.all_cycles())
+        {
+            for (edge_bottom, edge_top) in
+                cycle_bottom.edges().zip(cycle_top.edges())
+            {
+                if let CurveKind::Line(_) = edge_bottom.curve().global().kind()
+                {
+                    // Can't panic. Only circles can form edges without
+                    // vertices.
+                    let [vertices_bottom, vertices_top] =
+                        [edge_bottom, edge_top].map(|edge| {
+                            edge.vertices()
+                                .expect_vertices()
+                                .map(|vertex| *vertex.global())
+                        });
+
+                    create_non_continuous_side_face(
+                        is_sweep_along_negative_direction,
+                        vertices_bottom,
+                        vertices_top,
+                        color,
+                        &mut target,
+                    );
+                    continue;
+                }
+
+                create_conical_side_face(
+                    edge_bottom,
+                    edge_top,
+                    is_sweep_along_negative_direction,
+                    color,
+                    &mut target,
+                );
+            }
+        }
+    }
+
+    Ok(Solid::new().with_faces(target))
+}
+
+/// An error that can occur when sweeping a sketch with a draft angle
+///
+/// Returned by [`sweep_with_draft`].
+#[derive(Debug, thiserror::Error)]
+pub enum SweepError {
+    /// The draft angle is a right angle or more
+    #[error("Draft angle must be less than a right angle, but is {0} rad")]
+    InvalidDraft(Scalar),
+
+    /// The draft angle shrinks an edge to nothing or past it
+    #[error("Draft angle shrinks edge to nothing")]
+    VanishingEdge(Vec<Point<3>>),
+
+    /// An edge is a NURBS curve
+    #[error("Drafting NURBS edges is not supported yet")]
+    NurbsEdge(Vec<Point<3>>),
+
+    /// A corner between an arc and another edge isn't tangent-continuous
+    #[error(
+        "Drafting non-tangent corners between arcs and other edges is not \
+        supported yet"
+    )]
+    NonTangentCorner(Point<3>),
+}
+
+impl SweepError {
+    /// Access the positions of the edge or vertex that caused the error
+    ///
+    /// Can be used to highlight them in a model.
+    pub fn positions(&self) -> Vec<Point<3>> {
+        match self {
+            Self::InvalidDraft(_) => Vec::new(),
+            Self::NonTangentCorner(position) => vec![*position],
+            Self::VanishingEdge(positions) | Self::NurbsEdge(positions) => {
+                positions.clone()
+            }
+        }
+    }
+}
+
 fn create_bottom_faces(
     face: &Face,
     is_sweep_along_negative_direction: bool,
@@ -95,18 +250,13 @@ fn create_top_face(
 }
 
 fn create_non_continuous_side_face(
-    path: Vector<3>,
     is_sweep_along_negative_direction: bool,
     vertices_bottom: [GlobalVertex; 2],
+    vertices_top: [GlobalVertex; 2],
     color: Color,
     target: &mut Vec<Face>,
 ) {
     let vertices = {
-        let vertices_top = vertices_bottom.map(|vertex| {
-            let position = vertex.position() + path;
-            GlobalVertex::from_position(position)
-        });
-
         let [[a, b], [c, d]] = [vertices_bottom, vertices_top];
 
         if is_sweep_along_negative_direction {
@@ -124,8 +274,21 @@ fn create_non_continuous_side_face(
     let cycle = {
         let [a, b, c, d] = vertices;
 
-        let mut vertices =
-            vec![([0., 0.], a), ([1., 0.], b), ([1., 1.], c), ([0., 1.], d)];
+        // The side face is a parallelogram, unless the sweep is drafted. Then
+        // it's a trapezoid, and its third corner is not at `[1., 1.]`.
+        let corner =
+            if c.position() - b.position() == d.position() - a.position() {
+                Point::from([1., 1.])
+            } else {
+                surface.point_to_surface_coords(c.position())
+            };
+
+        let mut vertices = vec![
+            (Point::from([0., 0.]), a),
+            (Point::from([1., 0.]), b),
+            (corner, c),
+            (Point::from([0., 1.]), d),
+        ];
         if let Some(vertex) = vertices.first().cloned() {
             vertices.push(vertex);
         }
@@ -158,60 +321,404 @@ fn create_non_continuous_side_face(
             edges.push(edge);
         }
 
-        Cycle::new(surface).with_edges(edges)
+        Cycle::new(surface.clone()).with_edges(edges)
     };
 
     let face = Face::new(surface).with_exteriors([cycle]).with_color(color);
     target.push(face);
 }
 
-fn create_continuous_side_face(
-    edge: Edge,
+fn create_curved_side_face(
+    edge: &Edge,
     path: Vector<3>,
-    tolerance: Tolerance,
+    is_sweep_along_negative_direction: bool,
     color: Color,
     target: &mut Vec<Face>,
 ) {
-    let translation = Transform::translation(path);
-
-    // This is definitely the wrong surface, but it shouldn't matter. Since this
-    // code will hopefully soon be gone anyway (this is the last piece of code
-    // that prevents us from removing triangle representation), it hopefully
-    // won't start to matter at some point either.
-    let placeholder = Surface::xy_plane();
-
-    let cycle = Cycle::new(placeholder).with_edges([edge]);
-    let approx = CycleApprox::new(&cycle, tolerance);
-
-    let mut quads = Vec::new();
-    for segment in approx.segments() {
-        let [v0, v1] = segment.points();
-        let [v3, v2] = {
-            let segment = translation.transform_segment(&segment);
-            segment.points()
+    let curve = edge.curve().global().kind().clone();
+    if let CurveKind::Line(_) = curve {
+        unreachable!("Lines are swept into non-continuous side faces");
+    }
+
+    // An edge without vertices is a full circle. It starts and ends at a seam,
+    // where the circle coordinates wrap around.
+    let (range, vertices_bottom) = match edge.vertices().get() {
+        Some(vertices) => (
+            vertices.map(|vertex| vertex.position().t),
+            vertices.map(|vertex| *vertex.global()),
+        ),
+        None => {
+            let seam = GlobalVertex::from_position(
+                curve.point_from_curve_coords([0.]),
+            );
+            ([Scalar::ZERO, Scalar::PI * 2.], [seam; 2])
+        }
+    };
+
+    // Orient the curve, so the edge goes along it in the direction of
+    // increasing curve coordinates. The surface normal of the side face must
+    // point away from the solid, which additionally depends on the direction
+    // of the sweep.
+    let (curve, range) = if range[0] > range[1] {
+        (curve.reverse(), range.map(|t| -t))
+    } else {
+        (curve, range)
+    };
+    let (curve, [start, end], [a, b]) = if is_sweep_along_negative_direction {
+        let [start, end] = range;
+        let [a, b] = vertices_bottom;
+        (curve.reverse(), [-end, -start], [b, a])
+    } else {
+        (curve, range, vertices_bottom)
+    };
+    let surface = Surface::SweptCurve(SweptCurve {
+        curve: curve.clone(),
+        path,
+    });
+
+    // In surface coordinates, the side face is a rectangle. Its bottom and top
+    // edges follow the curve, its left and right edges follow the path.
+    let curve_top = curve.clone().transform(&Transform::translation(path));
+    let [c, d] = [b, a]
+        .map(|vertex| GlobalVertex::from_position(vertex.position() + path));
+
+    let along_curve =
+        |v: f64, curve: CurveKind<3>, vertices: [(Scalar, GlobalVertex); 2]| {
+            let local = CurveKind::Line(Line::from_origin_and_direction(
+                Point::from([0., v]),
+                Vector::from([1., 0.]),
+            ));
+            let global = GlobalCurve::from_kind(curve);
+
+            Edge::new(
+                Curve::new(local, global),
+                VerticesOfEdge::from_vertices(
+                    vertices.map(|(t, vertex)| {
+                        Vertex::new(Point::from([t]), vertex)
+                    }),
+                ),
+            )
         };
 
-        quads.push([v0, v1, v2, v3]);
-    }
+    let bottom = along_curve(0., curve, [(start, a), (end, b)]);
+    let right = along_path(end, [b, c], [0., 1.]);
+    let top = along_curve(1., curve_top, [(end, c), (start, d)]);
+    let left = along_path(start, [d, a], [1., 0.]);
+
+    let cycle =
+        Cycle::new(surface.clone()).with_edges([bottom, right, top, left]);
+
+    let face = Face::new(surface).with_exteriors([cycle]).with_color(color);
+    target.push(face);
+}
+
+fn create_conical_side_face(
+    edge_bottom: &Edge,
+    edge_top: &Edge,
+    is_sweep_along_negative_direction: bool,
+    color: Color,
+    target: &mut Vec<Face>,
+) {
+    let circles = [edge_bottom, edge_top].map(|edge| {
+        match edge.curve().global().kind() {
+            CurveKind::Circle(circle) => *circle,
+            _ => unreachable!("Only circles are swept into conical side faces"),
+        }
+    });
+
+    // An edge without vertices is a full circle. It starts and ends at a seam,
+    // where the circle coordinates wrap around.
+    let range = edge_range(edge_bottom);
+    let [vertices_bottom, vertices_top] =
+        [(edge_bottom, circles[0]), (edge_top, circles[1])].map(
+            |(edge, circle)| match edge.vertices().get() {
+                Some(vertices) => vertices.map(|vertex| *vertex.global()),
+                None => {
+                    let seam = GlobalVertex::from_position(
+                        circle.point_from_circle_coords([0.]),
+                    );
+                    [seam; 2]
+                }
+            },
+        );
+
+    // The same considerations apply as for `create_curved_side_face`. The
+    // drafted side face leans, but its normal still points the same way.
+    let (circles, range) = if range[0] > range[1] {
+        (circles.map(Circle::reverse), range.map(|t| -t))
+    } else {
+        (circles, range)
+    };
+    let (circles, [start, end], [a, b], [d, c]) =
+        if is_sweep_along_negative_direction {
+            let [start, end] = range;
+            let [[a, b], [d, c]] = [vertices_bottom, vertices_top];
+            (circles.map(Circle::reverse), [-end, -start], [b, a], [c, d])
+        } else {
+            (circles, range, vertices_bottom, vertices_top)
+        };
+
+    // The cone is linear between the bottom circle and the top one. Both
+    // cover the same range, so their NURBS representations only differ in
+    // their control points.
+    let [bottom, top] = circles.map(|circle| circle.to_nurbs([start, end]));
+    let surface = NurbsSurface::new(
+        [bottom.degree(), 1],
+        bottom
+            .control_points()
+            .iter()
+            .zip(top.control_points())
+            .map(|(&bottom, &top)| [bottom, top]),
+        bottom.weights().iter().map(|&weight| [weight; 2]),
+        [
+            bottom.knots().to_vec(),
+            [0., 0., 1., 1.].map(Scalar::from).to_vec(),
+        ],
+    );
+    let [[u_start, u_end], _] = surface.domain();
+
+    let along_curve = |v: f64, vertices: [(Scalar, GlobalVertex); 2]| {
+        let local = CurveKind::Line(Line::from_origin_and_direction(
+            Point::from([0., v]),
+            Vector::from([1., 0.]),
+        ));
+        let global =
+            GlobalCurve::from_kind(CurveKind::Nurbs(surface.curve_along_u(v)));
+
+        Edge::new(
+            Curve::new(local, global),
+            VerticesOfEdge::from_vertices(
+                vertices
+                    .map(|(u, vertex)| Vertex::new(Point::from([u]), vertex)),
+            ),
+        )
+    };
+
+    let bottom = along_curve(0., [(u_start, a), (u_end, b)]);
+    let right = along_path(u_end, [b, c], [0., 1.]);
+    let top = along_curve(1., [(u_end, c), (u_start, d)]);
+    let left = along_path(u_start, [d, a], [1., 0.]);
+
+    let surface = Surface::Nurbs(surface);
+    let cycle =
+        Cycle::new(surface.clone()).with_edges([bottom, right, top, left]);
+
+    let face = Face::new(surface).with_exteriors([cycle]).with_color(color);
+    target.push(face);
+}
+
+/// Create a side face edge that goes from one end of the path to the other
+///
+/// In surface coordinates, the edge is a straight line at `u`, from `v[0]` to
+/// `v[1]`.
+fn along_path(u: Scalar, vertices: [GlobalVertex; 2], v: [f64; 2]) -> Edge {
+    let points = v.map(|v| Point::from([u, Scalar::from(v)]));
+    let local = CurveKind::line_from_points(points);
+    let global = GlobalCurve::from_kind(CurveKind::line_from_points(
+        vertices.map(|vertex| vertex.position()),
+    ));
+
+    let [a, b] = vertices;
+    Edge::new(
+        Curve::new(local, global),
+        VerticesOfEdge::from_vertices([
+            Vertex::new(Point::from([0.]), a),
+            Vertex::new(Point::from([1.]), b),
+        ]),
+    )
+}
+
+/// Move the cycles of a face towards its inside, within its surface
+fn draft_face(face: &Face, inset: Scalar) -> Result<Face, SweepError> {
+    let surface = face.surface();
 
-    let mut side_face: Vec<(Triangle<3>, _)> = Vec::new();
-    for [v0, v1, v2, v3] in quads {
-        side_face.push(([v0, v1, v2].into(), color));
-        side_face.push(([v0, v2, v3].into(), color));
+    // Edges are moved to their right. Depending on the direction of the cycle,
+    // that's either towards the inside of the face or away from it.
+    let draft = |cycle: &Cycle, is_face_on_left: bool| {
+        let distance = if is_face_on_left { -inset } else { inset };
+        let edges = draft_cycle(surface, cycle, distance)?;
+        Ok(Cycle::new(surface.clone()).with_edges(edges))
+    };
+
+    let exteriors = face
+        .exteriors()
+        .map(|cycle| draft(cycle, signed_area(cycle.edges()) > 0.))
+        .collect::<Result<Vec<_>, SweepError>>()?;
+    let interiors = face
+        .interiors()
+        .map(|cycle| draft(cycle, signed_area(cycle.edges()) < 0.))
+        .collect::<Result<Vec<_>, SweepError>>()?;
+
+    Ok(Face::new(surface.clone())
+        .with_exteriors(exteriors)
+        .with_interiors(interiors)
+        .with_color(face.color()))
+}
+
+/// Move the edges of a cycle to their right by `distance`
+///
+/// Unlike an offset, this doesn't add any edges at the corners. Instead, the
+/// moved edges are extended or trimmed to meet again, which keeps every edge
+/// of the result matched to the edge of the cycle it came from.
+fn draft_cycle(
+    surface: &Surface,
+    cycle: &Cycle,
+    distance: Scalar,
+) -> Result<Vec<Edge>, SweepError> {
+    let edges: Vec<_> = cycle.edges().collect();
+
+    let curves = edges
+        .iter()
+        .map(|edge| match edge.curve().kind() {
+            CurveKind::Circle(circle) => {
+                let scale = offset_scale(edge, circle, distance);
+                if scale <= Scalar::ZERO {
+                    return Err(SweepError::VanishingEdge(edge_positions(
+                        edge,
+                    )));
+                }
+
+                Ok(CurveKind::Circle(Circle::new(
+                    circle.center(),
+                    circle.a() * scale,
+                    circle.b() * scale,
+                )))
+            }
+            CurveKind::Line(line) => {
+                let [start, _] = edge_range(edge);
+                let offset = normal(edge, start) * distance;
+
+                Ok(CurveKind::Line(Line::from_origin_and_direction(
+                    line.origin() + offset,
+                    line.direction(),
+                )))
+            }
+            CurveKind::Nurbs(_) => {
+                Err(SweepError::NurbsEdge(edge_positions(edge)))
+            }
+        })
+        .collect::<Result<Vec<_>, SweepError>>()?;
+
+    // Continuous edges form a cycle on their own, without any corners.
+    if let [edge] = edges.as_slice() {
+        if let (None, CurveKind::Circle(circle)) =
+            (edge.vertices().get(), &curves[0])
+        {
+            return Ok(vec![circle_edge(surface, *circle, None)]);
+        }
     }
 
-    target.push(Face::from_triangles(side_face));
+    // Points closer than this are considered identical.
+    let epsilon = distance.abs() * Scalar::from_f64(1e-9);
+
+    let corners = (0..edges.len())
+        .map(|i| {
+            let next = (i + 1) % edges.len();
+
+            let [_, t_in] = edge_range(edges[i]);
+            let [t_out, _] = edge_range(edges[next]);
+
+            let from = curves[i].point_from_curve_coords([t_in]);
+            let to = curves[next].point_from_curve_coords([t_out]);
+
+            // If the moved edges still meet, the corner is wherever they do.
+            // Arcs are preferred, so they go through their vertices exactly.
+            let meet = (to - from).magnitude() <= epsilon;
+
+            match (&curves[i], &curves[next]) {
+                (CurveKind::Circle(_), _) if meet => Ok(from),
+                (_, CurveKind::Circle(_)) if meet => Ok(to),
+                (CurveKind::Line(incoming), CurveKind::Line(outgoing)) => {
+                    if meet {
+                        return Ok(from);
+                    }
+
+                    let [dir_in, dir_out] =
+                        [incoming, outgoing].map(|line| line.direction());
+                    let cross = dir_in.u * dir_out.v - dir_in.v * dir_out.u;
+
+                    let between = to - from;
+                    let along =
+                        (between.u * dir_out.v - between.v * dir_out.u) / cross;
+
+                    Ok(from + dir_in * along)
+                }
+                _ => {
+                    let corner =
+                        edges[i].curve().kind().point_from_curve_coords([t_in]);
+                    Err(SweepError::NonTangentCorner(
+                        surface.point_from_surface_coords(corner),
+                    ))
+                }
+            }
+        })
+        .collect::<Result<Vec<_>, SweepError>>()?;
+
+    edges
+        .iter()
+        .zip(&curves)
+        .enumerate()
+        .map(|(i, (edge, curve))| {
+            let start = corners[(i + edges.len() - 1) % edges.len()];
+            let end = corners[i];
+
+            match curve {
+                CurveKind::Circle(circle) => {
+                    let curve =
+                        circle_edge(surface, *circle, None).curve().clone();
+                    let vertices = {
+                        let [t_start, t_end] = edge_range(edge);
+                        [(t_start, start), (t_end, end)]
+                    }
+                    .map(|(t, point)| {
+                        let position = surface.point_from_surface_coords(point);
+                        Vertex::new([t], GlobalVertex::from_position(position))
+                    });
+
+                    Ok(Edge::new(
+                        curve,
+                        VerticesOfEdge::from_vertices(vertices),
+                    ))
+                }
+                CurveKind::Line(_) => {
+                    // The corners of a line segment that shrinks to nothing, or
+                    // past it, swap places.
+                    let [a, b] = edge_range(edge).map(|t| {
+                        edge.curve().kind().point_from_curve_coords([t])
+                    });
+                    if (end - start).dot(&(b - a)) <= Scalar::ZERO {
+                        return Err(SweepError::VanishingEdge(edge_positions(
+                            edge,
+                        )));
+                    }
+
+                    Ok(Edge::build()
+                        .line_segment_from_points(surface, [start, end]))
+                }
+                CurveKind::Nurbs(_) => {
+                    unreachable!("NURBS edges are rejected above")
+                }
+            }
+        })
+        .collect()
 }
 
 #[cfg(test)]
 mod tests {
-    use fj_interop::mesh::Color;
-    use fj_math::{Point, Scalar, Vector};
+    use std::f64::consts::FRAC_PI_2;
+
+    use fj_interop::{debug::DebugInfo, mesh::Color};
+    use fj_math::{BezierCurve, Circle, Point, Scalar, Vector};
 
     use crate::{
-        algorithms::Tolerance,
+        algorithms::{triangulate, SweepError, Tolerance},
         iter::ObjectIters,
-        objects::{Face, Sketch, Surface},
+        objects::{
+            Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, Sketch, Surface,
+            SweptCurve, VerticesOfEdge,
+        },
+        test_utils::volume,
+        validation::{validate, ValidationConfig},
     };
 
     #[test]
@@ -274,6 +781,277 @@ mod tests {
         )
     }
 
+    #[test]
+    fn side_continuous() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        let surface = Surface::xy_plane();
+        let edge = Edge::build().circle_from_radius(Scalar::ONE);
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges([edge])]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        for (direction, expected_b) in
+            [([0., 0., 1.], [0., 1., 0.]), ([0., 0., -1.], [0., -1., 0.])]
+        {
+            let solid = super::sweep(
+                sketch.clone(),
+                direction,
+                Color([255, 0, 0, 255]),
+            );
+
+            let expected_surface = Surface::SweptCurve(SweptCurve {
+                curve: CurveKind::Circle(Circle::new(
+                    [0., 0., 0.],
+                    [1., 0., 0.],
+                    expected_b,
+                )),
+                path: Vector::from(direction),
+            });
+            let side_face = solid
+                .face_iter()
+                .find(|face| face.surface() == &expected_surface)
+                .expect("Expected cylindrical side face");
+            assert!(side_face.triangles().is_none());
+
+            let solid = validate(solid, &ValidationConfig::default())?;
+
+            let faces = solid.into_inner().into_faces().collect();
+            let mesh = triangulate(faces, tolerance, &mut DebugInfo::new());
+            let volume = mesh
+                .triangles()
+                .map(|triangle| {
+                    let [a, b, c] = triangle.inner.points();
+                    a.coords.dot(&b.coords.cross(&c.coords)) / 6.
+                })
+                .fold(Scalar::ZERO, |volume, triangle| volume + triangle);
+            assert!((volume - Scalar::PI).abs() < Scalar::from_f64(0.01));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn side_arc() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        // A half disc, bounded by a line and an arc.
+        let surface = Surface::xy_plane();
+        let line = Edge::build()
+            .line_segment_from_points(&surface, [[-1., 0.], [1., 0.]]);
+        let arc = Edge::build().arc_from_circle_and_points(
+            &surface,
+            Circle::new([0., 0.], [1., 0.], [0., 1.]),
+            [[1., 0.], [-1., 0.]],
+        );
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges([line, arc])]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        for direction in [[0., 0., 1.], [0., 0., -1.]] {
+            let solid = super::sweep(
+                sketch.clone(),
+                direction,
+                Color([255, 0, 0, 255]),
+            );
+
+            let solid = validate(solid, &ValidationConfig::default())?;
+
+            let faces = solid.into_inner().into_faces().collect();
+            let mesh = triangulate(faces, tolerance, &mut DebugInfo::new());
+            let volume = mesh
+                .triangles()
+                .map(|triangle| {
+                    let [a, b, c] = triangle.inner.points();
+                    a.coords.dot(&b.coords.cross(&c.coords)) / 6.
+                })
+                .fold(Scalar::ZERO, |volume, triangle| volume + triangle);
+            assert!((volume - Scalar::PI / 2.).abs() < Scalar::from_f64(0.01));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn side_nurbs() -> anyhow::Result<()> {
+        let tolerance = Tolerance::from_scalar(0.001)?;
+
+        // A parabolic segment, bounded by a line and a quadratic Bézier curve.
+        let surface = Surface::xy_plane();
+        let line = Edge::build()
+            .line_segment_from_points(&surface, [[-1., 0.], [1., 0.]]);
+        let curve = Edge::build().nurbs_from_curve(
+            &surface,
+            BezierCurve::from_control_points([[1., 0.], [0., 2.], [-1., 0.]])
+                .to_nurbs(),
+        );
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges([line, curve])]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        for direction in [[0., 0., 1.], [0., 0., -1.]] {
+            let solid = super::sweep(
+                sketch.clone(),
+                direction,
+                Color([255, 0, 0, 255]),
+            );
+
+            let solid = validate(solid, &ValidationConfig::default())?;
+
+            let faces = solid.into_inner().into_faces().collect();
+            let mesh = triangulate(faces, tolerance, &mut DebugInfo::new());
+            let volume = mesh
+                .triangles()
+                .map(|triangle| {
+                    let [a, b, c] = triangle.inner.points();
+                    a.coords.dot(&b.coords.cross(&c.coords)) / 6.
+                })
+                .fold(Scalar::ZERO, |volume, triangle| volume + triangle);
+            assert!(
+                (volume - Scalar::from(4. / 3.)).abs() < Scalar::from(0.01)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn draft_polygon() -> anyhow::Result<()> {
+        // A square with a circular hole.
+        let surface = Surface::xy_plane();
+        let hole = {
+            let local = Circle::new([0., 0.], [1., 0.], [0., -1.]);
+            let global = Circle::new([0., 0., 0.], [1., 0., 0.], [0., -1., 0.]);
+            let curve = Curve::new(
+                CurveKind::Circle(local),
+                GlobalCurve::from_kind(CurveKind::Circle(global)),
+            );
+            Edge::new(curve, VerticesOfEdge::none())
+        };
+        let face = Face::build(surface.clone())
+            .polygon_from_points([[-2., -2.], [2., -2.], [2., 2.], [-2., 2.]])
+            .into_face()
+            .with_interiors([Cycle::new(surface).with_edges([hole])]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        // Drafting inwards by half a unit shrinks the square to a side length
+        // of 3 and grows the hole to a radius of 1.5.
+        let draft = Scalar::from(0.5).atan2(Scalar::ONE);
+
+        for direction in [[0., 0., 1.], [0., 0., -1.]] {
+            let solid = super::sweep_with_draft(
+                sketch.clone(),
+                direction,
+                draft,
+                Color([255, 0, 0, 255]),
+            )?;
+
+            let square = Scalar::from((16. + 9. + 12.) / 3.);
+            let hole = Scalar::PI * (1. + 1.5 + 2.25) / 3.;
+            assert!(
+                (volume(solid)? - (square - hole)).abs()
+                    < Scalar::from_f64(0.01)
+            );
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn draft_disc() -> anyhow::Result<()> {
+        let surface = Surface::xy_plane();
+        let edge = Edge::build().circle_from_radius(Scalar::ONE);
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges([edge])]);
+        let sketch = Sketch::new().with_faces([face]);
+
+        // Negative draft angles taper the walls outwards.
+        for (draft, top) in [(0.5, 0.5), (-0.5, 1.5)] {
+            let draft = Scalar::from(draft).atan2(Scalar::ONE);
+
+            for direction in [[0., 0., 1.], [0., 0., -1.]] {
+                let solid = super::sweep_with_draft(
+                    sketch.clone(),
+                    direction,
+                    draft,
+                    Color([255, 0, 0, 255]),
+                )?;
+
+                let expected = Scalar::PI * (1. + top + top * top) / 3.;
+                assert!(
+                    (volume(solid)? - expected).abs() < Scalar::from_f64(0.01)
+                );
+            }
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn draft_unsupported() {
+        let surface = Surface::xy_plane();
+        let draft = |faces: Vec<Face>, draft: f64| {
+            super::sweep_with_draft(
+                Sketch::new().with_faces(faces),
+                [0., 0., 1.],
+                draft,
+                Color([255, 0, 0, 255]),
+            )
+        };
+
+        let square = Face::build(surface.clone())
+            .polygon_from_points([[-1., -1.], [1., -1.], [1., 1.], [-1., 1.]])
+            .into_face();
+        let disc = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface.clone())
+                .with_edges([Edge::build().circle_from_radius(Scalar::ONE)])]);
+
+        assert!(matches!(
+            draft(vec![square.clone()], FRAC_PI_2),
+            Err(SweepError::InvalidDraft(_))
+        ));
+
+        // Drafting by a radian moves the edges by more than half the width of
+        // the faces.
+        for face in [square, disc] {
+            assert!(matches!(
+                draft(vec![face], 1.),
+                Err(SweepError::VanishingEdge(_))
+            ));
+        }
+
+        let half_disc =
+            Face::new(surface.clone()).with_exteriors([Cycle::new(
+                surface.clone(),
+            )
+            .with_edges([
+                Edge::build().arc_from_circle_and_points(
+                    &surface,
+                    Circle::new([0., 0.], [1., 0.], [0., 1.]),
+                    [[1., 0.], [-1., 0.]],
+                ),
+                Edge::build()
+                    .line_segment_from_points(&surface, [[-1., 0.], [1., 0.]]),
+            ])]);
+        assert!(matches!(
+            draft(vec![half_disc], 0.1),
+            Err(SweepError::NonTangentCorner(_))
+        ));
+
+        let nurbs = Face::new(surface.clone()).with_exteriors([Cycle::new(
+            surface.clone(),
+        )
+        .with_edges([Edge::build().nurbs_from_curve(
+            &surface,
+            Circle::new([0., 0.], [1., 0.], [0., 1.])
+                .to_nurbs([Scalar::ZERO, Scalar::PI * 2.]),
+        )])]);
+        assert!(matches!(
+            draft(vec![nurbs], 0.1),
+            Err(SweepError::NurbsEdge(_))
+        ));
+    }
+
     fn test_side(
         direction: impl Into<Vector<3>>,
         expected_surfaces: [[impl Into<Point<3>>; 3]; 3],
@@ -298,8 +1076,6 @@ mod tests {
         expected_surfaces: impl IntoIterator<Item = [impl Into<Point<3>>; 3]>,
         expected_vertices: impl IntoIterator<Item = impl Into<Point<2>>>,
     ) -> anyhow::Result<()> {
-        let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
-
         let surface = Surface::xy_plane();
         let face = Face::build(surface).polygon_from_points([
             [0., 0.],
@@ -308,8 +1084,7 @@ mod tests {
         ]);
         let sketch = Sketch::new().with_faces([face]);
 
-        let solid =
-            super::sweep(sketch, direction, tolerance, Color([255, 0, 0, 255]));
+        let solid = super::sweep(sketch, direction, Color([255, 0, 0, 255]));
 
         let expected_vertices: Vec<_> = expected_vertices
             .into_iter()
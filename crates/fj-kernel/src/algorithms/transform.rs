@@ -1,10 +1,12 @@
-use fj_math::{Transform, Vector};
+use fj_math::{Line, NurbsSurface, Point, Scalar, Transform, Triangle, Vector};
 
 use crate::objects::{
-    Curve, Cycle, Edge, Face, GlobalCurve, GlobalVertex, Sketch, Solid,
-    Surface, Vertex,
+    Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, GlobalVertex, Sketch,
+    Solid, Surface, SweptCurve, Vertex, VerticesOfEdge,
 };
 
+use super::reverse_face;
+
 /// Transform an object
 ///
 /// # Implementation Note
@@ -14,6 +16,18 @@ use crate::objects::{
 ///
 /// More convenience methods can be added as required. The only reason this
 /// hasn't been done so far, is that no one has put in the work yet.
+///
+/// # Distorting Transforms
+///
+/// Transforms that aren't similarities, like non-uniform scaling, turn
+/// circles into ellipses, and cylinders, spheres, and tori into surfaces that
+/// those types can't represent. Such geometry is converted into NURBS, which
+/// describes it exactly, before it is transformed.
+///
+/// The conversion changes the curve and surface coordinates, so it is best
+/// done on a whole [`Face`], whose edges are updated along with the surface.
+/// Edges of curved surfaces must run along iso-curves of the surface, as the
+/// edges of all faces that the kernel creates do.
 pub trait TransformObject: Sized {
     /// Transform the object
     #[must_use]
@@ -35,28 +49,39 @@ pub trait TransformObject: Sized {
 impl TransformObject for Curve {
     fn transform(self, transform: &Transform) -> Self {
         // Don't need to transform `self.kind`, as that's in local form.
-        let global = self.global().transform(transform);
+        let global = self.global().clone().transform(transform);
 
-        Curve::new(*self.kind(), global)
+        Curve::new(self.kind().clone(), global)
     }
 }
 
 impl TransformObject for Cycle {
     fn transform(self, transform: &Transform) -> Self {
-        Self::new(self.surface().transform(transform))
-            .with_edges(self.into_edges().map(|edge| edge.transform(transform)))
+        let cycle = if transform.is_similarity() {
+            self
+        } else {
+            let range = nurbs_range(self.surface(), [&self]);
+            cycle_to_nurbs(&self, range)
+        };
+
+        Self::new(cycle.surface().clone().transform(transform)).with_edges(
+            cycle.into_edges().map(|edge| edge.transform(transform)),
+        )
     }
 }
 
 impl TransformObject for Edge {
     fn transform(self, transform: &Transform) -> Self {
-        let curve = Curve::new(
-            *self.curve().kind(),
-            self.curve().global().transform(transform),
-        );
+        let edge = if transform.is_similarity() {
+            self
+        } else {
+            edge_to_nurbs(&self)
+        };
+
+        let curve = edge.curve().clone().transform(transform);
 
         let vertices =
-            self.vertices().map(|vertex| vertex.transform(transform));
+            edge.vertices().map(|vertex| vertex.transform(transform));
 
         Self::new(curve, vertices)
     }
@@ -68,30 +93,51 @@ impl TransformObject for Face {
             let mut target = Vec::new();
 
             for (triangle, color) in triangles.clone() {
-                let triangle = transform.transform_triangle(&triangle);
+                let mut triangle = transform.transform_triangle(&triangle);
+
+                if is_reflection(transform) {
+                    let [a, b, c] = triangle.points();
+                    triangle = Triangle::from_points([a, c, b])
+                        .expect("Reordering points keeps triangle valid");
+                }
+
                 target.push((triangle, color));
             }
 
             return Self::from_triangles(target);
         }
 
-        let surface = self.surface().transform(transform);
+        let face = if transform.is_similarity() {
+            self
+        } else {
+            face_to_nurbs(&self)
+        };
+
+        let surface = face.surface().clone().transform(transform);
 
-        let exteriors = transform_cycles(self.exteriors(), transform);
-        let interiors = transform_cycles(self.interiors(), transform);
+        let exteriors = transform_cycles(face.exteriors(), transform);
+        let interiors = transform_cycles(face.interiors(), transform);
 
-        let color = self.color();
+        let color = face.color();
 
-        Face::new(surface)
+        let face = Face::new(surface)
             .with_exteriors(exteriors)
             .with_interiors(interiors)
-            .with_color(color)
+            .with_color(color);
+
+        // A reflection turns the face inside out. Reverse it, to keep it
+        // pointing outwards.
+        if is_reflection(transform) {
+            return reverse_face(&face);
+        }
+
+        face
     }
 }
 
 impl TransformObject for GlobalCurve {
     fn transform(self, transform: &Transform) -> Self {
-        let kind = self.kind().transform(transform);
+        let kind = self.kind().clone().transform(transform);
         GlobalCurve::from_kind(kind)
     }
 }
@@ -119,10 +165,25 @@ impl TransformObject for Solid {
 
 impl TransformObject for Surface {
     fn transform(self, transform: &Transform) -> Self {
-        match self {
+        // Without any edges to go by, the whole surface is converted.
+        let surface = if transform.is_similarity() {
+            self
+        } else {
+            match nurbs_range(&self, []) {
+                Some(range) => Self::Nurbs(self.to_nurbs(range)),
+                None => self,
+            }
+        };
+
+        match surface {
+            Self::Nurbs(surface) => {
+                Self::Nurbs(transform.transform_nurbs_surface(&surface))
+            }
+            Self::Sphere(surface) => Self::Sphere(surface.transform(transform)),
             Self::SweptCurve(surface) => {
                 Self::SweptCurve(surface.transform(transform))
             }
+            Self::Torus(surface) => Self::Torus(surface.transform(transform)),
         }
     }
 }
@@ -140,6 +201,10 @@ pub fn transform_faces(faces: &mut Vec<Face>, transform: &Transform) {
     }
 }
 
+fn is_reflection(transform: &Transform) -> bool {
+    transform.determinant() < Scalar::ZERO
+}
+
 fn transform_cycles<'a>(
     cycles: impl IntoIterator<Item = &'a Cycle> + 'a,
     transform: &'a Transform,
@@ -148,3 +213,252 @@ fn transform_cycles<'a>(
         .into_iter()
         .map(|cycle| cycle.clone().transform(transform))
 }
+
+/// Convert the curved geometry of a face into NURBS
+///
+/// All cycles of the face share the same NURBS surface, which covers all of
+/// them.
+fn face_to_nurbs(face: &Face) -> Face {
+    let range = nurbs_range(face.surface(), face.all_cycles());
+    let surface = match range {
+        Some(range) => Surface::Nurbs(face.surface().to_nurbs(range)),
+        None => face.surface().clone(),
+    };
+
+    let convert = |cycle| cycle_to_nurbs(cycle, range);
+    Face::new(surface)
+        .with_exteriors(face.exteriors().map(convert))
+        .with_interiors(face.interiors().map(convert))
+        .with_color(face.color())
+}
+
+/// Convert the curved geometry of a cycle into NURBS
+///
+/// If `range` is `Some`, the surface of the cycle is converted into a NURBS
+/// surface that covers that range, along with the edges on it. Otherwise, the
+/// surface stays as it is, and only the edges are converted.
+fn cycle_to_nurbs(cycle: &Cycle, range: Option<[[Scalar; 2]; 2]>) -> Cycle {
+    match range {
+        Some(range) => {
+            let surface = cycle.surface().to_nurbs(range);
+            let edges = cycle.edges().map(|edge| {
+                iso_edge_to_nurbs(edge, cycle.surface(), range, &surface)
+            });
+
+            Cycle::new(Surface::Nurbs(surface.clone())).with_edges(edges)
+        }
+        None => Cycle::new(cycle.surface().clone())
+            .with_edges(cycle.edges().map(edge_to_nurbs)),
+    }
+}
+
+/// Convert the curves of an edge on a flat surface into NURBS
+///
+/// Only circles need to be converted. The local and global form are converted
+/// over the same range, so the curve coordinates of the NURBS curves still
+/// match. The edge then covers the whole domain of those.
+fn edge_to_nurbs(edge: &Edge) -> Edge {
+    let (local, global) =
+        match (edge.curve().kind(), edge.curve().global().kind()) {
+            (CurveKind::Circle(local), CurveKind::Circle(global)) => {
+                (local, global)
+            }
+            _ => return edge.clone(),
+        };
+
+    // An edge without vertices is a full circle.
+    let range = match edge.vertices().get() {
+        Some(vertices) => vertices.map(|vertex| vertex.position().t),
+        None => [Scalar::ZERO, Scalar::PI * 2.],
+    };
+
+    let curve = Curve::new(
+        CurveKind::Nurbs(local.to_nurbs(range)),
+        GlobalCurve::from_kind(CurveKind::Nurbs(global.to_nurbs(range))),
+    );
+    let vertices = VerticesOfEdge::new(edge.vertices().get().map(|[a, b]| {
+        [
+            Vertex::new([0.], *a.global()),
+            Vertex::new([1.], *b.global()),
+        ]
+    }));
+
+    Edge::new(curve, vertices)
+}
+
+/// Convert an edge on a curved surface into an edge on its NURBS form
+///
+/// The edge must run along an iso-curve of `surface`. On the NURBS surface,
+/// that becomes an iso-curve too, which defines the global form of the edge.
+///
+/// # Panics
+///
+/// Panics, if the edge doesn't run along an iso-curve, as there's no exact
+/// representation for it on the NURBS surface.
+fn iso_edge_to_nurbs(
+    edge: &Edge,
+    surface: &Surface,
+    range: [[Scalar; 2]; 2],
+    nurbs: &NurbsSurface<3>,
+) -> Edge {
+    let line = match edge.curve().kind() {
+        CurveKind::Line(line) => line,
+        _ => panic!("Only edges along iso-curves can be converted to NURBS"),
+    };
+    let direction = line.direction();
+    let to_nurbs = |t: Point<1>| {
+        surface.point_to_nurbs_coords(range, line.point_from_line_coords(t))
+    };
+
+    // Along u, the v-coordinate is constant, and vice versa. The local form
+    // of the edge goes along the NURBS surface with unit speed, so its curve
+    // coordinates are those of the iso-curve.
+    let origin = to_nurbs(Point::from([0.]));
+    let (i, origin, unit, global) = if direction.v == Scalar::ZERO {
+        let global = nurbs.curve_along_u(origin.v);
+        (0, Point::from([Scalar::ZERO, origin.v]), [1., 0.], global)
+    } else if direction.u == Scalar::ZERO {
+        let global = nurbs.curve_along_v(origin.u);
+        (1, Point::from([origin.u, Scalar::ZERO]), [0., 1.], global)
+    } else {
+        panic!("Only edges along iso-curves can be converted to NURBS");
+    };
+
+    // Keep the direction of the edge, by reversing the curves, if necessary.
+    let (sign, unit, global) = if direction.components[i] < Scalar::ZERO {
+        (-Scalar::ONE, -Vector::from(unit), global.reverse())
+    } else {
+        (Scalar::ONE, Vector::from(unit), global)
+    };
+
+    let curve = Curve::new(
+        CurveKind::Line(Line::from_origin_and_direction(origin, unit)),
+        GlobalCurve::from_kind(CurveKind::Nurbs(global)),
+    );
+    let vertices = edge.vertices().map(|vertex| {
+        let t = to_nurbs(vertex.position()).coords.components[i] * sign;
+        Vertex::new([t], *vertex.global())
+    });
+
+    Edge::new(curve, vertices)
+}
+
+/// Compute the range of surface coordinates that a surface's NURBS form covers
+///
+/// Returns `None`, if the surface doesn't need to be converted to NURBS, which
+/// is the case for planes and surfaces that are already NURBS. Otherwise, the
+/// range covers all edges of `cycles`, or the whole surface, if there are no
+/// edges.
+///
+/// # Panics
+///
+/// Panics, if any of the edges has no vertices. On a curved surface, an edge
+/// without vertices is not bounded in surface coordinates.
+fn nurbs_range<'a>(
+    surface: &Surface,
+    cycles: impl IntoIterator<Item = &'a Cycle>,
+) -> Option<[[Scalar; 2]; 2]> {
+    let full_turn = [Scalar::ZERO, Scalar::PI * 2.];
+    let whole_surface = match surface {
+        Surface::Sphere(_) => [full_turn, [-Scalar::PI / 2., Scalar::PI / 2.]],
+        Surface::SweptCurve(SweptCurve {
+            curve: CurveKind::Circle(_),
+            ..
+        }) => [full_turn, [Scalar::ZERO, Scalar::ONE]],
+        Surface::Torus(_) => [full_turn, full_turn],
+        _ => return None,
+    };
+
+    let points: Vec<_> = cycles
+        .into_iter()
+        .flat_map(Cycle::edges)
+        .flat_map(|edge| {
+            let vertices = edge.vertices().get().expect(
+                "Can't convert edge without vertices on a curved surface",
+            );
+            vertices.map(|vertex| {
+                edge.curve()
+                    .kind()
+                    .point_from_curve_coords(vertex.position())
+            })
+        })
+        .collect();
+    if points.is_empty() {
+        return Some(whole_surface);
+    }
+
+    let range = |coord: fn(&Point<2>) -> Scalar| {
+        let coords = points.iter().map(coord);
+        [coords.clone().min(), coords.max()].map(Option::unwrap)
+    };
+    Some([range(|point| point.u), range(|point| point.v)])
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::mesh::Color;
+    use fj_math::{Scalar, Transform};
+
+    use crate::{
+        algorithms::sweep,
+        objects::{Cycle, Edge, Face, Sketch, Solid, Surface},
+        test_utils::volume,
+    };
+
+    use super::TransformObject;
+
+    #[test]
+    fn reflection() -> anyhow::Result<()> {
+        let surface = Surface::xy_plane();
+        let square = Face::build(surface.clone())
+            .polygon_from_points([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])
+            .into_face();
+        let circle = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface)
+                .with_edges([Edge::build().circle_from_radius(Scalar::ONE)])]);
+
+        for face in [square, circle] {
+            let solid = sweep(
+                Sketch::new().with_faces([face]),
+                [0., 0., 1.],
+                Color::default(),
+            );
+            let expected = volume(solid.clone())?;
+
+            let mirrored =
+                solid.transform(&Transform::reflection([1., 0., 0.]));
+            assert!((volume(mirrored)? - expected).abs() < Scalar::from(1e-6));
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn non_uniform_scaling() -> anyhow::Result<()> {
+        // The solids are small, to keep their approximations cheap.
+        let r = Scalar::from(0.2);
+        let solids = [
+            (
+                Solid::build().cylinder_from_radius_and_height(r, r),
+                Scalar::PI * r * r * r,
+            ),
+            (
+                Solid::build().sphere_from_radius(r),
+                Scalar::PI * r * r * r * 4. / 3.,
+            ),
+            (
+                Solid::build().torus_from_radii(r * 2., r),
+                Scalar::PI * Scalar::PI * r * r * r * 4.,
+            ),
+        ];
+
+        for (solid, volume_before) in solids {
+            let scaled = solid.transform(&Transform::scaling([1., 2., 3.]));
+
+            let expected = volume_before * 6.;
+            assert!((volume(scaled)? - expected).abs() < expected * 0.01);
+        }
+
+        Ok(())
+    }
+}
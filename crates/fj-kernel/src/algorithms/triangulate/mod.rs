@@ -2,7 +2,7 @@ mod delaunay;
 mod polygon;
 
 use fj_interop::{debug::DebugInfo, mesh::Mesh};
-use fj_math::Point;
+use fj_math::{Point, Triangle};
 
 use crate::objects::Face;
 
@@ -30,7 +30,7 @@ pub fn triangulate(
         let approx = FaceApprox::new(&face, tolerance);
 
         let points: Vec<_> = approx.points.into_iter().collect();
-        let face_as_polygon = Polygon::new(*surface)
+        let face_as_polygon = Polygon::new(surface.clone())
             .with_exterior(
                 approx
                     .exterior
@@ -52,7 +52,13 @@ pub fn triangulate(
 
         for triangle in triangles {
             let points = triangle.map(|point| *point.global_form());
-            mesh.push_triangle(points, face.color());
+
+            // Triangles that touch a point where the surface degenerates, like
+            // the pole of a sphere, can collapse into a line. They don't
+            // contribute anything to the mesh.
+            if let Some(triangle) = Triangle::from_points(points) {
+                mesh.push_triangle(triangle, face.color());
+            }
         }
     }
 
@@ -61,12 +67,17 @@ pub fn triangulate(
 
 #[cfg(test)]
 mod tests {
+    use std::f64::consts::{FRAC_1_SQRT_2, FRAC_PI_4, PI};
+
     use fj_interop::{debug::DebugInfo, mesh::Mesh};
-    use fj_math::{Point, Scalar};
+    use fj_math::{Circle, Line, NurbsSurface, Point, Scalar, Vector};
 
     use crate::{
         algorithms::Tolerance,
-        objects::{Face, Surface},
+        objects::{
+            Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, GlobalVertex,
+            Sphere, Surface, Torus, Vertex, VerticesOfEdge,
+        },
     };
 
     #[test]
@@ -176,6 +187,282 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn sphere_band() -> anyhow::Result<()> {
+        let sphere = Sphere::from_center_and_radius([0., 0., 0.], 1.);
+        let [a, b, c] = [sphere.a, sphere.b, sphere.c];
+
+        // A band around the equator, between 45° south and 45° north.
+        let latitude = FRAC_PI_4;
+        let (sin, cos) = latitude.sin_cos();
+
+        let south = Circle::new(sphere.center - c * sin, a * cos, b * cos);
+        let north = Circle::new(sphere.center + c * sin, a * cos, -b * cos);
+        let meridian = Circle::new(sphere.center, a, c);
+        let meridian_reversed = Circle::new(sphere.center, a, -c);
+
+        let bottom =
+            GlobalVertex::from_position(south.point_from_circle_coords([0.]));
+        let top =
+            GlobalVertex::from_position(north.point_from_circle_coords([0.]));
+
+        let full_turn = PI * 2.;
+        let face = face_bounded_by_circles(
+            Surface::Sphere(sphere),
+            [
+                (
+                    [0., -latitude],
+                    [1., 0.],
+                    south,
+                    [0., full_turn],
+                    [bottom; 2],
+                ),
+                (
+                    [full_turn, 0.],
+                    [0., 1.],
+                    meridian,
+                    [-latitude, latitude],
+                    [bottom, top],
+                ),
+                (
+                    [full_turn, latitude],
+                    [-1., 0.],
+                    north,
+                    [0., full_turn],
+                    [top; 2],
+                ),
+                (
+                    [0., 0.],
+                    [0., -1.],
+                    meridian_reversed,
+                    [-latitude, latitude],
+                    [top, bottom],
+                ),
+            ],
+        );
+
+        let triangles = triangulate_with_tolerance(face, 0.01)?;
+
+        let mut area = Scalar::ZERO;
+        for triangle in triangles.triangles() {
+            let [a, b, c] = triangle.inner.points();
+            let normal = (b - a).cross(&(c - a));
+
+            // The normal of the sphere points outward.
+            assert!(normal.dot(&(a - sphere.center)) > Scalar::ZERO);
+
+            area += normal.magnitude() / 2.;
+        }
+
+        let expected = Scalar::from(full_turn * sin * 2.);
+        assert!((area - expected).abs() < expected * 0.03);
+
+        Ok(())
+    }
+
+    #[test]
+    fn torus() -> anyhow::Result<()> {
+        let torus = Torus::from_center_and_radii([0., 0., 0.], 2., 1.);
+
+        let outer = Circle::new([0., 0., 0.], [3., 0., 0.], [0., 3., 0.]);
+        let outer_reversed =
+            Circle::new([0., 0., 0.], [3., 0., 0.], [0., -3., 0.]);
+        let tube = Circle::new([2., 0., 0.], [1., 0., 0.], [0., 0., 1.]);
+        let tube_reversed =
+            Circle::new([2., 0., 0.], [1., 0., 0.], [0., 0., -1.]);
+
+        let corner = GlobalVertex::from_position([3., 0., 0.]);
+
+        let full_turn = PI * 2.;
+        let range = [0., full_turn];
+        let face = face_bounded_by_circles(
+            Surface::Torus(torus),
+            [
+                ([0., 0.], [1., 0.], outer, range, [corner; 2]),
+                ([full_turn, 0.], [0., 1.], tube, range, [corner; 2]),
+                (
+                    [full_turn, full_turn],
+                    [-1., 0.],
+                    outer_reversed,
+                    range,
+                    [corner; 2],
+                ),
+                (
+                    [0., full_turn],
+                    [0., -1.],
+                    tube_reversed,
+                    range,
+                    [corner; 2],
+                ),
+            ],
+        );
+
+        let triangles = triangulate_with_tolerance(face, 0.01)?;
+
+        // The torus is closed, and its normal points outward, so we can
+        // compute its volume from the triangles.
+        let volume = triangles
+            .triangles()
+            .map(|triangle| {
+                let [a, b, c] = triangle.inner.points();
+                a.coords.dot(&b.coords.cross(&c.coords)) / 6.
+            })
+            .fold(Scalar::ZERO, |volume, triangle| volume + triangle);
+
+        let expected = Scalar::PI * Scalar::PI * 4.;
+        assert!((volume - expected).abs() < expected * 0.03);
+
+        Ok(())
+    }
+
+    #[test]
+    fn nurbs_surface() -> anyhow::Result<()> {
+        // A quarter of a cylinder with radius 1 and height 1.
+        let surface = NurbsSurface::new(
+            [2, 1],
+            [
+                [[1., 0., 0.], [1., 0., 1.]],
+                [[1., 1., 0.], [1., 1., 1.]],
+                [[0., 1., 0.], [0., 1., 1.]],
+            ],
+            [[1., 1.], [FRAC_1_SQRT_2, FRAC_1_SQRT_2], [1., 1.]],
+            [
+                [0., 0., 0., 1., 1., 1.].map(Scalar::from).to_vec(),
+                [0., 0., 1., 1.].map(Scalar::from).to_vec(),
+            ],
+        );
+
+        let [a, b, c, d] =
+            [[0., 0.], [1., 0.], [1., 1.], [0., 1.]].map(|point| {
+                GlobalVertex::from_position(
+                    surface.point_from_surface_coords(point),
+                )
+            });
+
+        // The edges follow the iso-curves of the surface, so their curve
+        // coordinates are the same locally and globally. The top and left
+        // edges run backwards along those curves.
+        let edges = [
+            (
+                [0., 0.],
+                [1., 0.],
+                surface.curve_along_u(0.),
+                [0., 1.],
+                [a, b],
+            ),
+            (
+                [1., 0.],
+                [0., 1.],
+                surface.curve_along_v(1.),
+                [0., 1.],
+                [b, c],
+            ),
+            (
+                [0., 1.],
+                [1., 0.],
+                surface.curve_along_u(1.),
+                [1., 0.],
+                [c, d],
+            ),
+            (
+                [0., 0.],
+                [0., 1.],
+                surface.curve_along_v(0.),
+                [1., 0.],
+                [d, a],
+            ),
+        ]
+        .map(|(origin, direction, global, range, vertices)| {
+            let [a, b] = range;
+            let [vertex_a, vertex_b] = vertices;
+
+            Edge::new(
+                Curve::new(
+                    CurveKind::Line(Line::from_origin_and_direction(
+                        Point::from(origin),
+                        Vector::from(direction),
+                    )),
+                    GlobalCurve::from_kind(CurveKind::Nurbs(global)),
+                ),
+                VerticesOfEdge::from_vertices([
+                    Vertex::new(Point::from([a]), vertex_a),
+                    Vertex::new(Point::from([b]), vertex_b),
+                ]),
+            )
+        });
+
+        let surface = Surface::Nurbs(surface);
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges(edges)]);
+
+        let triangles = triangulate_with_tolerance(face, 0.01)?;
+
+        let mut area = Scalar::ZERO;
+        for triangle in triangles.triangles() {
+            let [a, b, c] = triangle.inner.points();
+            let normal = (b - a).cross(&(c - a));
+
+            // The normal of the cylinder points away from its axis.
+            let outward = Vector::from([a.x, a.y, Scalar::ZERO]);
+            assert!(normal.dot(&outward) > Scalar::ZERO);
+
+            area += normal.magnitude() / 2.;
+        }
+
+        let expected = Scalar::PI / 2.;
+        assert!((area - expected).abs() < expected * 0.03);
+
+        Ok(())
+    }
+
+    /// An edge that is a line in surface coordinates, and a circle (or a part
+    /// of it) in global coordinates
+    ///
+    /// Consists of the origin and direction of the line, the circle, the range
+    /// of the edge in curve coordinates, and the vertices that bound it.
+    type CircleEdge =
+        ([f64; 2], [f64; 2], Circle<3>, [f64; 2], [GlobalVertex; 2]);
+
+    fn face_bounded_by_circles<const N: usize>(
+        surface: Surface,
+        edges: [CircleEdge; N],
+    ) -> Face {
+        let edges =
+            edges.map(|(origin, direction, circle, range, vertices)| {
+                let local = Line::from_origin_and_direction(
+                    Point::from(origin),
+                    Vector::from(direction),
+                );
+
+                let [a, b] = range;
+                let [vertex_a, vertex_b] = vertices;
+
+                Edge::new(
+                    Curve::new(
+                        CurveKind::Line(local),
+                        GlobalCurve::from_kind(CurveKind::Circle(circle)),
+                    ),
+                    VerticesOfEdge::from_vertices([
+                        Vertex::new(Point::from([a]), vertex_a),
+                        Vertex::new(Point::from([b]), vertex_b),
+                    ]),
+                )
+            });
+
+        Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges(edges)])
+    }
+
+    fn triangulate_with_tolerance(
+        face: Face,
+        tolerance: f64,
+    ) -> anyhow::Result<Mesh<Point<3>>> {
+        let tolerance = Tolerance::from_scalar(tolerance)?;
+
+        let mut debug_info = DebugInfo::new();
+        Ok(super::triangulate(vec![face], tolerance, &mut debug_info))
+    }
+
     fn triangulate(face: impl Into<Face>) -> anyhow::Result<Mesh<Point<3>>> {
         let tolerance = Tolerance::from_scalar(Scalar::ONE)?;
 
@@ -1,4 +1,4 @@
-use fj_math::{Circle, Line, Point, Scalar, Vector};
+use fj_math::{Circle, Line, NurbsCurve, Point, Scalar, Vector};
 
 use crate::objects::{
     Curve, CurveKind, Edge, GlobalCurve, GlobalVertex, Surface, Vertex,
@@ -29,6 +29,95 @@ impl EdgeBuilder {
         )
     }
 
+    /// Create an arc from a circle and the two points that bound it
+    ///
+    /// The arc starts at the first point and follows the direction of the
+    /// circle, until it reaches the second point. Both points are expected to
+    /// lie on the circle.
+    ///
+    /// The circle is defined in the coordinates of `surface`, which is expected
+    /// to be a plane.
+    pub fn arc_from_circle_and_points(
+        &self,
+        surface: &Surface,
+        circle: Circle<2>,
+        points: [impl Into<Point<2>>; 2],
+    ) -> Edge {
+        let points = points.map(Into::into);
+
+        let global_vertices = points.map(|position| {
+            let position = surface.point_from_surface_coords(position);
+            GlobalVertex::from_position(position)
+        });
+
+        let curve_local = CurveKind::Circle(circle);
+        let curve_global = {
+            let center = surface.point_from_surface_coords(circle.center());
+            let [a, b] = [circle.a(), circle.b()]
+                .map(|vector| surface.vector_from_surface_coords(vector));
+            GlobalCurve::from_kind(CurveKind::Circle(Circle::new(center, a, b)))
+        };
+
+        let vertices = {
+            let [start, end] =
+                points.map(|point| circle.point_to_circle_coords(point).t);
+
+            // The arc must always go forward along the circle, so the end may
+            // need to wrap around.
+            let end = if end <= start {
+                end + Scalar::PI * 2.
+            } else {
+                end
+            };
+
+            let [a, b] = global_vertices;
+            [
+                Vertex::new(Point::from([start]), a),
+                Vertex::new(Point::from([end]), b),
+            ]
+        };
+
+        Edge::new(
+            Curve::new(curve_local, curve_global),
+            VerticesOfEdge::from_vertices(vertices),
+        )
+    }
+
+    /// Create an edge from a NURBS curve
+    ///
+    /// The edge covers the whole domain of the curve. The curve is defined in
+    /// the coordinates of `surface`, which is expected to be a plane.
+    pub fn nurbs_from_curve(
+        &self,
+        surface: &Surface,
+        curve: NurbsCurve<2>,
+    ) -> Edge {
+        let curve_global = {
+            let control_points = curve
+                .control_points()
+                .iter()
+                .map(|&point| surface.point_from_surface_coords(point));
+            let curve = NurbsCurve::new(
+                curve.degree(),
+                control_points,
+                curve.weights().iter().copied(),
+                curve.knots().iter().copied(),
+            );
+            GlobalCurve::from_kind(CurveKind::Nurbs(curve))
+        };
+
+        let vertices = curve.domain().map(|t| {
+            let position = surface
+                .point_from_surface_coords(curve.point_from_curve_coords([t]));
+            Vertex::new(Point::from([t]), GlobalVertex::from_position(position))
+        });
+
+        Edge::new(
+            Curve::new(CurveKind::Nurbs(curve), curve_global),
+            VerticesOfEdge::from_vertices(vertices),
+        )
+    }
+
     /// Create a line segment from two points
     pub fn line_segment_from_points(
         &self,
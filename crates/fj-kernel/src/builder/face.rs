@@ -22,10 +22,10 @@ impl FaceBuilder {
         &self,
         points: impl IntoIterator<Item = impl Into<Point<2>>>,
     ) -> FacePolygon {
-        let face = Face::new(self.surface)
-            .with_exteriors([
-                Cycle::build(self.surface).polygon_from_points(points)
-            ]);
+        let face =
+            Face::new(self.surface.clone())
+                .with_exteriors([Cycle::build(self.surface.clone())
+                    .polygon_from_points(points)]);
 
         FacePolygon { face }
     }
@@ -43,7 +43,7 @@ impl FacePolygon {
         mut self,
         points: impl IntoIterator<Item = impl Into<Point<2>>>,
     ) -> Self {
-        let surface = *self.face.surface();
+        let surface = self.face.surface().clone();
         self.face = self.face.with_interiors([
             Cycle::build(surface).polygon_from_points(points)
         ]);
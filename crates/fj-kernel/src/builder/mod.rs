@@ -13,3 +13,5 @@ pub use self::{
     face::{FaceBuilder, FacePolygon},
     solid::SolidBuilder,
 };
+
+pub(crate) use self::solid::face_bounded_by_iso_curves;
@@ -1,8 +1,12 @@
-use fj_math::Scalar;
+use fj_interop::mesh::Color;
+use fj_math::{Circle, Line, NurbsCurve, NurbsSurface, Point, Scalar, Vector};
 
 use crate::{
-    algorithms::TransformObject,
-    objects::{Face, Solid, Surface},
+    algorithms::{reverse_face, sweep, TransformObject},
+    objects::{
+        Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, GlobalVertex, Sketch,
+        Solid, Sphere, Surface, Torus, Vertex, VerticesOfEdge,
+    },
 };
 
 /// API for building a [`Solid`]
@@ -30,9 +34,524 @@ impl SolidBuilder {
             Surface::yz_plane().translate([h, Z, Z]),  // right
         ];
 
+        // Some of the planes face inwards. Their faces need to be reversed.
+        let is_inwards = [true, false, false, true, true, false];
         let faces =
-            planes.map(|plane| Face::build(plane).polygon_from_points(points));
+            planes
+                .into_iter()
+                .zip(is_inwards)
+                .map(|(plane, is_inwards)| {
+                    let face = Face::build(plane).polygon_from_points(points);
+
+                    if is_inwards {
+                        reverse_face(&face)
+                    } else {
+                        face.into_face()
+                    }
+                });
+
+        Solid::new().with_faces(faces)
+    }
+
+    /// Create a cone from the radii of its ends and its height
+    ///
+    /// The axis of the cone is the z-axis. Its bottom is a disc on the xy-plane
+    /// with the first radius, its top a disc at `height` with the second. If
+    /// the second radius is zero, the cone ends in an apex instead.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the bottom radius or the height is not positive, or if the
+    /// top radius is negative.
+    pub fn cone_from_radii_and_height(
+        &self,
+        radii: [impl Into<Scalar>; 2],
+        height: impl Into<Scalar>,
+    ) -> Solid {
+        let [bottom, top] = radii.map(Into::into);
+        let height = height.into();
+
+        assert!(bottom > Scalar::ZERO, "Bottom radius must be positive");
+        assert!(top >= Scalar::ZERO, "Top radius must not be negative");
+        assert!(height > Scalar::ZERO, "Height must be positive");
+
+        let circle = Circle::new(
+            Point::origin(),
+            Vector::from([bottom, Scalar::ZERO]),
+            Vector::from([Scalar::ZERO, bottom]),
+        )
+        .to_nurbs([Scalar::ZERO, Scalar::PI * 2.]);
+
+        // The lateral surface is linear between the bottom circle and the top
+        // one, which is the bottom circle, scaled down and lifted up.
+        let scale = top / bottom;
+        let surface = NurbsSurface::new(
+            [circle.degree(), 1],
+            circle.control_points().iter().map(|point| {
+                [
+                    Point::from([point.u, point.v, Scalar::ZERO]),
+                    Point::from([point.u * scale, point.v * scale, height]),
+                ]
+            }),
+            circle.weights().iter().map(|&weight| [weight; 2]),
+            [
+                circle.knots().to_vec(),
+                [0., 0., 1., 1.].map(Scalar::from).to_vec(),
+            ],
+        );
+
+        let [[_, end], _] = surface.domain();
+        let end = end.into_f64();
+        let [a, b, c, d] =
+            [[0., 0.], [end, 0.], [end, 1.], [0., 1.]].map(Point::from);
+
+        let bottom_vertex =
+            GlobalVertex::from_position([bottom, Scalar::ZERO, Scalar::ZERO]);
+        let top_vertex =
+            GlobalVertex::from_position([top, Scalar::ZERO, height]);
+
+        let nurbs = |curve| GlobalCurve::from_kind(CurveKind::Nurbs(curve));
+        let [bottom_curve, top_curve] =
+            [0., 1.].map(|v| nurbs(surface.curve_along_u(v)));
+
+        let lateral = face_bounded_by_iso_curves(
+            Surface::Nurbs(surface.clone()),
+            [
+                ([a, b], bottom_curve.clone(), [0., end], [bottom_vertex; 2]),
+                (
+                    [b, c],
+                    nurbs(surface.curve_along_v(end)),
+                    [0., 1.],
+                    [bottom_vertex, top_vertex],
+                ),
+                ([c, d], top_curve.clone(), [end, 0.], [top_vertex; 2]),
+                (
+                    [d, a],
+                    nurbs(surface.curve_along_v(0.)),
+                    [1., 0.],
+                    [top_vertex, bottom_vertex],
+                ),
+            ],
+        );
+
+        // The discs share their edges with the lateral face.
+        let disc = |surface: Surface, scale: Scalar, global, vertex| {
+            let local = NurbsCurve::new(
+                circle.degree(),
+                circle
+                    .control_points()
+                    .iter()
+                    .map(|&point| Point::origin() + point.coords * scale),
+                circle.weights().iter().copied(),
+                circle.knots().iter().copied(),
+            );
+            let edge = Edge::new(
+                Curve::new(CurveKind::Nurbs(local), global),
+                VerticesOfEdge::from_vertices(
+                    [0., end].map(|t| Vertex::new(Point::from([t]), vertex)),
+                ),
+            );
+
+            Face::new(surface.clone())
+                .with_exteriors([Cycle::new(surface).with_edges([edge])])
+        };
+
+        let mut faces = vec![
+            lateral,
+            reverse_face(&disc(
+                Surface::xy_plane(),
+                Scalar::ONE,
+                bottom_curve,
+                bottom_vertex,
+            )),
+        ];
+        if top > Scalar::ZERO {
+            faces.push(disc(
+                Surface::xy_plane().translate([
+                    Scalar::ZERO,
+                    Scalar::ZERO,
+                    height,
+                ]),
+                scale,
+                top_curve,
+                top_vertex,
+            ));
+        }
+
+        Solid::new().with_faces(faces)
+    }
+
+    /// Create a cuboid (a box) from its size along the x-, y-, and z-axis
+    ///
+    /// The cuboid is centered on the origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any component of `size` is not positive.
+    pub fn cuboid_from_size(&self, size: [impl Into<Scalar>; 3]) -> Solid {
+        let [x, y, z] = half_size(size);
+
+        let faces = [
+            [[-x, -y, -z], [-x, y, -z], [x, -y, -z]], // bottom
+            [[-x, -y, z], [x, -y, z], [-x, y, z]],    // top
+            [[-x, -y, -z], [x, -y, -z], [-x, -y, z]], // front
+            [[-x, y, -z], [-x, y, z], [x, y, -z]],    // back
+            [[-x, -y, -z], [-x, -y, z], [-x, y, -z]], // left
+            [[x, -y, -z], [x, y, -z], [x, -y, z]],    // right
+        ]
+        .map(parallelogram);
 
         Solid::new().with_faces(faces)
     }
+
+    /// Create a cylinder from its radius and height
+    ///
+    /// The axis of the cylinder is the z-axis. Its bottom is on the xy-plane.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the radius or the height is not positive.
+    pub fn cylinder_from_radius_and_height(
+        &self,
+        radius: impl Into<Scalar>,
+        height: impl Into<Scalar>,
+    ) -> Solid {
+        let [radius, height] = [radius.into(), height.into()];
+        assert!(radius > Scalar::ZERO, "Radius must be positive");
+        assert!(height > Scalar::ZERO, "Height must be positive");
+
+        let surface = Surface::xy_plane();
+        let edge = Edge::build().circle_from_radius(radius);
+        let face = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges([edge])]);
+
+        sweep(
+            Sketch::new().with_faces([face]),
+            [Scalar::ZERO, Scalar::ZERO, height],
+            Color::default(),
+        )
+    }
+
+    /// Create a sphere from its radius
+    ///
+    /// The sphere is centered on the origin, with its poles on the z-axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the radius is not positive.
+    pub fn sphere_from_radius(&self, radius: impl Into<Scalar>) -> Solid {
+        let radius = radius.into();
+        assert!(radius > Scalar::ZERO, "Radius must be positive");
+
+        let sphere = Sphere::from_center_and_radius(Point::origin(), radius);
+        let [a, c] = [sphere.a, sphere.c];
+
+        // The face covers all of the sphere's surface coordinates. It is
+        // bounded by the seam, where the longitude wraps around, and by the
+        // poles, which are edges that have collapsed into a single point.
+        let full_turn = Scalar::PI * 2.;
+        let [south, north] = [-Scalar::PI / 2., Scalar::PI / 2.];
+        let [south_west, south_east, north_east, north_west] = [
+            [Scalar::ZERO, south],
+            [full_turn, south],
+            [full_turn, north],
+            [Scalar::ZERO, north],
+        ]
+        .map(Point::from);
+
+        let [south_pole, north_pole] = [sphere.center - c, sphere.center + c];
+        let [south_vertex, north_vertex] =
+            [south_pole, north_pole].map(GlobalVertex::from_position);
+
+        let circle = |circle| GlobalCurve::from_kind(CurveKind::Circle(circle));
+        let face = face_bounded_by_iso_curves(
+            Surface::Sphere(sphere),
+            [
+                (
+                    [south_west, south_east],
+                    pole(south_pole, full_turn),
+                    [0., full_turn.into_f64()],
+                    [south_vertex; 2],
+                ),
+                (
+                    [south_east, north_east],
+                    circle(Circle::new(sphere.center, a, c)),
+                    [south.into_f64(), north.into_f64()],
+                    [south_vertex, north_vertex],
+                ),
+                (
+                    [north_east, north_west],
+                    pole(north_pole, full_turn),
+                    [0., full_turn.into_f64()],
+                    [north_vertex; 2],
+                ),
+                (
+                    [north_west, south_west],
+                    circle(Circle::new(sphere.center, a, -c)),
+                    [south.into_f64(), north.into_f64()],
+                    [north_vertex, south_vertex],
+                ),
+            ],
+        );
+
+        Solid::new().with_faces([face])
+    }
+
+    /// Create a torus from its major and minor radius
+    ///
+    /// The torus is centered on the origin, with its axis being the z-axis.
+    /// The major radius is the radius of the circle that the center of the
+    /// tube follows, the minor radius is the radius of the tube.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the minor radius is not positive, or if it is not smaller
+    /// than the major radius.
+    pub fn torus_from_radii(
+        &self,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+    ) -> Solid {
+        let [major_radius, minor_radius] =
+            [major_radius.into(), minor_radius.into()];
+
+        assert!(minor_radius > Scalar::ZERO, "Minor radius must be positive");
+        assert!(
+            minor_radius < major_radius,
+            "Minor radius must be smaller than major radius"
+        );
+
+        let torus = Torus::from_center_and_radii(
+            Point::origin(),
+            major_radius,
+            minor_radius,
+        );
+
+        // The face covers all of the torus' surface coordinates. It is bounded
+        // by the two seams, where either of the coordinates wraps around. They
+        // meet at the outer equator.
+        let outer = torus.a * (major_radius + minor_radius) / major_radius;
+        let outer_b = torus.b * (major_radius + minor_radius) / major_radius;
+        let tube_center = torus.center + torus.a;
+        let tube = torus.a.normalize() * minor_radius;
+
+        let full_turn = Scalar::PI * 2.;
+        let [a, b, c, d] = [
+            [Scalar::ZERO, Scalar::ZERO],
+            [full_turn, Scalar::ZERO],
+            [full_turn, full_turn],
+            [Scalar::ZERO, full_turn],
+        ]
+        .map(Point::from);
+
+        let corner = GlobalVertex::from_position(torus.center + outer);
+
+        let circle = |center, a, b| {
+            GlobalCurve::from_kind(CurveKind::Circle(Circle::new(center, a, b)))
+        };
+        let range = [0., full_turn.into_f64()];
+        let face = face_bounded_by_iso_curves(
+            Surface::Torus(torus),
+            [
+                (
+                    [a, b],
+                    circle(torus.center, outer, outer_b),
+                    range,
+                    [corner; 2],
+                ),
+                (
+                    [b, c],
+                    circle(tube_center, tube, torus.c),
+                    range,
+                    [corner; 2],
+                ),
+                (
+                    [c, d],
+                    circle(torus.center, outer, -outer_b),
+                    range,
+                    [corner; 2],
+                ),
+                (
+                    [d, a],
+                    circle(tube_center, tube, -torus.c),
+                    range,
+                    [corner; 2],
+                ),
+            ],
+        );
+
+        Solid::new().with_faces([face])
+    }
+
+    /// Create a wedge from its size along the x-, y-, and z-axis
+    ///
+    /// The wedge is the half of a cuboid of the same size that is below the
+    /// plane through its top-left and bottom-right edges, when viewed along
+    /// the y-axis. Like the cuboid, it is centered on the origin.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any component of `size` is not positive.
+    pub fn wedge_from_size(&self, size: [impl Into<Scalar>; 3]) -> Solid {
+        let [x, y, z] = half_size(size);
+
+        let faces = [
+            parallelogram([[-x, -y, -z], [-x, y, -z], [x, -y, -z]]), // bottom
+            parallelogram([[-x, -y, -z], [-x, -y, z], [-x, y, -z]]), // left
+            parallelogram([[x, -y, -z], [x, y, -z], [-x, -y, z]]),   // slope
+            triangle([[-x, -y, -z], [x, -y, -z], [-x, -y, z]]),      // front
+            triangle([[-x, y, -z], [-x, y, z], [x, y, -z]]),         // back
+        ];
+
+        Solid::new().with_faces(faces)
+    }
+}
+
+fn half_size(size: [impl Into<Scalar>; 3]) -> [Scalar; 3] {
+    let size = size.map(Into::into);
+    assert!(
+        size.iter().all(|&length| length > Scalar::ZERO),
+        "Size must be positive"
+    );
+
+    size.map(|length| length / 2.)
+}
+
+/// Create a planar face in the shape of a parallelogram
+///
+/// The parallelogram has a corner at the first point, which is adjacent to the
+/// other two. Its normal is defined by the order of the points.
+fn parallelogram(points: [[Scalar; 3]; 3]) -> Face {
+    Face::build(Surface::plane_from_points(points))
+        .polygon_from_points([[0., 0.], [1., 0.], [1., 1.], [0., 1.]])
+        .into_face()
+}
+
+/// Create a planar face in the shape of a triangle
+///
+/// The normal of the triangle is defined by the order of the points.
+fn triangle(points: [[Scalar; 3]; 3]) -> Face {
+    Face::build(Surface::plane_from_points(points))
+        .polygon_from_points([[0., 0.], [1., 0.], [0., 1.]])
+        .into_face()
+}
+
+/// An edge that is a line segment in surface coordinates
+///
+/// Consists of the start and end of the line segment in surface coordinates,
+/// the curve in global coordinates, the range of that curve that the edge
+/// covers, and the vertices that bound it.
+pub(crate) type IsoCurveEdge =
+    ([Point<2>; 2], GlobalCurve, [f64; 2], [GlobalVertex; 2]);
+
+/// Create a face that is bounded by edges along iso-curves of its surface
+///
+/// The edges must form a counter-clockwise cycle in surface coordinates.
+pub(crate) fn face_bounded_by_iso_curves<const N: usize>(
+    surface: Surface,
+    edges: [IsoCurveEdge; N],
+) -> Face {
+    let edges = edges.map(|(points, global, range, vertices)| {
+        // The local curve must have the same curve coordinates as the global
+        // one.
+        let [start, end] = points;
+        let [a, b] = range.map(Scalar::from);
+        let direction = (end - start) / (b - a);
+        let local =
+            Line::from_origin_and_direction(start - direction * a, direction);
+
+        let [vertex_a, vertex_b] = vertices;
+        Edge::new(
+            Curve::new(CurveKind::Line(local), global),
+            VerticesOfEdge::from_vertices([
+                Vertex::new(Point::from([a]), vertex_a),
+                Vertex::new(Point::from([b]), vertex_b),
+            ]),
+        )
+    });
+
+    Face::new(surface.clone())
+        .with_exteriors([Cycle::new(surface).with_edges(edges)])
+}
+
+/// A curve that has collapsed into a single point, like the pole of a sphere
+///
+/// Its curve coordinates range from zero to `length`.
+fn pole(point: Point<3>, length: Scalar) -> GlobalCurve {
+    GlobalCurve::from_kind(CurveKind::Nurbs(NurbsCurve::new(
+        1,
+        [point; 2],
+        [Scalar::ONE; 2],
+        [Scalar::ZERO, Scalar::ZERO, length, length],
+    )))
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_math::Scalar;
+
+    use crate::{objects::Solid, test_utils::volume};
+
+    #[test]
+    fn cone() -> anyhow::Result<()> {
+        let solid = Solid::build().cone_from_radii_and_height([1., 0.], 3.);
+
+        let expected = Scalar::PI;
+        assert!((volume(solid)? - expected).abs() < expected * 0.01);
+        Ok(())
+    }
+
+    #[test]
+    fn cone_frustum() -> anyhow::Result<()> {
+        let solid = Solid::build().cone_from_radii_and_height([2., 1.], 3.);
+
+        let expected = Scalar::PI * 7.;
+        assert!((volume(solid)? - expected).abs() < expected * 0.01);
+        Ok(())
+    }
+
+    #[test]
+    fn cuboid() -> anyhow::Result<()> {
+        let solid = Solid::build().cuboid_from_size([1., 2., 3.]);
+
+        let expected = Scalar::from(6.);
+        assert!((volume(solid)? - expected).abs() < Scalar::from(1e-9));
+        Ok(())
+    }
+
+    #[test]
+    fn cylinder() -> anyhow::Result<()> {
+        let solid = Solid::build().cylinder_from_radius_and_height(1., 2.);
+
+        let expected = Scalar::PI * 2.;
+        assert!((volume(solid)? - expected).abs() < expected * 0.01);
+        Ok(())
+    }
+
+    #[test]
+    fn sphere() -> anyhow::Result<()> {
+        let solid = Solid::build().sphere_from_radius(1.);
+
+        let expected = Scalar::PI * 4. / 3.;
+        assert!((volume(solid)? - expected).abs() < expected * 0.01);
+        Ok(())
+    }
+
+    #[test]
+    fn torus() -> anyhow::Result<()> {
+        let solid = Solid::build().torus_from_radii(2., 1.);
+
+        let expected = Scalar::PI * Scalar::PI * 4.;
+        assert!((volume(solid)? - expected).abs() < expected * 0.01);
+        Ok(())
+    }
+
+    #[test]
+    fn wedge() -> anyhow::Result<()> {
+        let solid = Solid::build().wedge_from_size([1., 2., 3.]);
+
+        let expected = Scalar::from(3.);
+        assert!((volume(solid)? - expected).abs() < Scalar::from(1e-9));
+        Ok(())
+    }
 }
@@ -92,3 +92,6 @@ pub mod builder;
 pub mod iter;
 pub mod objects;
 pub mod validation;
+
+#[cfg(test)]
+mod test_utils;
@@ -1,11 +1,11 @@
-use fj_math::{Circle, Line, Point, Transform, Vector};
+use fj_math::{Circle, Line, NurbsCurve, Point, Scalar, Transform, Vector};
 
 use crate::builder::{CurveBuilder, GlobalCurveBuilder};
 
 use super::Surface;
 
 /// A curve, defined in local surface coordinates
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Curve {
     kind: CurveKind<2>,
     global: GlobalCurve,
@@ -34,7 +34,7 @@ impl Curve {
 }
 
 /// A curve, defined in global (3D) coordinates
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct GlobalCurve {
     kind: CurveKind<3>,
 }
@@ -69,13 +69,16 @@ impl GlobalCurve {
 /// The `D` parameter defines the dimensions in which the curve is defined.
 /// Typically, only `2` or `3` make sense, which means the curve is defined on
 /// a surface or in a space, respectively.
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum CurveKind<const D: usize> {
     /// A circle
     Circle(Circle<D>),
 
     /// A line
     Line(Line<D>),
+
+    /// A NURBS curve
+    Nurbs(NurbsCurve<D>),
 }
 
 impl<const D: usize> CurveKind<D> {
@@ -85,10 +88,17 @@ impl<const D: usize> CurveKind<D> {
     }
 
     /// Access the origin of the curve's coordinate system
+    ///
+    /// NURBS curves don't have a natural origin. For those, this is the point
+    /// at the start of the curve's domain.
     pub fn origin(&self) -> Point<D> {
         match self {
             Self::Circle(curve) => curve.center(),
             Self::Line(curve) => curve.origin(),
+            Self::Nurbs(curve) => {
+                let [start, _] = curve.domain();
+                curve.point_from_curve_coords([start])
+            }
         }
     }
 
@@ -98,6 +108,7 @@ impl<const D: usize> CurveKind<D> {
         match self {
             Self::Circle(curve) => Self::Circle(curve.reverse()),
             Self::Line(curve) => Self::Line(curve.reverse()),
+            Self::Nurbs(curve) => Self::Nurbs(curve.reverse()),
         }
     }
 
@@ -109,10 +120,29 @@ impl<const D: usize> CurveKind<D> {
         match self {
             Self::Circle(curve) => curve.point_from_circle_coords(point),
             Self::Line(curve) => curve.point_from_line_coords(point),
+            Self::Nurbs(curve) => curve.point_from_curve_coords(point),
+        }
+    }
+
+    /// Convert a point in model coordinates to curve coordinates
+    ///
+    /// Returns the curve coordinate of the curve point that is closest to the
+    /// provided point.
+    pub fn point_to_curve_coords(
+        &self,
+        point: impl Into<Point<D>>,
+    ) -> Point<1> {
+        match self {
+            Self::Circle(curve) => curve.point_to_circle_coords(point),
+            Self::Line(curve) => curve.point_to_line_coords(point),
+            Self::Nurbs(curve) => curve.point_to_curve_coords(point),
         }
     }
 
     /// Convert a vector on the curve into model coordinates
+    ///
+    /// NURBS curves are not linear in their curve coordinates. For those, the
+    /// vector is interpreted as a point, relative to [`CurveKind::origin`].
     pub fn vector_from_curve_coords(
         &self,
         point: impl Into<Vector<1>>,
@@ -120,6 +150,12 @@ impl<const D: usize> CurveKind<D> {
         match self {
             Self::Circle(curve) => curve.vector_from_circle_coords(point),
             Self::Line(curve) => curve.vector_from_line_coords(point),
+            Self::Nurbs(curve) => {
+                let point = Point {
+                    coords: point.into(),
+                };
+                curve.point_from_curve_coords(point) - self.origin()
+            }
         }
     }
 }
@@ -150,15 +186,36 @@ impl CurveKind<3> {
     }
 
     /// Transform the surface
+    ///
+    /// A transform that distorts a circle turns it into an ellipse. Those are
+    /// represented as a NURBS curve that covers the full circle, which means
+    /// the curve coordinates change from angles to the domain of that curve.
+    /// Transforming an [`Edge`] or a [`Face`] takes care of updating anything
+    /// that refers to the curve coordinates.
+    ///
+    /// [`Edge`]: super::Edge
+    /// [`Face`]: super::Face
     #[must_use]
     pub fn transform(self, transform: &Transform) -> Self {
         match self {
             CurveKind::Circle(curve) => {
-                CurveKind::Circle(transform.transform_circle(&curve))
+                match transform.transform_circle(&curve) {
+                    Some(circle) => CurveKind::Circle(circle),
+                    None => {
+                        let ellipse =
+                            curve.to_nurbs([Scalar::ZERO, Scalar::PI * 2.]);
+                        CurveKind::Nurbs(
+                            transform.transform_nurbs_curve(&ellipse),
+                        )
+                    }
+                }
             }
             CurveKind::Line(curve) => {
                 CurveKind::Line(transform.transform_line(&curve))
             }
+            CurveKind::Nurbs(curve) => {
+                CurveKind::Nurbs(transform.transform_nurbs_curve(&curve))
+            }
         }
     }
 }
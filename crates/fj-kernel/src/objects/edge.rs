@@ -5,7 +5,7 @@ use crate::builder::EdgeBuilder;
 use super::{Curve, Vertex};
 
 /// An edge of a shape
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Edge {
     curve: Curve,
     vertices: VerticesOfEdge,
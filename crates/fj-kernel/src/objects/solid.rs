@@ -2,23 +2,20 @@ use std::collections::BTreeSet;
 
 use crate::builder::SolidBuilder;
 
-use super::Face;
+use super::{Face, Shell};
 
 /// A 3-dimensional shape
 ///
-/// # Implementation Note
+/// A solid is bounded by one outer shell, and any number of inner shells,
+/// which are cavities within the solid.
 ///
-/// The faces that make up the solid must form a closed shape. This is not
-/// currently validated.
+/// # Implementation Note
 ///
-/// In fact, solids could be made up of several closed shells. One outer shell,
-/// and multiple inner ones (cavities within the solid). There should probably
-/// a separate `Shell` object that is a collection of faces, and validates that
-/// those faces form a closed shape. `Solid` should be a collection of such
-/// `Shell`s, and validate that those `Shell`s don't intersect.
+/// The shells of a solid must not intersect. This is not currently validated.
 #[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct Solid {
-    faces: BTreeSet<Face>,
+    outer: Shell,
+    inners: BTreeSet<Shell>,
 }
 
 impl Solid {
@@ -29,31 +26,69 @@ impl Solid {
 
     /// Construct an empty instance of `Solid`
     pub fn new() -> Self {
+        Self::from_outer_shell(Shell::new())
+    }
+
+    /// Construct a `Solid` that is bounded by the given shell
+    pub fn from_outer_shell(outer: Shell) -> Self {
         Self {
-            faces: BTreeSet::new(),
+            outer,
+            inners: BTreeSet::new(),
         }
     }
 
-    /// Add faces to the solid
+    /// Add faces to the solid's outer shell
     ///
     /// Consumes the solid and returns the updated instance.
     pub fn with_faces(
         mut self,
         faces: impl IntoIterator<Item = impl Into<Face>>,
     ) -> Self {
-        let faces = faces.into_iter().map(Into::into);
-        self.faces.extend(faces);
+        self.outer = self.outer.with_faces(faces);
+        self
+    }
+
+    /// Add inner shells to the solid
+    ///
+    /// Consumes the solid and returns the updated instance.
+    pub fn with_inner_shells(
+        mut self,
+        shells: impl IntoIterator<Item = impl Into<Shell>>,
+    ) -> Self {
+        let shells = shells.into_iter().map(Into::into);
+        self.inners.extend(shells);
         self
     }
 
-    /// Access the solid's faces
+    /// Access the solid's outer shell
+    pub fn outer_shell(&self) -> &Shell {
+        &self.outer
+    }
+
+    /// Access the solid's inner shells
+    pub fn inner_shells(&self) -> impl Iterator<Item = &Shell> {
+        self.inners.iter()
+    }
+
+    /// Access all of the solid's shells
+    ///
+    /// This is equivalent to chaining the iterators returned by
+    /// [`Solid::outer_shell`] and [`Solid::inner_shells`].
+    pub fn all_shells(&self) -> impl Iterator<Item = &Shell> {
+        [&self.outer].into_iter().chain(self.inner_shells())
+    }
+
+    /// Access the faces of all of the solid's shells
     pub fn faces(&self) -> impl Iterator<Item = &Face> {
-        self.faces.iter()
+        self.all_shells().flat_map(Shell::faces)
     }
 
-    /// Convert the solid into a list of faces
+    /// Convert the solid into a list of the faces of all of its shells
     pub fn into_faces(self) -> impl Iterator<Item = Face> {
-        self.faces.into_iter()
+        [self.outer]
+            .into_iter()
+            .chain(self.inners)
+            .flat_map(Shell::into_faces)
     }
 }
 
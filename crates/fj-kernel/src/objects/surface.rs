@@ -1,12 +1,23 @@
-use fj_math::{Line, Point, Transform, Vector};
+use fj_math::{
+    Circle, Line, NurbsCurve, NurbsSurface, Point, Scalar, Transform, Vector,
+};
 
 use super::CurveKind;
 
 /// A two-dimensional shape
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub enum Surface {
+    /// A NURBS surface
+    Nurbs(NurbsSurface<3>),
+
+    /// A sphere
+    Sphere(Sphere),
+
     /// A swept curve
     SweptCurve(SweptCurve),
+
+    /// A torus
+    Torus(Torus),
 }
 
 impl Surface {
@@ -48,7 +59,54 @@ impl Surface {
     #[must_use]
     pub fn reverse(self) -> Self {
         match self {
+            Self::Nurbs(surface) => Self::Nurbs(surface.reverse_v()),
+            Self::Sphere(surface) => Self::Sphere(surface.reverse()),
             Self::SweptCurve(surface) => Self::SweptCurve(surface.reverse()),
+            Self::Torus(surface) => Self::Torus(surface.reverse()),
+        }
+    }
+
+    /// Convert the surface into a NURBS surface
+    ///
+    /// `range` is the range of the u- and v-coordinates that the NURBS surface
+    /// must cover. The NURBS surface describes that part of the surface
+    /// exactly, but its surface coordinates differ from the original ones.
+    /// Use [`Surface::point_to_nurbs_coords`] to convert between them.
+    ///
+    /// NURBS surfaces are returned as they are, and so are the curves of swept
+    /// surfaces, if those are NURBS curves themselves.
+    pub fn to_nurbs(&self, range: [[Scalar; 2]; 2]) -> NurbsSurface<3> {
+        match self {
+            Self::Nurbs(surface) => surface.clone(),
+            Self::Sphere(surface) => surface.to_nurbs(range),
+            Self::SweptCurve(surface) => surface.to_nurbs(range),
+            Self::Torus(surface) => surface.to_nurbs(range),
+        }
+    }
+
+    /// Convert surface coordinates into those of the NURBS representation
+    ///
+    /// Converts the point into the surface coordinates of the NURBS surface
+    /// that [`Surface::to_nurbs`] returns for `range`. Coordinates that go
+    /// around a circle are converted, all others are left as they are.
+    pub fn point_to_nurbs_coords(
+        &self,
+        range: [[Scalar; 2]; 2],
+        point: impl Into<Point<2>>,
+    ) -> Point<2> {
+        let point = point.into();
+        let [range_u, range_v] = range;
+
+        match self {
+            Self::Sphere(_) | Self::Torus(_) => Point::from([
+                arc_coords(range_u, point.u),
+                arc_coords(range_v, point.v),
+            ]),
+            Self::SweptCurve(SweptCurve {
+                curve: CurveKind::Circle(_),
+                ..
+            }) => Point::from([arc_coords(range_u, point.u), point.v]),
+            _ => point,
         }
     }
 
@@ -58,27 +116,56 @@ impl Surface {
         point: impl Into<Point<2>>,
     ) -> Point<3> {
         match self {
+            Self::Nurbs(surface) => surface.point_from_surface_coords(point),
+            Self::Sphere(surface) => surface.point_from_surface_coords(point),
             Self::SweptCurve(surface) => {
                 surface.point_from_surface_coords(point)
             }
+            Self::Torus(surface) => surface.point_from_surface_coords(point),
+        }
+    }
+
+    /// Convert a point in model coordinates to surface coordinates
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        match self {
+            Self::Nurbs(surface) => surface.point_to_surface_coords(point),
+            Self::Sphere(surface) => surface.point_to_surface_coords(point),
+            Self::SweptCurve(surface) => surface.point_to_surface_coords(point),
+            Self::Torus(surface) => surface.point_to_surface_coords(point),
         }
     }
 
     /// Convert a vector in surface coordinates to model coordinates
+    ///
+    /// NURBS surfaces are not linear in their surface coordinates. For those,
+    /// the vector is interpreted as a point, relative to the point at the start
+    /// of the surface's domain.
     pub fn vector_from_surface_coords(
         &self,
         vector: impl Into<Vector<2>>,
     ) -> Vector<3> {
         match self {
+            Self::Nurbs(surface) => {
+                let [[min_u, _], [min_v, _]] = surface.domain();
+
+                surface.point_from_surface_coords(Point {
+                    coords: vector.into(),
+                }) - surface.point_from_surface_coords([min_u, min_v])
+            }
+            Self::Sphere(surface) => surface.vector_from_surface_coords(vector),
             Self::SweptCurve(surface) => {
                 surface.vector_from_surface_coords(vector)
             }
+            Self::Torus(surface) => surface.vector_from_surface_coords(vector),
         }
     }
 }
 
 /// A surface that was swept from a curve
-#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
 pub struct SweptCurve {
     /// The curve that this surface was swept from
     pub curve: CurveKind<3>,
@@ -113,6 +200,101 @@ impl SweptCurve {
             + self.path_to_line().vector_from_line_coords([point.v])
     }
 
+    /// Convert a point in model coordinates to surface coordinates
+    ///
+    /// Projects the point onto the surface before computing the surface
+    /// coordinates. Like [`Line::point_to_line_coords`], this method will not
+    /// result in an error, if the point is not on the surface.
+    ///
+    /// For surfaces swept from a circle (cylinders), the returned u-coordinate
+    /// is in the range `0.` (inclusive) to `PI * 2.` (exclusive), as returned
+    /// by [`Circle::point_to_circle_coords`].
+    ///
+    /// For surfaces swept from a NURBS curve, the returned v-coordinate is
+    /// restricted to the range from `0.` to `1.`, meaning the point must be
+    /// between the curve and the end of the path.
+    ///
+    /// [`Circle::point_to_circle_coords`]: fj_math::Circle::point_to_circle_coords
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let point = point.into();
+
+        match &self.curve {
+            CurveKind::Circle(circle) => {
+                // The path is not parallel to the plane of the circle (or the
+                // surface would be degenerate), so we can find the v-coordinate
+                // by checking how far along the path we need to move the plane
+                // of the circle, to make it contain the point.
+                let normal = circle.a().cross(&circle.b());
+                let v = (point - circle.center()).dot(&normal)
+                    / self.path.dot(&normal);
+
+                let u = circle.point_to_circle_coords(point - self.path * v);
+
+                Point::from([u.t, v])
+            }
+            CurveKind::Line(line) => {
+                // The point relative to the origin of the surface can be
+                // expressed as a linear combination of the two vectors that
+                // span the plane. We solve for the coefficients of that
+                // combination, using the normal equations of the resulting
+                // system.
+                let vector = point - line.origin();
+                let u = line.direction();
+                let v = self.path;
+
+                let uu = u.dot(&u);
+                let uv = u.dot(&v);
+                let vv = v.dot(&v);
+                let pu = vector.dot(&u);
+                let pv = vector.dot(&v);
+
+                let det = uu * vv - uv * uv;
+
+                Point::from([
+                    (pu * vv - pv * uv) / det,
+                    (pv * uu - pu * uv) / det,
+                ])
+            }
+            CurveKind::Nurbs(curve) => {
+                let surface = self
+                    .to_nurbs([curve.domain(), [Scalar::ZERO, Scalar::ONE]]);
+                surface.point_to_surface_coords(point)
+            }
+        }
+    }
+
+    /// Convert the surface into a NURBS surface
+    ///
+    /// See [`Surface::to_nurbs`]. A curve swept along a straight path is a
+    /// NURBS surface that is linear along the path.
+    pub fn to_nurbs(&self, range: [[Scalar; 2]; 2]) -> NurbsSurface<3> {
+        let [[u_start, u_end], [v_start, v_end]] = range;
+
+        let curve = match &self.curve {
+            CurveKind::Circle(circle) => circle.to_nurbs([u_start, u_end]),
+            CurveKind::Line(line) => NurbsCurve::new(
+                1,
+                [u_start, u_end].map(|u| line.point_from_line_coords([u])),
+                [Scalar::ONE; 2],
+                [u_start, u_start, u_end, u_end],
+            ),
+            CurveKind::Nurbs(curve) => curve.clone(),
+        };
+
+        NurbsSurface::new(
+            [curve.degree(), 1],
+            curve
+                .control_points()
+                .iter()
+                .map(|&point| [v_start, v_end].map(|v| point + self.path * v)),
+            curve.weights().iter().map(|&weight| [weight; 2]),
+            [curve.knots().to_vec(), vec![v_start, v_start, v_end, v_end]],
+        )
+    }
+
     /// Convert a vector in surface coordinates to model coordinates
     pub fn vector_from_surface_coords(
         &self,
@@ -128,14 +310,378 @@ impl SweptCurve {
     }
 }
 
+/// A sphere
+///
+/// The surface coordinates of a sphere are its longitude (u) and latitude (v).
+/// The longitude goes around the axis defined by [`Sphere::c`], starting at
+/// [`Sphere::a`], from `0.` to `PI * 2.`. The latitude goes from `-PI / 2.` at
+/// the bottom pole, to `PI / 2.` at the top pole.
+///
+/// `a`, `b`, and `c` must be perpendicular to each other, and their length
+/// must be equal to the radius of the sphere. If they form a right-handed
+/// coordinate system, the normal of the surface points outward.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Sphere {
+    /// The center of the sphere
+    pub center: Point<3>,
+
+    /// The direction of zero longitude and latitude
+    pub a: Vector<3>,
+
+    /// The direction of a quarter turn of longitude, at zero latitude
+    pub b: Vector<3>,
+
+    /// The direction of the top pole
+    pub c: Vector<3>,
+}
+
+impl Sphere {
+    /// Construct a sphere from its center and radius
+    ///
+    /// The poles of the sphere are on the z-axis, and its normal points
+    /// outward.
+    pub fn from_center_and_radius(
+        center: impl Into<Point<3>>,
+        radius: impl Into<Scalar>,
+    ) -> Self {
+        let radius = radius.into();
+
+        Self {
+            center: center.into(),
+            a: Vector::unit_x() * radius,
+            b: Vector::unit_y() * radius,
+            c: Vector::unit_z() * radius,
+        }
+    }
+
+    /// Access the radius of the sphere
+    pub fn radius(&self) -> Scalar {
+        self.a.magnitude()
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// Like for swept surfaces, this mirrors the v-coordinate.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.c = -self.c;
+        self
+    }
+
+    /// Transform the surface
+    ///
+    /// Distorted spheres can't be represented by this type. Transforming a
+    /// [`Surface`] takes care of that, by converting it into a NURBS surface
+    /// first.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the transform is not a similarity, as that would distort
+    /// the sphere.
+    #[must_use]
+    pub fn transform(mut self, transform: &Transform) -> Self {
+        assert!(
+            transform.is_similarity(),
+            "Distorting a sphere requires converting it to NURBS"
+        );
+
+        self.center = transform.transform_point(&self.center);
+        self.a = transform.transform_vector(&self.a);
+        self.b = transform.transform_vector(&self.b);
+        self.c = transform.transform_vector(&self.c);
+        self
+    }
+
+    /// Convert the surface into a NURBS surface
+    ///
+    /// See [`Surface::to_nurbs`]. The surface is the tensor product of the
+    /// arcs of longitude and latitude.
+    pub fn to_nurbs(&self, range: [[Scalar; 2]; 2]) -> NurbsSurface<3> {
+        tensor_product_of_arcs(range, |[x, y], [rho, z]| {
+            self.center + (self.a * x + self.b * y) * rho + self.c * z
+        })
+    }
+
+    /// Convert a point in surface coordinates to model coordinates
+    pub fn point_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        self.center + self.vector_from_surface_coords(point.into().coords)
+    }
+
+    /// Convert a point in model coordinates to surface coordinates
+    ///
+    /// Projects the point onto the surface before computing the surface
+    /// coordinates. The longitude of points on the axis (including the poles)
+    /// is undefined, and the method returns `0.` for them.
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let vector = point.into() - self.center;
+        let [x, y, z] = [self.a, self.b, self.c].map(|axis| vector.dot(&axis));
+
+        let u = angle_from_cos_and_sin(x, y);
+        let v = Scalar::atan2(z, (x * x + y * y).sqrt());
+
+        Point::from([u, v])
+    }
+
+    /// Convert a vector in surface coordinates to model coordinates
+    ///
+    /// Like with circles, the vector is interpreted as pointing from the
+    /// origin of the coordinate system, and the returned vector points from
+    /// the center of the sphere.
+    pub fn vector_from_surface_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+
+        let (sin_u, cos_u) = vector.u.sin_cos();
+        let (sin_v, cos_v) = vector.v.sin_cos();
+
+        (self.a * cos_u + self.b * sin_u) * cos_v + self.c * sin_v
+    }
+}
+
+/// A torus
+///
+/// The torus is defined by its major circle, which the center of the tube
+/// follows, and the minor radius, which is the radius of the tube.
+///
+/// The u-coordinate goes around the major circle, starting at [`Torus::a`],
+/// from `0.` to `PI * 2.`. The v-coordinate goes around the tube, from `0.` at
+/// the outer equator, through the top (in the direction of [`Torus::c`]), to
+/// `PI * 2.`.
+///
+/// `a`, `b`, and `c` must be perpendicular to each other. `a` and `b` must be
+/// of equal length. If they form a right-handed coordinate system, the normal
+/// of the surface points outward.
+#[derive(Clone, Copy, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct Torus {
+    /// The center of the torus
+    pub center: Point<3>,
+
+    /// The start of the major circle
+    ///
+    /// The length of this vector defines the major radius.
+    pub a: Vector<3>,
+
+    /// The direction of a quarter turn along the major circle
+    pub b: Vector<3>,
+
+    /// The axis of the torus
+    ///
+    /// The length of this vector defines the minor radius.
+    pub c: Vector<3>,
+}
+
+impl Torus {
+    /// Construct a torus from its center and radii
+    ///
+    /// The axis of the torus is parallel to the z-axis, and its normal points
+    /// outward.
+    pub fn from_center_and_radii(
+        center: impl Into<Point<3>>,
+        major_radius: impl Into<Scalar>,
+        minor_radius: impl Into<Scalar>,
+    ) -> Self {
+        let major_radius = major_radius.into();
+
+        Self {
+            center: center.into(),
+            a: Vector::unit_x() * major_radius,
+            b: Vector::unit_y() * major_radius,
+            c: Vector::unit_z() * minor_radius.into(),
+        }
+    }
+
+    /// Access the major radius of the torus
+    pub fn major_radius(&self) -> Scalar {
+        self.a.magnitude()
+    }
+
+    /// Access the minor radius of the torus
+    pub fn minor_radius(&self) -> Scalar {
+        self.c.magnitude()
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// Like for swept surfaces, this mirrors the v-coordinate.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.c = -self.c;
+        self
+    }
+
+    /// Transform the surface
+    ///
+    /// Distorted toruss can't be represented by this type. Transforming a
+    /// [`Surface`] takes care of that, by converting it into a NURBS surface
+    /// first.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the transform is not a similarity, as that would distort
+    /// the torus.
+    #[must_use]
+    pub fn transform(mut self, transform: &Transform) -> Self {
+        assert!(
+            transform.is_similarity(),
+            "Distorting a torus requires converting it to NURBS"
+        );
+
+        self.center = transform.transform_point(&self.center);
+        self.a = transform.transform_vector(&self.a);
+        self.b = transform.transform_vector(&self.b);
+        self.c = transform.transform_vector(&self.c);
+        self
+    }
+
+    /// Convert the surface into a NURBS surface
+    ///
+    /// See [`Surface::to_nurbs`]. The surface is the tensor product of the
+    /// arcs around the major circle and around the tube.
+    pub fn to_nurbs(&self, range: [[Scalar; 2]; 2]) -> NurbsSurface<3> {
+        let ratio = self.minor_radius() / self.major_radius();
+
+        tensor_product_of_arcs(range, |[x, y], [rho, z]| {
+            self.center
+                + (self.a * x + self.b * y) * (Scalar::ONE + ratio * rho)
+                + self.c * z
+        })
+    }
+
+    /// Convert a point in surface coordinates to model coordinates
+    pub fn point_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<3> {
+        self.center + self.vector_from_surface_coords(point.into().coords)
+    }
+
+    /// Convert a point in model coordinates to surface coordinates
+    ///
+    /// Projects the point onto the surface before computing the surface
+    /// coordinates.
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<3>>,
+    ) -> Point<2> {
+        let vector = point.into() - self.center;
+
+        let u =
+            angle_from_cos_and_sin(vector.dot(&self.a), vector.dot(&self.b));
+
+        // The point relative to the center of the tube, at the u-coordinate we
+        // just computed.
+        let radial = self.radial(u);
+        let vector = vector - radial * self.major_radius();
+
+        let v = angle_from_cos_and_sin(
+            vector.dot(&radial),
+            vector.dot(&self.c.normalize()),
+        );
+
+        Point::from([u, v])
+    }
+
+    /// Convert a vector in surface coordinates to model coordinates
+    ///
+    /// Like with circles, the vector is interpreted as pointing from the
+    /// origin of the coordinate system, and the returned vector points from
+    /// the center of the torus.
+    pub fn vector_from_surface_coords(
+        &self,
+        vector: impl Into<Vector<2>>,
+    ) -> Vector<3> {
+        let vector = vector.into();
+
+        let radial = self.radial(vector.u);
+        let (sin_v, cos_v) = vector.v.sin_cos();
+
+        radial * (self.major_radius() + self.minor_radius() * cos_v)
+            + self.c * sin_v
+    }
+
+    /// The unit vector that points from the center towards the given
+    /// u-coordinate
+    fn radial(&self, u: Scalar) -> Vector<3> {
+        let (sin, cos) = u.sin_cos();
+        (self.a * cos + self.b * sin).normalize()
+    }
+}
+
+/// Convert a coordinate that goes around a circle into NURBS coordinates
+///
+/// See [`Surface::point_to_nurbs_coords`].
+fn arc_coords(range: [Scalar; 2], t: Scalar) -> Scalar {
+    unit_circle().point_to_nurbs_coords(range, [t]).t
+}
+
+fn unit_circle() -> Circle<2> {
+    Circle::new(
+        Point::origin(),
+        Vector::from([1., 0.]),
+        Vector::from([0., 1.]),
+    )
+}
+
+/// Create a NURBS surface from the tensor product of two arcs
+///
+/// `point` computes the control points of the surface from those of the arcs
+/// of the unit circle along u and v. It must be affine in each of them, for
+/// the surface to be exact.
+fn tensor_product_of_arcs(
+    range: [[Scalar; 2]; 2],
+    point: impl Fn([Scalar; 2], [Scalar; 2]) -> Point<3>,
+) -> NurbsSurface<3> {
+    let [along_u, along_v] = range.map(|range| unit_circle().to_nurbs(range));
+
+    NurbsSurface::new(
+        [along_u.degree(), along_v.degree()],
+        along_u.control_points().iter().map(|u| {
+            along_v
+                .control_points()
+                .iter()
+                .map(|v| point([u.u, u.v], [v.u, v.v]))
+                .collect::<Vec<_>>()
+        }),
+        along_u.weights().iter().map(|&weight_u| {
+            along_v
+                .weights()
+                .iter()
+                .map(move |&weight_v| weight_u * weight_v)
+        }),
+        [along_u.knots().to_vec(), along_v.knots().to_vec()],
+    )
+}
+
+/// Compute an angle between `0.` (inclusive) and `PI * 2.` (exclusive) from
+/// values that are proportional to its cosine and sine
+fn angle_from_cos_and_sin(cos: Scalar, sin: Scalar) -> Scalar {
+    let angle = Scalar::atan2(sin, cos);
+
+    if angle < Scalar::ZERO {
+        angle + Scalar::PI * 2.
+    } else {
+        angle
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use fj_math::{Line, Point, Vector};
+    use std::f64::consts::{FRAC_PI_2, FRAC_PI_4, PI};
+
+    use fj_math::{Circle, Line, Point, Scalar, Vector};
     use pretty_assertions::assert_eq;
 
     use crate::objects::CurveKind;
 
-    use super::SweptCurve;
+    use super::{Sphere, SweptCurve, Torus};
 
     #[test]
     fn reverse() {
@@ -175,6 +721,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn point_to_surface_coords() {
+        let swept = SweptCurve {
+            curve: CurveKind::Line(Line::from_origin_and_direction(
+                Point::from([1., 1., 1.]),
+                Vector::from([0., 2., 0.]),
+            )),
+            path: Vector::from([0., 0., 2.]),
+        };
+
+        assert_eq!(
+            swept.point_to_surface_coords([1., 5., 9.]),
+            Point::from([2., 4.]),
+        );
+
+        // Points that are not on the surface get projected onto it.
+        assert_eq!(
+            swept.point_to_surface_coords([3., 5., 9.]),
+            Point::from([2., 4.]),
+        );
+    }
+
+    #[test]
+    fn point_to_surface_coords_cylinder() {
+        let swept = SweptCurve {
+            curve: CurveKind::Circle(Circle::new(
+                Point::from([1., 1., 1.]),
+                Vector::from([2., 0., 0.]),
+                Vector::from([0., 2., 0.]),
+            )),
+            path: Vector::from([0., 0., 2.]),
+        };
+
+        assert_eq!(
+            swept.point_to_surface_coords([1., 3., 5.]),
+            Point::from([FRAC_PI_2, 2.]),
+        );
+
+        // Points that are not on the surface get projected onto it.
+        assert_eq!(
+            swept.point_to_surface_coords([1., 2., 5.]),
+            Point::from([FRAC_PI_2, 2.]),
+        );
+    }
+
     #[test]
     fn vector_from_surface_coords() {
         let swept = SweptCurve {
@@ -190,4 +781,85 @@ mod tests {
             Vector::from([0., 4., 8.]),
         );
     }
+
+    #[test]
+    fn sphere_surface_coords() {
+        let sphere = Sphere::from_center_and_radius([1., 2., 3.], 2.);
+
+        assert_close(
+            sphere.point_from_surface_coords([FRAC_PI_2, 0.]),
+            Point::from([1., 4., 3.]),
+        );
+        assert_close(
+            sphere.point_from_surface_coords([PI, FRAC_PI_2]),
+            Point::from([1., 2., 5.]),
+        );
+
+        assert_close(
+            sphere.point_to_surface_coords([1., 4., 3.]),
+            Point::from([FRAC_PI_2, 0.]),
+        );
+        assert_close(
+            sphere.point_to_surface_coords([3., 2., 5.]),
+            Point::from([0., FRAC_PI_4]),
+        );
+
+        // Points that are not on the surface get projected onto it.
+        assert_close(
+            sphere.point_to_surface_coords([1., 3., 3.]),
+            Point::from([FRAC_PI_2, 0.]),
+        );
+    }
+
+    #[test]
+    fn sphere_reverse() {
+        let sphere = Sphere::from_center_and_radius([0., 0., 0.], 1.);
+        let reversed = sphere.reverse();
+
+        // The surface stays the same, but the v-coordinate runs the other
+        // way, which flips the normal.
+        assert_close(
+            reversed.point_from_surface_coords([FRAC_PI_2, -FRAC_PI_4]),
+            sphere.point_from_surface_coords([FRAC_PI_2, FRAC_PI_4]),
+        );
+    }
+
+    #[test]
+    fn torus_surface_coords() {
+        let torus = Torus::from_center_and_radii([0., 0., 1.], 2., 1.);
+
+        assert_close(
+            torus.point_from_surface_coords([0., 0.]),
+            Point::from([3., 0., 1.]),
+        );
+        assert_close(
+            torus.point_from_surface_coords([FRAC_PI_2, FRAC_PI_2]),
+            Point::from([0., 2., 2.]),
+        );
+        assert_close(
+            torus.point_from_surface_coords([PI, PI]),
+            Point::from([-1., 0., 1.]),
+        );
+
+        assert_close(
+            torus.point_to_surface_coords([3., 0., 1.]),
+            Point::from([0., 0.]),
+        );
+        assert_close(
+            torus.point_to_surface_coords([0., 2., 2.]),
+            Point::from([FRAC_PI_2, FRAC_PI_2]),
+        );
+        assert_close(
+            torus.point_to_surface_coords([-1., 0., 1.]),
+            Point::from([PI, PI]),
+        );
+    }
+
+    fn assert_close<const D: usize>(a: Point<D>, b: impl Into<Point<D>>) {
+        let b = b.into();
+        assert!(
+            (a - b).magnitude() < Scalar::from_f64(1e-12),
+            "{a:?} != {b:?}"
+        );
+    }
 }
@@ -0,0 +1,83 @@
+//! Utilities that are shared between tests
+
+use fj_interop::debug::DebugInfo;
+use fj_math::{Circle, Scalar};
+
+use crate::{
+    algorithms::{triangulate, Tolerance, TransformObject},
+    objects::{
+        Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, Sketch, Solid,
+        Surface, VerticesOfEdge,
+    },
+    validation::{validate, ValidationConfig},
+};
+
+/// Compute the volume of a solid
+///
+/// Validates the solid, then sums up the signed volumes of the tetrahedra that
+/// each triangle of its approximation forms with the origin. The result is
+/// negative, if the solid is inside out.
+pub fn volume(solid: Solid) -> anyhow::Result<Scalar> {
+    let tolerance = Tolerance::from_scalar(0.001)?;
+
+    let solid = validate(solid, &ValidationConfig::default())?;
+
+    let faces = solid.into_inner().into_faces().collect();
+    let mesh = triangulate(faces, tolerance, &mut DebugInfo::new());
+    let volume = mesh
+        .triangles()
+        .map(|triangle| {
+            let [a, b, c] = triangle.inner.points();
+            a.coords.dot(&b.coords.cross(&c.coords)) / 6.
+        })
+        .fold(Scalar::ZERO, |volume, triangle| volume + triangle);
+
+    Ok(volume)
+}
+
+/// Create a sketch of a circle in the xy-plane
+pub fn circle(center: [f64; 2], radius: f64) -> Sketch {
+    let [x, y] = center;
+    let radius = Scalar::from(radius);
+
+    let curve = Curve::new(
+        CurveKind::Circle(Circle::new(
+            [x, y],
+            [radius, Scalar::ZERO],
+            [Scalar::ZERO, radius],
+        )),
+        GlobalCurve::from_kind(CurveKind::Circle(Circle::new(
+            [x, y, 0.],
+            [radius, Scalar::ZERO, Scalar::ZERO],
+            [Scalar::ZERO, radius, Scalar::ZERO],
+        ))),
+    );
+    let edge = Edge::new(curve, VerticesOfEdge::none());
+
+    let surface = Surface::xy_plane();
+    let face = Face::new(surface.clone())
+        .with_exteriors([Cycle::new(surface).with_edges([edge])]);
+
+    Sketch::new().with_faces([face])
+}
+
+/// Create a sketch of an axis-aligned rectangle in the xy-plane
+pub fn rectangle(origin: [f64; 2], size: [f64; 2]) -> Sketch {
+    let [x, y] = origin;
+    let [l_x, l_y] = size;
+
+    let face = Face::build(Surface::xy_plane()).polygon_from_points([
+        [x, y],
+        [x + l_x, y],
+        [x + l_x, y + l_y],
+        [x, y + l_y],
+    ]);
+
+    Sketch::new().with_faces([face])
+}
+
+/// Create an axis-aligned cuboid from the corner closest to negative infinity
+pub fn cuboid(corner: [f64; 3], size: [f64; 3]) -> Solid {
+    let center = [0, 1, 2].map(|i| corner[i] + size[i] / 2.);
+    Solid::build().cuboid_from_size(size).translate(center)
+}
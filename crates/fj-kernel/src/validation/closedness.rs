@@ -0,0 +1,225 @@
+use std::fmt;
+
+use fj_math::{Point, Scalar};
+
+use crate::{
+    algorithms::oriented_cycles,
+    objects::{Edge, Shell},
+};
+
+use super::{edge_positions, geometric::edge_range};
+
+pub fn validate_shell(
+    shell: &Shell,
+    max_distance: impl Into<Scalar>,
+) -> Result<(), ClosednessIssues> {
+    let max_distance = max_distance.into();
+
+    // Tri-rep faces don't have any edges that could be matched up.
+    if shell.faces().any(|face| face.triangles().is_some()) {
+        return Ok(());
+    }
+
+    // Edges are matched by their geometry, not by their identity. Neighboring
+    // faces don't necessarily refer to the same curve, and edges without
+    // vertices don't have anything else to compare.
+    //
+    // Edges that collapse into a single point, like the ones at the poles of
+    // a sphere, don't border anything and are ignored.
+    let mut half_edges: Vec<_> = shell
+        .faces()
+        .flat_map(oriented_cycles)
+        .flatten()
+        .map(HalfEdge::new)
+        .filter(|half_edge| !half_edge.is_degenerate(max_distance))
+        .collect();
+
+    // Matching half-edges have the same midpoint. Sorting by one of its
+    // coordinates limits the search for them to a narrow window.
+    half_edges.sort_by_key(|half_edge| half_edge.mid().x);
+
+    let mut issues = ClosednessIssues::default();
+
+    for (i, half_edge) in half_edges.iter().enumerate() {
+        let x = half_edge.mid().x;
+        let first = half_edges
+            .partition_point(|other| other.mid().x < x - max_distance);
+
+        let matches: Vec<_> = (first..half_edges.len())
+            .take_while(|&j| half_edges[j].mid().x <= x + max_distance)
+            .filter(|&j| {
+                j != i && half_edge.is_same_edge(&half_edges[j], max_distance)
+            })
+            .collect();
+
+        // Issues that involve multiple half-edges are only reported once.
+        match matches.as_slice() {
+            [] => issues.boundary_edges.push(half_edge.edge.clone()),
+            &[j] => {
+                if i < j && !half_edge.is_opposite(&half_edges[j]) {
+                    issues.misoriented_edges.push(half_edge.edge.clone());
+                }
+            }
+            _ => {
+                if matches.iter().all(|&j| i < j) {
+                    issues.non_manifold_edges.push(half_edge.edge.clone());
+                }
+            }
+        }
+    }
+
+    if !issues.is_empty() {
+        return Err(issues);
+    }
+
+    Ok(())
+}
+
+/// An edge, as a face goes along it
+///
+/// The edge is oriented, such that its face is on its left, when looking at
+/// the front of the face.
+struct HalfEdge {
+    edge: Edge,
+
+    /// Points at the start, a quarter, half, three quarters, and the end of
+    /// the edge
+    points: [Point<3>; 5],
+}
+
+impl HalfEdge {
+    fn new(edge: Edge) -> Self {
+        let [start, end] = edge_range(&edge);
+
+        let points = [0., 0.25, 0.5, 0.75, 1.].map(|fraction| {
+            let t = start + (end - start) * fraction;
+            edge.curve().global().kind().point_from_curve_coords([t])
+        });
+
+        Self { edge, points }
+    }
+
+    fn start(&self) -> Point<3> {
+        self.points[0]
+    }
+
+    fn mid(&self) -> Point<3> {
+        self.points[2]
+    }
+
+    fn end(&self) -> Point<3> {
+        self.points[4]
+    }
+
+    fn is_degenerate(&self, max_distance: Scalar) -> bool {
+        self.points
+            .iter()
+            .all(|&point| distance(point, self.start()) <= max_distance)
+    }
+
+    fn is_same_edge(&self, other: &Self, max_distance: Scalar) -> bool {
+        let is_close =
+            |a: Point<3>, b: Point<3>| distance(a, b) <= max_distance;
+
+        let same_ends = is_close(self.start(), other.start())
+            && is_close(self.end(), other.end());
+        let opposite_ends = is_close(self.start(), other.end())
+            && is_close(self.end(), other.start());
+
+        (same_ends || opposite_ends) && is_close(self.mid(), other.mid())
+    }
+
+    /// Determine whether another half-edge of the same edge goes the other way
+    ///
+    /// The start and end of closed edges are the same, so this compares the
+    /// points in between. They don't need to match exactly, as the edges might
+    /// be parametrized differently.
+    fn is_opposite(&self, other: &Self) -> bool {
+        let [_, quarter, _, three_quarters, _] = self.points;
+        let other_quarter = other.points[1];
+
+        distance(other_quarter, three_quarters)
+            < distance(other_quarter, quarter)
+    }
+}
+
+fn distance(a: Point<3>, b: Point<3>) -> Scalar {
+    (a - b).magnitude()
+}
+
+/// Closedness issues found during validation
+///
+/// Used by [`ValidationError`].
+///
+/// [`ValidationError`]: super::ValidationError
+#[derive(Debug, Default, thiserror::Error)]
+pub struct ClosednessIssues {
+    /// Edges that only border a single face
+    pub boundary_edges: Vec<Edge>,
+
+    /// Edges that are shared by more than two faces
+    pub non_manifold_edges: Vec<Edge>,
+
+    /// Edges that are shared by two faces going along them the same way
+    ///
+    /// This means one of the faces is facing the wrong way.
+    pub misoriented_edges: Vec<Edge>,
+}
+
+impl ClosednessIssues {
+    fn is_empty(&self) -> bool {
+        self.boundary_edges.is_empty()
+            && self.non_manifold_edges.is_empty()
+            && self.misoriented_edges.is_empty()
+    }
+
+    pub(super) fn positions(&self) -> Vec<Point<3>> {
+        [
+            &self.boundary_edges,
+            &self.non_manifold_edges,
+            &self.misoriented_edges,
+        ]
+        .into_iter()
+        .flatten()
+        .flat_map(edge_positions)
+        .collect()
+    }
+}
+
+impl fmt::Display for ClosednessIssues {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "Closedness issues found:")?;
+
+        for (description, edges) in [
+            ("Boundary edges", &self.boundary_edges),
+            ("Non-manifold edges", &self.non_manifold_edges),
+            ("Misoriented edges", &self.misoriented_edges),
+        ] {
+            if !edges.is_empty() {
+                writeln!(f, "- {}:", description)?;
+
+                for edge in edges {
+                    writeln!(f, "  - {}", DisplayEdge(edge))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+struct DisplayEdge<'r>(&'r Edge);
+
+impl fmt::Display for DisplayEdge<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.0.vertices().get() {
+            Some([a, b]) => write!(
+                f,
+                "from {:?} to {:?}",
+                a.global().position(),
+                b.global().position(),
+            ),
+            None => write!(f, "{:?}", self.0.curve().global().kind()),
+        }
+    }
+}
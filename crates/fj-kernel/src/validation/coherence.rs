@@ -34,6 +34,7 @@ pub fn validate_edge(
 
     if !edge_vertex_mismatches.is_empty() {
         return Err(CoherenceIssues {
+            edge: Box::new(edge.clone()),
             edge_vertex_mismatches,
         });
     }
@@ -41,20 +42,32 @@ pub fn validate_edge(
     Ok(())
 }
 
-/// Geometric issues found during validation
+/// Coherence issues found during validation
 ///
 /// Used by [`ValidationError`].
 ///
 /// [`ValidationError`]: super::ValidationError
-#[derive(Debug, Default, thiserror::Error)]
+#[derive(Debug, thiserror::Error)]
 pub struct CoherenceIssues {
+    /// The edge whose local and global forms don't match
+    pub edge: Box<Edge>,
+
     /// Mismatches between the local and global forms of edge vertices
     pub edge_vertex_mismatches: Vec<CoherenceMismatch<Point<1>, Point<3>>>,
 }
 
+impl CoherenceIssues {
+    pub(super) fn positions(&self) -> Vec<Point<3>> {
+        self.edge_vertex_mismatches
+            .iter()
+            .map(|mismatch| mismatch.global)
+            .collect()
+    }
+}
+
 impl fmt::Display for CoherenceIssues {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        writeln!(f, "Geometric issues found:")?;
+        writeln!(f, "Coherence issues found:")?;
 
         if !self.edge_vertex_mismatches.is_empty() {
             writeln!(f, "- Edge vertex mismatches:")?;
@@ -0,0 +1,428 @@
+use fj_interop::debug::DebugInfo;
+use fj_math::{Aabb, Point, Scalar, Triangle, Vector};
+
+use crate::{
+    algorithms::{
+        oriented_cycles, signed_area, triangulate, FaceApprox, Tolerance,
+    },
+    objects::{CurveKind, Cycle, Edge, Face, Solid},
+};
+
+use super::edge_positions;
+
+/// The number of points per edge, when sampling curved edges
+const CURVE_SAMPLES: u64 = 32;
+
+pub fn validate_edge(
+    edge: &Edge,
+    min_distance: Scalar,
+) -> Result<(), GeometricIssues> {
+    // An edge can collapse into a single point in 3D, while still having a
+    // length in surface coordinates. This happens wherever the surface
+    // degenerates, like at the pole of a sphere, and is fine.
+    let fractions = [0., 0.25, 0.5, 0.75, 1.];
+    let [start, end] = edge_range(edge);
+    let t = |fraction| start + (end - start) * fraction;
+
+    let local = fractions.map(|fraction| {
+        edge.curve().kind().point_from_curve_coords([t(fraction)])
+    });
+    let global = fractions.map(|fraction| {
+        edge.curve()
+            .global()
+            .kind()
+            .point_from_curve_coords([t(fraction)])
+    });
+
+    if is_point(&local, min_distance) && is_point(&global, min_distance) {
+        return Err(GeometricIssues::DegenerateEdge {
+            edge: Box::new(edge.clone()),
+            position: global[0],
+        });
+    }
+
+    Ok(())
+}
+
+pub fn validate_cycle(
+    cycle: &Cycle,
+    min_distance: Scalar,
+) -> Result<(), GeometricIssues> {
+    let polylines: Vec<_> = cycle.edges().map(sample_edge).collect();
+
+    for (i, a) in polylines.iter().enumerate() {
+        for b in &polylines[i + 1..] {
+            if !aabb_2d(a).overlaps(&aabb_2d(b), min_distance) {
+                continue;
+            }
+
+            // Edges touch where one ends and the other starts. That's not an
+            // intersection.
+            let ends = [a[0], a[a.len() - 1]];
+            let is_shared_end = |point: Point<2>| {
+                ends.iter().any(|&end| {
+                    distance(point, end) <= min_distance
+                        && (distance(end, b[0]) <= min_distance
+                            || distance(end, b[b.len() - 1]) <= min_distance)
+                })
+            };
+
+            for segment_a in a.windows(2) {
+                for segment_b in b.windows(2) {
+                    let point = match segment_segment_intersection(
+                        [segment_a[0], segment_a[1]],
+                        [segment_b[0], segment_b[1]],
+                    ) {
+                        Some(point) => point,
+                        None => continue,
+                    };
+
+                    if !is_shared_end(point) {
+                        return Err(GeometricIssues::SelfIntersectingCycle {
+                            cycle: Box::new(cycle.clone()),
+                            position: cycle
+                                .surface()
+                                .point_from_surface_coords(point),
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_face(
+    face: &Face,
+    min_distance: Scalar,
+) -> Result<(), GeometricIssues> {
+    if face.triangles().is_some() {
+        return Ok(());
+    }
+
+    // Oriented exterior cycles have a positive area, oriented interior ones a
+    // negative one. Together, they add up to the area of the face.
+    let area: f64 = oriented_cycles(face).iter().map(signed_area).sum();
+
+    if area <= (min_distance * min_distance).into_f64() {
+        return Err(GeometricIssues::ZeroAreaFace {
+            face: Box::new(face.clone()),
+        });
+    }
+
+    Ok(())
+}
+
+pub fn validate_solid(
+    solid: &Solid,
+    min_distance: Scalar,
+) -> Result<(), GeometricIssues> {
+    let faces: Vec<_> = solid
+        .faces()
+        .filter(|face| face.triangles().is_none())
+        .collect();
+
+    // Faces are compared using approximations, whose accuracy is derived from
+    // the size of the solid. Not all edges have vertices, so the size is
+    // determined by sampling the edges instead.
+    let points: Vec<_> = faces
+        .iter()
+        .flat_map(|face| face.all_cycles())
+        .flat_map(|cycle| cycle.edges())
+        .flat_map(|edge| {
+            let [start, end] = edge_range(edge);
+            [0., 0.25, 0.5, 0.75, 1.].map(|fraction| {
+                edge.curve()
+                    .global()
+                    .kind()
+                    .point_from_curve_coords([start + (end - start) * fraction])
+            })
+        })
+        .collect();
+    if points.is_empty() {
+        return Ok(());
+    }
+    let aabb = match Aabb::<3>::from_points(points) {
+        aabb if aabb.size().magnitude() > min_distance => aabb,
+        _ => return Ok(()),
+    };
+    let tolerance = Tolerance::from_scalar(aabb.size().magnitude() * 1e-3)
+        .expect("Tolerance derived from size of solid is positive");
+
+    // Intersections this close to the boundary of a face are indistinguishable
+    // from faces that meet there, due to the approximation.
+    let margin = tolerance.inner() * 4.;
+
+    let approx: Vec<_> = faces
+        .iter()
+        .filter_map(|&face| {
+            FaceGeometry::new(face, tolerance).map(|geometry| (face, geometry))
+        })
+        .collect();
+
+    // Where two faces intersect, an edge of one of them passes through the
+    // other. That's not true for intersections that form closed loops, which
+    // aren't detected.
+    for (i, (face_a, a)) in approx.iter().enumerate() {
+        for (j, (face_b, b)) in approx.iter().enumerate() {
+            if i == j || !a.aabb.overlaps(&b.aabb, margin) {
+                continue;
+            }
+
+            for segment in a.boundary.iter().flat_map(|polyline| {
+                polyline.windows(2).map(|segment| [segment[0], segment[1]])
+            }) {
+                let [start, end] = segment;
+                let direction = end - start;
+
+                if !Aabb::<3>::from_points(segment).overlaps(&b.aabb, margin) {
+                    continue;
+                }
+
+                for triangle in &b.triangles {
+                    let toi = match triangle
+                        .cast_local_ray(start, direction, 1., true)
+                    {
+                        Some(toi) => toi,
+                        None => continue,
+                    };
+                    let point = start + direction * toi;
+
+                    if b.distance_to_boundary(point) > margin {
+                        return Err(GeometricIssues::IntersectingFaces {
+                            faces: Box::new([
+                                (*face_a).clone(),
+                                (*face_b).clone(),
+                            ]),
+                            position: point,
+                        });
+                    }
+                }
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// The approximated geometry of a face, as required to check for intersections
+struct FaceGeometry {
+    aabb: Aabb<3>,
+    boundary: Vec<Vec<Point<3>>>,
+    triangles: Vec<Triangle<3>>,
+}
+
+impl FaceGeometry {
+    /// Returns `None`, if the face doesn't have any area to intersect with
+    fn new(face: &Face, tolerance: Tolerance) -> Option<Self> {
+        let approx = FaceApprox::new(face, tolerance);
+
+        let boundary: Vec<Vec<_>> = [approx.exterior]
+            .into_iter()
+            .chain(approx.interiors)
+            .map(|cycle| {
+                cycle
+                    .points
+                    .into_iter()
+                    .map(|point| *point.global_form())
+                    .collect()
+            })
+            .collect();
+
+        let triangles: Vec<_> =
+            triangulate(vec![face.clone()], tolerance, &mut DebugInfo::new())
+                .triangles()
+                .map(|triangle| triangle.inner)
+                .collect();
+        if triangles.is_empty() {
+            return None;
+        }
+
+        let aabb = Aabb::<3>::from_points(
+            triangles.iter().flat_map(|triangle| triangle.points()),
+        );
+
+        Some(Self {
+            aabb,
+            boundary,
+            triangles,
+        })
+    }
+
+    fn distance_to_boundary(&self, point: Point<3>) -> Scalar {
+        self.boundary
+            .iter()
+            .flat_map(|polyline| {
+                let closing = [polyline[polyline.len() - 1], polyline[0]];
+                polyline
+                    .windows(2)
+                    .map(|segment| [segment[0], segment[1]])
+                    .chain([closing])
+            })
+            .map(|segment| distance_to_segment(point, segment))
+            .min()
+            .unwrap_or(Scalar::MAX)
+    }
+}
+
+trait Overlaps {
+    fn overlaps(&self, other: &Self, margin: Scalar) -> bool;
+}
+
+impl<const D: usize> Overlaps for Aabb<D> {
+    fn overlaps(&self, other: &Self, margin: Scalar) -> bool {
+        (0..D).all(|i| {
+            self.min.coords.components[i]
+                <= other.max.coords.components[i] + margin
+                && other.min.coords.components[i]
+                    <= self.max.coords.components[i] + margin
+        })
+    }
+}
+
+/// The range of the edge, in curve coordinates
+///
+/// An edge without vertices covers the whole domain of its curve.
+pub(super) fn edge_range(edge: &Edge) -> [Scalar; 2] {
+    match (edge.vertices().get(), edge.curve().kind()) {
+        (Some(vertices), _) => vertices.map(|vertex| vertex.position().t),
+        (None, CurveKind::Nurbs(nurbs)) => nurbs.domain(),
+        (None, _) => [Scalar::ZERO, Scalar::PI * 2.],
+    }
+}
+
+/// Sample an edge in surface coordinates, from its start to its end
+fn sample_edge(edge: &Edge) -> Vec<Point<2>> {
+    let [start, end] = edge_range(edge);
+
+    let num_samples = match edge.curve().kind() {
+        CurveKind::Line(_) => 1,
+        _ => CURVE_SAMPLES,
+    };
+
+    (0..=num_samples)
+        .map(|i| {
+            let t = start
+                + (end - start) * Scalar::from_u64(i)
+                    / Scalar::from_u64(num_samples);
+            edge.curve().kind().point_from_curve_coords([t])
+        })
+        .collect()
+}
+
+fn aabb_2d(points: &[Point<2>]) -> Aabb<2> {
+    Aabb::<2>::from_points(points.iter().copied())
+}
+
+fn is_point<const D: usize>(points: &[Point<D>], min_distance: Scalar) -> bool {
+    points
+        .iter()
+        .all(|&point| distance(point, points[0]) <= min_distance)
+}
+
+fn distance<const D: usize>(a: Point<D>, b: Point<D>) -> Scalar {
+    (a - b).magnitude()
+}
+
+fn distance_to_segment(point: Point<3>, [a, b]: [Point<3>; 2]) -> Scalar {
+    let ab = b - a;
+    let length_squared = ab.dot(&ab);
+
+    let t = if length_squared == Scalar::ZERO {
+        Scalar::ZERO
+    } else {
+        ((point - a).dot(&ab) / length_squared)
+            .max(Scalar::ZERO)
+            .min(Scalar::ONE)
+    };
+
+    distance(point, a + ab * t)
+}
+
+fn segment_segment_intersection(
+    [a, b]: [Point<2>; 2],
+    [c, d]: [Point<2>; 2],
+) -> Option<Point<2>> {
+    let cross = |u: Vector<2>, v: Vector<2>| u.u * v.v - u.v * v.u;
+
+    let ab = b - a;
+    let cd = d - c;
+    let denominator = cross(ab, cd);
+
+    // Parallel segments are not considered to intersect. Where they overlap,
+    // so do non-parallel neighbors of theirs.
+    if denominator == Scalar::ZERO {
+        return None;
+    }
+
+    let t = cross(c - a, cd) / denominator;
+    let s = cross(c - a, ab) / denominator;
+
+    let range = Scalar::ZERO..=Scalar::ONE;
+    if range.contains(&t) && range.contains(&s) {
+        return Some(a + ab * t);
+    }
+
+    None
+}
+
+/// Geometric issues found during validation
+///
+/// Used by [`ValidationError`].
+///
+/// [`ValidationError`]: super::ValidationError
+#[derive(Debug, thiserror::Error)]
+pub enum GeometricIssues {
+    /// An edge has zero length
+    #[error("Edge at {position:?} has zero length")]
+    DegenerateEdge {
+        /// The edge
+        edge: Box<Edge>,
+
+        /// Where the edge is
+        position: Point<3>,
+    },
+
+    /// Two faces of a solid intersect
+    #[error("Faces intersect at {position:?}")]
+    IntersectingFaces {
+        /// The faces
+        faces: Box<[Face; 2]>,
+
+        /// A point where the faces intersect
+        position: Point<3>,
+    },
+
+    /// A cycle intersects itself
+    #[error("Cycle intersects itself at {position:?}")]
+    SelfIntersectingCycle {
+        /// The cycle
+        cycle: Box<Cycle>,
+
+        /// A point where the cycle intersects itself
+        position: Point<3>,
+    },
+
+    /// A face has zero area
+    #[error("Face has zero area")]
+    ZeroAreaFace {
+        /// The face
+        face: Box<Face>,
+    },
+}
+
+impl GeometricIssues {
+    pub(super) fn positions(&self) -> Vec<Point<3>> {
+        match self {
+            Self::DegenerateEdge { position, .. }
+            | Self::IntersectingFaces { position, .. }
+            | Self::SelfIntersectingCycle { position, .. } => vec![*position],
+            Self::ZeroAreaFace { face } => face
+                .all_cycles()
+                .flat_map(|cycle| cycle.edges())
+                .flat_map(edge_positions)
+                .collect(),
+        }
+    }
+}
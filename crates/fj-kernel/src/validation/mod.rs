@@ -1,8 +1,10 @@
 //! Infrastructure for validating shapes
 //!
 //! Validation enforces various constraints about shapes and the objects that
-//! constitute them. These constraints fall into 4 categories:
+//! constitute them. These constraints fall into 5 categories:
 //!
+//! - **Closedness:** The faces of a shell must form a closed shape. Every
+//!   edge must be shared by exactly two faces, with opposite orientation.
 //! - **Coherence:** Local forms of objects must be consistent with their
 //!   canonical forms.
 //! - **Geometric:** Comprises various object-specific constraints, for example
@@ -14,25 +16,82 @@
 //! Please note that not all of these validation categories are fully
 //! implemented, as of this writing.
 
+mod closedness;
 mod coherence;
+mod geometric;
+mod structural;
 mod uniqueness;
 
 pub use self::{
+    closedness::ClosednessIssues,
     coherence::{CoherenceIssues, CoherenceMismatch},
+    geometric::GeometricIssues,
+    structural::StructuralIssues,
     uniqueness::UniquenessIssues,
 };
 
-use std::{collections::HashSet, ops::Deref};
+use std::{collections::HashSet, convert::Infallible, error, fmt, ops::Deref};
 
-use fj_math::Scalar;
+use fj_math::{Point, Scalar};
 
-use crate::iter::ObjectIters;
+use crate::{iter::ObjectIters, objects::Edge};
 
 /// Validate the given object
+///
+/// Returns the first error that is found, unless
+/// [`ValidationConfig::collect_all`] is set. In that case, all errors are
+/// returned as [`ValidationError::Multiple`].
 pub fn validate<T>(
     object: T,
     config: &ValidationConfig,
 ) -> Result<Validated<T>, ValidationError>
+where
+    T: for<'r> ObjectIters<'r>,
+{
+    if config.collect_all {
+        return Ok(validate_all(object, config)?);
+    }
+
+    check(&object, config, Err)?;
+    Ok(Validated(object))
+}
+
+/// Validate the given object, collecting all errors
+///
+/// Unlike [`validate`], this doesn't stop at the first error. It runs all
+/// checks and returns a [`ValidationReport`] that lists every error found.
+pub fn validate_all<T>(
+    object: T,
+    config: &ValidationConfig,
+) -> Result<Validated<T>, ValidationReport>
+where
+    T: for<'r> ObjectIters<'r>,
+{
+    let mut errors = Vec::new();
+
+    let result = check(&object, config, |error| {
+        errors.push(error);
+        Ok::<_, Infallible>(())
+    });
+    if let Err(never) = result {
+        match never {}
+    }
+
+    if !errors.is_empty() {
+        return Err(ValidationReport { errors });
+    }
+
+    Ok(Validated(object))
+}
+
+/// Run all checks, passing each error found to `handle`
+///
+/// Validation stops, if `handle` returns an error.
+fn check<T, E>(
+    object: &T,
+    config: &ValidationConfig,
+    mut handle: impl FnMut(ValidationError) -> Result<(), E>,
+) -> Result<(), E>
 where
     T: for<'r> ObjectIters<'r>,
 {
@@ -43,16 +102,46 @@ where
             vertex,
             &vertices,
             config.distinct_min_distance,
-        )?;
+        )
+        .or_else(|issues| handle(issues.into()))?;
 
         vertices.insert(*vertex);
     }
 
     for edge in object.edge_iter() {
-        coherence::validate_edge(edge, config.identical_max_distance)?;
+        coherence::validate_edge(edge, config.identical_max_distance)
+            .or_else(|issues| handle(issues.into()))?;
+        geometric::validate_edge(edge, config.distinct_min_distance)
+            .or_else(|issues| handle(issues.into()))?;
+        structural::validate_edge(edge, config.identical_max_distance)
+            .or_else(|issues| handle(issues.into()))?;
     }
 
-    Ok(Validated(object))
+    for cycle in object.cycle_iter() {
+        geometric::validate_cycle(cycle, config.distinct_min_distance)
+            .or_else(|issues| handle(issues.into()))?;
+        structural::validate_cycle(cycle, config.identical_max_distance)
+            .or_else(|issues| handle(issues.into()))?;
+    }
+
+    for face in object.face_iter() {
+        geometric::validate_face(face, config.distinct_min_distance)
+            .or_else(|issues| handle(issues.into()))?;
+    }
+
+    if config.check_face_intersections {
+        for solid in object.solid_iter() {
+            geometric::validate_solid(solid, config.distinct_min_distance)
+                .or_else(|issues| handle(issues.into()))?;
+        }
+    }
+
+    for shell in object.shell_iter() {
+        closedness::validate_shell(shell, config.distinct_min_distance)
+            .or_else(|issues| handle(issues.into()))?;
+    }
+
+    Ok(())
 }
 
 /// Configuration required for the validation process
@@ -71,6 +160,18 @@ pub struct ValidationConfig {
     /// that distance is less than the one defined in this field, can not be
     /// considered identical.
     pub identical_max_distance: Scalar,
+
+    /// Collect all errors, instead of stopping at the first one
+    ///
+    /// This is slower, as all checks need to run, even if the first one
+    /// already failed. Useful for reporting all problems with a model at once.
+    pub collect_all: bool,
+
+    /// Check that the faces of a solid don't intersect each other
+    ///
+    /// This requires triangulating every face of every solid, which is too slow
+    /// to do on every validation. Disabled by default.
+    pub check_face_intersections: bool,
 }
 
 impl Default for ValidationConfig {
@@ -83,6 +184,9 @@ impl Default for ValidationConfig {
             // false positives due to floating-point accuracy issues), we can
             // adjust it.
             identical_max_distance: Scalar::from_f64(5e-14),
+
+            collect_all: false,
+            check_face_intersections: false,
         }
     }
 }
@@ -112,31 +216,154 @@ impl<T> Deref for Validated<T> {
 #[allow(clippy::large_enum_variant)]
 #[derive(Debug, thiserror::Error)]
 pub enum ValidationError {
+    /// Closedness validation failed
+    #[error("Closedness validation failed")]
+    Closedness(#[from] ClosednessIssues),
+
     /// Coherence validation failed
     #[error("Coherence validation failed")]
     Coherence(#[from] CoherenceIssues),
 
     /// Geometric validation failed
     #[error("Geometric validation failed")]
-    Geometric,
+    Geometric(#[from] GeometricIssues),
+
+    /// Multiple validation errors were found
+    ///
+    /// Only returned by [`validate`], if [`ValidationConfig::collect_all`] is
+    /// set.
+    #[error("Multiple validation errors found")]
+    Multiple(#[from] ValidationReport),
+
+    /// Structural validation failed
+    #[error("Structural validation failed")]
+    Structural(#[from] StructuralIssues),
 
     /// Uniqueness validation failed
     #[error("Uniqueness validation failed")]
     Uniqueness(#[from] UniquenessIssues),
 }
 
+impl ValidationError {
+    /// Access the positions of the objects that failed validation
+    ///
+    /// Can be used to highlight those objects in a model.
+    pub fn positions(&self) -> Vec<Point<3>> {
+        match self {
+            Self::Closedness(issues) => issues.positions(),
+            Self::Coherence(issues) => issues.positions(),
+            Self::Geometric(issues) => issues.positions(),
+            Self::Multiple(report) => report.positions(),
+            Self::Structural(issues) => issues.positions(),
+            Self::Uniqueness(issues) => issues.positions(),
+        }
+    }
+}
+
+/// All errors found during a validation
+///
+/// Returned by [`validate_all`], or by [`validate`] as part of
+/// [`ValidationError::Multiple`].
+#[derive(Debug, thiserror::Error)]
+pub struct ValidationReport {
+    /// The errors found
+    pub errors: Vec<ValidationError>,
+}
+
+impl ValidationReport {
+    /// Access the positions of all objects that failed validation
+    ///
+    /// See [`ValidationError::positions`].
+    pub fn positions(&self) -> Vec<Point<3>> {
+        self.errors
+            .iter()
+            .flat_map(ValidationError::positions)
+            .collect()
+    }
+}
+
+impl fmt::Display for ValidationReport {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        writeln!(f, "{} validation errors found:", self.errors.len())?;
+
+        for error in &self.errors {
+            writeln!(f, "- {}", error)?;
+
+            if let Some(source) = error::Error::source(error) {
+                for line in source.to_string().lines() {
+                    writeln!(f, "  {}", line)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// Access the positions of an edge's vertices
+///
+/// Falls back to a point on the edge's curve, if the edge has no vertices.
+pub(crate) fn edge_positions(edge: &Edge) -> Vec<Point<3>> {
+    match edge.vertices().get() {
+        Some(vertices) => {
+            vertices.map(|vertex| vertex.global().position()).to_vec()
+        }
+        None => {
+            vec![edge.curve().global().kind().point_from_curve_coords([0.])]
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use fj_math::{Point, Scalar};
+    use fj_interop::mesh::Color;
+    use fj_math::{NurbsCurve, Point, Scalar, Triangle};
 
     use crate::{
+        algorithms::{reverse_face, TransformObject},
         objects::{
-            Curve, CurveKind, Edge, GlobalCurve, GlobalVertex, Vertex,
-            VerticesOfEdge,
+            Curve, CurveKind, Cycle, Edge, Face, GlobalCurve, GlobalVertex,
+            Shell, Solid, Surface, Vertex, VerticesOfEdge,
+        },
+        validation::{
+            validate, validate_all, GeometricIssues, StructuralIssues,
+            ValidationConfig, ValidationError,
         },
-        validation::{validate, ValidationConfig, ValidationError},
     };
 
+    #[test]
+    fn closedness_shell() -> anyhow::Result<()> {
+        let faces: Vec<_> = Solid::build()
+            .cube_from_edge_length(1.)
+            .into_faces()
+            .collect();
+        let config = ValidationConfig::default();
+
+        validate(Shell::new().with_faces(faces.clone()), &config)?;
+
+        // A shell with a missing face is not closed.
+        let shell = Shell::new().with_faces(faces[1..].to_vec());
+        let result = validate(shell, &config);
+        assert!(matches!(
+            result,
+            Err(ValidationError::Closedness(issues))
+                if issues.boundary_edges.len() == 4
+        ));
+
+        // A shell with a face that points inwards is not closed either.
+        let mut faces = faces;
+        faces[0] = reverse_face(&faces[0]);
+        let shell = Shell::new().with_faces(faces);
+        let result = validate(shell, &config);
+        assert!(matches!(
+            result,
+            Err(ValidationError::Closedness(issues))
+                if issues.misoriented_edges.len() == 4
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn coherence_edge() {
         let a = Point::from([0., 0., 0.]);
@@ -161,7 +388,7 @@ mod tests {
         let edge = Edge::new(curve, vertices);
 
         let result = validate(
-            edge,
+            edge.clone(),
             &ValidationConfig {
                 identical_max_distance: deviation * 2.,
                 ..ValidationConfig::default()
@@ -179,6 +406,191 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn geometric_edge() {
+        let a = Point::from([0., 0., 0.]);
+
+        // Lines can't have zero length, but NURBS curves can.
+        let curve = {
+            fn point<const D: usize>(point: Point<D>) -> CurveKind<D> {
+                CurveKind::Nurbs(NurbsCurve::new(
+                    1,
+                    [point; 2],
+                    [1., 1.].map(Scalar::from),
+                    [0., 0., 1., 1.].map(Scalar::from),
+                ))
+            }
+
+            let curve_local = point(Point::from([0., 0.]));
+            let curve_global = GlobalCurve::from_kind(point(a));
+            Curve::new(curve_local, curve_global)
+        };
+
+        let a = GlobalVertex::from_position(a);
+        let vertices = VerticesOfEdge::from_vertices([
+            Vertex::new(Point::from([0.]), a),
+            Vertex::new(Point::from([1.]), a),
+        ]);
+
+        let edge = Edge::new(curve, vertices);
+
+        let result = validate(edge, &ValidationConfig::default());
+        assert!(matches!(
+            result,
+            Err(ValidationError::Geometric(
+                GeometricIssues::DegenerateEdge { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn geometric_cycle() {
+        let cycle = Cycle::build(Surface::xy_plane()).polygon_from_points([
+            [0., 0.],
+            [1., 1.],
+            [1., 0.],
+            [0., 1.],
+        ]);
+
+        let result = validate(cycle, &ValidationConfig::default());
+        assert!(matches!(
+            result,
+            Err(ValidationError::Geometric(
+                GeometricIssues::SelfIntersectingCycle { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn geometric_face() {
+        let face = Face::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 0.], [2., 0.]])
+            .into_face();
+
+        let result = validate(face, &ValidationConfig::default());
+        assert!(matches!(
+            result,
+            Err(ValidationError::Geometric(
+                GeometricIssues::ZeroAreaFace { .. }
+            ))
+        ));
+    }
+
+    #[test]
+    fn geometric_solid() -> anyhow::Result<()> {
+        let cube = Solid::build().cube_from_edge_length(1.);
+        let config = ValidationConfig {
+            check_face_intersections: true,
+            ..ValidationConfig::default()
+        };
+
+        let apart = cube.clone().translate([2., 0., 0.]);
+        let solid = Solid::new()
+            .with_faces(cube.clone().into_faces())
+            .with_faces(apart.into_faces());
+        validate(solid, &config)?;
+
+        let overlapping = cube.clone().translate([0.5, 0.5, 0.5]);
+        let solid = Solid::new()
+            .with_faces(cube.into_faces())
+            .with_faces(overlapping.into_faces());
+        let result = validate(solid.clone(), &config);
+        assert!(matches!(
+            result,
+            Err(ValidationError::Geometric(
+                GeometricIssues::IntersectingFaces { .. }
+            ))
+        ));
+
+        // The check is opt-in.
+        validate(solid, &ValidationConfig::default())?;
+
+        // Faces in triangle representation don't have vertices to derive the
+        // size of the solid from.
+        let tetrahedron =
+            [[0., 0., 0.], [1., 0., 0.], [0., 1., 0.], [0., 0., 1.]]
+                .map(Point::from);
+        let [a, b, c, d] = tetrahedron;
+        let faces =
+            [[a, c, b], [a, b, d], [b, c, d], [a, d, c]].map(|points| {
+                let triangle = Triangle::from_points(points)
+                    .expect("Tetrahedron faces are not degenerate");
+                Face::from_triangles(vec![(triangle, Color::default())])
+            });
+        validate(Solid::new().with_faces(faces), &config)?;
+
+        Ok(())
+    }
+
+    #[test]
+    fn structural_edge() {
+        let curve = Curve::build(Surface::xy_plane())
+            .line_from_points([[0., 0.], [1., 0.]]);
+
+        // The global form of the second vertex is not on the curve.
+        let a = GlobalVertex::from_position([0., 0., 0.]);
+        let b = GlobalVertex::from_position([1., 1., 0.]);
+        let vertices = VerticesOfEdge::from_vertices([
+            Vertex::new(Point::from([0.]), a),
+            Vertex::new(Point::from([1.]), b),
+        ]);
+
+        let edge = Edge::new(curve, vertices);
+
+        // The vertex mismatch is also a coherence issue, which is found first.
+        let config = ValidationConfig {
+            collect_all: true,
+            ..ValidationConfig::default()
+        };
+
+        let errors = match validate(edge, &config) {
+            Err(ValidationError::Multiple(report)) => report.errors,
+            result => panic!("Expected multiple errors, got {result:?}"),
+        };
+        assert!(errors.iter().any(|error| matches!(
+            error,
+            ValidationError::Structural(
+                StructuralIssues::VertexNotOnCurve { .. }
+            )
+        )));
+    }
+
+    #[test]
+    fn structural_cycle() -> anyhow::Result<()> {
+        let points = [[0., 0.], [1., 0.], [1., 1.], [0., 1.]];
+        let config = ValidationConfig::default();
+
+        let cycle =
+            Cycle::build(Surface::xy_plane()).polygon_from_points(points);
+        validate(cycle.clone(), &config)?;
+
+        // A cycle with a missing edge is not closed.
+        let edges: Vec<_> = cycle.edges().skip(1).cloned().collect();
+        let open = Cycle::new(Surface::xy_plane()).with_edges(edges);
+        let result = validate(open, &config);
+        assert!(matches!(
+            result,
+            Err(ValidationError::Structural(
+                StructuralIssues::CycleNotClosed { .. }
+            ))
+        ));
+
+        // The edges of a cycle must be on its surface.
+        let edges = Cycle::build(Surface::xz_plane())
+            .polygon_from_points(points)
+            .into_edges();
+        let misplaced = Cycle::new(Surface::xy_plane()).with_edges(edges);
+        let result = validate(misplaced, &config);
+        assert!(matches!(
+            result,
+            Err(ValidationError::Structural(
+                StructuralIssues::EdgeNotOnSurface { .. }
+            ))
+        ));
+
+        Ok(())
+    }
+
     #[test]
     fn uniqueness_vertex() -> anyhow::Result<()> {
         let mut shape = Vec::new();
@@ -206,4 +618,116 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn validate_all_errors() {
+        let self_intersecting = Cycle::build(Surface::xy_plane())
+            .polygon_from_points([[0., 0.], [1., 1.], [1., 0.], [0., 1.]]);
+        let open = {
+            let edges = Cycle::build(Surface::xy_plane())
+                .polygon_from_points([[2., 0.], [3., 0.], [3., 1.], [2., 1.]])
+                .into_edges()
+                .skip(1);
+            Cycle::new(Surface::xy_plane()).with_edges(edges)
+        };
+        let cycles = vec![self_intersecting, open];
+        let config = ValidationConfig::default();
+
+        // Regular validation stops at the first error...
+        let result = validate(cycles.clone(), &config);
+        assert!(result.is_err());
+
+        // ...but all errors end up in the report.
+        let report = validate_all(cycles, &config)
+            .expect_err("Invalid cycles should fail validation");
+        assert_eq!(report.errors.len(), 2);
+        assert!(report.errors.iter().any(|error| matches!(
+            error,
+            ValidationError::Geometric(
+                GeometricIssues::SelfIntersectingCycle { .. }
+            )
+        )));
+        assert!(report.errors.iter().any(|error| matches!(
+            error,
+            ValidationError::Structural(
+                StructuralIssues::CycleNotClosed { .. }
+            )
+        )));
+        assert!(!report.positions().is_empty());
+    }
+
+    #[test]
+    fn validate_collect_all() {
+        let cube: Vec<_> = Solid::build()
+            .cube_from_edge_length(1.)
+            .into_faces()
+            .collect();
+        let corner = GlobalVertex::from_position([0.5, 0.5, 0.5]);
+
+        // A triangle next to the cube. One of its vertices is almost, but not
+        // quite, at one of the cube's corners. The local form of that vertex
+        // is off, on one of the edges.
+        let surface = Surface::xy_plane().translate([0., 0., 0.5]);
+        let mut edges: Vec<_> = Cycle::build(surface.clone())
+            .polygon_from_points([[0.5 + 1e-7, 0.5], [2., 1.], [1., 2.]])
+            .into_edges()
+            .collect();
+        let incoherent = {
+            let edge = edges.pop().expect("Triangle has edges");
+            let [a, b] = edge.vertices().expect_vertices();
+            let b = Vertex::new(Point::from([0.75]), *b.global());
+            Edge::new(
+                edge.curve().clone(),
+                VerticesOfEdge::from_vertices([a, b]),
+            )
+        };
+        edges.push(incoherent.clone());
+        let triangle = Face::new(surface.clone())
+            .with_exteriors([Cycle::new(surface).with_edges(edges)]);
+
+        // The bottom face of the cube is missing.
+        let shell = Shell::new()
+            .with_faces(cube[1..].to_vec())
+            .with_faces([triangle]);
+
+        let config = ValidationConfig {
+            collect_all: true,
+            ..ValidationConfig::default()
+        };
+        let errors = match validate(shell, &config) {
+            Err(ValidationError::Multiple(report)) => report.errors,
+            result => panic!("Expected multiple errors, got {result:?}"),
+        };
+
+        let mut uniqueness = 0;
+        let mut coherence = 0;
+        let mut closedness = 0;
+        for error in &errors {
+            match error {
+                ValidationError::Uniqueness(issues) => {
+                    assert_eq!(issues.duplicate_vertex, Some(corner));
+                    uniqueness += 1;
+                }
+                ValidationError::Coherence(issues) => {
+                    assert_eq!(*issues.edge, incoherent);
+                    coherence += 1;
+                }
+                ValidationError::Closedness(issues) => {
+                    // The 4 edges around the missing face, as well as the 3
+                    // edges of the triangle.
+                    assert_eq!(issues.boundary_edges.len(), 7);
+                    assert!(issues.misoriented_edges.is_empty());
+                    assert!(issues.non_manifold_edges.is_empty());
+                    closedness += 1;
+                }
+                error => panic!("Unexpected error: {error}"),
+            }
+        }
+        assert_eq!([uniqueness, coherence, closedness], [1, 1, 1]);
+
+        // Without the flag, validation still stops at the first error.
+        let shell = Shell::new().with_faces(cube[1..].to_vec());
+        let result = validate(shell, &ValidationConfig::default());
+        assert!(matches!(result, Err(ValidationError::Closedness(_))));
+    }
 }
@@ -0,0 +1,136 @@
+use fj_math::{Point, Scalar};
+
+use crate::objects::{Cycle, Edge, GlobalVertex};
+
+use super::{edge_positions, geometric::edge_range};
+
+pub fn validate_edge(
+    edge: &Edge,
+    max_distance: Scalar,
+) -> Result<(), StructuralIssues> {
+    // The local form of a vertex is defined in curve coordinates, so it can't
+    // help but lie on the curve. Its global form is independent of the curve
+    // though, and needs to be checked.
+    for vertex in edge.vertices().iter() {
+        let curve = edge.curve().global().kind();
+        let position = vertex.global().position();
+        let closest = curve
+            .point_from_curve_coords(curve.point_to_curve_coords(position));
+
+        if (closest - position).magnitude() > max_distance {
+            return Err(StructuralIssues::VertexNotOnCurve {
+                edge: Box::new(edge.clone()),
+                vertex: *vertex.global(),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+pub fn validate_cycle(
+    cycle: &Cycle,
+    max_distance: Scalar,
+) -> Result<(), StructuralIssues> {
+    // The local form of an edge's curve is defined in the coordinates of the
+    // cycle's surface. Converted into model coordinates, it must match the
+    // global form of the curve.
+    for edge in cycle.edges() {
+        let [start, end] = edge_range(edge);
+
+        for fraction in [0., 0.25, 0.5, 0.75, 1.] {
+            let t = start + (end - start) * fraction;
+
+            let local = edge.curve().kind().point_from_curve_coords([t]);
+            let local_as_global =
+                cycle.surface().point_from_surface_coords(local);
+            let global =
+                edge.curve().global().kind().point_from_curve_coords([t]);
+
+            if (local_as_global - global).magnitude() > max_distance {
+                return Err(StructuralIssues::EdgeNotOnSurface {
+                    cycle: Box::new(cycle.clone()),
+                    edge: Box::new(edge.clone()),
+                    position: global,
+                });
+            }
+        }
+    }
+
+    // A cycle that consists of a single edge without vertices, like a circle,
+    // is closed by definition. In any other cycle, every edge must start where
+    // the previous one ends.
+    let edges: Vec<_> = cycle.edges().collect();
+    if let [edge] = edges.as_slice() {
+        if edge.vertices().get().is_none() {
+            return Ok(());
+        }
+    }
+
+    for (i, edge) in edges.iter().enumerate() {
+        let next = edges[(i + 1) % edges.len()];
+
+        let end = edge.vertices().get().map(|[_, b]| *b.global());
+        let start = next.vertices().get().map(|[a, _]| *a.global());
+
+        if end.is_none() || end != start {
+            return Err(StructuralIssues::CycleNotClosed {
+                cycle: Box::new(cycle.clone()),
+                edge: Box::new((*edge).clone()),
+            });
+        }
+    }
+
+    Ok(())
+}
+
+/// Structural issues found during validation
+///
+/// Used by [`ValidationError`].
+///
+/// [`ValidationError`]: super::ValidationError
+#[derive(Debug, thiserror::Error)]
+pub enum StructuralIssues {
+    /// An edge of a cycle is not connected to the next one
+    #[error("Cycle is not closed after edge {edge:?}")]
+    CycleNotClosed {
+        /// The cycle
+        cycle: Box<Cycle>,
+
+        /// The edge that is not connected to the next one
+        edge: Box<Edge>,
+    },
+
+    /// An edge of a cycle does not lie on the cycle's surface
+    #[error("Edge is not on the surface of its cycle at {position:?}")]
+    EdgeNotOnSurface {
+        /// The cycle
+        cycle: Box<Cycle>,
+
+        /// The edge
+        edge: Box<Edge>,
+
+        /// A point of the edge that is not on the surface
+        position: Point<3>,
+    },
+
+    /// A vertex of an edge does not lie on the edge's curve
+    #[error("Vertex {vertex:?} is not on the curve of its edge")]
+    VertexNotOnCurve {
+        /// The edge
+        edge: Box<Edge>,
+
+        /// The vertex
+        vertex: GlobalVertex,
+    },
+}
+
+impl StructuralIssues {
+    pub(super) fn positions(&self) -> Vec<Point<3>> {
+        match self {
+            Self::CycleNotClosed { edge, .. } => edge_positions(edge),
+            Self::EdgeNotOnSurface { position, .. } => vec![*position],
+            Self::VertexNotOnCurve { vertex, .. } => vec![vertex.position()],
+        }
+    }
+}
@@ -1,6 +1,6 @@
 use std::{collections::HashSet, fmt};
 
-use fj_math::Scalar;
+use fj_math::{Point, Scalar};
 
 use crate::objects::GlobalVertex;
 
@@ -38,6 +38,15 @@ pub struct UniquenessIssues {
     pub duplicate_vertex: Option<GlobalVertex>,
 }
 
+impl UniquenessIssues {
+    pub(super) fn positions(&self) -> Vec<Point<3>> {
+        self.duplicate_vertex
+            .iter()
+            .map(|vertex| vertex.position())
+            .collect()
+    }
+}
+
 impl fmt::Display for UniquenessIssues {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         writeln!(f, "Uniqueness issues found:")?;
@@ -0,0 +1,219 @@
+use crate::{NurbsCurve, Point, Scalar, Vector};
+
+/// An n-dimensional Bézier curve
+///
+/// The curve is defined by its control points. Its degree is one less than the
+/// number of control points. The dimensionality of the curve is defined by the
+/// const generic `D` parameter.
+///
+/// Curve coordinates range from `0`, at the first control point, to `1`, at
+/// the last one.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct BezierCurve<const D: usize> {
+    control_points: Vec<Point<D>>,
+}
+
+impl<const D: usize> BezierCurve<D> {
+    /// Construct a Bézier curve from its control points
+    ///
+    /// # Panics
+    ///
+    /// Panics, if less than two control points are provided.
+    pub fn from_control_points(
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+    ) -> Self {
+        let control_points: Vec<_> =
+            control_points.into_iter().map(Into::into).collect();
+
+        assert!(
+            control_points.len() >= 2,
+            "Bézier curve needs at least two control points"
+        );
+
+        Self { control_points }
+    }
+
+    /// Access the degree of the curve
+    pub fn degree(&self) -> usize {
+        self.control_points.len() - 1
+    }
+
+    /// Access the control points of the curve
+    pub fn control_points(&self) -> &[Point<D>] {
+        &self.control_points
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// The reversed curve starts where this one ends, and vice versa.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.control_points.reverse();
+        self
+    }
+
+    /// Convert a point in curve coordinates into a `D`-dimensional point
+    pub fn point_from_curve_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let t = point.into().t;
+
+        // De Casteljau's algorithm
+        let mut points = self.control_points.clone();
+        for n in (1..points.len()).rev() {
+            for i in 0..n {
+                points[i] = points[i] + (points[i + 1] - points[i]) * t;
+            }
+        }
+
+        points[0]
+    }
+
+    /// Compute the first derivative of the curve at the given curve coordinate
+    pub fn derivative_from_curve_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Vector<D> {
+        let t = point.into().t;
+
+        // The derivative of a Bézier curve is another Bézier curve, of one
+        // degree less, whose control points are the scaled differences of the
+        // original control points.
+        let degree = Scalar::from_u64(self.degree() as u64);
+        let mut vectors: Vec<_> = self
+            .control_points
+            .windows(2)
+            .map(|points| (points[1] - points[0]) * degree)
+            .collect();
+
+        for n in (1..vectors.len()).rev() {
+            for i in 0..n {
+                vectors[i] = vectors[i] + (vectors[i + 1] - vectors[i]) * t;
+            }
+        }
+
+        vectors[0]
+    }
+
+    /// Split the curve at the given curve coordinate
+    ///
+    /// Returns the two parts of the curve, each of which has the same degree
+    /// as this one and covers the full range of curve coordinates.
+    pub fn split(&self, point: impl Into<Point<1>>) -> [Self; 2] {
+        let t = point.into().t;
+
+        let mut points = self.control_points.clone();
+        let mut first = vec![points[0]];
+        let mut second = vec![points[points.len() - 1]];
+
+        for n in (1..points.len()).rev() {
+            for i in 0..n {
+                points[i] = points[i] + (points[i + 1] - points[i]) * t;
+            }
+            first.push(points[0]);
+            second.push(points[n - 1]);
+        }
+
+        second.reverse();
+
+        [first, second].map(|control_points| Self { control_points })
+    }
+
+    /// Convert the curve into a NURBS curve
+    ///
+    /// The NURBS curve is identical to this one, including its curve
+    /// coordinates.
+    pub fn to_nurbs(&self) -> NurbsCurve<D> {
+        NurbsCurve::from_control_points(
+            self.degree(),
+            self.control_points.iter().copied(),
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Scalar, Vector};
+
+    use super::BezierCurve;
+
+    #[test]
+    fn point_from_curve_coords() {
+        let curve =
+            BezierCurve::from_control_points([[0., 0.], [1., 2.], [2., 0.]]);
+
+        assert_eq!(curve.point_from_curve_coords([0.]), Point::from([0., 0.]));
+        assert_eq!(curve.point_from_curve_coords([0.5]), Point::from([1., 1.]));
+        assert_eq!(curve.point_from_curve_coords([1.]), Point::from([2., 0.]));
+    }
+
+    #[test]
+    fn derivative_from_curve_coords() {
+        let curve =
+            BezierCurve::from_control_points([[0., 0.], [1., 2.], [2., 0.]]);
+
+        assert_eq!(
+            curve.derivative_from_curve_coords([0.]),
+            Vector::from([2., 4.])
+        );
+        assert_eq!(
+            curve.derivative_from_curve_coords([0.5]),
+            Vector::from([2., 0.])
+        );
+
+        let line = BezierCurve::from_control_points([[0., 0.], [1., 2.]]);
+        assert_eq!(
+            line.derivative_from_curve_coords([0.5]),
+            Vector::from([1., 2.])
+        );
+    }
+
+    #[test]
+    fn split() {
+        let curve = BezierCurve::from_control_points([
+            [0., 0.],
+            [1., 2.],
+            [3., 2.],
+            [4., 0.],
+        ]);
+
+        let [a, b] = curve.split([0.25]);
+
+        for i in 0..=10 {
+            let t = i as f64 / 10.;
+
+            assert_close(
+                a.point_from_curve_coords([t]),
+                curve.point_from_curve_coords([t * 0.25]),
+            );
+            assert_close(
+                b.point_from_curve_coords([t]),
+                curve.point_from_curve_coords([t * 0.75 + 0.25]),
+            );
+        }
+    }
+
+    #[test]
+    fn to_nurbs() {
+        let curve = BezierCurve::from_control_points([
+            [0., 0.],
+            [1., 2.],
+            [3., 2.],
+            [4., 0.],
+        ]);
+        let nurbs = curve.to_nurbs();
+
+        for i in 0..=10 {
+            let t = i as f64 / 10.;
+            assert_close(
+                nurbs.point_from_curve_coords([t]),
+                curve.point_from_curve_coords([t]),
+            );
+        }
+    }
+
+    fn assert_close(a: Point<2>, b: Point<2>) {
+        assert!((a - b).magnitude() < Scalar::from(1e-12), "{a:?} != {b:?}");
+    }
+}
@@ -0,0 +1,125 @@
+use crate::{BezierCurve, Point, Scalar};
+
+/// An n-dimensional, uniform Catmull-Rom spline
+///
+/// The spline passes through all of its points, except the first and the last
+/// one. Those only define the tangents at the start and end of the spline. The
+/// dimensionality of the spline is defined by the const generic `D` parameter.
+///
+/// Curve coordinates range from `0`, at the second point, to `n - 3`, at the
+/// second-to-last point, where `n` is the number of points. Each span between
+/// two points covers a range of `1`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct CatmullRomSpline<const D: usize> {
+    points: Vec<Point<D>>,
+}
+
+impl<const D: usize> CatmullRomSpline<D> {
+    /// Construct a Catmull-Rom spline from its points
+    ///
+    /// # Panics
+    ///
+    /// Panics, if less than four points are provided.
+    pub fn from_points(
+        points: impl IntoIterator<Item = impl Into<Point<D>>>,
+    ) -> Self {
+        let points: Vec<_> = points.into_iter().map(Into::into).collect();
+
+        assert!(
+            points.len() >= 4,
+            "Catmull-Rom spline needs at least four points"
+        );
+
+        Self { points }
+    }
+
+    /// Access the points of the spline
+    pub fn points(&self) -> &[Point<D>] {
+        &self.points
+    }
+
+    /// Access the number of spans of the spline
+    pub fn num_spans(&self) -> usize {
+        self.points.len() - 3
+    }
+
+    /// Convert a point in curve coordinates into a `D`-dimensional point
+    pub fn point_from_curve_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let t = point.into().t;
+
+        let last_span = Scalar::from_u64(self.num_spans() as u64 - 1);
+        let span = t.floor().max(Scalar::ZERO).min(last_span);
+
+        self.span(span.into_u64() as usize)
+            .point_from_curve_coords([t - span])
+    }
+
+    /// Convert the spline into Bézier curves, one per span
+    ///
+    /// The conversion is exact. Each span's curve coordinates are shifted, to
+    /// range from `0` to `1`.
+    pub fn to_bezier_curves(&self) -> Vec<BezierCurve<D>> {
+        (0..self.num_spans()).map(|i| self.span(i)).collect()
+    }
+
+    fn span(&self, i: usize) -> BezierCurve<D> {
+        let [p0, p1, p2, p3] = [0, 1, 2, 3].map(|j| self.points[i + j]);
+
+        // The tangent at each point is half the vector between its neighbors.
+        // A cubic Bézier curve's tangents at its ends are three times the
+        // vectors to the inner control points.
+        BezierCurve::from_control_points([
+            p1,
+            p1 + (p2 - p0) / 6.,
+            p2 - (p3 - p1) / 6.,
+            p2,
+        ])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Point, Scalar};
+
+    use super::CatmullRomSpline;
+
+    #[test]
+    fn passes_through_points() {
+        let spline = CatmullRomSpline::from_points([
+            [0., 0.],
+            [1., 1.],
+            [2., 0.],
+            [3., 1.],
+            [4., 0.],
+        ]);
+
+        assert_eq!(spline.num_spans(), 2);
+        assert_eq!(spline.point_from_curve_coords([0.]), Point::from([1., 1.]));
+        assert_eq!(spline.point_from_curve_coords([1.]), Point::from([2., 0.]));
+        assert_eq!(spline.point_from_curve_coords([2.]), Point::from([3., 1.]));
+    }
+
+    #[test]
+    fn spans_are_tangent() {
+        let spline = CatmullRomSpline::from_points([
+            [0., 0.],
+            [1., 1.],
+            [2., 0.],
+            [3., 2.],
+            [5., 0.],
+        ]);
+
+        let [a, b] = [0, 1].map(|i| spline.to_bezier_curves()[i].clone());
+        let epsilon = Scalar::from(1e-12);
+
+        assert!(
+            (a.derivative_from_curve_coords([1.])
+                - b.derivative_from_curve_coords([0.]))
+            .magnitude()
+                < epsilon
+        );
+    }
+}
@@ -1,6 +1,4 @@
-use approx::AbsDiffEq;
-
-use crate::{Point, Scalar, Vector};
+use crate::{NurbsCurve, Point, Scalar, Vector};
 
 /// An n-dimensional circle
 ///
@@ -33,21 +31,14 @@ impl<const D: usize> Circle<D> {
         let a = a.into();
         let b = b.into();
 
-        assert_eq!(
-            a.magnitude(),
-            b.magnitude(),
-            "`a` and `b` must be of equal length"
-        );
+        assert!(is_equal_length(a, b), "`a` and `b` must be of equal length");
         assert_ne!(
             a.magnitude(),
             Scalar::ZERO,
             "circle radius must not be zero"
         );
-        // Requiring the vector to be *precisely* perpendicular is not
-        // practical, because of numerical inaccuracy. This epsilon value seems
-        // seems to work for now, but maybe it needs to become configurable.
         assert!(
-            a.dot(&b) < Scalar::default_epsilon(),
+            is_perpendicular(a, b),
             "`a` and `b` must be perpendicular to each other"
         );
 
@@ -102,8 +93,15 @@ impl<const D: usize> Circle<D> {
         &self,
         point: impl Into<Point<D>>,
     ) -> Point<1> {
-        let vector = (point.into() - self.center).to_uv();
-        let atan = Scalar::atan2(vector.v, vector.u);
+        let vector = point.into() - self.center;
+
+        // `a` and `b` are perpendicular and of equal length, so the components
+        // of the vector along them are the cosine and sine of the angle, scaled
+        // by the same factor.
+        let cos = vector.dot(&self.a);
+        let sin = vector.dot(&self.b);
+
+        let atan = Scalar::atan2(sin, cos);
         let coord = if atan >= Scalar::ZERO {
             atan
         } else {
@@ -130,6 +128,117 @@ impl<const D: usize> Circle<D> {
 
         self.a * cos + self.b * sin
     }
+
+    /// Convert an arc of the circle into a NURBS curve
+    ///
+    /// The arc goes from the first to the second circle coordinate in `range`,
+    /// which may be more than a full turn apart. The NURBS curve describes the
+    /// arc exactly, but is defined over the domain `0..1`.
+    pub fn to_nurbs(&self, range: [impl Into<Scalar>; 2]) -> NurbsCurve<D> {
+        let [start, end] = range.map(Into::into);
+        let (num_spans, span) = spans_of_arc(end - start);
+        let weight = (span / 2.).cos();
+
+        let mut control_points = Vec::new();
+        let mut weights = Vec::new();
+        let mut knots = vec![Scalar::ZERO; 3];
+
+        for i in 0..num_spans {
+            let t = start + span * i as f64;
+
+            if i > 0 {
+                let knot = Scalar::from_u64(i) / Scalar::from_u64(num_spans);
+                knots.extend([knot; 2]);
+            }
+
+            // The middle control point is where the tangents at the ends of
+            // the span intersect.
+            control_points.push(self.point_from_circle_coords([t]));
+            control_points.push(
+                self.center
+                    + self.vector_from_circle_coords([t + span / 2.]) / weight,
+            );
+            weights.extend([Scalar::ONE, weight]);
+        }
+
+        control_points.push(self.point_from_circle_coords([end]));
+        weights.push(Scalar::ONE);
+        knots.extend([Scalar::ONE; 3]);
+
+        NurbsCurve::new(2, control_points, weights, knots)
+    }
+
+    /// Convert circle coordinates into curve coordinates of a NURBS arc
+    ///
+    /// Returns the curve coordinate at which the NURBS curve, that
+    /// [`Circle::to_nurbs`] returns for `range`, passes through the point at
+    /// the given circle coordinate. The conversion only depends on `range`, so
+    /// it applies to any circle that is converted over the same range.
+    pub fn point_to_nurbs_coords(
+        &self,
+        range: [impl Into<Scalar>; 2],
+        point: impl Into<Point<1>>,
+    ) -> Point<1> {
+        let [start, end] = range.map(Into::into);
+        let (num_spans, span) = spans_of_arc(end - start);
+
+        if span == Scalar::ZERO {
+            return Point::from([0.]);
+        }
+
+        // Find the span that the point is in. Points outside of the range are
+        // extrapolated from the first or last span.
+        let position = (point.into().t - start) / span;
+        let last_span = Scalar::from_u64(num_spans - 1);
+        let i = position.floor().max(Scalar::ZERO).min(last_span);
+
+        // A rational quadratic Bézier curve with these weights is the
+        // stereographic projection of its arc, which means the tangent of half
+        // the angle from the middle of the arc is linear in the curve
+        // coordinate.
+        let half_span = span / 2.;
+        let angle = (position - i) * span - half_span;
+        let s =
+            ((angle / 2.).tan() / (half_span / 2.).tan() + Scalar::ONE) / 2.;
+
+        Point::from([(i + s) / Scalar::from_u64(num_spans)])
+    }
+}
+
+/// Compute the number and angle of the spans of an arc's NURBS representation
+fn spans_of_arc(angle: Scalar) -> (u64, Scalar) {
+    // Each span of the NURBS curve is a rational quadratic Bézier curve, which
+    // can cover at most a quarter of a circle while keeping a nice shape.
+    let num_spans = (angle.abs() / (Scalar::PI / 2.))
+        .ceil()
+        .max(Scalar::ONE)
+        .into_u64();
+
+    (num_spans, angle / num_spans as f64)
+}
+
+/// The tolerance of the requirements on `a` and `b`, relative to the radius
+///
+/// Requiring the vectors to be *precisely* perpendicular and of equal length is
+/// not practical, because of numerical inaccuracy. This value seems to work for
+/// now, but maybe it needs to become configurable.
+const RELATIVE_EPSILON: f64 = 1e-9;
+
+/// Check whether `a` and `b` are of equal length, as required for a circle
+pub(crate) fn is_equal_length<const D: usize>(
+    a: Vector<D>,
+    b: Vector<D>,
+) -> bool {
+    let [a, b] = [a, b].map(|vector| vector.magnitude());
+    (a - b).abs() <= a.max(b) * RELATIVE_EPSILON
+}
+
+/// Check whether `a` and `b` are perpendicular, as required for a circle
+pub(crate) fn is_perpendicular<const D: usize>(
+    a: Vector<D>,
+    b: Vector<D>,
+) -> bool {
+    a.dot(&b).abs() <= a.magnitude() * b.magnitude() * RELATIVE_EPSILON
 }
 
 impl<const D: usize> approx::AbsDiffEq for Circle<D> {
@@ -150,10 +259,66 @@ impl<const D: usize> approx::AbsDiffEq for Circle<D> {
 mod tests {
     use std::f64::consts::{FRAC_PI_2, PI};
 
-    use crate::{Point, Vector};
+    use crate::{Point, Scalar, Vector};
 
     use super::Circle;
 
+    #[test]
+    fn to_nurbs() {
+        let circle = Circle {
+            center: Point::from([1., 2.]),
+            a: Vector::from([2., 0.]),
+            b: Vector::from([0., 2.]),
+        };
+
+        for range in [[0., PI * 2.], [FRAC_PI_2, 0.], [0., 3.]] {
+            let curve = circle.to_nurbs(range);
+
+            for i in 0..=10 {
+                let t = i as f64 / 10.;
+                let point = curve.point_from_curve_coords([t]);
+
+                let radius = (point - circle.center()).magnitude();
+                assert!(
+                    (radius - Scalar::from(2.)).abs() < Scalar::from(1e-12)
+                );
+            }
+
+            let [start, end] =
+                range.map(|t| circle.point_from_circle_coords([t]));
+            assert_eq!(curve.point_from_curve_coords([0.]), start);
+            assert!(
+                (curve.point_from_curve_coords([1.]) - end).magnitude()
+                    < Scalar::from(1e-12)
+            );
+        }
+    }
+
+    #[test]
+    fn point_to_nurbs_coords() {
+        let circle = Circle {
+            center: Point::from([1., 2.]),
+            a: Vector::from([2., 0.]),
+            b: Vector::from([0., 2.]),
+        };
+
+        for range in [[0., PI * 2.], [FRAC_PI_2, 0.], [1., -3.]] {
+            let curve = circle.to_nurbs(range);
+
+            for i in 0..=10 {
+                let [start, end] = range;
+                let t = start + (end - start) * i as f64 / 10.;
+
+                let s = circle.point_to_nurbs_coords(range, [t]);
+                let expected = circle.point_from_circle_coords([t]);
+                assert!(
+                    (curve.point_from_curve_coords(s) - expected).magnitude()
+                        < Scalar::from(1e-12)
+                );
+            }
+        }
+    }
+
     #[test]
     fn point_to_circle_coords() {
         let circle = Circle {
@@ -178,5 +343,20 @@ mod tests {
             circle.point_to_circle_coords([1., 1., 3.]),
             Point::from([FRAC_PI_2 * 3.]),
         );
+
+        let circle = Circle {
+            center: Point::from([1., 2., 3.]),
+            a: Vector::from([0., 0., 1.]),
+            b: Vector::from([0., -1., 0.]),
+        };
+
+        assert_eq!(
+            circle.point_to_circle_coords([1., 2., 4.]),
+            Point::from([0.]),
+        );
+        assert_eq!(
+            circle.point_to_circle_coords([1., 1., 3.]),
+            Point::from([FRAC_PI_2]),
+        );
     }
 }
@@ -36,9 +36,12 @@
 #![warn(missing_docs)]
 
 mod aabb;
+mod bezier;
+mod catmull_rom;
 mod circle;
 mod coordinates;
 mod line;
+mod nurbs;
 mod point;
 mod poly_chain;
 mod scalar;
@@ -49,9 +52,12 @@ mod vector;
 
 pub use self::{
     aabb::Aabb,
+    bezier::BezierCurve,
+    catmull_rom::CatmullRomSpline,
     circle::Circle,
     coordinates::{Uv, Xyz, T},
     line::Line,
+    nurbs::{NurbsCurve, NurbsSurface},
     point::Point,
     poly_chain::PolyChain,
     scalar::Scalar,
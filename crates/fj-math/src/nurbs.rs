@@ -0,0 +1,1327 @@
+use nalgebra::DMatrix;
+
+use crate::{Point, Scalar, Vector};
+
+/// An n-dimensional NURBS curve
+///
+/// A non-uniform rational B-spline, defined by its degree, its control points,
+/// a weight per control point, and a knot vector. The dimensionality of the
+/// curve is defined by the const generic `D` parameter.
+///
+/// Curve coordinates are parameter values within the range returned by
+/// [`NurbsCurve::domain`].
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct NurbsCurve<const D: usize> {
+    degree: usize,
+    control_points: Vec<Point<D>>,
+    weights: Vec<Scalar>,
+    knots: Vec<Scalar>,
+}
+
+impl<const D: usize> NurbsCurve<D> {
+    /// Construct a NURBS curve
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the following requirements are not met:
+    ///
+    /// - `degree` must not be zero.
+    /// - There must be more control points than `degree`.
+    /// - There must be one weight per control point, and all weights must be
+    ///   positive.
+    /// - The number of knots must be the number of control points, plus
+    ///   `degree`, plus one.
+    /// - The knots must be non-decreasing, the domain they define must not be
+    ///   empty, and no knot within the domain may be repeated more than
+    ///   `degree` times.
+    pub fn new(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+        weights: impl IntoIterator<Item = impl Into<Scalar>>,
+        knots: impl IntoIterator<Item = impl Into<Scalar>>,
+    ) -> Self {
+        let control_points: Vec<_> =
+            control_points.into_iter().map(Into::into).collect();
+        let weights: Vec<_> = weights.into_iter().map(Into::into).collect();
+        let knots: Vec<_> = knots.into_iter().map(Into::into).collect();
+
+        assert_ne!(degree, 0, "degree must not be zero");
+        assert!(
+            control_points.len() > degree,
+            "there must be more control points than the degree"
+        );
+        assert_eq!(
+            weights.len(),
+            control_points.len(),
+            "there must be one weight per control point"
+        );
+        assert!(
+            weights.iter().all(|&weight| weight > Scalar::ZERO),
+            "weights must be positive"
+        );
+        assert_eq!(
+            knots.len(),
+            control_points.len() + degree + 1,
+            "number of knots must be number of control points + degree + 1"
+        );
+        assert!(
+            knots.windows(2).all(|knots| knots[0] <= knots[1]),
+            "knots must be non-decreasing"
+        );
+
+        let curve = Self {
+            degree,
+            control_points,
+            weights,
+            knots,
+        };
+
+        let [start, end] = curve.domain();
+        assert!(start < end, "domain must not be empty");
+        assert!(
+            curve
+                .knots
+                .iter()
+                .filter(|&&knot| start < knot && knot < end)
+                .all(|&knot| curve.multiplicity(knot) <= degree),
+            "knots within the domain must not be repeated more than `degree` \
+            times"
+        );
+
+        curve
+    }
+
+    /// Construct a non-rational B-spline curve from its control points
+    ///
+    /// Uses a clamped, uniform knot vector over the domain `0..1`, meaning the
+    /// curve starts at the first control point and ends at the last one. All
+    /// weights are one.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `degree` is zero, or if there are no more control points
+    /// than `degree`.
+    pub fn from_control_points(
+        degree: usize,
+        control_points: impl IntoIterator<Item = impl Into<Point<D>>>,
+    ) -> Self {
+        let control_points: Vec<_> =
+            control_points.into_iter().map(Into::into).collect();
+
+        let num_spans = control_points.len().saturating_sub(degree).max(1);
+        let knots = (0..control_points.len() + degree + 1).map(|i| {
+            let i = i.saturating_sub(degree).min(num_spans);
+            Scalar::from_u64(i as u64) / Scalar::from_u64(num_spans as u64)
+        });
+        let weights = vec![Scalar::ONE; control_points.len()];
+
+        Self::new(degree, control_points, weights, knots)
+    }
+
+    /// Access the degree of the curve
+    pub fn degree(&self) -> usize {
+        self.degree
+    }
+
+    /// Access the control points of the curve
+    pub fn control_points(&self) -> &[Point<D>] {
+        &self.control_points
+    }
+
+    /// Access the weights of the control points
+    pub fn weights(&self) -> &[Scalar] {
+        &self.weights
+    }
+
+    /// Access the knot vector of the curve
+    pub fn knots(&self) -> &[Scalar] {
+        &self.knots
+    }
+
+    /// Access the range of curve coordinates over which the curve is defined
+    pub fn domain(&self) -> [Scalar; 2] {
+        [
+            self.knots[self.degree],
+            self.knots[self.control_points.len()],
+        ]
+    }
+
+    /// Access the distinct knot values within the domain, including its ends
+    ///
+    /// Each span between two neighboring values is a polynomial (or rational)
+    /// piece of the curve.
+    pub fn breakpoints(&self) -> Vec<Scalar> {
+        let [start, end] = self.domain();
+
+        let mut breakpoints: Vec<_> = self
+            .knots
+            .iter()
+            .copied()
+            .filter(|&knot| start <= knot && knot <= end)
+            .collect();
+        breakpoints.dedup();
+
+        breakpoints
+    }
+
+    /// Create a new instance that is reversed
+    ///
+    /// The reversed curve is defined over the negated domain, meaning the
+    /// point at curve coordinate `t` of the reversed curve is the point at `-t`
+    /// of the original one.
+    #[must_use]
+    pub fn reverse(mut self) -> Self {
+        self.control_points.reverse();
+        self.weights.reverse();
+        self.knots = self.knots.iter().rev().map(|&knot| -knot).collect();
+        self
+    }
+
+    /// Convert a point in curve coordinates into a `D`-dimensional point
+    pub fn point_from_curve_coords(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> Point<D> {
+        let [point] = self.derivatives::<1>(point);
+        Point::origin() + point
+    }
+
+    /// Compute derivatives of the curve at the given curve coordinate
+    ///
+    /// Returns `N` vectors. The first one is the position of the curve point,
+    /// relative to the origin. Each following one is the next-higher
+    /// derivative.
+    pub fn derivatives<const N: usize>(
+        &self,
+        point: impl Into<Point<1>>,
+    ) -> [Vector<D>; N] {
+        let t = point.into().t.into_f64();
+        let p = self.degree;
+
+        let knots = to_f64(&self.knots);
+        let span = find_span(&knots, p, self.control_points.len(), t);
+        let basis = basis_function_derivatives::<N>(&knots, p, span, t);
+
+        // Derivatives of the curve in homogeneous coordinates, with the
+        // weighted control points and the weights kept separately.
+        let mut points = [Vector::from([Scalar::ZERO; D]); N];
+        let mut weights = [0.; N];
+        for (k, basis) in basis.iter().enumerate() {
+            for (j, &n) in basis.iter().enumerate() {
+                let i = span - p + j;
+                let weight = self.weights[i].into_f64() * n;
+
+                points[k] = points[k] + self.control_points[i].coords * weight;
+                weights[k] += weight;
+            }
+        }
+
+        let mut derivatives = [Vector::from([Scalar::ZERO; D]); N];
+        for k in 0..N {
+            let mut derivative = points[k];
+            for i in 1..=k {
+                derivative = derivative
+                    - derivatives[k - i] * (binomial(k, i) * weights[i]);
+            }
+            derivatives[k] = derivative / weights[0];
+        }
+
+        derivatives
+    }
+
+    /// Convert a `D`-dimensional point to curve coordinates
+    ///
+    /// Returns the curve coordinate of the curve point that is closest to the
+    /// provided point. The result is restricted to the domain of the curve.
+    ///
+    /// Callers are advised to be careful about the points they pass, as the
+    /// point not being on the curve, intentional or not, will not result in an
+    /// error.
+    pub fn point_to_curve_coords(
+        &self,
+        point: impl Into<Point<D>>,
+    ) -> Point<1> {
+        let point = point.into();
+        let [start, end] = self.domain();
+
+        // Newton's method finds the closest point reliably, if it starts close
+        // enough. Find a good starting value by sampling the curve.
+        let mut t = self
+            .samples()
+            .into_iter()
+            .min_by_key(|&t| {
+                (self.point_from_curve_coords([t]) - point).magnitude()
+            })
+            .unwrap_or(start);
+
+        for _ in 0..MAX_ITERATIONS {
+            let [position, d1, d2] = self.derivatives::<3>([t]);
+            let distance = position - point.coords;
+
+            let f = d1.dot(&distance);
+            let df = d2.dot(&distance) + d1.dot(&d1);
+            if df == Scalar::ZERO {
+                break;
+            }
+
+            let next = (t - f / df).max(start).min(end);
+            let is_converged = (next - t).abs().into_f64() <= EPSILON;
+
+            t = next;
+            if is_converged {
+                break;
+            }
+        }
+
+        Point::from([t])
+    }
+
+    /// Create a new instance with an additional knot
+    ///
+    /// The new instance describes the same curve, with one more control point.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `t` is not strictly within the domain, or if `t` is already a
+    /// knot that is repeated `degree` times.
+    #[must_use]
+    pub fn insert_knot(&self, t: impl Into<Point<1>>) -> Self {
+        let t = t.into().t;
+
+        let [start, end] = self.domain();
+        assert!(
+            start < t && t < end,
+            "knot must be inserted within the domain"
+        );
+
+        let p = self.degree;
+        let s = self.multiplicity(t);
+        assert!(s < p, "knot must not be repeated more than `degree` times");
+
+        let knots = to_f64(&self.knots);
+        let k = find_span(&knots, p, self.control_points.len(), t.into_f64());
+
+        let points = self.homogeneous_control_points();
+        let mut new_points = Vec::with_capacity(points.len() + 1);
+
+        new_points.extend_from_slice(&points[..=k - p]);
+        for i in k - p + 1..=k - s {
+            let alpha =
+                (t - self.knots[i]) / (self.knots[i + p] - self.knots[i]);
+
+            let (a, a_weight) = points[i - 1];
+            let (b, b_weight) = points[i];
+
+            new_points.push((
+                a * (Scalar::ONE - alpha) + b * alpha,
+                a_weight * (Scalar::ONE - alpha) + b_weight * alpha,
+            ));
+        }
+        new_points.extend_from_slice(&points[k - s..]);
+
+        let mut knots = self.knots.clone();
+        knots.insert(k + 1, t);
+
+        Self::from_homogeneous(p, new_points, knots)
+    }
+
+    /// Create a new instance with a degree that is higher by one
+    ///
+    /// The new instance describes the same curve. The multiplicity of each knot
+    /// is increased by one, to keep the continuity of the curve unchanged.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the knot vector is not clamped, meaning the first and the
+    /// last knot value are not repeated `degree + 1` times.
+    #[must_use]
+    pub fn elevate_degree(&self) -> Self {
+        let p = self.degree;
+        let [start, end] = self.domain();
+        assert!(
+            self.multiplicity(start) == p + 1
+                && self.multiplicity(end) == p + 1,
+            "degree elevation requires a clamped knot vector"
+        );
+
+        let degree = p + 1;
+        let mut knots = Vec::new();
+        for (i, &knot) in self.knots.iter().enumerate() {
+            knots.push(knot);
+
+            let is_last_of_its_value = self.knots.get(i + 1) != Some(&knot);
+            if is_last_of_its_value {
+                knots.push(knot);
+            }
+        }
+
+        // The elevated curve is computed by interpolating the homogeneous form
+        // of the original curve at the Greville abscissae of the new knot
+        // vector. The original curve lies within the space of the new basis
+        // functions, which means the interpolation reproduces it exactly.
+        let num_points = knots.len() - degree - 1;
+        let knots_f64 = to_f64(&knots);
+
+        let mut basis = DMatrix::<f64>::zeros(num_points, num_points);
+        let mut values = DMatrix::<f64>::zeros(num_points, D + 1);
+
+        for i in 0..num_points {
+            let t = knots_f64[i + 1..=i + degree].iter().sum::<f64>()
+                / degree as f64;
+
+            let span = find_span(&knots_f64, degree, num_points, t);
+            let [functions] =
+                basis_function_derivatives(&knots_f64, degree, span, t);
+            for (j, n) in functions.into_iter().enumerate() {
+                basis[(i, span - degree + j)] = n;
+            }
+
+            let (point, weight) = self.homogeneous_point(t);
+            for (j, component) in point.components.into_iter().enumerate() {
+                values[(i, j)] = component.into_f64();
+            }
+            values[(i, D)] = weight.into_f64();
+        }
+
+        let solution = basis
+            .lu()
+            .solve(&values)
+            .expect("Basis functions at Greville abscissae are independent");
+
+        let points = (0..num_points)
+            .map(|i| {
+                let mut point = Vector::from([Scalar::ZERO; D]);
+                for (j, component) in point.components.iter_mut().enumerate() {
+                    *component = Scalar::from_f64(solution[(i, j)]);
+                }
+
+                (point, Scalar::from_f64(solution[(i, D)]))
+            })
+            .collect();
+
+        Self::from_homogeneous(degree, points, knots)
+    }
+
+    /// Split the curve at the given curve coordinate
+    ///
+    /// Returns the two parts of the curve. Both keep the curve coordinates of
+    /// this curve, meaning the first part ends, and the second part starts, at
+    /// `t`.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `t` is not strictly within the domain.
+    pub fn split(&self, t: impl Into<Point<1>>) -> [Self; 2] {
+        let t = t.into().t;
+
+        let [start, end] = self.domain();
+        assert!(
+            start < t && t < end,
+            "curve must be split within the domain"
+        );
+
+        let mut curve = self.clone();
+        while curve.multiplicity(t) < curve.degree {
+            curve = curve.insert_knot([t]);
+        }
+
+        // With the knot repeated `degree` times, the curve passes through the
+        // control point right before the first occurrence of the knot.
+        let p = curve.degree;
+        let r = curve
+            .knots
+            .iter()
+            .position(|&knot| knot == t)
+            .expect("Knot has just been inserted");
+
+        let first = Self::new(
+            p,
+            curve.control_points[..r].iter().copied(),
+            curve.weights[..r].iter().copied(),
+            curve.knots[..r + p].iter().copied().chain([t]),
+        );
+        let second = Self::new(
+            p,
+            curve.control_points[r - 1..].iter().copied(),
+            curve.weights[r - 1..].iter().copied(),
+            [t].into_iter().chain(curve.knots[r..].iter().copied()),
+        );
+
+        [first, second]
+    }
+
+    /// Create a new instance that is defined over the given domain
+    ///
+    /// The new instance describes the same curve. Its curve coordinates are
+    /// mapped linearly from the old domain to the new one.
+    #[must_use]
+    pub fn reparametrize(&self, domain: [impl Into<Scalar>; 2]) -> Self {
+        let [start, end] = self.domain();
+        let [new_start, new_end] = domain.map(Into::into);
+
+        let knots = self.knots.iter().map(|&knot| {
+            new_start + (knot - start) / (end - start) * (new_end - new_start)
+        });
+
+        Self::new(
+            self.degree,
+            self.control_points.iter().copied(),
+            self.weights.iter().copied(),
+            knots,
+        )
+    }
+
+    fn from_homogeneous(
+        degree: usize,
+        points: Vec<(Vector<D>, Scalar)>,
+        knots: Vec<Scalar>,
+    ) -> Self {
+        let (control_points, weights): (Vec<_>, Vec<_>) = points
+            .into_iter()
+            .map(|(point, weight)| (Point::origin() + point / weight, weight))
+            .unzip();
+
+        Self::new(degree, control_points, weights, knots)
+    }
+
+    fn homogeneous_control_points(&self) -> Vec<(Vector<D>, Scalar)> {
+        self.control_points
+            .iter()
+            .zip(&self.weights)
+            .map(|(point, &weight)| (point.coords * weight, weight))
+            .collect()
+    }
+
+    /// Evaluate the curve in homogeneous coordinates
+    ///
+    /// Unlike the rational curve itself, this is a piecewise polynomial.
+    fn homogeneous_point(&self, t: f64) -> (Vector<D>, Scalar) {
+        let p = self.degree;
+
+        let knots = to_f64(&self.knots);
+        let span = find_span(&knots, p, self.control_points.len(), t);
+        let [functions] = basis_function_derivatives(&knots, p, span, t);
+
+        let mut point = Vector::from([Scalar::ZERO; D]);
+        let mut weight = Scalar::ZERO;
+        for (j, n) in functions.into_iter().enumerate() {
+            let i = span - p + j;
+            let w = self.weights[i] * n;
+
+            point = point + self.control_points[i].coords * w;
+            weight += w;
+        }
+
+        (point, weight)
+    }
+
+    fn multiplicity(&self, t: Scalar) -> usize {
+        self.knots.iter().filter(|&&knot| knot == t).count()
+    }
+
+    /// Curve coordinates that are spread over all spans of the curve
+    fn samples(&self) -> Vec<Scalar> {
+        let breakpoints = self.breakpoints();
+        let n = SAMPLES_PER_DEGREE * self.degree;
+
+        let mut samples = Vec::new();
+        for span in breakpoints.windows(2) {
+            let [start, end] = [span[0], span[1]];
+            for i in 0..n {
+                samples.push(start + (end - start) * (i as f64 / n as f64));
+            }
+        }
+        samples.extend(breakpoints.last());
+
+        samples
+    }
+}
+
+/// A NURBS surface
+///
+/// A non-uniform rational B-spline surface, defined by a degree and a knot
+/// vector for each of its two directions, and a grid of control points with a
+/// weight per control point. The dimensionality of the surface is defined by
+/// the const generic `D` parameter.
+///
+/// The control points are stored per row of constant `u`, meaning
+/// `control_points()[i][j]` is the control point with index `i` in the
+/// direction of `u`, and index `j` in the direction of `v`.
+#[derive(Clone, Debug, Eq, PartialEq, Hash, Ord, PartialOrd)]
+pub struct NurbsSurface<const D: usize> {
+    degree: [usize; 2],
+    control_points: Vec<Vec<Point<D>>>,
+    weights: Vec<Vec<Scalar>>,
+    knots: [Vec<Scalar>; 2],
+}
+
+impl<const D: usize> NurbsSurface<D> {
+    /// Construct a NURBS surface
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the rows of control points or weights don't all have the same
+    /// length, or if any of the requirements of [`NurbsCurve::new`] is not met
+    /// in either direction of the surface.
+    pub fn new(
+        degree: [usize; 2],
+        control_points: impl IntoIterator<
+            Item = impl IntoIterator<Item = impl Into<Point<D>>>,
+        >,
+        weights: impl IntoIterator<
+            Item = impl IntoIterator<Item = impl Into<Scalar>>,
+        >,
+        knots: [Vec<Scalar>; 2],
+    ) -> Self {
+        let control_points: Vec<Vec<_>> = control_points
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+        let weights: Vec<Vec<_>> = weights
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+
+        let num_v = control_points.first().map(Vec::len).unwrap_or(0);
+        assert!(
+            control_points.iter().all(|row| row.len() == num_v),
+            "rows of control points must have the same length"
+        );
+        assert_eq!(
+            weights.len(),
+            control_points.len(),
+            "there must be one weight per control point"
+        );
+        assert!(
+            weights.iter().all(|row| row.len() == num_v),
+            "there must be one weight per control point"
+        );
+
+        let surface = Self {
+            degree,
+            control_points,
+            weights,
+            knots,
+        };
+
+        // Constructing the curves along both directions validates the surface
+        // in each of them.
+        surface.curves_along_u();
+        surface.curves_along_v();
+
+        surface
+    }
+
+    /// Access the degree of the surface in the directions of `u` and `v`
+    pub fn degree(&self) -> [usize; 2] {
+        self.degree
+    }
+
+    /// Access the control points of the surface
+    pub fn control_points(&self) -> &[Vec<Point<D>>] {
+        &self.control_points
+    }
+
+    /// Access the weights of the control points
+    pub fn weights(&self) -> &[Vec<Scalar>] {
+        &self.weights
+    }
+
+    /// Access the knot vectors in the directions of `u` and `v`
+    pub fn knots(&self) -> &[Vec<Scalar>; 2] {
+        &self.knots
+    }
+
+    /// Access the ranges of `u` and `v`, over which the surface is defined
+    pub fn domain(&self) -> [[Scalar; 2]; 2] {
+        let num_u = self.control_points.len();
+        let num_v = self.control_points[0].len();
+
+        [
+            [self.knots[0][self.degree[0]], self.knots[0][num_u]],
+            [self.knots[1][self.degree[1]], self.knots[1][num_v]],
+        ]
+    }
+
+    /// Access the curve along `u`, at the given constant `v`
+    ///
+    /// The curve coordinates of the returned curve are the `u` coordinates of
+    /// the surface.
+    pub fn curve_along_u(&self, v: impl Into<Scalar>) -> NurbsCurve<D> {
+        let v = v.into().into_f64();
+
+        let points = self
+            .curves_along_v()
+            .iter()
+            .map(|curve| curve.homogeneous_point(v))
+            .collect();
+
+        NurbsCurve::from_homogeneous(
+            self.degree[0],
+            points,
+            self.knots[0].clone(),
+        )
+    }
+
+    /// Access the curve along `v`, at the given constant `u`
+    ///
+    /// The curve coordinates of the returned curve are the `v` coordinates of
+    /// the surface.
+    pub fn curve_along_v(&self, u: impl Into<Scalar>) -> NurbsCurve<D> {
+        let u = u.into().into_f64();
+
+        let points = self
+            .curves_along_u()
+            .iter()
+            .map(|curve| curve.homogeneous_point(u))
+            .collect();
+
+        NurbsCurve::from_homogeneous(
+            self.degree[1],
+            points,
+            self.knots[1].clone(),
+        )
+    }
+
+    /// Create a new instance that is reversed in the direction of `v`
+    ///
+    /// The point at surface coordinates `(u, v)` of the reversed surface is the
+    /// point at `(u, -v)` of the original one.
+    #[must_use]
+    pub fn reverse_v(&self) -> Self {
+        self.map_curves_along_v(|curve| curve.clone().reverse())
+    }
+
+    /// Convert a point in surface coordinates into a `D`-dimensional point
+    pub fn point_from_surface_coords(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> Point<D> {
+        let [[position]] = self.derivatives::<1>(point);
+        Point::origin() + position
+    }
+
+    /// Compute partial derivatives of the surface at the given coordinates
+    ///
+    /// Element `[k][l]` of the result is the derivative of the surface, taken
+    /// `k` times with respect to `u`, and `l` times with respect to `v`.
+    /// Element `[0][0]` is the position of the surface point, relative to the
+    /// origin. Only elements where `k + l < N` are computed; all others are
+    /// zero.
+    pub fn derivatives<const N: usize>(
+        &self,
+        point: impl Into<Point<2>>,
+    ) -> [[Vector<D>; N]; N] {
+        let point = point.into();
+        let [p, q] = self.degree;
+
+        let knots_u = to_f64(&self.knots[0]);
+        let knots_v = to_f64(&self.knots[1]);
+
+        let u = point.u.into_f64();
+        let v = point.v.into_f64();
+
+        let span_u = find_span(&knots_u, p, self.control_points.len(), u);
+        let span_v = find_span(&knots_v, q, self.control_points[0].len(), v);
+
+        let basis_u = basis_function_derivatives::<N>(&knots_u, p, span_u, u);
+        let basis_v = basis_function_derivatives::<N>(&knots_v, q, span_v, v);
+
+        let zero = Vector::from([Scalar::ZERO; D]);
+
+        // Derivatives of the surface in homogeneous coordinates, with the
+        // weighted control points and the weights kept separately.
+        let mut points = [[zero; N]; N];
+        let mut weights = [[0.; N]; N];
+        for k in 0..N {
+            for l in 0..N - k {
+                for (i, &nu) in basis_u[k].iter().enumerate() {
+                    for (j, &nv) in basis_v[l].iter().enumerate() {
+                        let i = span_u - p + i;
+                        let j = span_v - q + j;
+                        let weight = self.weights[i][j].into_f64() * nu * nv;
+
+                        points[k][l] = points[k][l]
+                            + self.control_points[i][j].coords * weight;
+                        weights[k][l] += weight;
+                    }
+                }
+            }
+        }
+
+        let mut derivatives = [[zero; N]; N];
+        for k in 0..N {
+            for l in 0..N - k {
+                let mut derivative = points[k][l];
+
+                for j in 1..=l {
+                    derivative = derivative
+                        - derivatives[k][l - j]
+                            * (binomial(l, j) * weights[0][j]);
+                }
+                for i in 1..=k {
+                    derivative = derivative
+                        - derivatives[k - i][l]
+                            * (binomial(k, i) * weights[i][0]);
+
+                    for j in 1..=l {
+                        derivative = derivative
+                            - derivatives[k - i][l - j]
+                                * (binomial(k, i)
+                                    * binomial(l, j)
+                                    * weights[i][j]);
+                    }
+                }
+
+                derivatives[k][l] = derivative / weights[0][0];
+            }
+        }
+
+        derivatives
+    }
+
+    /// Convert a `D`-dimensional point to surface coordinates
+    ///
+    /// Returns the surface coordinates of the surface point that is closest to
+    /// the provided point. The result is restricted to the domain of the
+    /// surface.
+    ///
+    /// Callers are advised to be careful about the points they pass, as the
+    /// point not being on the surface, intentional or not, will not result in
+    /// an error.
+    pub fn point_to_surface_coords(
+        &self,
+        point: impl Into<Point<D>>,
+    ) -> Point<2> {
+        let point = point.into();
+        let [[min_u, max_u], [min_v, max_v]] = self.domain();
+
+        // Newton's method finds the closest point reliably, if it starts close
+        // enough. Find a good starting value by sampling the surface.
+        let samples_u = self.curve_along_u(min_v).samples();
+        let samples_v = self.curve_along_v(min_u).samples();
+        let mut uv = samples_u
+            .iter()
+            .flat_map(|&u| samples_v.iter().map(move |&v| Point::from([u, v])))
+            .min_by_key(|&uv| {
+                (self.point_from_surface_coords(uv) - point).magnitude()
+            })
+            .unwrap_or_else(|| Point::from([min_u, min_v]));
+
+        for _ in 0..MAX_ITERATIONS {
+            let derivatives = self.derivatives::<3>(uv);
+            let distance = derivatives[0][0] - point.coords;
+            let [su, sv] = [derivatives[1][0], derivatives[0][1]];
+            let [suu, suv, svv] =
+                [derivatives[2][0], derivatives[1][1], derivatives[0][2]];
+
+            let f = su.dot(&distance);
+            let g = sv.dot(&distance);
+
+            let a = su.dot(&su) + distance.dot(&suu);
+            let b = su.dot(&sv) + distance.dot(&suv);
+            let d = sv.dot(&sv) + distance.dot(&svv);
+
+            let determinant = a * d - b * b;
+            if determinant == Scalar::ZERO {
+                break;
+            }
+
+            let du = (b * g - d * f) / determinant;
+            let dv = (b * f - a * g) / determinant;
+
+            let next = Point::from([
+                (uv.u + du).max(min_u).min(max_u),
+                (uv.v + dv).max(min_v).min(max_v),
+            ]);
+            let is_converged = (next - uv).magnitude().into_f64() <= EPSILON;
+
+            uv = next;
+            if is_converged {
+                break;
+            }
+        }
+
+        uv
+    }
+
+    /// Create a new instance with an additional knot in the direction of `u`
+    ///
+    /// See [`NurbsCurve::insert_knot`].
+    #[must_use]
+    pub fn insert_knot_u(&self, u: impl Into<Scalar>) -> Self {
+        let u = u.into();
+        self.map_curves_along_u(|curve| curve.insert_knot([u]))
+    }
+
+    /// Create a new instance with an additional knot in the direction of `v`
+    ///
+    /// See [`NurbsCurve::insert_knot`].
+    #[must_use]
+    pub fn insert_knot_v(&self, v: impl Into<Scalar>) -> Self {
+        let v = v.into();
+        self.map_curves_along_v(|curve| curve.insert_knot([v]))
+    }
+
+    /// Create a new instance with a degree in `u` that is higher by one
+    ///
+    /// See [`NurbsCurve::elevate_degree`].
+    #[must_use]
+    pub fn elevate_degree_u(&self) -> Self {
+        self.map_curves_along_u(NurbsCurve::elevate_degree)
+    }
+
+    /// Create a new instance with a degree in `v` that is higher by one
+    ///
+    /// See [`NurbsCurve::elevate_degree`].
+    #[must_use]
+    pub fn elevate_degree_v(&self) -> Self {
+        self.map_curves_along_v(NurbsCurve::elevate_degree)
+    }
+
+    /// The curves defined by the columns of control points, one per `j`
+    fn curves_along_u(&self) -> Vec<NurbsCurve<D>> {
+        (0..self.control_points[0].len())
+            .map(|j| {
+                NurbsCurve::new(
+                    self.degree[0],
+                    self.control_points.iter().map(|row| row[j]),
+                    self.weights.iter().map(|row| row[j]),
+                    self.knots[0].iter().copied(),
+                )
+            })
+            .collect()
+    }
+
+    /// The curves defined by the rows of control points, one per `i`
+    fn curves_along_v(&self) -> Vec<NurbsCurve<D>> {
+        self.control_points
+            .iter()
+            .zip(&self.weights)
+            .map(|(points, weights)| {
+                NurbsCurve::new(
+                    self.degree[1],
+                    points.iter().copied(),
+                    weights.iter().copied(),
+                    self.knots[1].iter().copied(),
+                )
+            })
+            .collect()
+    }
+
+    fn map_curves_along_u(
+        &self,
+        f: impl Fn(&NurbsCurve<D>) -> NurbsCurve<D>,
+    ) -> Self {
+        let curves: Vec<_> = self.curves_along_u().iter().map(f).collect();
+
+        let num_u = curves[0].control_points.len();
+        let control_points = (0..num_u)
+            .map(|i| curves.iter().map(move |curve| curve.control_points[i]));
+        let weights = (0..num_u)
+            .map(|i| curves.iter().map(move |curve| curve.weights[i]));
+
+        Self::new(
+            [curves[0].degree, self.degree[1]],
+            control_points,
+            weights,
+            [curves[0].knots.clone(), self.knots[1].clone()],
+        )
+    }
+
+    fn map_curves_along_v(
+        &self,
+        f: impl Fn(&NurbsCurve<D>) -> NurbsCurve<D>,
+    ) -> Self {
+        let curves: Vec<_> = self.curves_along_v().iter().map(f).collect();
+
+        Self::new(
+            [self.degree[0], curves[0].degree],
+            curves.iter().map(|curve| curve.control_points.clone()),
+            curves.iter().map(|curve| curve.weights.clone()),
+            [self.knots[0].clone(), curves[0].knots.clone()],
+        )
+    }
+}
+
+/// The number of curve samples per span and degree, to start Newton's method
+const SAMPLES_PER_DEGREE: usize = 4;
+
+/// The maximum number of iterations of Newton's method
+const MAX_ITERATIONS: usize = 32;
+
+/// The step size below which Newton's method is considered to have converged
+const EPSILON: f64 = 1e-12;
+
+fn to_f64(knots: &[Scalar]) -> Vec<f64> {
+    knots.iter().map(|knot| knot.into_f64()).collect()
+}
+
+/// Find the index of the knot span that contains `t`
+///
+/// Returns the last non-empty span that starts at or before `t`. If `t` is
+/// before the domain, returns the first non-empty span instead.
+fn find_span(
+    knots: &[f64],
+    degree: usize,
+    num_control_points: usize,
+    t: f64,
+) -> usize {
+    let mut span = None;
+
+    for i in degree..num_control_points {
+        if knots[i] < knots[i + 1] && (span.is_none() || knots[i] <= t) {
+            span = Some(i);
+        }
+    }
+
+    span.expect("Domain of B-spline must not be empty")
+}
+
+/// Compute the non-zero basis functions and their derivatives
+///
+/// Returns the `degree + 1` basis functions that are non-zero within the given
+/// knot span, for the first `N` derivatives, the zeroth being the functions
+/// themselves. This is algorithm A2.3 from
+/// The NURBS Book by Les Piegl and Wayne Tiller.
+fn basis_function_derivatives<const N: usize>(
+    knots: &[f64],
+    degree: usize,
+    span: usize,
+    t: f64,
+) -> [Vec<f64>; N] {
+    let p = degree;
+
+    // Basis functions in the upper triangle, knot differences in the lower one.
+    let mut ndu = vec![vec![0.; p + 1]; p + 1];
+    let mut left = vec![0.; p + 1];
+    let mut right = vec![0.; p + 1];
+
+    ndu[0][0] = 1.;
+    for j in 1..=p {
+        left[j] = t - knots[span + 1 - j];
+        right[j] = knots[span + j] - t;
+
+        let mut saved = 0.;
+        for r in 0..j {
+            ndu[j][r] = right[r + 1] + left[j - r];
+            let temp = ndu[r][j - 1] / ndu[j][r];
+
+            ndu[r][j] = saved + right[r + 1] * temp;
+            saved = left[j - r] * temp;
+        }
+        ndu[j][j] = saved;
+    }
+
+    let mut derivatives: [Vec<f64>; N] = [(); N].map(|_| vec![0.; p + 1]);
+    for (j, derivative) in derivatives[0].iter_mut().enumerate() {
+        *derivative = ndu[j][p];
+    }
+
+    let max_order = p.min(N - 1);
+
+    let mut a = [vec![0.; p + 1], vec![0.; p + 1]];
+    for r in 0..=p {
+        let (mut s1, mut s2) = (0, 1);
+        a[0][0] = 1.;
+
+        for k in 1..=max_order {
+            let mut d = 0.;
+            let pk = p - k;
+
+            if r >= k {
+                a[s2][0] = a[s1][0] / ndu[pk + 1][r - k];
+                d = a[s2][0] * ndu[r - k][pk];
+            }
+
+            let j1 = if r + 1 >= k { 1 } else { k - r };
+            let j2 = if r <= pk + 1 { k - 1 } else { p - r };
+            for j in j1..=j2 {
+                // `j >= k - r`, so this can't underflow.
+                let rkj = r + j - k;
+
+                a[s2][j] = (a[s1][j] - a[s1][j - 1]) / ndu[pk + 1][rkj];
+                d += a[s2][j] * ndu[rkj][pk];
+            }
+
+            if r <= pk {
+                a[s2][k] = -a[s1][k - 1] / ndu[pk + 1][r];
+                d += a[s2][k] * ndu[r][pk];
+            }
+
+            derivatives[k][r] = d;
+            std::mem::swap(&mut s1, &mut s2);
+        }
+    }
+
+    // Multiply by `p! / (p - k)!`.
+    let mut factor = 1.;
+    for (k, derivatives) in derivatives
+        .iter_mut()
+        .enumerate()
+        .take(max_order + 1)
+        .skip(1)
+    {
+        factor *= (p + 1 - k) as f64;
+        for derivative in derivatives {
+            *derivative *= factor;
+        }
+    }
+
+    derivatives
+}
+
+fn binomial(n: usize, k: usize) -> f64 {
+    (0..k).fold(1., |result, i| result * (n - i) as f64 / (i + 1) as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::FRAC_1_SQRT_2;
+
+    use crate::{Point, Scalar, Vector};
+
+    use super::{NurbsCurve, NurbsSurface};
+
+    #[test]
+    fn points_on_quarter_circle() {
+        let circle = quarter_circle();
+
+        for i in 0..=10 {
+            let t = i as f64 / 10.;
+
+            let point = circle.point_from_curve_coords([t]);
+            assert_close(point.coords.magnitude(), 1.);
+
+            // The tangent of a circle is perpendicular to its radius.
+            let [_, tangent] = circle.derivatives::<2>([t]);
+            assert_close(tangent.dot(&point.coords), 0.);
+        }
+
+        assert_eq!(circle.point_from_curve_coords([0.]), Point::from([1., 0.]));
+        assert_eq!(circle.point_from_curve_coords([1.]), Point::from([0., 1.]));
+    }
+
+    #[test]
+    fn derivatives() {
+        // A parabola, `y = x^2`, defined by a quadratic Bézier curve.
+        let curve = NurbsCurve::from_control_points(
+            2,
+            [[-1., 1.], [0., -1.], [1., 1.]],
+        );
+
+        let [position, d1, d2] = curve.derivatives::<3>([0.25]);
+        assert_close_vector(position, [-0.5, 0.25]);
+        assert_close_vector(d1, [2., -2.]);
+        assert_close_vector(d2, [0., 8.]);
+
+        // Derivatives of the rational curve, compared to finite differences.
+        let circle = quarter_circle();
+        let h = 1e-6;
+        let [_, d1, d2] = circle.derivatives::<3>([0.5]);
+        let [a, b] = [0.5 - h, 0.5 + h].map(|t| circle.derivatives::<2>([t]));
+        assert_close_vector(d1, ((b[0] - a[0]) / (2. * h)).components);
+        assert!(
+            (d2 - (b[1] - a[1]) / (2. * h)).magnitude() < Scalar::from(1e-6)
+        );
+    }
+
+    #[test]
+    fn insert_knot() {
+        let circle = quarter_circle();
+
+        let refined = circle.insert_knot([0.3]).insert_knot([0.3]);
+        assert_eq!(refined.control_points().len(), 5);
+        assert_eq!(refined.knots().len(), 8);
+
+        assert_same_curve(&circle, &refined);
+    }
+
+    #[test]
+    fn elevate_degree() {
+        let circle = quarter_circle().insert_knot([0.5]);
+
+        let elevated = circle.elevate_degree();
+        assert_eq!(elevated.degree(), 3);
+        assert_eq!(elevated.control_points().len(), 6);
+
+        assert_same_curve(&circle, &elevated);
+    }
+
+    #[test]
+    fn reverse() {
+        let circle = quarter_circle();
+        let reversed = circle.clone().reverse();
+
+        assert_eq!(reversed.domain(), [Scalar::from(-1.), Scalar::ZERO]);
+        for i in 0..=10 {
+            let t = i as f64 / 10.;
+            assert_close_vector(
+                circle.point_from_curve_coords([t]).coords,
+                reversed.point_from_curve_coords([-t]).coords.components,
+            );
+        }
+    }
+
+    #[test]
+    fn split() {
+        let curve = quarter_circle().elevate_degree();
+
+        let [a, b] = curve.split([0.25]);
+        assert_eq!(a.domain(), [Scalar::ZERO, Scalar::from(0.25)]);
+        assert_eq!(b.domain(), [Scalar::from(0.25), Scalar::ONE]);
+
+        for i in 0..=10 {
+            let t = i as f64 / 10.;
+
+            let [t_a, t_b] = [t * 0.25, 0.25 + t * 0.75];
+            assert_close_vector(
+                a.point_from_curve_coords([t_a]).coords,
+                curve.point_from_curve_coords([t_a]).coords.components,
+            );
+            assert_close_vector(
+                b.point_from_curve_coords([t_b]).coords,
+                curve.point_from_curve_coords([t_b]).coords.components,
+            );
+        }
+    }
+
+    #[test]
+    fn reparametrize() {
+        let curve = quarter_circle();
+        let reparametrized = curve.reparametrize([2., 4.]);
+
+        assert_eq!(reparametrized.domain(), [2., 4.].map(Scalar::from));
+        assert_close_vector(
+            reparametrized.point_from_curve_coords([3.]).coords,
+            curve.point_from_curve_coords([0.5]).coords.components,
+        );
+    }
+
+    #[test]
+    fn point_to_curve_coords() {
+        let circle = quarter_circle();
+
+        for i in 0..=10 {
+            let t = i as f64 / 10.;
+            let point = circle.point_from_curve_coords([t]);
+
+            assert_close(circle.point_to_curve_coords(point).t.into_f64(), t);
+        }
+    }
+
+    #[test]
+    fn surface() {
+        let surface = cylinder_patch();
+
+        for [u, v] in [[0., 0.], [0.5, 0.5], [1., 0.25], [0.25, 1.]] {
+            let point = surface.point_from_surface_coords([u, v]);
+
+            let expected = quarter_circle().point_from_curve_coords([u]);
+            assert_close_vector(
+                point.coords,
+                [expected.u.into_f64(), expected.v.into_f64(), v],
+            );
+
+            let uv = surface.point_to_surface_coords(point);
+            assert_close(uv.u.into_f64(), u);
+            assert_close(uv.v.into_f64(), v);
+
+            let derivatives = surface.derivatives::<2>([u, v]);
+            assert_close_vector(derivatives[0][1], [0., 0., 1.]);
+        }
+    }
+
+    #[test]
+    fn surface_operations() {
+        let surface = cylinder_patch();
+
+        let modified = surface
+            .insert_knot_u(0.5)
+            .insert_knot_v(0.25)
+            .elevate_degree_u()
+            .elevate_degree_v();
+        assert_eq!(modified.degree(), [3, 2]);
+
+        let reversed = surface.reverse_v();
+
+        for i in 0..=4 {
+            for j in 0..=4 {
+                let [u, v] = [i as f64 / 4., j as f64 / 4.];
+                let point = surface.point_from_surface_coords([u, v]).coords;
+
+                assert_close_vector(
+                    modified.point_from_surface_coords([u, v]).coords,
+                    point.components,
+                );
+                assert_close_vector(
+                    reversed.point_from_surface_coords([u, -v]).coords,
+                    point.components,
+                );
+            }
+        }
+
+        assert_eq!(surface.curve_along_v(0.5).degree(), 1);
+        assert_same_curve(
+            &surface.curve_along_u(1.),
+            &NurbsCurve::new(
+                2,
+                [[1., 0., 1.], [1., 1., 1.], [0., 1., 1.]],
+                [1., FRAC_1_SQRT_2, 1.],
+                [0., 0., 0., 1., 1., 1.],
+            ),
+        );
+    }
+
+    /// A quarter of the unit circle, as a rational quadratic Bézier curve
+    fn quarter_circle() -> NurbsCurve<2> {
+        NurbsCurve::new(
+            2,
+            [[1., 0.], [1., 1.], [0., 1.]],
+            [1., FRAC_1_SQRT_2, 1.],
+            [0., 0., 0., 1., 1., 1.],
+        )
+    }
+
+    /// A quarter of the unit cylinder, with a height of one
+    fn cylinder_patch() -> NurbsSurface<3> {
+        NurbsSurface::new(
+            [2, 1],
+            [
+                [[1., 0., 0.], [1., 0., 1.]],
+                [[1., 1., 0.], [1., 1., 1.]],
+                [[0., 1., 0.], [0., 1., 1.]],
+            ],
+            [[1., 1.], [FRAC_1_SQRT_2, FRAC_1_SQRT_2], [1., 1.]],
+            [
+                [0., 0., 0., 1., 1., 1.].map(Scalar::from).to_vec(),
+                [0., 0., 1., 1.].map(Scalar::from).to_vec(),
+            ],
+        )
+    }
+
+    fn assert_same_curve<const D: usize>(a: &NurbsCurve<D>, b: &NurbsCurve<D>) {
+        assert_eq!(a.domain(), b.domain());
+
+        for i in 0..=20 {
+            let t = i as f64 / 20.;
+            assert_close_vector(
+                a.point_from_curve_coords([t]).coords,
+                b.point_from_curve_coords([t]).coords.components,
+            );
+        }
+    }
+
+    fn assert_close(a: impl Into<Scalar>, b: impl Into<Scalar>) {
+        let [a, b] = [a.into(), b.into()];
+        assert!((a - b).abs() < Scalar::from(1e-9), "{a:?} != {b:?}");
+    }
+
+    fn assert_close_vector<const D: usize>(
+        a: Vector<D>,
+        b: [impl Into<Scalar>; D],
+    ) {
+        let b = Vector::from(b.map(Into::into));
+        assert!((a - b).magnitude() < Scalar::from(1e-9), "{a:?} != {b:?}");
+    }
+}
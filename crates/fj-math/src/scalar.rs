@@ -76,11 +76,21 @@ impl Scalar {
         self.0.ceil().into()
     }
 
+    /// Compute the largest integer smaller than or equal to this scalar
+    pub fn floor(self) -> Self {
+        self.0.floor().into()
+    }
+
     /// Round the scalar
     pub fn round(self) -> Self {
         self.0.round().into()
     }
 
+    /// Compute the square root
+    pub fn sqrt(self) -> Self {
+        self.0.sqrt().into()
+    }
+
     /// Compute the cosine
     pub fn cos(self) -> Self {
         self.0.cos().into()
@@ -92,6 +102,11 @@ impl Scalar {
         (sin.into(), cos.into())
     }
 
+    /// Compute the tangent
+    pub fn tan(self) -> Self {
+        self.0.tan().into()
+    }
+
     /// Compute the arccosine
     pub fn acos(self) -> Self {
         self.0.acos().into()
@@ -2,7 +2,10 @@ use std::ops;
 
 use nalgebra::Perspective3;
 
-use crate::{Circle, Line, Scalar};
+use crate::{
+    circle::{is_equal_length, is_perpendicular},
+    Circle, Line, NurbsCurve, NurbsSurface, Scalar,
+};
 
 use super::{Aabb, Point, Segment, Triangle, Vector};
 
@@ -40,6 +43,74 @@ impl Transform {
         ))
     }
 
+    /// Construct a scaling
+    ///
+    /// Each component of `factors` is the factor along the respective axis. A
+    /// uniform scaling has the same factor along all axes.
+    pub fn scaling(factors: impl Into<Vector<3>>) -> Self {
+        let factors = factors.into();
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            nalgebra::Matrix4::new_nonuniform_scaling(&factors.to_na()),
+        ))
+    }
+
+    /// Construct a reflection at the plane through the origin with the given
+    /// normal
+    pub fn reflection(normal: impl Into<Vector<3>>) -> Self {
+        let normal = normal.into().normalize().to_na();
+        let matrix = nalgebra::Matrix3::identity()
+            - normal * normal.transpose() * 2.;
+
+        Self(nalgebra::Transform::from_matrix_unchecked(
+            matrix.to_homogeneous(),
+        ))
+    }
+
+    /// Construct a transform from a matrix
+    ///
+    /// The matrix is given row by row, and operates on homogeneous
+    /// coordinates.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the matrix doesn't represent an affine transform, meaning its
+    /// last row is not `[0., 0., 0., 1.]`.
+    pub fn from_matrix(rows: [[f64; 4]; 4]) -> Self {
+        assert_eq!(
+            rows[3],
+            [0., 0., 0., 1.],
+            "Matrix must represent an affine transform"
+        );
+
+        let matrix = nalgebra::Matrix4::from_row_slice(&rows.concat());
+        Self(nalgebra::Transform::from_matrix_unchecked(matrix))
+    }
+
+    /// Compute the determinant of the linear part of the transform
+    ///
+    /// The determinant is negative, if the transform includes a reflection,
+    /// which reverses the orientation of any shape it is applied to.
+    pub fn determinant(&self) -> Scalar {
+        self.linear_part().determinant().into()
+    }
+
+    /// Check whether the transform preserves angles
+    ///
+    /// Such a transform, a similarity, consists of rotations, reflections,
+    /// translations, and uniform scalings. It maps circles to circles and
+    /// spheres to spheres.
+    pub fn is_similarity(&self) -> bool {
+        let linear = self.linear_part();
+        let metric = linear.transpose() * linear;
+
+        // For a similarity, this is the square of its scaling factor.
+        let scale = metric.trace() / 3.;
+        let deviation = metric - nalgebra::Matrix3::identity() * scale;
+
+        deviation.amax() <= scale * 1e-9
+    }
+
     /// Transform the given point
     pub fn transform_point(&self, point: &Point<3>) -> Point<3> {
         Point::from(self.0.transform_point(&point.to_na()))
@@ -80,11 +151,48 @@ impl Transform {
     }
 
     /// Transform the given circle
-    pub fn transform_circle(&self, circle: &Circle<3>) -> Circle<3> {
-        Circle::new(
-            self.transform_point(&circle.center()),
-            self.transform_vector(&circle.a()),
-            self.transform_vector(&circle.b()),
+    ///
+    /// Returns `None`, if the transform distorts the circle into an ellipse.
+    pub fn transform_circle(&self, circle: &Circle<3>) -> Option<Circle<3>> {
+        let a = self.transform_vector(&circle.a());
+        let b = self.transform_vector(&circle.b());
+
+        if !is_equal_length(a, b) || !is_perpendicular(a, b) {
+            return None;
+        }
+
+        Some(Circle::new(self.transform_point(&circle.center()), a, b))
+    }
+
+    /// Transform the given NURBS curve
+    pub fn transform_nurbs_curve(
+        &self,
+        curve: &NurbsCurve<3>,
+    ) -> NurbsCurve<3> {
+        NurbsCurve::new(
+            curve.degree(),
+            curve
+                .control_points()
+                .iter()
+                .map(|point| self.transform_point(point)),
+            curve.weights().iter().copied(),
+            curve.knots().iter().copied(),
+        )
+    }
+
+    /// Transform the given NURBS surface
+    pub fn transform_nurbs_surface(
+        &self,
+        surface: &NurbsSurface<3>,
+    ) -> NurbsSurface<3> {
+        NurbsSurface::new(
+            surface.degree(),
+            surface
+                .control_points()
+                .iter()
+                .map(|row| row.iter().map(|point| self.transform_point(point))),
+            surface.weights().iter().map(|row| row.iter().copied()),
+            surface.knots().clone(),
         )
     }
 
@@ -120,11 +228,14 @@ impl Transform {
     }
 
     /// Transform the given axis-aligned bounding box
+    ///
+    /// Returns the axis-aligned bounding box of the transformed box.
     pub fn transform_aabb(&self, aabb: &Aabb<3>) -> Aabb<3> {
-        Aabb {
-            min: self.transform_point(&aabb.min),
-            max: self.transform_point(&aabb.max),
-        }
+        Aabb::<3>::from_points(
+            aabb.vertices()
+                .into_iter()
+                .map(|vertex| self.transform_point(&vertex)),
+        )
     }
 
     /// Exposes the data of this Transform as a slice of f64.
@@ -132,6 +243,10 @@ impl Transform {
         self.0.matrix().data.as_slice()
     }
 
+    fn linear_part(&self) -> nalgebra::Matrix3<f64> {
+        self.0.matrix().fixed_resize::<3, 3>(0.)
+    }
+
     /// Extract the rotation component of this transform
     pub fn extract_rotation(&self) -> Transform {
         Self(nalgebra::Transform::from_matrix_unchecked(
@@ -157,7 +272,7 @@ impl ops::Mul<Self> for Transform {
 mod tests {
     use approx::assert_abs_diff_eq;
 
-    use crate::{Line, Point, Scalar, Vector};
+    use crate::{Aabb, Circle, Line, Point, Scalar, Vector};
 
     use super::Transform;
 
@@ -212,4 +327,76 @@ mod tests {
             epsilon = 1e-8,
         );
     }
+
+    #[test]
+    fn reflection() {
+        let reflection = Transform::reflection([1., 1., 0.]);
+
+        assert_abs_diff_eq!(
+            reflection.transform_point(&Point::from([1., 0., 3.])),
+            Point::from([0., -1., 3.]),
+            epsilon = Scalar::from(1e-8),
+        );
+        assert!(reflection.determinant() < Scalar::ZERO);
+        assert!(reflection.is_similarity());
+    }
+
+    #[test]
+    fn from_matrix() {
+        let transform = Transform::from_matrix([
+            [0., -1., 0., 1.],
+            [1., 0., 0., 2.],
+            [0., 0., 1., 3.],
+            [0., 0., 0., 1.],
+        ]);
+
+        assert_abs_diff_eq!(
+            transform.data(),
+            (Transform::translation([1., 2., 3.])
+                * Transform::rotation(Vector::unit_z() * (Scalar::PI / 2.)))
+            .data(),
+            epsilon = 1e-8,
+        );
+    }
+
+    #[test]
+    fn transform_circle() {
+        let circle = Circle::new(
+            Point::from([0., 0., 0.]),
+            Vector::from([1., 0., 0.]),
+            Vector::from([0., 1., 0.]),
+        );
+
+        let uniform = Transform::scaling([2., 2., 2.]);
+        assert!(uniform.is_similarity());
+        assert_eq!(
+            uniform.transform_circle(&circle),
+            Some(Circle::new(
+                Point::from([0., 0., 0.]),
+                Vector::from([2., 0., 0.]),
+                Vector::from([0., 2., 0.]),
+            ))
+        );
+
+        let non_uniform = Transform::scaling([2., 1., 1.]);
+        assert!(!non_uniform.is_similarity());
+        assert_eq!(non_uniform.transform_circle(&circle), None);
+    }
+
+    #[test]
+    fn transform_aabb() {
+        let aabb = Aabb {
+            min: Point::from([0., 0., 0.]),
+            max: Point::from([1., 1., 1.]),
+        };
+        let transform = Transform::reflection([1., 0., 0.]);
+
+        assert_eq!(
+            transform.transform_aabb(&aabb),
+            Aabb {
+                min: Point::from([-1., 0., 0.]),
+                max: Point::from([0., 1., 1.]),
+            }
+        );
+    }
 }
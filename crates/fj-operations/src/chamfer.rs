@@ -0,0 +1,38 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{chamfer, Tolerance},
+    objects::Solid,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::Aabb;
+
+use super::{edge_selector::select_edges, OperationError, Shape};
+
+impl Shape for fj::Chamfer {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        let faces = self
+            .shape()
+            .compute_brep(config, tolerance, debug_info)?
+            .into_inner();
+
+        let solid = chamfer(
+            &Solid::new().with_faces(faces),
+            self.distance(),
+            select_edges(self.edges()),
+        )?;
+        Ok(validate(solid, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // Beveling only ever removes material, as only convex edges are
+        // supported.
+        self.shape().bounding_volume()
+    }
+}
@@ -0,0 +1,141 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{transform_faces, Tolerance},
+    objects::Face,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::{Aabb, Transform, Vector};
+
+use super::{OperationError, Shape};
+
+impl Shape for fj::CircularPattern {
+    type Brep = Vec<Face>;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        // The shape is only computed once. The other copies are transformed
+        // from that.
+        let seed = self
+            .shape()
+            .compute_brep(config, tolerance, debug_info)?
+            .into_inner();
+
+        let mut faces = Vec::new();
+        for transform in transforms(self) {
+            let mut copy = seed.clone();
+            transform_faces(&mut copy, &transform);
+            faces.extend(copy);
+        }
+
+        Ok(validate(faces, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let aabb = self.shape().bounding_volume();
+
+        transforms(self)
+            .map(|transform| transform.transform_aabb(&aabb))
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or(aabb)
+    }
+}
+
+fn transforms(
+    pattern: &fj::CircularPattern,
+) -> impl Iterator<Item = Transform> + '_ {
+    let origin = Vector::from(pattern.axis_origin());
+    let axis = Vector::from(pattern.axis_direction()).normalize();
+
+    (0..pattern.count()).map(move |i| {
+        let angle = pattern.step() * f64::from(i);
+
+        Transform::translation(origin)
+            * Transform::rotation(axis * angle)
+            * Transform::translation(-origin)
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use fj_kernel::algorithms::transform_faces;
+    use fj_math::{Transform, Vector};
+
+    use crate::test_utils::{cube, faces};
+
+    #[test]
+    #[should_panic(expected = "Pattern needs at least one copy")]
+    fn count_zero() {
+        pattern(fj::Angle::from_rad(TAU), 0);
+    }
+
+    #[test]
+    fn count_one() -> anyhow::Result<()> {
+        let pattern = pattern(fj::Angle::from_rad(TAU / 2.), 1);
+        assert_eq!(faces(&pattern.into())?, faces(&cube([2., 0.]))?);
+        Ok(())
+    }
+
+    #[test]
+    fn full_turn() -> anyhow::Result<()> {
+        let pattern = pattern(fj::Angle::from_rad(TAU), 4);
+
+        let seed = faces(&cube([2., 0.]))?;
+        let faces = faces(&pattern.into())?;
+        let copies: Vec<_> = faces.chunks(seed.len()).collect();
+        assert_eq!(copies.len(), 4);
+
+        // The copies are spaced evenly, and the last one doesn't end up where
+        // the first one is.
+        for (i, copy) in copies.iter().enumerate() {
+            let mut expected = seed.clone();
+            transform_faces(
+                &mut expected,
+                &Transform::rotation(
+                    Vector::from([0., 0., TAU / 4.]) * i as f64,
+                ),
+            );
+            assert_eq!(*copy, expected);
+        }
+        assert_ne!(copies[3], copies[0]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn copies_are_transformed_seed() -> anyhow::Result<()> {
+        let pattern = pattern(fj::Angle::from_rad(TAU / 2.), 3);
+
+        let seed = faces(&cube([2., 0.]))?;
+        let faces = faces(&pattern.into())?;
+        assert_eq!(faces.len(), seed.len() * 3);
+
+        for (i, copy) in faces.chunks(seed.len()).enumerate() {
+            let mut expected = seed.clone();
+            transform_faces(
+                &mut expected,
+                &Transform::rotation(
+                    Vector::from([0., 0., TAU / 4.]) * i as f64,
+                ),
+            );
+            assert_eq!(copy, expected);
+        }
+
+        Ok(())
+    }
+
+    fn pattern(angle: fj::Angle, count: u32) -> fj::CircularPattern {
+        fj::CircularPattern::from_axis(
+            cube([2., 0.]),
+            [0., 0., 0.],
+            [0., 0., 1.],
+            angle,
+            count,
+        )
+    }
+}
@@ -1,13 +1,12 @@
 use fj_interop::{debug::DebugInfo, mesh::Color};
 use fj_kernel::{
-    algorithms::Tolerance,
-    iter::ObjectIters,
-    objects::{Curve, Cycle, Edge, Face, GlobalCurve, Sketch},
-    validation::{validate, Validated, ValidationConfig, ValidationError},
+    algorithms::{BooleanOps, Tolerance},
+    objects::Sketch,
+    validation::{validate, Validated, ValidationConfig},
 };
 use fj_math::Aabb;
 
-use super::Shape;
+use super::{OperationError, Shape};
 
 impl Shape for fj::Difference2d {
     type Brep = Sketch;
@@ -17,15 +16,7 @@ impl Shape for fj::Difference2d {
         config: &ValidationConfig,
         tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
-        // This method assumes that `b` is fully contained within `a`:
-        // https://github.com/hannobraun/Fornjot/issues/92
-
-        let mut faces = Vec::new();
-
-        let mut exteriors = Vec::new();
-        let mut interiors = Vec::new();
-
+    ) -> Result<Validated<Self::Brep>, OperationError> {
         // Can be cleaned up, once `each_ref` and `try_map` are stable:
         // - https://doc.rust-lang.org/std/primitive.array.html#method.each_ref
         // - https://doc.rust-lang.org/std/primitive.array.html#method.try_map
@@ -34,51 +25,14 @@ impl Shape for fj::Difference2d {
             .map(|shape| shape.compute_brep(config, tolerance, debug_info));
         let [a, b] = [a?, b?];
 
-        if let Some(face) = a.face_iter().next() {
-            // If there's at least one face to subtract from, we can proceed.
-
-            let surface = face.surface();
-
-            for face in a.face_iter() {
-                assert_eq!(
-                    surface,
-                    face.surface(),
-                    "Trying to subtract faces with different surfaces.",
-                );
+        let difference = a.difference(&b, tolerance)?;
+        let difference = Sketch::new().with_faces(
+            difference
+                .into_faces()
+                .map(|face| face.with_color(Color(self.color()))),
+        );
 
-                for cycle in face.exteriors() {
-                    let cycle = add_cycle(cycle.clone(), false);
-                    exteriors.push(cycle);
-                }
-                for cycle in face.interiors() {
-                    let cycle = add_cycle(cycle.clone(), true);
-                    interiors.push(cycle);
-                }
-            }
-
-            for face in b.face_iter() {
-                assert_eq!(
-                    surface,
-                    face.surface(),
-                    "Trying to subtract faces with different surfaces.",
-                );
-
-                for cycle in face.exteriors() {
-                    let cycle = add_cycle(cycle.clone(), true);
-                    interiors.push(cycle);
-                }
-            }
-
-            faces.push(
-                Face::new(*surface)
-                    .with_exteriors(exteriors)
-                    .with_interiors(interiors)
-                    .with_color(Color(self.color())),
-            );
-        }
-
-        let difference = Sketch::new().with_faces(faces);
-        validate(difference, config)
+        Ok(validate(difference, config)?)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -88,36 +42,3 @@ impl Shape for fj::Difference2d {
         self.shapes()[0].bounding_volume()
     }
 }
-
-fn add_cycle(cycle: Cycle, reverse: bool) -> Cycle {
-    let mut edges = Vec::new();
-    for edge in cycle.edges() {
-        let curve_local = if reverse {
-            edge.curve().kind().reverse()
-        } else {
-            *edge.curve().kind()
-        };
-
-        let curve_global = GlobalCurve::from_kind(if reverse {
-            edge.curve().global().kind().reverse()
-        } else {
-            *edge.curve().global().kind()
-        });
-
-        let vertices = if reverse {
-            edge.vertices().reverse()
-        } else {
-            *edge.vertices()
-        };
-
-        let edge = Edge::new(Curve::new(curve_local, curve_global), vertices);
-
-        edges.push(edge);
-    }
-
-    if reverse {
-        edges.reverse();
-    }
-
-    Cycle::new(*cycle.surface()).with_edges(edges)
-}
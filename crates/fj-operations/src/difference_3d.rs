@@ -0,0 +1,38 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{BooleanOps, Tolerance},
+    objects::Solid,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::Aabb;
+
+use super::{OperationError, Shape};
+
+impl Shape for fj::Difference3d {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        let [a, b] = self.shapes();
+
+        let a = a.compute_brep(config, tolerance, debug_info)?;
+        let b = b.compute_brep(config, tolerance, debug_info)?;
+
+        let a = Solid::new().with_faces(a.into_inner());
+        let b = Solid::new().with_faces(b.into_inner());
+
+        let difference = a.difference(&b, tolerance)?;
+        Ok(validate(difference, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // This is a conservative estimate of the bounding box: It's never going
+        // to be bigger than the bounding box of the original shape that another
+        // is being subtracted from.
+        self.shapes()[0].bounding_volume()
+    }
+}
@@ -0,0 +1,33 @@
+use fj_kernel::objects::{CurveKind, Edge};
+use fj_math::{Aabb, Point, Scalar, Vector};
+
+/// Create a function that decides whether an edge is selected
+pub fn select_edges(selector: fj::EdgeSelector) -> impl Fn(&Edge) -> bool {
+    move |edge| {
+        let vertices = match edge.vertices().get() {
+            Some(vertices) => vertices.map(|vertex| vertex.global().position()),
+            None => return false,
+        };
+
+        match selector {
+            fj::EdgeSelector::ParallelTo(direction) => {
+                if let CurveKind::Line(_) = edge.curve().global().kind() {
+                    let [a, b] = vertices;
+                    let along = (b - a).normalize();
+                    let direction = Vector::from(direction).normalize();
+
+                    return along.cross(&direction).magnitude()
+                        < Scalar::from(1e-9);
+                }
+
+                false
+            }
+            fj::EdgeSelector::Within { min, max } => {
+                let [min, max] = [min, max].map(Point::from);
+                let aabb = Aabb { min, max };
+
+                vertices.into_iter().all(|vertex| aabb.contains(vertex))
+            }
+        }
+    }
+}
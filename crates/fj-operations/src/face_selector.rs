@@ -0,0 +1,37 @@
+use fj_kernel::objects::{CurveKind, Face, Surface, SweptCurve};
+use fj_math::{Aabb, Point, Scalar, Vector};
+
+/// Create a function that decides whether a face is selected
+pub fn select_faces(selector: fj::FaceSelector) -> impl Fn(&Face) -> bool {
+    move |face| match selector {
+        fj::FaceSelector::None => false,
+        fj::FaceSelector::FacingTowards(direction) => {
+            if let Surface::SweptCurve(SweptCurve {
+                curve: CurveKind::Line(line),
+                path,
+            }) = face.surface()
+            {
+                let normal = line.direction().cross(path).normalize();
+                let direction = Vector::from(direction).normalize();
+
+                return (normal - direction).magnitude() < Scalar::from(1e-9);
+            }
+
+            false
+        }
+        fj::FaceSelector::Within { min, max } => {
+            let [min, max] = [min, max].map(Point::from);
+            let aabb = Aabb { min, max };
+
+            let mut vertices = face
+                .all_cycles()
+                .flat_map(|cycle| cycle.edges())
+                .flat_map(|edge| edge.vertices().iter())
+                .map(|vertex| vertex.global().position())
+                .peekable();
+
+            vertices.peek().is_some()
+                && vertices.all(|vertex| aabb.contains(vertex))
+        }
+    }
+}
@@ -2,11 +2,11 @@ use fj_interop::debug::DebugInfo;
 use fj_kernel::{
     algorithms::Tolerance,
     objects::Face,
-    validation::{validate, Validated, ValidationConfig, ValidationError},
+    validation::{validate, Validated, ValidationConfig},
 };
 use fj_math::Aabb;
 
-use super::Shape;
+use super::{OperationError, Shape};
 
 impl Shape for fj::Group {
     type Brep = Vec<Face>;
@@ -16,7 +16,7 @@ impl Shape for fj::Group {
         config: &ValidationConfig,
         tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, OperationError> {
         let mut faces = Vec::new();
 
         let a = self.a.compute_brep(config, tolerance, debug_info)?;
@@ -25,7 +25,7 @@ impl Shape for fj::Group {
         faces.extend(a.into_inner());
         faces.extend(b.into_inner());
 
-        validate(faces, config)
+        Ok(validate(faces, config)?)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
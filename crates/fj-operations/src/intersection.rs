@@ -0,0 +1,38 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{BooleanOps, Tolerance},
+    objects::Solid,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::Aabb;
+
+use super::{OperationError, Shape};
+
+impl Shape for fj::Intersection {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        let [a, b] = self.shapes();
+
+        let a = a.compute_brep(config, tolerance, debug_info)?;
+        let b = b.compute_brep(config, tolerance, debug_info)?;
+
+        let a = Solid::new().with_faces(a.into_inner());
+        let b = Solid::new().with_faces(b.into_inner());
+
+        let intersection = a.intersection(&b, tolerance)?;
+        Ok(validate(intersection, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // This is a conservative estimate of the bounding box: The intersection
+        // is never going to be bigger than the bounding box of either of the
+        // original shapes.
+        self.shapes()[0].bounding_volume()
+    }
+}
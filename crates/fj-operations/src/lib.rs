@@ -18,19 +18,42 @@
 
 pub mod shape_processor;
 
+mod chamfer;
+mod circular_pattern;
 mod difference_2d;
+mod difference_3d;
+mod edge_selector;
+mod face_selector;
+mod fillet;
 mod group;
+mod intersection;
+mod intersection_2d;
+mod linear_pattern;
+mod loft;
+mod offset_2d;
+mod path_sweep;
+mod primitive;
+mod revolve;
+mod shell;
 mod sketch;
 mod sweep;
+mod text;
 mod transform;
+mod union;
+mod union_2d;
+
+#[cfg(test)]
+mod test_utils;
 
 use fj_interop::debug::DebugInfo;
 use fj_kernel::{
-    algorithms::Tolerance,
+    algorithms::{
+        BlendError, BooleanError, HollowError, SweepError, Tolerance,
+    },
     objects::{Face, Sketch},
     validation::{validate, Validated, ValidationConfig, ValidationError},
 };
-use fj_math::Aabb;
+use fj_math::{Aabb, Point};
 
 /// Implemented for all operations from the [`fj`] crate
 pub trait Shape {
@@ -43,7 +66,7 @@ pub trait Shape {
         config: &ValidationConfig,
         tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError>;
+    ) -> Result<Validated<Self::Brep>, OperationError>;
 
     /// Access the axis-aligned bounding box of a shape
     ///
@@ -52,6 +75,52 @@ pub trait Shape {
     fn bounding_volume(&self) -> Aabb<3>;
 }
 
+/// An error that can occur while computing the boundary representation of a
+/// shape
+#[allow(clippy::large_enum_variant)]
+#[derive(Debug, thiserror::Error)]
+pub enum OperationError {
+    /// Blending the edges of a solid failed
+    #[error("Blending edges failed")]
+    Blend(#[from] BlendError),
+
+    /// A boolean operation failed
+    #[error("Boolean operation failed")]
+    Boolean(#[from] BooleanError),
+
+    /// The font of a text can't be parsed
+    #[error("Failed to parse font")]
+    Font(#[from] ttf_parser::FaceParsingError),
+
+    /// Hollowing out a solid failed
+    #[error("Hollowing failed")]
+    Hollow(#[from] HollowError),
+
+    /// Sweeping a sketch failed
+    #[error("Sweeping failed")]
+    Sweep(#[from] SweepError),
+
+    /// The shape failed validation
+    #[error("Validation failed")]
+    Validation(#[from] ValidationError),
+}
+
+impl OperationError {
+    /// Access the positions of the objects that caused the error
+    ///
+    /// Can be used to highlight those objects in a model.
+    pub fn positions(&self) -> Vec<Point<3>> {
+        match self {
+            Self::Blend(error) => error.positions(),
+            Self::Boolean(error) => error.positions(),
+            Self::Font(_) => Vec::new(),
+            Self::Hollow(error) => error.positions(),
+            Self::Sweep(error) => error.positions(),
+            Self::Validation(error) => error.positions(),
+        }
+    }
+}
+
 impl Shape for fj::Shape {
     type Brep = Vec<Face>;
 
@@ -60,39 +129,137 @@ impl Shape for fj::Shape {
         config: &ValidationConfig,
         tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, OperationError> {
         match self {
-            Self::Shape2d(shape) => validate(
+            Self::Chamfer(shape) => Ok(validate(
+                shape
+                    .compute_brep(config, tolerance, debug_info)?
+                    .into_inner()
+                    .into_faces()
+                    .collect(),
+                config,
+            )?),
+            Self::CircularPattern(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Difference(shape) => Ok(validate(
                 shape
                     .compute_brep(config, tolerance, debug_info)?
                     .into_inner()
                     .into_faces()
                     .collect(),
                 config,
-            ),
+            )?),
+            Self::Fillet(shape) => Ok(validate(
+                shape
+                    .compute_brep(config, tolerance, debug_info)?
+                    .into_inner()
+                    .into_faces()
+                    .collect(),
+                config,
+            )?),
+            Self::LinearPattern(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Loft(shape) => Ok(validate(
+                shape
+                    .compute_brep(config, tolerance, debug_info)?
+                    .into_inner()
+                    .into_faces()
+                    .collect(),
+                config,
+            )?),
+            Self::PathSweep(shape) => Ok(validate(
+                shape
+                    .compute_brep(config, tolerance, debug_info)?
+                    .into_inner()
+                    .into_faces()
+                    .collect(),
+                config,
+            )?),
+            Self::Primitive(shape) => Ok(validate(
+                shape
+                    .compute_brep(config, tolerance, debug_info)?
+                    .into_inner()
+                    .into_faces()
+                    .collect(),
+                config,
+            )?),
+            Self::Revolve(shape) => Ok(validate(
+                shape
+                    .compute_brep(config, tolerance, debug_info)?
+                    .into_inner()
+                    .into_faces()
+                    .collect(),
+                config,
+            )?),
+            Self::Shape2d(shape) => Ok(validate(
+                shape
+                    .compute_brep(config, tolerance, debug_info)?
+                    .into_inner()
+                    .into_faces()
+                    .collect(),
+                config,
+            )?),
+            Self::Shell(shape) => Ok(validate(
+                shape
+                    .compute_brep(config, tolerance, debug_info)?
+                    .into_inner()
+                    .into_faces()
+                    .collect(),
+                config,
+            )?),
             Self::Group(shape) => {
                 shape.compute_brep(config, tolerance, debug_info)
             }
-            Self::Sweep(shape) => validate(
+            Self::Intersection(shape) => Ok(validate(
                 shape
                     .compute_brep(config, tolerance, debug_info)?
                     .into_inner()
                     .into_faces()
                     .collect(),
                 config,
-            ),
+            )?),
+            Self::Sweep(shape) => Ok(validate(
+                shape
+                    .compute_brep(config, tolerance, debug_info)?
+                    .into_inner()
+                    .into_faces()
+                    .collect(),
+                config,
+            )?),
             Self::Transform(shape) => {
                 shape.compute_brep(config, tolerance, debug_info)
             }
+            Self::Union(shape) => Ok(validate(
+                shape
+                    .compute_brep(config, tolerance, debug_info)?
+                    .into_inner()
+                    .into_faces()
+                    .collect(),
+                config,
+            )?),
         }
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
         match self {
+            Self::Chamfer(shape) => shape.bounding_volume(),
+            Self::CircularPattern(shape) => shape.bounding_volume(),
+            Self::Difference(shape) => shape.bounding_volume(),
+            Self::Fillet(shape) => shape.bounding_volume(),
+            Self::Primitive(shape) => shape.bounding_volume(),
+            Self::Revolve(shape) => shape.bounding_volume(),
             Self::Shape2d(shape) => shape.bounding_volume(),
+            Self::Shell(shape) => shape.bounding_volume(),
             Self::Group(shape) => shape.bounding_volume(),
+            Self::Intersection(shape) => shape.bounding_volume(),
+            Self::LinearPattern(shape) => shape.bounding_volume(),
+            Self::Loft(shape) => shape.bounding_volume(),
+            Self::PathSweep(shape) => shape.bounding_volume(),
             Self::Sweep(shape) => shape.bounding_volume(),
             Self::Transform(shape) => shape.bounding_volume(),
+            Self::Union(shape) => shape.bounding_volume(),
         }
     }
 }
@@ -105,21 +272,37 @@ impl Shape for fj::Shape2d {
         config: &ValidationConfig,
         tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, OperationError> {
         match self {
             Self::Difference(shape) => {
                 shape.compute_brep(config, tolerance, debug_info)
             }
+            Self::Intersection(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Offset(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
             Self::Sketch(shape) => {
                 shape.compute_brep(config, tolerance, debug_info)
             }
+            Self::Text(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
+            Self::Union(shape) => {
+                shape.compute_brep(config, tolerance, debug_info)
+            }
         }
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
         match self {
             Self::Difference(shape) => shape.bounding_volume(),
+            Self::Intersection(shape) => shape.bounding_volume(),
+            Self::Offset(shape) => shape.bounding_volume(),
             Self::Sketch(shape) => shape.bounding_volume(),
+            Self::Text(shape) => shape.bounding_volume(),
+            Self::Union(shape) => shape.bounding_volume(),
         }
     }
 }
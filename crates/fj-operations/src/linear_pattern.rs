@@ -0,0 +1,96 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{transform_faces, Tolerance},
+    objects::Face,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::{Aabb, Transform, Vector};
+
+use super::{OperationError, Shape};
+
+impl Shape for fj::LinearPattern {
+    type Brep = Vec<Face>;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        // The shape is only computed once. The other copies are transformed
+        // from that.
+        let seed = self
+            .shape()
+            .compute_brep(config, tolerance, debug_info)?
+            .into_inner();
+
+        let mut faces = Vec::new();
+        for transform in transforms(self) {
+            let mut copy = seed.clone();
+            transform_faces(&mut copy, &transform);
+            faces.extend(copy);
+        }
+
+        Ok(validate(faces, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let aabb = self.shape().bounding_volume();
+
+        transforms(self)
+            .map(|transform| transform.transform_aabb(&aabb))
+            .reduce(|a, b| a.merged(&b))
+            .unwrap_or(aabb)
+    }
+}
+
+fn transforms(
+    pattern: &fj::LinearPattern,
+) -> impl Iterator<Item = Transform> + '_ {
+    (0..pattern.count()).map(|i| {
+        Transform::translation(Vector::from(pattern.offset()) * f64::from(i))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_kernel::algorithms::transform_faces;
+    use fj_math::{Transform, Vector};
+
+    use crate::test_utils::{cube, faces};
+
+    #[test]
+    #[should_panic(expected = "Pattern needs at least one copy")]
+    fn count_zero() {
+        fj::LinearPattern::from_shape(cube([0., 0.]), [2., 0., 0.], 0);
+    }
+
+    #[test]
+    fn count_one() -> anyhow::Result<()> {
+        let pattern =
+            fj::LinearPattern::from_shape(cube([0., 0.]), [2., 0., 0.], 1);
+        assert_eq!(faces(&pattern.into())?, faces(&cube([0., 0.]))?);
+        Ok(())
+    }
+
+    #[test]
+    fn copies_are_transformed_seed() -> anyhow::Result<()> {
+        let offset = [2., 0., 0.];
+        let pattern = fj::LinearPattern::from_shape(cube([0., 0.]), offset, 3);
+
+        let seed = faces(&cube([0., 0.]))?;
+        let faces = faces(&pattern.into())?;
+        assert_eq!(faces.len(), seed.len() * 3);
+
+        for (i, copy) in faces.chunks(seed.len()).enumerate() {
+            let mut expected = seed.clone();
+            transform_faces(
+                &mut expected,
+                &Transform::translation(Vector::from(offset) * i as f64),
+            );
+            assert_eq!(copy, expected);
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,53 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{loft, Tolerance, TransformObject},
+    objects::Solid,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::{Aabb, Vector};
+
+use super::{OperationError, Shape};
+
+impl Shape for fj::Loft {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        let mut profiles = Vec::new();
+        for profile in self.profiles() {
+            let sketch = profile
+                .shape()
+                .compute_brep(config, tolerance, debug_info)?
+                .into_inner();
+
+            profiles.push(sketch.translate(offset(profile)));
+        }
+
+        let color = self.profiles()[0].shape().color();
+
+        let solid = loft(profiles, Color(color));
+        Ok(validate(solid, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // The ruled surfaces between the profiles are bounded by the profiles.
+        self.profiles()
+            .iter()
+            .map(|profile| {
+                let aabb = profile.shape().bounding_volume();
+                Aabb::<3>::from_points(
+                    aabb.vertices().map(|point| point + offset(profile)),
+                )
+            })
+            .reduce(|a, b| a.merged(&b))
+            .expect("Loft has at least two profiles")
+    }
+}
+
+fn offset(profile: &fj::LoftProfile) -> Vector<3> {
+    Vector::from([0., 0., profile.height()])
+}
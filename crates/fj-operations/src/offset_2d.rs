@@ -0,0 +1,56 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{offset, OffsetJoin, Tolerance},
+    objects::Sketch,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::{Aabb, Vector};
+
+use super::{OperationError, Shape};
+
+impl Shape for fj::Offset2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        let sketch =
+            self.shape().compute_brep(config, tolerance, debug_info)?;
+
+        let join = match self.join() {
+            fj::OffsetJoin::Round => OffsetJoin::Round,
+            fj::OffsetJoin::Miter => OffsetJoin::Miter,
+            fj::OffsetJoin::Square => OffsetJoin::Square,
+        };
+
+        let offset = offset(&sketch, self.distance(), join, tolerance);
+        let offset = Sketch::new().with_faces(
+            offset
+                .into_faces()
+                .map(|face| face.with_color(Color(self.color()))),
+        );
+
+        Ok(validate(offset, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let aabb = self.shape().bounding_volume();
+
+        // Shrinking a shape never makes it larger.
+        if self.distance() <= 0. {
+            return aabb;
+        }
+
+        // No join reaches further from a corner than twice the distance.
+        let growth = self.distance() * 2.;
+        let growth = Vector::from([growth, growth, 0.]);
+
+        Aabb {
+            min: aabb.min - growth,
+            max: aabb.max + growth,
+        }
+    }
+}
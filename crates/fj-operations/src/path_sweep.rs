@@ -0,0 +1,171 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{sweep_along_path, PathFrame, Tolerance},
+    objects::{CurveKind, GlobalCurve, Solid},
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::{
+    Aabb, BezierCurve, CatmullRomSpline, Circle, NurbsCurve, Point, Scalar,
+    Vector,
+};
+
+use super::{OperationError, Shape};
+
+impl Shape for fj::PathSweep {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        let sketch =
+            self.shape().compute_brep(config, tolerance, debug_info)?;
+        let frame = match self.frame() {
+            fj::SweepFrame::RotationMinimizing => PathFrame::RotationMinimizing,
+            fj::SweepFrame::Frenet => PathFrame::Frenet,
+            fj::SweepFrame::Up(up) => PathFrame::Up(Vector::from(up)),
+        };
+        let color = self.shape().color();
+
+        let solid = sweep_along_path(
+            sketch.into_inner(),
+            sections(self.path()),
+            frame,
+            Scalar::from(self.twist()),
+            tolerance,
+            Color(color),
+        );
+        Ok(validate(solid, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // Wherever the shape is placed along the path, it stays within this
+        // distance of it.
+        let radius = self
+            .shape()
+            .bounding_volume()
+            .vertices()
+            .into_iter()
+            .map(|vertex| vertex.coords.magnitude())
+            .fold(Scalar::ZERO, Scalar::max);
+        let radius = Vector::from([radius; 3]);
+
+        // The control points of each section of the path enclose it.
+        let points = sections(self.path())
+            .into_iter()
+            .flat_map(|(curve, _)| match curve.kind() {
+                CurveKind::Circle(circle) => {
+                    let radius = Vector::from([circle.a().magnitude(); 3]);
+                    vec![circle.center() - radius, circle.center() + radius]
+                }
+                CurveKind::Line(line) => {
+                    vec![line.origin(), line.origin() + line.direction()]
+                }
+                CurveKind::Nurbs(curve) => curve.control_points().to_vec(),
+            })
+            .flat_map(|point| [point - radius, point + radius]);
+
+        Aabb::<3>::from_points(points)
+    }
+}
+
+/// Convert the path into the curve sections that the kernel sweeps along
+fn sections(path: &fj::SweepPath) -> Vec<(GlobalCurve, [Point<1>; 2])> {
+    match path {
+        fj::SweepPath::Helix {
+            radius,
+            pitch,
+            turns,
+        } => {
+            let end = Scalar::PI * 2. * *turns;
+
+            if *pitch == 0. {
+                let circle = Circle::new(
+                    Point::origin(),
+                    [*radius, 0., 0.],
+                    [0., *radius, 0.],
+                );
+                return vec![(
+                    GlobalCurve::from_kind(CurveKind::Circle(circle)),
+                    [Scalar::ZERO, end].map(|t| Point::from([t])),
+                )];
+            }
+
+            // A helix is not a NURBS curve, but cubic curves that match its
+            // position and tangent at both ends of short spans come very
+            // close. Sixteen spans per turn keep them within a tiny fraction
+            // of the radius.
+            let helix = |angle: Scalar| {
+                let (sin, cos) = angle.sin_cos();
+                let rise = Scalar::from(*pitch) / (Scalar::PI * 2.);
+
+                let point =
+                    Point::from([cos * *radius, sin * *radius, angle * rise]);
+                let tangent =
+                    Vector::from([-sin * *radius, cos * *radius, rise]);
+
+                (point, tangent)
+            };
+
+            let spans = (*turns * 16.).ceil().max(1.) as u64;
+            let step = end / Scalar::from_u64(spans);
+
+            (0..spans)
+                .map(|i| {
+                    let [(a, tangent_a), (b, tangent_b)] =
+                        [i, i + 1].map(|i| helix(step * Scalar::from_u64(i)));
+
+                    let curve = BezierCurve::from_control_points([
+                        a,
+                        a + tangent_a * step / 3.,
+                        b - tangent_b * step / 3.,
+                        b,
+                    ]);
+                    section(curve.to_nurbs())
+                })
+                .collect()
+        }
+        fj::SweepPath::Polyline(points) => points
+            .points()
+            .windows(2)
+            .map(|points| {
+                (
+                    GlobalCurve::from_kind(CurveKind::line_from_points([
+                        points[0], points[1],
+                    ])),
+                    [[0.], [1.]].map(Point::from),
+                )
+            })
+            .collect(),
+        fj::SweepPath::Spline(points) => {
+            let points: Vec<_> = points
+                .points()
+                .iter()
+                .map(|&point| Point::from(point))
+                .collect();
+
+            // The spline's tangents at its start and end are defined by points
+            // beyond them. Mirroring the neighboring points provides those.
+            let last = points.len() - 1;
+            let first = points[0] + (points[0] - points[1]);
+            let beyond_last = points[last] + (points[last] - points[last - 1]);
+
+            let spline = CatmullRomSpline::from_points(
+                [first].into_iter().chain(points).chain([beyond_last]),
+            );
+
+            spline
+                .to_bezier_curves()
+                .into_iter()
+                .map(|curve| section(curve.to_nurbs()))
+                .collect()
+        }
+    }
+}
+
+fn section(curve: NurbsCurve<3>) -> (GlobalCurve, [Point<1>; 2]) {
+    let range = curve.domain().map(|t| Point::from([t]));
+    (GlobalCurve::from_kind(CurveKind::Nurbs(curve)), range)
+}
@@ -0,0 +1,82 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::Tolerance,
+    objects::Solid,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::{Aabb, Point};
+
+use super::{OperationError, Shape};
+
+impl Shape for fj::Primitive {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        _: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        let builder = Solid::build();
+
+        let solid = match self {
+            Self::Cone(cone) => builder.cone_from_radii_and_height(
+                [cone.radius(), cone.top_radius()],
+                cone.height(),
+            ),
+            Self::Cuboid(cuboid) => builder.cuboid_from_size(cuboid.size()),
+            Self::Cylinder(cylinder) => builder
+                .cylinder_from_radius_and_height(
+                    cylinder.radius(),
+                    cylinder.height(),
+                ),
+            Self::Sphere(sphere) => builder.sphere_from_radius(sphere.radius()),
+            Self::Torus(torus) => builder
+                .torus_from_radii(torus.major_radius(), torus.minor_radius()),
+            Self::Wedge(wedge) => builder.wedge_from_size(wedge.size()),
+        };
+
+        let solid = Solid::new().with_faces(
+            solid
+                .into_faces()
+                .map(|face| face.with_color(Color(self.color()))),
+        );
+
+        Ok(validate(solid, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [min, max] = match self {
+            Self::Cone(cone) => {
+                let r = cone.radius().max(cone.top_radius());
+                [[-r, -r, 0.], [r, r, cone.height()]]
+            }
+            Self::Cuboid(cuboid) => {
+                let [x, y, z] = cuboid.size().map(|length| length / 2.);
+                [[-x, -y, -z], [x, y, z]]
+            }
+            Self::Cylinder(cylinder) => {
+                let r = cylinder.radius();
+                [[-r, -r, 0.], [r, r, cylinder.height()]]
+            }
+            Self::Sphere(sphere) => {
+                let r = sphere.radius();
+                [[-r, -r, -r], [r, r, r]]
+            }
+            Self::Torus(torus) => {
+                let r = torus.major_radius() + torus.minor_radius();
+                let z = torus.minor_radius();
+                [[-r, -r, -z], [r, r, z]]
+            }
+            Self::Wedge(wedge) => {
+                let [x, y, z] = wedge.size().map(|length| length / 2.);
+                [[-x, -y, -z], [x, y, z]]
+            }
+        };
+
+        Aabb {
+            min: Point::from(min),
+            max: Point::from(max),
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{revolve, Tolerance},
+    objects::Solid,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::{Aabb, Line, Point, Scalar, Vector};
+
+use super::{OperationError, Shape};
+
+impl Shape for fj::Revolve {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        let sketch =
+            self.shape().compute_brep(config, tolerance, debug_info)?;
+        let axis = Line::from_origin_and_direction(
+            Point::from(self.axis_origin()),
+            Vector::from(self.axis_direction()),
+        );
+        let color = self.shape().color();
+
+        // `fj::Angle` wraps around at a full turn, so a full turn ends up as
+        // zero.
+        let angle = if self.angle().rad() == 0. {
+            Scalar::PI * 2.
+        } else {
+            Scalar::from_f64(self.angle().rad())
+        };
+
+        let solid =
+            revolve(sketch.into_inner(), axis, angle, tolerance, Color(color));
+        Ok(validate(solid, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // This is a conservative estimate of the bounding box: The revolved
+        // shape is always contained in a cylinder around the axis, which
+        // contains the bounding box of the original shape.
+        let aabb = self.shape().bounding_volume();
+
+        let origin = Point::from(self.axis_origin());
+        let direction = Vector::from(self.axis_direction()).normalize();
+
+        let mut radius = 0f64;
+        let mut min = f64::MAX;
+        let mut max = f64::MIN;
+
+        for vertex in aabb.vertices() {
+            let offset = vertex - origin;
+
+            radius =
+                radius.max(offset.cross(&direction).magnitude().into_f64());
+
+            let t = offset.dot(&direction).into_f64();
+            min = min.min(t);
+            max = max.max(t);
+        }
+
+        // The extent of a circle with the given radius around the axis, along
+        // each of the coordinate axes.
+        let extent = Vector::from(direction.components.map(|component| {
+            let component = component.into_f64();
+            radius * (1. - component * component).max(0.).sqrt()
+        }));
+
+        let [start, end] = [min, max].map(|t| origin + direction * t);
+        Aabb::<3>::from_points([
+            start - extent,
+            start + extent,
+            end - extent,
+            end + extent,
+        ])
+    }
+}
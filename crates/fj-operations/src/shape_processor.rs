@@ -3,16 +3,19 @@
 use fj_interop::{debug::DebugInfo, processed_shape::ProcessedShape};
 use fj_kernel::{
     algorithms::{triangulate, InvalidTolerance, Tolerance},
-    validation::{ValidationConfig, ValidationError},
+    validation::ValidationConfig,
 };
 use fj_math::Scalar;
 
-use crate::Shape as _;
+use crate::{OperationError, Shape as _};
 
 /// Processes an [`fj::Shape`] into a [`ProcessedShape`]
 pub struct ShapeProcessor {
     /// The tolerance value used for creating the triangle mesh
     pub tolerance: Option<Tolerance>,
+
+    /// The configuration used for validating the shape
+    pub validation_config: ValidationConfig,
 }
 
 impl ShapeProcessor {
@@ -38,9 +41,12 @@ impl ShapeProcessor {
             Some(user_defined_tolerance) => user_defined_tolerance,
         };
 
-        let config = ValidationConfig::default();
         let mut debug_info = DebugInfo::new();
-        let shape = shape.compute_brep(&config, tolerance, &mut debug_info)?;
+        let shape = shape.compute_brep(
+            &self.validation_config,
+            tolerance,
+            &mut debug_info,
+        )?;
         let mesh = triangulate(shape.into_inner(), tolerance, &mut debug_info);
 
         Ok(ProcessedShape {
@@ -57,7 +63,7 @@ impl ShapeProcessor {
 pub enum Error {
     /// Error converting to shape
     #[error("Error converting to shape")]
-    ToShape(#[from] ValidationError),
+    ToShape(#[from] OperationError),
 
     /// Model has zero size
     #[error("Model has zero size")]
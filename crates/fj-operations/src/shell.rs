@@ -0,0 +1,37 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{hollow, Tolerance},
+    objects::Solid,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::Aabb;
+
+use super::{face_selector::select_faces, OperationError, Shape};
+
+impl Shape for fj::Shell {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        let faces = self
+            .shape()
+            .compute_brep(config, tolerance, debug_info)?
+            .into_inner();
+
+        let solid = hollow(
+            &Solid::new().with_faces(faces),
+            self.thickness(),
+            select_faces(self.open_faces()),
+        )?;
+        Ok(validate(solid, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // Hollowing only ever removes material.
+        self.shape().bounding_volume()
+    }
+}
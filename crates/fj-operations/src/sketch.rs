@@ -2,11 +2,13 @@ use fj_interop::{debug::DebugInfo, mesh::Color};
 use fj_kernel::{
     algorithms::Tolerance,
     objects::{Cycle, Edge, Face, Sketch, Surface},
-    validation::{validate, Validated, ValidationConfig, ValidationError},
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::{
+    Aabb, BezierCurve, CatmullRomSpline, Circle, Point, Scalar, Vector,
 };
-use fj_math::{Aabb, Point, Scalar};
 
-use super::Shape;
+use super::{OperationError, Shape};
 
 impl Shape for fj::Sketch {
     type Brep = Sketch;
@@ -16,7 +18,7 @@ impl Shape for fj::Sketch {
         config: &ValidationConfig,
         _: Tolerance,
         _: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, OperationError> {
         let surface = Surface::xy_plane();
 
         let face = match self.chain() {
@@ -26,7 +28,7 @@ impl Shape for fj::Sketch {
 
                 let edge = Edge::build()
                     .circle_from_radius(Scalar::from_f64(circle.radius()));
-                let cycle = Cycle::new(surface).with_edges([edge]);
+                let cycle = Cycle::new(surface.clone()).with_edges([edge]);
 
                 Face::new(surface)
                     .with_exteriors([cycle])
@@ -41,10 +43,47 @@ impl Shape for fj::Sketch {
                     .into_face()
                     .with_color(Color(self.color()))
             }
+            fj::Chain::SegmentChain(segment_chain) => {
+                let mut edges = Vec::new();
+
+                let mut start = Point::from(segment_chain.start());
+                for segment in closed_segments(segment_chain) {
+                    let end = Point::from(segment.end());
+
+                    let edge = match segment {
+                        fj::Segment::Line { .. } => Edge::build()
+                            .line_segment_from_points(&surface, [start, end]),
+                        fj::Segment::Arc {
+                            center, direction, ..
+                        } => Edge::build().arc_from_circle_and_points(
+                            &surface,
+                            arc_circle(start, center, direction),
+                            [start, end],
+                        ),
+                        fj::Segment::QuadraticBezier { .. }
+                        | fj::Segment::CubicBezier { .. }
+                        | fj::Segment::CatmullRom { .. } => {
+                            // Can't panic. All of these segments are curves.
+                            let curve = bezier_curve(start, segment).unwrap();
+                            Edge::build()
+                                .nurbs_from_curve(&surface, curve.to_nurbs())
+                        }
+                    };
+                    edges.push(edge);
+
+                    start = end;
+                }
+
+                let cycle = Cycle::new(surface.clone()).with_edges(edges);
+
+                Face::new(surface)
+                    .with_exteriors([cycle])
+                    .with_color(Color(self.color()))
+            }
         };
 
         let sketch = Sketch::new().with_faces([face]);
-        validate(sketch, config)
+        Ok(validate(sketch, config)?)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -60,6 +99,142 @@ impl Shape for fj::Sketch {
                     .map(Point::from)
                     .map(Point::to_xyz),
             ),
+            fj::Chain::SegmentChain(segment_chain) => {
+                let mut points = vec![Point::from(segment_chain.start())];
+
+                for segment in closed_segments(segment_chain) {
+                    // Can't panic. There's always at least the start point.
+                    let start = *points.last().unwrap();
+                    let end = Point::from(segment.end());
+
+                    if let fj::Segment::Arc {
+                        center, direction, ..
+                    } = segment
+                    {
+                        // Besides its end points, an arc can only extend the
+                        // bounding volume where it crosses one of the axes
+                        // through its center.
+                        let circle = arc_circle(start, center, direction);
+                        let end_t = arc_end(&circle, end);
+                        let radius = circle.a().magnitude();
+
+                        for direction in
+                            [[1., 0.], [0., 1.], [-1., 0.], [0., -1.]]
+                        {
+                            let point = circle.center()
+                                + Vector::from(direction) * radius;
+                            if circle.point_to_circle_coords(point).t < end_t {
+                                points.push(point);
+                            }
+                        }
+                    }
+
+                    // A Bézier curve lies within the convex hull of its
+                    // control points.
+                    if let Some(curve) = bezier_curve(start, segment) {
+                        points.extend(curve.control_points());
+                    }
+
+                    points.push(end);
+                }
+
+                Aabb::<3>::from_points(points.into_iter().map(Point::to_xyz))
+            }
         }
     }
 }
+
+/// Iterate over the segments of the chain, including the one that closes it
+fn closed_segments(
+    segment_chain: &fj::SegmentChain,
+) -> impl Iterator<Item = fj::Segment> + '_ {
+    let start = segment_chain.start();
+    let end = segment_chain
+        .segments()
+        .last()
+        .map(|segment| segment.end())
+        .unwrap_or(start);
+
+    let closing_line =
+        (end != start).then_some(fj::Segment::Line { end: start });
+
+    segment_chain.segments().iter().copied().chain(closing_line)
+}
+
+/// Convert a segment into a Bézier curve
+///
+/// Returns `None`, if the segment is a line or an arc.
+fn bezier_curve(
+    start: Point<2>,
+    segment: fj::Segment,
+) -> Option<BezierCurve<2>> {
+    let curve = match segment {
+        fj::Segment::Line { .. } | fj::Segment::Arc { .. } => return None,
+        fj::Segment::QuadraticBezier { control, end } => {
+            BezierCurve::from_control_points([
+                start,
+                control.into(),
+                end.into(),
+            ])
+        }
+        fj::Segment::CubicBezier {
+            controls: [a, b],
+            end,
+        } => BezierCurve::from_control_points([
+            start,
+            a.into(),
+            b.into(),
+            end.into(),
+        ]),
+        fj::Segment::CatmullRom {
+            previous,
+            end,
+            next,
+        } => {
+            let spline = CatmullRomSpline::from_points([
+                previous.into(),
+                start,
+                end.into(),
+                next.into(),
+            ]);
+
+            // Can't panic. A spline with four points has exactly one span.
+            spline.to_bezier_curves().pop().unwrap()
+        }
+    };
+
+    Some(curve)
+}
+
+/// Create the circle that an arc is a part of
+///
+/// The circle coordinate of the arc's start point is zero, and its coordinates
+/// increase in the direction of the arc.
+fn arc_circle(
+    start: Point<2>,
+    center: [f64; 2],
+    direction: fj::ArcDirection,
+) -> Circle<2> {
+    let center = Point::from(center);
+
+    let a = start - center;
+    let b = Vector::from([-a.v, a.u]);
+    let b = match direction {
+        fj::ArcDirection::Counterclockwise => b,
+        fj::ArcDirection::Clockwise => -b,
+    };
+
+    Circle::new(center, a, b)
+}
+
+/// Compute the circle coordinate of an arc's end point
+///
+/// Expects the circle to come from [`arc_circle`].
+fn arc_end(circle: &Circle<2>, end: Point<2>) -> Scalar {
+    let t = circle.point_to_circle_coords(end).t;
+    if t == Scalar::ZERO {
+        Scalar::PI * 2.
+    } else {
+        t
+    }
+}
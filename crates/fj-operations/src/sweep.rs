@@ -1,12 +1,14 @@
+use std::f64::consts::{PI, TAU};
+
 use fj_interop::{debug::DebugInfo, mesh::Color};
 use fj_kernel::{
-    algorithms::{sweep, Tolerance},
+    algorithms::{sweep_with_draft, Tolerance},
     objects::Solid,
-    validation::{validate, Validated, ValidationConfig, ValidationError},
+    validation::{validate, Validated, ValidationConfig},
 };
 use fj_math::{Aabb, Vector};
 
-use super::Shape;
+use super::{OperationError, Shape};
 
 impl Shape for fj::Sweep {
     type Brep = Solid;
@@ -16,24 +18,73 @@ impl Shape for fj::Sweep {
         config: &ValidationConfig,
         tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, OperationError> {
         let sketch =
             self.shape().compute_brep(config, tolerance, debug_info)?;
         let path = Vector::from(self.path());
         let color = self.shape().color();
 
-        let solid = sweep(sketch.into_inner(), path, tolerance, Color(color));
-        validate(solid, config)
+        let solid = sweep_with_draft(
+            sketch.into_inner(),
+            path,
+            draft(self),
+            Color(color),
+        )?;
+        Ok(validate(solid, config)?)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
-        self.shape()
-            .bounding_volume()
-            .merged(&Aabb::<3>::from_points(
-                self.shape()
-                    .bounding_volume()
-                    .vertices()
-                    .map(|v| v + self.path()),
-            ))
+        let shape = self.shape().bounding_volume();
+
+        let mut end =
+            Aabb::<3>::from_points(shape.vertices().map(|v| v + self.path()));
+
+        // A negative draft angle makes the end of the sweep grow. It never
+        // grows by more than the offset, in any direction.
+        let draft = draft(self);
+        if draft < 0. {
+            let offset = Vector::from(self.path()).magnitude().into_f64()
+                * draft.abs().tan();
+
+            end.min = end.min - Vector::from([offset; 3]);
+            end.max = end.max + Vector::from([offset; 3]);
+        }
+
+        shape.merged(&end)
+    }
+}
+
+/// The draft angle of the sweep in radians, between `-PI` and `PI`
+fn draft(sweep: &fj::Sweep) -> f64 {
+    let draft = sweep.draft().rad();
+
+    if draft > PI {
+        draft - TAU
+    } else {
+        draft
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use fj::Angle;
+
+    use crate::{test_utils::faces, OperationError};
+
+    #[test]
+    fn draft_right_angle() {
+        let shape = fj::Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]);
+
+        for deg in [90., 270.] {
+            let sweep =
+                fj::Sweep::from_path(shape.clone().into(), [0., 0., 1.])
+                    .with_draft(Angle::from_deg(deg));
+
+            let error = faces(&sweep.into()).unwrap_err();
+            assert!(matches!(
+                error.downcast_ref::<OperationError>(),
+                Some(OperationError::Sweep(_))
+            ));
+        }
     }
 }
@@ -0,0 +1,30 @@
+//! Utilities that are shared between tests
+
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::Tolerance, objects::Face, validation::ValidationConfig,
+};
+
+use crate::Shape as _;
+
+/// Create a unit cube, by sweeping a square with the given corner along z
+pub fn cube(corner: [f64; 2]) -> fj::Shape {
+    let [x, y] = corner;
+    let square = fj::Sketch::from_points(vec![
+        [x, y],
+        [x + 1., y],
+        [x + 1., y + 1.],
+        [x, y + 1.],
+    ]);
+    fj::Sweep::from_path(square.into(), [0., 0., 1.]).into()
+}
+
+/// Compute the faces of a shape
+pub fn faces(shape: &fj::Shape) -> anyhow::Result<Vec<Face>> {
+    let faces = shape.compute_brep(
+        &ValidationConfig::default(),
+        Tolerance::from_scalar(0.001)?,
+        &mut DebugInfo::new(),
+    )?;
+    Ok(faces.into_inner())
+}
@@ -0,0 +1,437 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{
+        signed_area, BooleanError, BooleanOps, CycleApprox, Tolerance,
+    },
+    objects::{Cycle, Edge, Face, Sketch, Surface},
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::{Aabb, BezierCurve, Point, Vector};
+use ttf_parser::{FaceParsingError, GlyphId, OutlineBuilder};
+
+use super::{OperationError, Shape};
+
+/// The font that is used, unless a model provides its own
+const BUNDLED_FONT: &[u8] = include_bytes!("fonts/B612-Regular.ttf");
+
+impl Shape for fj::Text {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        _: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        let surface = Surface::xy_plane();
+        let font = parse_font(self.font())?;
+        let layout = Layout::new(self, &font);
+
+        let mut faces = Vec::new();
+
+        for &(glyph, offset) in &layout.glyphs {
+            let mut outline = Outline {
+                scale: layout.scale,
+                offset,
+                contours: Vec::new(),
+                current: Vec::new(),
+                start: Point::origin(),
+                last: Point::origin(),
+            };
+            font.outline_glyph(glyph, &mut outline);
+
+            faces.extend(glyph_faces(&surface, outline.contours, tolerance)?);
+        }
+
+        let sketch = Sketch::new().with_faces(
+            faces
+                .into_iter()
+                .map(|face| face.with_color(Color(self.color()))),
+        );
+
+        Ok(validate(sketch, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        // A font that can't be parsed is reported by `compute_brep`.
+        let font = match parse_font(self.font()) {
+            Ok(font) => font,
+            Err(_) => {
+                return Aabb {
+                    min: Point::origin(),
+                    max: Point::origin(),
+                }
+            }
+        };
+        let layout = Layout::new(self, &font);
+
+        let points: Vec<_> = layout
+            .glyphs
+            .iter()
+            .filter_map(|&(glyph, offset)| {
+                let rect = font.glyph_bounding_box(glyph)?;
+
+                let corners =
+                    [[rect.x_min, rect.y_min], [rect.x_max, rect.y_max]].map(
+                        |[x, y]| {
+                            let point =
+                                Point::from([f64::from(x), f64::from(y)]);
+                            (offset + point.coords * layout.scale).to_xyz()
+                        },
+                    );
+                Some(corners)
+            })
+            .flatten()
+            .collect();
+
+        if points.is_empty() {
+            return Aabb {
+                min: Point::origin(),
+                max: Point::origin(),
+            };
+        }
+
+        Aabb::<3>::from_points(points)
+    }
+}
+
+fn parse_font(
+    font: &fj::Font,
+) -> Result<ttf_parser::Face<'_>, FaceParsingError> {
+    let data = match font {
+        fj::Font::Bundled => BUNDLED_FONT,
+        fj::Font::Custom(data) => data.bytes(),
+    };
+
+    ttf_parser::Face::from_slice(data, 0)
+}
+
+/// The positions of the glyphs that make up a text
+struct Layout {
+    /// The factor that converts font units into model units
+    scale: f64,
+
+    /// The glyphs, with the position of their origin in model units
+    glyphs: Vec<(GlyphId, Point<2>)>,
+}
+
+impl Layout {
+    fn new(text: &fj::Text, font: &ttf_parser::Face) -> Self {
+        let scale = text.size() / f64::from(font.units_per_em());
+        let line_height = [font.ascender(), -font.descender(), font.line_gap()]
+            .into_iter()
+            .map(f64::from)
+            .sum::<f64>()
+            * scale;
+
+        let mut glyphs = Vec::new();
+
+        for (i, line) in text.text().lines().enumerate() {
+            let y = -line_height * i as f64;
+
+            let mut line_glyphs = Vec::new();
+            let mut x = 0.;
+            let mut previous = None;
+
+            for c in line.chars() {
+                // Characters the font doesn't cover are displayed using the
+                // glyph for missing characters, which is always the first one.
+                let glyph = font.glyph_index(c).unwrap_or(GlyphId(0));
+
+                if let Some(previous) = previous {
+                    x += f64::from(kerning(font, previous, glyph)) * scale;
+                }
+
+                line_glyphs.push((glyph, x));
+
+                let advance = font.glyph_hor_advance(glyph).unwrap_or(0);
+                x += f64::from(advance) * scale;
+                previous = Some(glyph);
+            }
+
+            let width = x;
+            let shift = match text.alignment() {
+                fj::TextAlignment::Left => 0.,
+                fj::TextAlignment::Center => -width / 2.,
+                fj::TextAlignment::Right => -width,
+            };
+
+            glyphs.extend(
+                line_glyphs
+                    .into_iter()
+                    .map(|(glyph, x)| (glyph, Point::from([x + shift, y]))),
+            );
+        }
+
+        Self { scale, glyphs }
+    }
+}
+
+fn kerning(font: &ttf_parser::Face, left: GlyphId, right: GlyphId) -> i16 {
+    font.tables()
+        .kern
+        .and_then(|kern| {
+            kern.subtables
+                .into_iter()
+                .filter(|subtable| subtable.horizontal && !subtable.variable)
+                .find_map(|subtable| subtable.glyphs_kerning(left, right))
+        })
+        .unwrap_or(0)
+}
+
+/// A closed contour of a glyph's outline, in model units
+///
+/// Each segment is a Bézier curve. Lines are curves of degree 1.
+type Contour = Vec<BezierCurve<2>>;
+
+/// Collects the contours of a glyph from the font parser
+struct Outline {
+    scale: f64,
+    offset: Point<2>,
+
+    contours: Vec<Contour>,
+    current: Contour,
+
+    start: Point<2>,
+    last: Point<2>,
+}
+
+impl Outline {
+    fn point(&self, x: f32, y: f32) -> Point<2> {
+        self.offset + Vector::from([f64::from(x), f64::from(y)]) * self.scale
+    }
+
+    fn push(&mut self, points: Vec<Point<2>>) {
+        // Can't panic. Every segment has at least two points.
+        let end = points[points.len() - 1];
+
+        // Fonts sometimes contain segments that don't go anywhere.
+        if points.iter().all(|&point| point == self.last) {
+            return;
+        }
+
+        self.current.push(BezierCurve::from_control_points(points));
+        self.last = end;
+    }
+}
+
+impl OutlineBuilder for Outline {
+    fn move_to(&mut self, x: f32, y: f32) {
+        self.start = self.point(x, y);
+        self.last = self.start;
+    }
+
+    fn line_to(&mut self, x: f32, y: f32) {
+        let points = vec![self.last, self.point(x, y)];
+        self.push(points);
+    }
+
+    fn quad_to(&mut self, x1: f32, y1: f32, x: f32, y: f32) {
+        let points = vec![self.last, self.point(x1, y1), self.point(x, y)];
+        self.push(points);
+    }
+
+    fn curve_to(&mut self, x1: f32, y1: f32, x2: f32, y2: f32, x: f32, y: f32) {
+        let points = vec![
+            self.last,
+            self.point(x1, y1),
+            self.point(x2, y2),
+            self.point(x, y),
+        ];
+        self.push(points);
+    }
+
+    fn close(&mut self) {
+        // Contours are closed implicitly, if they don't end where they
+        // started.
+        if self.last != self.start {
+            let points = vec![self.last, self.start];
+            self.push(points);
+        }
+
+        let contour = std::mem::take(&mut self.current);
+        if !contour.is_empty() {
+            self.contours.push(contour);
+        }
+    }
+}
+
+/// Build faces from the contours of a glyph
+///
+/// Fonts fill the regions that contours wind around in one direction, while
+/// contours that go the other way cut counters out of them. TrueType and
+/// OpenType fonts use opposite conventions for that direction, but the largest
+/// contour always bounds a filled region.
+///
+/// The contours are combined from the largest to the smallest. That way,
+/// counters are cut out of the regions around them, and any filled regions
+/// within counters are added back afterwards.
+fn glyph_faces(
+    surface: &Surface,
+    contours: Vec<Contour>,
+    tolerance: Tolerance,
+) -> Result<Vec<Face>, BooleanError> {
+    let mut polygons: Vec<_> = contours
+        .iter()
+        .map(|contour| polygon(surface, contour, tolerance))
+        .filter(|(_, area)| *area != 0.)
+        .collect();
+    polygons.sort_by(|(_, a), (_, b)| b.abs().total_cmp(&a.abs()));
+
+    let filled = match polygons.first() {
+        Some((_, area)) => area.signum(),
+        None => return Ok(Vec::new()),
+    };
+
+    let mut glyph = Sketch::new();
+
+    for (cycle, area) in polygons {
+        let face = Face::new(surface.clone()).with_exteriors([cycle]);
+        let face = Sketch::new().with_faces([face]);
+
+        glyph = if area.signum() == filled {
+            glyph.union(&face, tolerance)?
+        } else {
+            glyph.difference(&face, tolerance)?
+        };
+    }
+
+    Ok(glyph.into_faces().collect())
+}
+
+/// Approximate a contour with a counter-clockwise polygon
+///
+/// The boolean operations that combine the contours would approximate their
+/// curves anyway. Doing that up front makes it easy to bring them into
+/// counter-clockwise order. Also returns the signed area of the contour, which
+/// is negative, if it goes clockwise.
+fn polygon(
+    surface: &Surface,
+    contour: &Contour,
+    tolerance: Tolerance,
+) -> (Cycle, f64) {
+    let edges = contour.iter().map(|curve| {
+        if curve.degree() == 1 {
+            let [a, b] = [0, 1].map(|i| curve.control_points()[i]);
+            Edge::build().line_segment_from_points(surface, [a, b])
+        } else {
+            Edge::build().nurbs_from_curve(surface, curve.to_nurbs())
+        }
+    });
+    let cycle = Cycle::new(surface.clone()).with_edges(edges);
+
+    let mut points: Vec<_> = CycleApprox::new(&cycle, tolerance)
+        .points
+        .into_iter()
+        .map(|point| *point.local_form())
+        .collect();
+
+    // The approximation ends with the point it started with, but a polygon is
+    // closed implicitly.
+    if points.len() > 1 && points.first() == points.last() {
+        points.pop();
+    }
+
+    let area = signed_area(cycle.edges());
+    if area < 0. {
+        points.reverse();
+    }
+
+    let polygon = Cycle::build(surface.clone()).polygon_from_points(points);
+    (polygon, area)
+}
+
+#[cfg(test)]
+mod tests {
+    use fj_interop::debug::DebugInfo;
+    use fj_kernel::{algorithms::Tolerance, validation::ValidationConfig};
+
+    use crate::{OperationError, Shape as _};
+
+    use super::{kerning, parse_font, Layout};
+
+    #[test]
+    fn counter() -> anyhow::Result<()> {
+        let text = fj::Text::from_text("o", 10.);
+
+        let sketch = text
+            .compute_brep(
+                &ValidationConfig::default(),
+                Tolerance::from_scalar(0.01)?,
+                &mut DebugInfo::new(),
+            )?
+            .into_inner();
+
+        let faces: Vec<_> = sketch.faces().collect();
+        assert_eq!(faces.len(), 1);
+        assert_eq!(faces[0].exteriors().count(), 1);
+        assert_eq!(faces[0].interiors().count(), 1);
+
+        Ok(())
+    }
+
+    #[test]
+    fn alignment() -> anyhow::Result<()> {
+        let font = parse_font(&fj::Font::Bundled)?;
+        let layout = |alignment| {
+            let text =
+                fj::Text::from_text("Fornjot", 2.).with_alignment(alignment);
+            Layout::new(&text, &font)
+        };
+
+        let left = layout(fj::TextAlignment::Left);
+        let center = layout(fj::TextAlignment::Center);
+        let right = layout(fj::TextAlignment::Right);
+
+        // Right-aligned text ends at the origin.
+        let &(glyph, origin) = right.glyphs.last().expect("Text is not empty");
+        let advance = font.glyph_hor_advance(glyph).unwrap_or(0);
+        let end = origin.u.into_f64() + f64::from(advance) * right.scale;
+        assert!(end.abs() < 1e-12);
+
+        // Centered text is shifted by half of that.
+        let glyphs = left.glyphs.iter().zip(&center.glyphs).zip(&right.glyphs);
+        for ((&(_, left), &(_, center)), &(_, right)) in glyphs {
+            let shift = (right - left) / 2.;
+            assert!((center - left - shift).magnitude().into_f64() < 1e-12);
+        }
+
+        Ok(())
+    }
+
+    #[test]
+    fn kerning_pairs() -> anyhow::Result<()> {
+        let font = parse_font(&fj::Font::Bundled)?;
+        let [t, o] = ['T', 'o'].map(|c| {
+            font.glyph_index(c).expect("Bundled font covers character")
+        });
+
+        let kerning = kerning(&font, t, o);
+        assert!(kerning < 0);
+
+        let text = fj::Text::from_text("To", 2.);
+        let layout = Layout::new(&text, &font);
+
+        let advance = font.glyph_hor_advance(t).unwrap_or(0);
+        let expected = (f64::from(advance) + f64::from(kerning)) * layout.scale;
+        let [(_, first), (_, second)] = [layout.glyphs[0], layout.glyphs[1]];
+        assert!(((second - first).u.into_f64() - expected).abs() < 1e-12);
+
+        Ok(())
+    }
+
+    #[test]
+    fn invalid_font() -> anyhow::Result<()> {
+        let font = fj::Font::from_bytes(vec![0; 16]);
+        let text = fj::Text::from_text("o", 1.).with_font(font);
+
+        let result = text.compute_brep(
+            &ValidationConfig::default(),
+            Tolerance::from_scalar(0.01)?,
+            &mut DebugInfo::new(),
+        );
+        assert!(matches!(result, Err(OperationError::Font(_))));
+
+        Ok(())
+    }
+}
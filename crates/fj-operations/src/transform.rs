@@ -2,11 +2,11 @@ use fj_interop::debug::DebugInfo;
 use fj_kernel::{
     algorithms::{transform_faces, Tolerance},
     objects::Face,
-    validation::{validate, Validated, ValidationConfig, ValidationError},
+    validation::{validate, Validated, ValidationConfig},
 };
-use fj_math::{Aabb, Transform, Vector};
+use fj_math::{Aabb, Transform};
 
-use super::Shape;
+use super::{OperationError, Shape};
 
 impl Shape for fj::Transform {
     type Brep = Vec<Face>;
@@ -16,7 +16,7 @@ impl Shape for fj::Transform {
         config: &ValidationConfig,
         tolerance: Tolerance,
         debug_info: &mut DebugInfo,
-    ) -> Result<Validated<Self::Brep>, ValidationError> {
+    ) -> Result<Validated<Self::Brep>, OperationError> {
         let mut faces = self
             .shape
             .compute_brep(config, tolerance, debug_info)?
@@ -24,7 +24,7 @@ impl Shape for fj::Transform {
 
         transform_faces(&mut faces, &make_transform(self));
 
-        validate(faces, config)
+        Ok(validate(faces, config)?)
     }
 
     fn bounding_volume(&self) -> Aabb<3> {
@@ -33,7 +33,5 @@ impl Shape for fj::Transform {
 }
 
 fn make_transform(transform: &fj::Transform) -> Transform {
-    let axis = Vector::from(transform.axis).normalize();
-    Transform::translation(transform.offset)
-        * Transform::rotation(axis * transform.angle.rad())
+    Transform::from_matrix(transform.matrix)
 }
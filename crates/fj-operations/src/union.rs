@@ -0,0 +1,40 @@
+use fj_interop::debug::DebugInfo;
+use fj_kernel::{
+    algorithms::{BooleanOps, Tolerance},
+    objects::Solid,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::Aabb;
+
+use super::{OperationError, Shape};
+
+impl Shape for fj::Union {
+    type Brep = Solid;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        let [a, b] = self.shapes();
+
+        let a = a.compute_brep(config, tolerance, debug_info)?;
+        let b = b.compute_brep(config, tolerance, debug_info)?;
+
+        let a = Solid::new().with_faces(a.into_inner());
+        let b = Solid::new().with_faces(b.into_inner());
+
+        let union = a.union(&b, tolerance)?;
+        Ok(validate(union, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [a, b] = self.shapes();
+
+        let a = a.bounding_volume();
+        let b = b.bounding_volume();
+
+        a.merged(&b)
+    }
+}
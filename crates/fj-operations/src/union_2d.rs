@@ -0,0 +1,46 @@
+use fj_interop::{debug::DebugInfo, mesh::Color};
+use fj_kernel::{
+    algorithms::{BooleanOps, Tolerance},
+    objects::Sketch,
+    validation::{validate, Validated, ValidationConfig},
+};
+use fj_math::Aabb;
+
+use super::{OperationError, Shape};
+
+impl Shape for fj::Union2d {
+    type Brep = Sketch;
+
+    fn compute_brep(
+        &self,
+        config: &ValidationConfig,
+        tolerance: Tolerance,
+        debug_info: &mut DebugInfo,
+    ) -> Result<Validated<Self::Brep>, OperationError> {
+        // Can be cleaned up, once `each_ref` and `try_map` are stable:
+        // - https://doc.rust-lang.org/std/primitive.array.html#method.each_ref
+        // - https://doc.rust-lang.org/std/primitive.array.html#method.try_map
+        let [a, b] = self.shapes();
+        let [a, b] = [a, b]
+            .map(|shape| shape.compute_brep(config, tolerance, debug_info));
+        let [a, b] = [a?, b?];
+
+        let union = a.union(&b, tolerance)?;
+        let union = Sketch::new().with_faces(
+            union
+                .into_faces()
+                .map(|face| face.with_color(Color(self.color()))),
+        );
+
+        Ok(validate(union, config)?)
+    }
+
+    fn bounding_volume(&self) -> Aabb<3> {
+        let [a, b] = self.shapes();
+
+        let a = a.bounding_volume();
+        let b = b.bounding_volume();
+
+        a.merged(&b)
+    }
+}
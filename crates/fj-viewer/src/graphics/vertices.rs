@@ -128,6 +128,13 @@ impl From<&DebugInfo> for Vertices {
             }
         }
 
+        for &position in &debug_info.validation_issues {
+            let normal = [0.; 3];
+            let magenta = [1., 0., 1., 1.];
+
+            self_.push_cross(position, normal, magenta);
+        }
+
         self_
     }
 }
@@ -6,8 +6,8 @@
 use std::error;
 
 use fj_host::Watcher;
-use fj_interop::status_report::StatusReport;
-use fj_operations::shape_processor::ShapeProcessor;
+use fj_interop::{debug::DebugInfo, status_report::StatusReport};
+use fj_operations::shape_processor::{self, ShapeProcessor};
 use fj_viewer::{
     camera::Camera,
     graphics::{self, DrawConfig, Renderer},
@@ -67,6 +67,23 @@ pub fn run(
                     shape = Some(new_shape);
                 }
                 Err(err) => {
+                    // Highlight the validation errors, on top of the last shape
+                    // that could be processed.
+                    if let (
+                        shape_processor::Error::ToShape(error),
+                        Some(shape),
+                    ) = (&err, &shape)
+                    {
+                        let mut debug_info = DebugInfo::new();
+                        debug_info.validation_issues = error.positions();
+
+                        renderer.update_geometry(
+                            (&shape.mesh).into(),
+                            (&debug_info).into(),
+                            shape.aabb,
+                        );
+                    }
+
                     // Can be cleaned up, once `Report` is stable:
                     // https://doc.rust-lang.org/std/error/struct.Report.html
 
@@ -0,0 +1,70 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{EdgeSelector, Shape};
+
+/// A 3-dimensional shape with beveled edges
+///
+/// Each selected edge is replaced by a flat face, which cuts the given distance
+/// off both faces that meet at the edge.
+///
+/// # Limitations
+///
+/// The same limitations as for [`Fillet`] apply.
+///
+/// [`Fillet`]: crate::Fillet
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Chamfer {
+    /// The shape whose edges are beveled
+    shape: Shape,
+
+    /// The edges that are beveled
+    edges: EdgeSelector,
+
+    /// The distance that is cut off the faces at each beveled edge
+    distance: f64,
+}
+
+impl Chamfer {
+    /// Create a `Chamfer` of the selected edges of a shape
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `distance` is not positive.
+    pub fn from_shape(
+        shape: Shape,
+        edges: EdgeSelector,
+        distance: f64,
+    ) -> Self {
+        assert!(distance > 0., "Chamfer distance must be positive");
+
+        Self {
+            shape,
+            edges,
+            distance,
+        }
+    }
+
+    /// Access the shape whose edges are beveled
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the edges that are beveled
+    pub fn edges(&self) -> EdgeSelector {
+        self.edges
+    }
+
+    /// Access the distance that is cut off the faces at each beveled edge
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+}
+
+impl From<Chamfer> for Shape {
+    fn from(shape: Chamfer) -> Self {
+        Self::Chamfer(Box::new(shape))
+    }
+}
@@ -0,0 +1,193 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Angle, Shape};
+
+/// A number of copies of a 3-dimensional shape, around an axis
+///
+/// The first copy is the shape itself. The further copies are rotated around
+/// the axis according to the right-hand rule, spaced evenly over the angle,
+/// with the last copy rotated by the full angle.
+///
+/// A full turn is treated differently: The copies are spaced evenly all the way
+/// around the axis, without the last copy ending up where the first one is.
+/// Since [`Angle`] always lies within one full turn, a full turn ends up as an
+/// angle of zero, or as an angle that is just short of a full turn, due to
+/// floating-point inaccuracy (`Angle::from_deg(360.)`, for example). Both are
+/// considered a full turn. [`CircularPattern::full_turn`] can be used to make
+/// this explicit.
+///
+/// # Limitations
+///
+/// Like the shapes in a [`Group`], the copies must not touch or overlap. This
+/// is not currently checked.
+///
+/// [`Group`]: crate::Group
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct CircularPattern {
+    /// The shape being copied
+    shape: Shape,
+
+    /// A point on the axis of the pattern
+    axis_origin: [f64; 3],
+
+    /// The direction of the axis of the pattern
+    axis_direction: [f64; 3],
+
+    /// The angle between the first and the last copy
+    angle: Angle,
+
+    /// The number of copies, including the shape itself
+    count: u32,
+}
+
+impl CircularPattern {
+    /// Create a `CircularPattern` of a shape around an axis
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `count` is zero.
+    pub fn from_axis(
+        shape: Shape,
+        axis_origin: [f64; 3],
+        axis_direction: [f64; 3],
+        angle: Angle,
+        count: u32,
+    ) -> Self {
+        assert!(count > 0, "Pattern needs at least one copy");
+
+        Self {
+            shape,
+            axis_origin,
+            axis_direction,
+            angle,
+            count,
+        }
+    }
+
+    /// Create a `CircularPattern` of a shape, all the way around an axis
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `count` is zero.
+    pub fn full_turn(
+        shape: Shape,
+        axis_origin: [f64; 3],
+        axis_direction: [f64; 3],
+        count: u32,
+    ) -> Self {
+        Self::from_axis(
+            shape,
+            axis_origin,
+            axis_direction,
+            Angle::from_rad(0.),
+            count,
+        )
+    }
+
+    /// Access the shape being copied
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the origin of the axis of the pattern
+    pub fn axis_origin(&self) -> [f64; 3] {
+        self.axis_origin
+    }
+
+    /// Access the direction of the axis of the pattern
+    pub fn axis_direction(&self) -> [f64; 3] {
+        self.axis_direction
+    }
+
+    /// Access the angle between the first and the last copy
+    pub fn angle(&self) -> Angle {
+        self.angle
+    }
+
+    /// Access the number of copies, including the shape itself
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+
+    /// Check whether the pattern goes all the way around the axis
+    pub fn is_full_turn(&self) -> bool {
+        // Angles that are this close to zero or a full turn can only be the
+        // result of floating-point inaccuracy.
+        const TOLERANCE: f64 = 1e-9;
+
+        let angle = self.angle.rad();
+        angle <= TOLERANCE || std::f64::consts::TAU - angle <= TOLERANCE
+    }
+
+    /// Compute the angle by which each copy is rotated relative to the
+    /// previous one, in radians
+    pub fn step(&self) -> f64 {
+        let angle = self.angle.rad();
+
+        if self.is_full_turn() {
+            std::f64::consts::TAU / f64::from(self.count)
+        } else if self.count > 1 {
+            angle / f64::from(self.count - 1)
+        } else {
+            0.
+        }
+    }
+}
+
+impl From<CircularPattern> for Shape {
+    fn from(shape: CircularPattern) -> Self {
+        Self::CircularPattern(Box::new(shape))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::TAU;
+
+    use crate::{Angle, Circle, Shape, Sketch, Sweep};
+
+    use super::CircularPattern;
+
+    #[test]
+    fn step() {
+        let shape: Shape = Sweep::from_path(
+            Sketch::from_circle(Circle::from_radius(1.)).into(),
+            [0., 0., 1.],
+        )
+        .into();
+        let pattern = |angle: f64, count| {
+            CircularPattern::from_axis(
+                shape.clone(),
+                [0., 0., 0.],
+                [0., 0., 1.],
+                Angle::from_rad(angle),
+                count,
+            )
+        };
+
+        assert_eq!(pattern(TAU, 4).step(), TAU / 4.);
+        assert_eq!(pattern(TAU / 2., 3).step(), TAU / 4.);
+        assert_eq!(pattern(TAU / 2., 1).step(), 0.);
+
+        // A full turn, however it is specified, doesn't end where it starts.
+        let full_turn = CircularPattern::full_turn(
+            shape.clone(),
+            [0., 0., 0.],
+            [0., 0., 1.],
+            4,
+        );
+        assert_eq!(full_turn.step(), TAU / 4.);
+        let from_deg = CircularPattern::from_axis(
+            shape,
+            [0., 0., 0.],
+            [0., 0., 1.],
+            Angle::from_deg(360.),
+            4,
+        );
+        assert!(from_deg.is_full_turn());
+        assert_eq!(from_deg.step(), TAU / 4.);
+    }
+}
@@ -0,0 +1,38 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Shape;
+
+/// A difference between two 3-dimensional shapes
+///
+/// The result covers the space that is occupied by the first shape, but not by
+/// the second one. This can be used to drill holes or cut pockets.
+///
+/// # Limitations
+///
+/// Only shapes bounded by planar faces are fully supported. Curved faces are
+/// approximated, according to the tolerance used for the model.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Difference3d {
+    shapes: [Shape; 2],
+}
+
+impl Difference3d {
+    /// Create a `Difference3d` from two shapes
+    pub fn from_shapes(shapes: [Shape; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Access the shapes that make up the difference
+    pub fn shapes(&self) -> &[Shape; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Difference3d> for Shape {
+    fn from(shape: Difference3d) -> Self {
+        Self::Difference(Box::new(shape))
+    }
+}
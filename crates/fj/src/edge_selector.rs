@@ -0,0 +1,29 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Selects edges of a 3-dimensional shape
+///
+/// Used by operations that modify specific edges of a shape, like [`Fillet`]
+/// and [`Chamfer`].
+///
+/// [`Fillet`]: crate::Fillet
+/// [`Chamfer`]: crate::Chamfer
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum EdgeSelector {
+    /// Select all straight edges that are parallel to the given direction
+    ParallelTo([f64; 3]),
+
+    /// Select all edges, whose vertices lie within the given box
+    ///
+    /// The box is axis-aligned and defined by its minimum and maximum points.
+    /// Edges that have no vertices, like full circles, are never selected.
+    Within {
+        /// The minimum point of the box
+        min: [f64; 3],
+
+        /// The maximum point of the box
+        max: [f64; 3],
+    },
+}
@@ -0,0 +1,32 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+/// Selects faces of a 3-dimensional shape
+///
+/// Used by operations that modify specific faces of a shape, like [`Shell`].
+///
+/// [`Shell`]: crate::Shell
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum FaceSelector {
+    /// Select no faces
+    None,
+
+    /// Select all planar faces that face in the given direction
+    ///
+    /// A face faces in a direction, if its outward normal points that way.
+    FacingTowards([f64; 3]),
+
+    /// Select all faces, whose vertices lie within the given box
+    ///
+    /// The box is axis-aligned and defined by its minimum and maximum points.
+    /// Faces that have no vertices, like circular disks, are never selected.
+    Within {
+        /// The minimum point of the box
+        min: [f64; 3],
+
+        /// The maximum point of the box
+        max: [f64; 3],
+    },
+}
@@ -0,0 +1,66 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{EdgeSelector, Shape};
+
+/// A 3-dimensional shape with rounded edges
+///
+/// Each selected edge is replaced by a section of a cylinder with the given
+/// radius, which blends smoothly into the faces that meet at the edge.
+///
+/// # Limitations
+///
+/// Only straight, convex edges between two planar faces can be rounded. No two
+/// selected edges may meet at a vertex. Processing a shape that doesn't meet
+/// these requirements results in an error.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Fillet {
+    /// The shape whose edges are rounded
+    shape: Shape,
+
+    /// The edges that are rounded
+    edges: EdgeSelector,
+
+    /// The radius of the rounded edges
+    radius: f64,
+}
+
+impl Fillet {
+    /// Create a `Fillet` of the selected edges of a shape
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `radius` is not positive.
+    pub fn from_shape(shape: Shape, edges: EdgeSelector, radius: f64) -> Self {
+        assert!(radius > 0., "Fillet radius must be positive");
+
+        Self {
+            shape,
+            edges,
+            radius,
+        }
+    }
+
+    /// Access the shape whose edges are rounded
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the edges that are rounded
+    pub fn edges(&self) -> EdgeSelector {
+        self.edges
+    }
+
+    /// Access the radius of the rounded edges
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+}
+
+impl From<Fillet> for Shape {
+    fn from(shape: Fillet) -> Self {
+        Self::Fillet(Box::new(shape))
+    }
+}
@@ -0,0 +1,38 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Shape;
+
+/// An intersection of two 3-dimensional shapes
+///
+/// The result covers only the space that is occupied by both shapes. This can
+/// be used to trim a shape to an envelope.
+///
+/// # Limitations
+///
+/// Only shapes bounded by planar faces are fully supported. Curved faces are
+/// approximated, according to the tolerance used for the model.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Intersection {
+    shapes: [Shape; 2],
+}
+
+impl Intersection {
+    /// Create an `Intersection` from two shapes
+    pub fn from_shapes(shapes: [Shape; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Access the shapes that make up the intersection
+    pub fn shapes(&self) -> &[Shape; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Intersection> for Shape {
+    fn from(shape: Intersection) -> Self {
+        Self::Intersection(Box::new(shape))
+    }
+}
@@ -23,14 +23,35 @@ pub mod syntax;
 #[doc(hidden)]
 pub mod abi;
 mod angle;
+mod chamfer;
+mod circular_pattern;
+mod difference_3d;
+mod edge_selector;
+mod face_selector;
+mod fillet;
 mod group;
+mod intersection;
+mod linear_pattern;
+mod loft;
 pub mod models;
+mod offset_2d;
+mod path_sweep;
+mod primitive;
+mod revolve;
 mod shape_2d;
+mod shell;
 mod sweep;
+mod text;
 mod transform;
+mod union;
 
 pub use self::{
-    angle::*, group::Group, shape_2d::*, sweep::Sweep, transform::Transform,
+    angle::*, chamfer::Chamfer, circular_pattern::CircularPattern,
+    difference_3d::Difference3d, edge_selector::EdgeSelector,
+    face_selector::FaceSelector, fillet::Fillet, group::Group,
+    intersection::Intersection, linear_pattern::LinearPattern, loft::*,
+    offset_2d::*, path_sweep::*, primitive::*, revolve::Revolve, shape_2d::*,
+    shell::Shell, sweep::Sweep, text::*, transform::Transform, union::Union,
 };
 pub use fj_proc::*;
 #[cfg(feature = "serde")]
@@ -42,15 +63,51 @@ use serde::{Deserialize, Serialize};
 #[repr(C)]
 #[allow(improper_ctypes)] // Box isn't FFI-safe
 pub enum Shape {
+    /// A 3-dimensional shape with beveled edges
+    Chamfer(Box<Chamfer>),
+
+    /// A number of copies of a 3-dimensional shape, around an axis
+    CircularPattern(Box<CircularPattern>),
+
+    /// A difference between two 3-dimensional shapes
+    Difference(Box<Difference3d>),
+
+    /// A 3-dimensional shape with rounded edges
+    Fillet(Box<Fillet>),
+
     /// A group of two 3-dimensional shapes
     Group(Box<Group>),
 
+    /// An intersection of two 3-dimensional shapes
+    Intersection(Box<Intersection>),
+
+    /// A number of copies of a 3-dimensional shape, along a straight line
+    LinearPattern(Box<LinearPattern>),
+
+    /// A transition between a number of 2-dimensional profiles
+    Loft(Loft),
+
+    /// A sweep of a 2-dimensional shape along a path
+    PathSweep(PathSweep),
+
+    /// A primitive 3-dimensional shape, like a sphere
+    Primitive(Primitive),
+
+    /// A revolution of a 2-dimensional shape around an axis
+    Revolve(Revolve),
+
     /// A 2D shape
     Shape2d(Shape2d),
 
+    /// A hollowed-out 3-dimensional shape
+    Shell(Box<Shell>),
+
     /// A sweep of 2-dimensional shape along the z-axis
     Sweep(Sweep),
 
     /// A transformed 3-dimensional shape
     Transform(Box<Transform>),
+
+    /// A union of two 3-dimensional shapes
+    Union(Box<Union>),
 }
@@ -0,0 +1,67 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Shape;
+
+/// A number of copies of a 3-dimensional shape, along a straight line
+///
+/// The first copy is the shape itself. Each further copy is offset from the
+/// previous one by the same vector.
+///
+/// # Limitations
+///
+/// Like the shapes in a [`Group`], the copies must not touch or overlap. This
+/// is not currently checked.
+///
+/// [`Group`]: crate::Group
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct LinearPattern {
+    /// The shape being copied
+    shape: Shape,
+
+    /// The offset between consecutive copies
+    offset: [f64; 3],
+
+    /// The number of copies, including the shape itself
+    count: u32,
+}
+
+impl LinearPattern {
+    /// Create a `LinearPattern` of a shape
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `count` is zero.
+    pub fn from_shape(shape: Shape, offset: [f64; 3], count: u32) -> Self {
+        assert!(count > 0, "Pattern needs at least one copy");
+
+        Self {
+            shape,
+            offset,
+            count,
+        }
+    }
+
+    /// Access the shape being copied
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the offset between consecutive copies
+    pub fn offset(&self) -> [f64; 3] {
+        self.offset
+    }
+
+    /// Access the number of copies, including the shape itself
+    pub fn count(&self) -> u32 {
+        self.count
+    }
+}
+
+impl From<LinearPattern> for Shape {
+    fn from(shape: LinearPattern) -> Self {
+        Self::LinearPattern(Box::new(shape))
+    }
+}
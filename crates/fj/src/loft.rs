@@ -0,0 +1,145 @@
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+
+use crate::{abi::ffi_safe, Shape, Shape2d};
+
+/// A transition between a number of 2-dimensional profiles
+///
+/// Each profile is placed parallel to the xy-plane, at its height. The profiles
+/// are connected, in order, by ruled surfaces. The first and the last profile
+/// close the resulting shape at either end.
+///
+/// The edges of each profile are matched with the edges of the next one,
+/// starting at the start of each profile. Profiles don't need to have the same
+/// number of edges. Where their number differs, edges are split into equal
+/// parts, as required to match them up.
+///
+/// # Limitations
+///
+/// Each profile must consist of exactly one face, and all profiles must have
+/// the same number of holes.
+#[derive(Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct Loft {
+    profiles: ffi_safe::Vec<LoftProfile>,
+}
+
+impl Loft {
+    /// Create a `Loft` from pairs of profiles and their heights
+    ///
+    /// # Panics
+    ///
+    /// Panics, if less than two profiles are provided.
+    pub fn from_profiles(
+        profiles: impl IntoIterator<Item = (Shape2d, f64)>,
+    ) -> Self {
+        let profiles: Vec<_> = profiles
+            .into_iter()
+            .map(|(shape, height)| LoftProfile { shape, height })
+            .collect();
+
+        assert!(profiles.len() >= 2, "Loft needs at least two profiles");
+
+        Self {
+            profiles: profiles.into(),
+        }
+    }
+
+    /// Access the profiles of the loft
+    pub fn profiles(&self) -> &[LoftProfile] {
+        &self.profiles
+    }
+}
+
+impl From<Loft> for Shape {
+    fn from(shape: Loft) -> Self {
+        Self::Loft(shape)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::Serialize for Loft {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let serde_loft = LoftSerde {
+            profiles: self.profiles.to_vec(),
+        };
+
+        serde_loft.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> de::Deserialize<'de> for Loft {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        LoftSerde::deserialize(deserializer).map(|serde_loft| Self {
+            profiles: serde_loft.profiles.into(),
+        })
+    }
+}
+
+/// An owned, non-repr-C [`Loft`]
+///
+/// Serves the same purpose for [`Loft`] that `PolyChainSerde` serves for
+/// [`PolyChain`].
+///
+/// [`PolyChain`]: crate::PolyChain
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "Loft")]
+struct LoftSerde {
+    profiles: Vec<LoftProfile>,
+}
+
+/// A profile of a [`Loft`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct LoftProfile {
+    shape: Shape2d,
+    height: f64,
+}
+
+impl LoftProfile {
+    /// Access the 2-dimensional shape of the profile
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the height at which the profile is placed
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_loft_serialize_loopback() {
+        use serde_json::{from_str, to_string};
+
+        use crate::{Circle, Sketch};
+
+        use super::Loft;
+
+        let loft = Loft::from_profiles([
+            (Sketch::from_circle(Circle::from_radius(1.)).into(), 0.),
+            (
+                Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]).into(),
+                1.,
+            ),
+        ]);
+
+        let json = to_string(&loft).expect("failed to serialize loft");
+        let loft_de: Loft =
+            from_str(&json).expect("failed to deserialize loft");
+
+        assert_eq!(loft, loft_de);
+    }
+}
@@ -0,0 +1,101 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Shape, Shape2d};
+
+/// A 2-dimensional shape, grown or shrunk by a distance
+///
+/// Every edge of the shape is moved by the distance, perpendicular to itself.
+/// Positive distances grow the shape, negative distances shrink it. Where this
+/// opens up gaps at convex corners, they are closed according to the
+/// [`OffsetJoin`].
+///
+/// Parts of the shape that end up overlapping are merged. Parts that are too
+/// narrow to survive shrinking the shape vanish, as do holes that are closed
+/// by growing it.
+///
+/// # Limitations
+///
+/// Shapes with edges that are Bézier curves are not supported.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Offset2d {
+    /// The shape that is offset
+    shape: Shape2d,
+
+    /// The distance that the edges of the shape are moved by
+    distance: f64,
+
+    /// How the offset edges are joined at convex corners
+    join: OffsetJoin,
+}
+
+impl Offset2d {
+    /// Create an `Offset2d` of a shape, by the given distance
+    ///
+    /// Uses [`OffsetJoin::Round`]. Use [`Offset2d::with_join`] to change that.
+    pub fn from_shape(shape: Shape2d, distance: f64) -> Self {
+        Self {
+            shape,
+            distance,
+            join: OffsetJoin::Round,
+        }
+    }
+
+    /// Change how the offset edges are joined at convex corners
+    pub fn with_join(mut self, join: OffsetJoin) -> Self {
+        self.join = join;
+        self
+    }
+
+    /// Get the rendering color of the larger object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shape.color()
+    }
+
+    /// Access the shape that is offset
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the distance that the edges of the shape are moved by
+    pub fn distance(&self) -> f64 {
+        self.distance
+    }
+
+    /// Access how the offset edges are joined at convex corners
+    pub fn join(&self) -> OffsetJoin {
+        self.join
+    }
+}
+
+impl From<Offset2d> for Shape {
+    fn from(shape: Offset2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Offset2d> for Shape2d {
+    fn from(shape: Offset2d) -> Self {
+        Self::Offset(Box::new(shape))
+    }
+}
+
+/// How the edges of an [`Offset2d`] are joined at convex corners
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum OffsetJoin {
+    /// Join the edges with an arc around the original corner
+    Round,
+
+    /// Extend the edges, until they meet
+    ///
+    /// Corners where this would result in a spike that reaches further than
+    /// twice the offset distance are squared off instead.
+    Miter,
+
+    /// Extend the edges by the offset distance, then connect them
+    Square,
+}
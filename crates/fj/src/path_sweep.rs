@@ -0,0 +1,264 @@
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+
+use crate::{abi::ffi_safe, Angle, Shape, Shape2d};
+
+/// A sweep of a 2-dimensional shape along a path
+///
+/// The shape is placed at the start of the path, with its origin on the path,
+/// and its z-axis pointing along the path. From there, it follows the path to
+/// its end. How the shape is rotated around the path, as it follows it, is
+/// defined by the [`SweepFrame`] and the twist of the sweep.
+///
+/// Curved paths are approximated, as are twisted sweeps. Sweeps along straight
+/// sections of a path are exact. Where two of those meet at a corner, they are
+/// mitered.
+///
+/// # Limitations
+///
+/// The shape must consist of exactly one face. The path must not be closed,
+/// and it must not be curved so tightly that the swept shape intersects
+/// itself.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct PathSweep {
+    /// The 2-dimensional shape being swept
+    shape: Shape2d,
+
+    /// The path that the shape is swept along
+    path: SweepPath,
+
+    /// The orientation of the shape along the path
+    frame: SweepFrame,
+
+    /// The rotation of the shape around the path, in radians
+    twist: f64,
+}
+
+impl PathSweep {
+    /// Create a `PathSweep` along the given path
+    ///
+    /// The shape is kept from rotating around the path, as much as possible.
+    /// Use [`PathSweep::with_frame`] and [`PathSweep::with_twist`] to change
+    /// that.
+    pub fn from_path(shape: Shape2d, path: SweepPath) -> Self {
+        Self {
+            shape,
+            path,
+            frame: SweepFrame::RotationMinimizing,
+            twist: 0.,
+        }
+    }
+
+    /// Define how the shape is oriented along the path
+    pub fn with_frame(mut self, frame: SweepFrame) -> Self {
+        self.frame = frame;
+        self
+    }
+
+    /// Rotate the shape around the path, as it follows it
+    ///
+    /// The rotation is distributed evenly over the length of the path. It is
+    /// specified in radians, instead of as an [`Angle`], as it can exceed a
+    /// full turn.
+    pub fn with_twist(mut self, twist: f64) -> Self {
+        self.twist = twist;
+        self
+    }
+
+    /// Access the shape being swept
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the path of the sweep
+    pub fn path(&self) -> &SweepPath {
+        &self.path
+    }
+
+    /// Access the orientation of the shape along the path
+    pub fn frame(&self) -> SweepFrame {
+        self.frame
+    }
+
+    /// Access the rotation of the shape around the path, in radians
+    pub fn twist(&self) -> f64 {
+        self.twist
+    }
+}
+
+impl From<PathSweep> for Shape {
+    fn from(shape: PathSweep) -> Self {
+        Self::PathSweep(shape)
+    }
+}
+
+/// The path of a [`PathSweep`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum SweepPath {
+    /// A helix around the z-axis
+    ///
+    /// The helix starts at `[radius, 0., 0.]`, and winds around the z-axis
+    /// counterclockwise. It rises by `pitch` with each full turn. A helix with
+    /// a pitch of zero is an arc in the xy-plane.
+    Helix {
+        /// The distance of the helix from the z-axis
+        radius: f64,
+
+        /// The distance along the z-axis between consecutive turns
+        pitch: f64,
+
+        /// The number of turns, which doesn't need to be a whole number
+        turns: f64,
+    },
+
+    /// Straight lines between consecutive points
+    Polyline(PathPoints),
+
+    /// A uniform Catmull-Rom spline through all of the points
+    ///
+    /// At its start and end, the spline points towards its neighboring point.
+    Spline(PathPoints),
+}
+
+impl SweepPath {
+    /// Create an arc around the z-axis
+    ///
+    /// The arc starts at `[radius, 0., 0.]`, and goes counterclockwise.
+    pub fn arc(radius: f64, angle: Angle) -> Self {
+        Self::Helix {
+            radius,
+            pitch: 0.,
+            turns: angle.rev(),
+        }
+    }
+
+    /// Create a helix around the z-axis
+    ///
+    /// See [`SweepPath::Helix`].
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `radius` or `turns` are not positive.
+    pub fn helix(radius: f64, pitch: f64, turns: f64) -> Self {
+        assert!(radius > 0., "Radius of a helix must be positive");
+        assert!(turns > 0., "Turns of a helix must be positive");
+
+        Self::Helix {
+            radius,
+            pitch,
+            turns,
+        }
+    }
+
+    /// Create a polyline through the given points
+    ///
+    /// # Panics
+    ///
+    /// Panics, if less than two points are provided.
+    pub fn polyline(points: impl IntoIterator<Item = [f64; 3]>) -> Self {
+        let points: Vec<_> = points.into_iter().collect();
+        assert!(points.len() >= 2, "Polyline needs at least two points");
+
+        Self::Polyline(PathPoints(points.into()))
+    }
+
+    /// Create a spline through the given points
+    ///
+    /// # Panics
+    ///
+    /// Panics, if less than two points are provided.
+    pub fn spline(points: impl IntoIterator<Item = [f64; 3]>) -> Self {
+        let points: Vec<_> = points.into_iter().collect();
+        assert!(points.len() >= 2, "Spline needs at least two points");
+
+        Self::Spline(PathPoints(points.into()))
+    }
+}
+
+/// The points of a [`SweepPath`]
+#[derive(Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct PathPoints(ffi_safe::Vec<[f64; 3]>);
+
+impl PathPoints {
+    /// Access the points
+    pub fn points(&self) -> &[[f64; 3]] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::Serialize for PathPoints {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.points().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> de::Deserialize<'de> for PathPoints {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Vec::<[f64; 3]>::deserialize(deserializer)
+            .map(|points| Self(points.into()))
+    }
+}
+
+/// The orientation of the shape of a [`PathSweep`] along its path
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum SweepFrame {
+    /// Rotate the shape as little as possible, while it follows the path
+    RotationMinimizing,
+
+    /// Align the x-axis of the shape with the principal normal of the path
+    ///
+    /// The principal normal points towards the center of the path's curvature.
+    /// This keeps a shape swept along a helix aligned with the helix' axis.
+    /// Where the path is straight, the shape is rotated as little as possible.
+    Frenet,
+
+    /// Keep the y-axis of the shape pointing in the given direction
+    ///
+    /// The path must never be parallel to this direction.
+    Up([f64; 3]),
+}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_path_sweep_serialize_loopback() {
+        use serde_json::{from_str, to_string};
+
+        use crate::{Circle, Shape2d, Sketch};
+
+        use super::{PathSweep, SweepFrame, SweepPath};
+
+        let shape: Shape2d =
+            Sketch::from_circle(Circle::from_radius(1.)).into();
+        for path in [
+            SweepPath::helix(5., 2., 3.),
+            SweepPath::polyline([[0., 0., 0.], [0., 0., 1.], [1., 0., 1.]]),
+        ] {
+            let sweep = PathSweep::from_path(shape.clone(), path)
+                .with_frame(SweepFrame::Up([0., 0., 1.]))
+                .with_twist(1.);
+
+            let json = to_string(&sweep).expect("failed to serialize sweep");
+            let sweep_de: PathSweep =
+                from_str(&json).expect("failed to deserialize sweep");
+
+            assert_eq!(sweep, sweep_de);
+        }
+    }
+}
@@ -0,0 +1,469 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Shape;
+
+/// A primitive 3-dimensional shape
+///
+/// Primitives are described by a few parameters, instead of being built from
+/// a sketch. Their geometry is exact, meaning curved surfaces are not
+/// approximated by polygons.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum Primitive {
+    /// A cone, or a frustum of one
+    Cone(Cone),
+
+    /// A cuboid (a box)
+    Cuboid(Cuboid),
+
+    /// A cylinder
+    Cylinder(Cylinder),
+
+    /// A sphere
+    Sphere(Sphere),
+
+    /// A torus
+    Torus(Torus),
+
+    /// A wedge
+    Wedge(Wedge),
+}
+
+impl Primitive {
+    /// Get the rendering color of the primitive in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        match self {
+            Self::Cone(shape) => shape.color(),
+            Self::Cuboid(shape) => shape.color(),
+            Self::Cylinder(shape) => shape.color(),
+            Self::Sphere(shape) => shape.color(),
+            Self::Torus(shape) => shape.color(),
+            Self::Wedge(shape) => shape.color(),
+        }
+    }
+}
+
+impl From<Primitive> for Shape {
+    fn from(shape: Primitive) -> Self {
+        Self::Primitive(shape)
+    }
+}
+
+/// A cone
+///
+/// The axis of the cone is the z-axis. Its base is a disc on the xy-plane, and
+/// its apex is on the z-axis, at its height. Use [`Cone::with_top_radius`] to
+/// cut the cone off at its height instead, which makes it a frustum.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Cone {
+    /// The radius of the base
+    radius: f64,
+
+    /// The radius of the top, which is zero for a cone that ends in an apex
+    top_radius: f64,
+
+    /// The height
+    height: f64,
+
+    /// The color of the cone in RGBA
+    color: [u8; 4],
+}
+
+impl Cone {
+    /// Create a `Cone` from the radius of its base and its height
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `radius` or `height` is not positive.
+    pub fn from_radius_and_height(radius: f64, height: f64) -> Self {
+        assert!(radius > 0., "Cone radius must be positive");
+        assert!(height > 0., "Cone height must be positive");
+
+        Self {
+            radius,
+            top_radius: 0.,
+            height,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Cut the cone off with a disc of the given radius
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `top_radius` is negative.
+    pub fn with_top_radius(mut self, top_radius: f64) -> Self {
+        assert!(top_radius >= 0., "Cone top radius must not be negative");
+        self.top_radius = top_radius;
+        self
+    }
+
+    /// Set the rendering color of the cone in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the radius of the base
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Access the radius of the top
+    pub fn top_radius(&self) -> f64 {
+        self.top_radius
+    }
+
+    /// Access the height
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Get the rendering color of the cone in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Cone> for Primitive {
+    fn from(shape: Cone) -> Self {
+        Self::Cone(shape)
+    }
+}
+
+impl From<Cone> for Shape {
+    fn from(shape: Cone) -> Self {
+        Self::Primitive(shape.into())
+    }
+}
+
+/// A cuboid (a box)
+///
+/// The cuboid is centered on the origin, and its edges are parallel to the
+/// axes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Cuboid {
+    /// The size along the x-, y-, and z-axis
+    size: [f64; 3],
+
+    /// The color of the cuboid in RGBA
+    color: [u8; 4],
+}
+
+impl Cuboid {
+    /// Create a `Cuboid` from its size along the x-, y-, and z-axis
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any component of `size` is not positive.
+    pub fn from_size(size: [f64; 3]) -> Self {
+        assert!(
+            size.iter().all(|&length| length > 0.),
+            "Cuboid size must be positive"
+        );
+
+        Self {
+            size,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the rendering color of the cuboid in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the size along the x-, y-, and z-axis
+    pub fn size(&self) -> [f64; 3] {
+        self.size
+    }
+
+    /// Get the rendering color of the cuboid in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Cuboid> for Primitive {
+    fn from(shape: Cuboid) -> Self {
+        Self::Cuboid(shape)
+    }
+}
+
+impl From<Cuboid> for Shape {
+    fn from(shape: Cuboid) -> Self {
+        Self::Primitive(shape.into())
+    }
+}
+
+/// A cylinder
+///
+/// The axis of the cylinder is the z-axis. Its base is a disc on the
+/// xy-plane.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Cylinder {
+    /// The radius
+    radius: f64,
+
+    /// The height
+    height: f64,
+
+    /// The color of the cylinder in RGBA
+    color: [u8; 4],
+}
+
+impl Cylinder {
+    /// Create a `Cylinder` from its radius and height
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `radius` or `height` is not positive.
+    pub fn from_radius_and_height(radius: f64, height: f64) -> Self {
+        assert!(radius > 0., "Cylinder radius must be positive");
+        assert!(height > 0., "Cylinder height must be positive");
+
+        Self {
+            radius,
+            height,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the rendering color of the cylinder in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the radius
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Access the height
+    pub fn height(&self) -> f64 {
+        self.height
+    }
+
+    /// Get the rendering color of the cylinder in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Cylinder> for Primitive {
+    fn from(shape: Cylinder) -> Self {
+        Self::Cylinder(shape)
+    }
+}
+
+impl From<Cylinder> for Shape {
+    fn from(shape: Cylinder) -> Self {
+        Self::Primitive(shape.into())
+    }
+}
+
+/// A sphere
+///
+/// The sphere is centered on the origin.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Sphere {
+    /// The radius
+    radius: f64,
+
+    /// The color of the sphere in RGBA
+    color: [u8; 4],
+}
+
+impl Sphere {
+    /// Create a `Sphere` from its radius
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `radius` is not positive.
+    pub fn from_radius(radius: f64) -> Self {
+        assert!(radius > 0., "Sphere radius must be positive");
+
+        Self {
+            radius,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the rendering color of the sphere in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the radius
+    pub fn radius(&self) -> f64 {
+        self.radius
+    }
+
+    /// Get the rendering color of the sphere in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Sphere> for Primitive {
+    fn from(shape: Sphere) -> Self {
+        Self::Sphere(shape)
+    }
+}
+
+impl From<Sphere> for Shape {
+    fn from(shape: Sphere) -> Self {
+        Self::Primitive(shape.into())
+    }
+}
+
+/// A torus
+///
+/// The torus is centered on the origin, and its axis is the z-axis.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Torus {
+    /// The radius of the circle that the center of the tube follows
+    major_radius: f64,
+
+    /// The radius of the tube
+    minor_radius: f64,
+
+    /// The color of the torus in RGBA
+    color: [u8; 4],
+}
+
+impl Torus {
+    /// Create a `Torus` from its major and minor radius
+    ///
+    /// The major radius is the radius of the circle that the center of the
+    /// tube follows. The minor radius is the radius of the tube.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `minor_radius` is not positive, or if it is not smaller than
+    /// `major_radius`.
+    pub fn from_radii(major_radius: f64, minor_radius: f64) -> Self {
+        assert!(minor_radius > 0., "Torus minor radius must be positive");
+        assert!(
+            minor_radius < major_radius,
+            "Torus minor radius must be smaller than major radius"
+        );
+
+        Self {
+            major_radius,
+            minor_radius,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the rendering color of the torus in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the radius of the circle that the center of the tube follows
+    pub fn major_radius(&self) -> f64 {
+        self.major_radius
+    }
+
+    /// Access the radius of the tube
+    pub fn minor_radius(&self) -> f64 {
+        self.minor_radius
+    }
+
+    /// Get the rendering color of the torus in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Torus> for Primitive {
+    fn from(shape: Torus) -> Self {
+        Self::Torus(shape)
+    }
+}
+
+impl From<Torus> for Shape {
+    fn from(shape: Torus) -> Self {
+        Self::Primitive(shape.into())
+    }
+}
+
+/// A wedge
+///
+/// The wedge is half of a [`Cuboid`] of the same size, cut diagonally. Viewed
+/// along the y-axis, it is a right triangle, with its right angle at the
+/// bottom left. Like the cuboid, it is centered on the origin.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Wedge {
+    /// The size along the x-, y-, and z-axis
+    size: [f64; 3],
+
+    /// The color of the wedge in RGBA
+    color: [u8; 4],
+}
+
+impl Wedge {
+    /// Create a `Wedge` from its size along the x-, y-, and z-axis
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any component of `size` is not positive.
+    pub fn from_size(size: [f64; 3]) -> Self {
+        assert!(
+            size.iter().all(|&length| length > 0.),
+            "Wedge size must be positive"
+        );
+
+        Self {
+            size,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set the rendering color of the wedge in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the size along the x-, y-, and z-axis
+    pub fn size(&self) -> [f64; 3] {
+        self.size
+    }
+
+    /// Get the rendering color of the wedge in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Wedge> for Primitive {
+    fn from(shape: Wedge) -> Self {
+        Self::Wedge(shape)
+    }
+}
+
+impl From<Wedge> for Shape {
+    fn from(shape: Wedge) -> Self {
+        Self::Primitive(shape.into())
+    }
+}
@@ -0,0 +1,72 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{Angle, Shape, Shape2d};
+
+/// A revolution of a 2-dimensional shape around an axis
+///
+/// The shape is rotated around the axis according to the right-hand rule.
+/// Since [`Angle`] always lies within one full turn, an angle of zero (which is
+/// what a full turn ends up as) revolves the shape all the way around.
+///
+/// The shape must lie on one side of the axis. It may touch the axis, but not
+/// cross it.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Revolve {
+    /// The 2-dimensional shape being revolved
+    shape: Shape2d,
+
+    /// A point on the axis of the revolution
+    axis_origin: [f64; 3],
+
+    /// The direction of the axis of the revolution
+    axis_direction: [f64; 3],
+
+    /// The angle of the revolution
+    angle: Angle,
+}
+
+impl Revolve {
+    /// Create a `Revolve` around an axis
+    pub fn from_axis(
+        shape: Shape2d,
+        axis_origin: [f64; 3],
+        axis_direction: [f64; 3],
+        angle: Angle,
+    ) -> Self {
+        Self {
+            shape,
+            axis_origin,
+            axis_direction,
+            angle,
+        }
+    }
+
+    /// Access the shape being revolved
+    pub fn shape(&self) -> &Shape2d {
+        &self.shape
+    }
+
+    /// Access the point on the axis of the revolution
+    pub fn axis_origin(&self) -> [f64; 3] {
+        self.axis_origin
+    }
+
+    /// Access the direction of the axis of the revolution
+    pub fn axis_direction(&self) -> [f64; 3] {
+        self.axis_direction
+    }
+
+    /// Access the angle of the revolution
+    pub fn angle(&self) -> Angle {
+        self.angle
+    }
+}
+
+impl From<Revolve> for Shape {
+    fn from(shape: Revolve) -> Self {
+        Self::Revolve(shape)
+    }
+}
@@ -1,9 +1,10 @@
 #[cfg(feature = "serde")]
 use serde::{de, ser, Deserialize, Serialize};
+use std::iter;
 use std::mem;
 use std::sync::atomic;
 
-use crate::Shape;
+use crate::{abi::ffi_safe, Offset2d, Shape, Text};
 
 /// A 2-dimensional shape
 #[derive(Clone, Debug, PartialEq)]
@@ -13,8 +14,20 @@ pub enum Shape2d {
     /// A difference between two shapes
     Difference(Box<Difference2d>),
 
+    /// An intersection of two shapes
+    Intersection(Box<Intersection2d>),
+
+    /// A shape, grown or shrunk by a distance
+    Offset(Box<Offset2d>),
+
     /// A sketch
     Sketch(Sketch),
+
+    /// Text, converted into outlines
+    Text(Box<Text>),
+
+    /// A union of two shapes
+    Union(Box<Union2d>),
 }
 
 impl Shape2d {
@@ -23,6 +36,10 @@ impl Shape2d {
         match &self {
             Shape2d::Sketch(s) => s.color(),
             Shape2d::Difference(d) => d.color(),
+            Shape2d::Intersection(i) => i.color(),
+            Shape2d::Offset(o) => o.color(),
+            Shape2d::Text(t) => t.color(),
+            Shape2d::Union(u) => u.color(),
         }
     }
 }
@@ -64,11 +81,87 @@ impl From<Difference2d> for Shape2d {
     }
 }
 
+/// A union of two shapes
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Union2d {
+    shapes: [Shape2d; 2],
+}
+
+impl Union2d {
+    /// Create a `Union2d` from two shapes
+    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Get the rendering color of the larger object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes[0].color()
+    }
+
+    /// Access the shapes that make up the union
+    pub fn shapes(&self) -> &[Shape2d; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Union2d> for Shape {
+    fn from(shape: Union2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Union2d> for Shape2d {
+    fn from(shape: Union2d) -> Self {
+        Self::Union(Box::new(shape))
+    }
+}
+
+/// An intersection of two shapes
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Intersection2d {
+    shapes: [Shape2d; 2],
+}
+
+impl Intersection2d {
+    /// Create an `Intersection2d` from two shapes
+    pub fn from_shapes(shapes: [Shape2d; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Get the rendering color of the larger object in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.shapes[0].color()
+    }
+
+    /// Access the shapes that make up the intersection
+    pub fn shapes(&self) -> &[Shape2d; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Intersection2d> for Shape {
+    fn from(shape: Intersection2d) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Intersection2d> for Shape2d {
+    fn from(shape: Intersection2d) -> Self {
+        Self::Intersection(Box::new(shape))
+    }
+}
+
 /// A sketch
 ///
-/// Sketches are currently limited to a single cycle of straight lines,
-/// represented by a number of points. For example, if the points a, b, and c
-/// are provided, the edges ab, bc, and ca are assumed.
+/// Sketches are currently limited to a single cycle. That cycle is either a
+/// circle, a number of points connected by straight lines, or a chain of
+/// segments that can be straight lines, circular arcs, or curves. For example,
+/// if the points a, b, and c are provided, the edges ab, bc, and ca are
+/// assumed.
 ///
 /// Nothing about these edges is checked right now, but algorithms might assume
 /// that the edges are non-overlapping. If you create a `Sketch` with
@@ -100,6 +193,121 @@ impl Sketch {
         }
     }
 
+    /// Create a sketch from a chain of segments
+    ///
+    /// See [`SegmentChain::build`] for how to create the chain.
+    pub fn from_segments(segments: SegmentChain) -> Self {
+        Self {
+            chain: Chain::SegmentChain(segments),
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Create a sketch of a regular polygon, centered on the origin
+    ///
+    /// `radius` is the distance from the center to the corners. The first
+    /// corner lies on the positive x-axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `num_sides` is less than 3, or if `radius` is not positive.
+    pub fn regular_polygon(num_sides: u32, radius: f64) -> Self {
+        assert!(num_sides >= 3, "Polygon must have at least 3 sides");
+        assert!(radius > 0., "Polygon radius must be positive");
+
+        let points = (0..num_sides)
+            .map(|i| {
+                let angle =
+                    std::f64::consts::TAU * f64::from(i) / f64::from(num_sides);
+                let (sin, cos) = angle.sin_cos();
+                [cos * radius, sin * radius]
+            })
+            .collect();
+
+        Self::from_points(points)
+    }
+
+    /// Create a sketch of a rectangle, centered on the origin
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `width` or `height` is not positive.
+    pub fn rectangle(width: f64, height: f64) -> Self {
+        assert!(width > 0. && height > 0., "Rectangle size must be positive");
+
+        let [x, y] = [width / 2., height / 2.];
+        Self::from_points(vec![[-x, -y], [x, -y], [x, y], [-x, y]])
+    }
+
+    /// Create a sketch of a rectangle with rounded corners, centered on the
+    /// origin
+    ///
+    /// The corners are circular arcs with the given radius.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `width` or `height` is not positive, or if `radius` is
+    /// negative or larger than half of either of them.
+    pub fn rounded_rectangle(width: f64, height: f64, radius: f64) -> Self {
+        assert!(width > 0. && height > 0., "Rectangle size must be positive");
+        assert!(radius >= 0., "Corner radius must not be negative");
+        assert!(
+            radius <= width / 2. && radius <= height / 2.,
+            "Corner radius must not be larger than half the rectangle size"
+        );
+
+        if radius == 0. {
+            return Self::rectangle(width, height);
+        }
+
+        let [x, y] = [width / 2., height / 2.];
+        let r = radius;
+
+        // Each side is followed by the corner at its end. The sides vanish, if
+        // the arcs of the corners take up all of the space.
+        let corners = [
+            ([x, y - r], [x - r, y - r], [x - r, y]),
+            ([-x + r, y], [-x + r, y - r], [-x, y - r]),
+            ([-x, -y + r], [-x + r, -y + r], [-x + r, -y]),
+            ([x - r, -y], [x - r, -y + r], [x, -y + r]),
+        ];
+
+        let mut chain = SegmentChain::build([x, -y + r]);
+        let mut current = [x, -y + r];
+        for (side_end, center, arc_end) in corners {
+            if side_end != current {
+                chain = chain.line_to(side_end);
+            }
+            chain = chain.arc_around(
+                center,
+                arc_end,
+                ArcDirection::Counterclockwise,
+            );
+            current = arc_end;
+        }
+
+        Self::from_segments(chain.into_chain())
+    }
+
+    /// Create a sketch of a slot, centered on the origin
+    ///
+    /// A slot is a rectangle along the x-axis, with semicircles at its ends.
+    /// `length` is the overall length of the slot, including those.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `width` is not positive, or if `length` is smaller than
+    /// `width`.
+    pub fn slot(length: f64, width: f64) -> Self {
+        assert!(width > 0., "Slot width must be positive");
+        assert!(
+            length >= width,
+            "Slot length must not be smaller than its width"
+        );
+
+        Self::rounded_rectangle(length, width, width / 2.)
+    }
+
     /// Set the rendering color of the sketch in RGBA
     pub fn with_color(mut self, color: [u8; 4]) -> Self {
         self.color = color;
@@ -127,6 +335,9 @@ pub enum Chain {
 
     /// The chain is a polygonal chain
     PolyChain(PolyChain),
+
+    /// The chain consists of straight lines, circular arcs, and curves
+    SegmentChain(SegmentChain),
 }
 
 /// A circle that is part of a [`Sketch`]
@@ -308,6 +519,415 @@ struct PolyChainSerde {
     points: Vec<[f64; 2]>,
 }
 
+/// A chain of segments that is part of a [`Sketch`]
+///
+/// The chain starts at a start point, and each segment starts where the
+/// previous one ended. The chain is closed. If the last segment doesn't end at
+/// the start point, a straight line from its end to the start point is assumed.
+#[derive(Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct SegmentChain {
+    start: [f64; 2],
+    segments: ffi_safe::Vec<Segment>,
+}
+
+impl SegmentChain {
+    /// Build a chain of segments, beginning at the provided start point
+    pub fn build(start: [f64; 2]) -> SegmentChainBuilder {
+        SegmentChainBuilder {
+            start,
+            segments: Vec::new(),
+        }
+    }
+
+    /// Access the start point of the chain
+    pub fn start(&self) -> [f64; 2] {
+        self.start
+    }
+
+    /// Access the segments of the chain
+    pub fn segments(&self) -> &[Segment] {
+        &self.segments
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::Serialize for SegmentChain {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        let serde_chain = SegmentChainSerde {
+            start: self.start,
+            segments: self.segments.to_vec(),
+        };
+
+        serde_chain.serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> de::Deserialize<'de> for SegmentChain {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        SegmentChainSerde::deserialize(deserializer).map(|serde_chain| Self {
+            start: serde_chain.start,
+            segments: serde_chain.segments.into(),
+        })
+    }
+}
+
+/// An owned, non-repr-C [`SegmentChain`]
+///
+/// Serves the same purpose for [`SegmentChain`] that [`PolyChainSerde`] serves
+/// for [`PolyChain`].
+#[cfg(feature = "serde")]
+#[derive(Serialize, Deserialize)]
+#[serde(rename = "SegmentChain")]
+struct SegmentChainSerde {
+    start: [f64; 2],
+    segments: Vec<Segment>,
+}
+
+/// API for building a [`SegmentChain`]
+///
+/// Also see [`SegmentChain::build`].
+#[derive(Clone, Debug)]
+pub struct SegmentChainBuilder {
+    start: [f64; 2],
+    segments: Vec<Segment>,
+}
+
+impl SegmentChainBuilder {
+    /// Add a straight line from the current point to `end`
+    pub fn line_to(mut self, end: [f64; 2]) -> Self {
+        self.segments.push(Segment::Line { end });
+        self
+    }
+
+    /// Add an arc around `center`, from the current point to `end`
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `end` is not as far from `center` as the current point is.
+    pub fn arc_around(
+        mut self,
+        center: [f64; 2],
+        end: [f64; 2],
+        direction: ArcDirection,
+    ) -> Self {
+        let start = self.current_point();
+
+        let radius = distance(start, center);
+        assert!(
+            (distance(end, center) - radius).abs() <= radius * 1e-9,
+            "Start and end of arc must be equally far from its center"
+        );
+
+        self.segments.push(Segment::Arc {
+            center,
+            end,
+            direction,
+        });
+        self
+    }
+
+    /// Add an arc from the current point to `end`, passing through `via`
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the current point, `via`, and `end` lie on a straight line.
+    pub fn arc_through(mut self, via: [f64; 2], end: [f64; 2]) -> Self {
+        let start = self.current_point();
+
+        let [a, b, c] = [start, via, end];
+        let d = 2. * cross(sub(b, a), sub(c, a));
+        assert!(
+            d.abs() > f64::EPSILON * dot(sub(c, a), sub(c, a)),
+            "Can't create arc through points that lie on a straight line"
+        );
+
+        let [a2, b2, c2] = [a, b, c].map(|point| dot(point, point));
+        let center = [
+            (a2 * (b[1] - c[1]) + b2 * (c[1] - a[1]) + c2 * (a[1] - b[1])) / d,
+            (a2 * (c[0] - b[0]) + b2 * (a[0] - c[0]) + c2 * (b[0] - a[0])) / d,
+        ];
+        let direction = if d > 0. {
+            ArcDirection::Counterclockwise
+        } else {
+            ArcDirection::Clockwise
+        };
+
+        self.segments.push(Segment::Arc {
+            center,
+            end,
+            direction,
+        });
+        self
+    }
+
+    /// Add an arc from the current point to `end`, tangent to the last segment
+    ///
+    /// # Panics
+    ///
+    /// Panics, if there is no previous segment, or if `end` lies on the
+    /// straight line that continues the previous segment.
+    pub fn tangent_arc_to(mut self, end: [f64; 2]) -> Self {
+        let start = self.current_point();
+        let tangent = match self.segments.last() {
+            Some(segment) => {
+                let previous_start = match self.segments.len() {
+                    1 => self.start,
+                    n => self.segments[n - 2].end(),
+                };
+                segment.tangent_at_end(previous_start)
+            }
+            None => panic!("Tangent arc requires a previous segment"),
+        };
+
+        // The center lies on the normal of the tangent, at the distance that
+        // makes it equally far from the start and end points.
+        let normal = [-tangent[1], tangent[0]];
+        let chord = sub(end, start);
+        let normal_dot_chord = dot(normal, chord);
+        assert!(
+            normal_dot_chord.abs() > f64::EPSILON * dot(chord, chord).sqrt(),
+            "Tangent arc can't end on the line that continues the previous \
+            segment"
+        );
+
+        let signed_radius = dot(chord, chord) / (2. * normal_dot_chord);
+        let center = [
+            start[0] + normal[0] * signed_radius,
+            start[1] + normal[1] * signed_radius,
+        ];
+        let direction = if signed_radius > 0. {
+            ArcDirection::Counterclockwise
+        } else {
+            ArcDirection::Clockwise
+        };
+
+        self.segments.push(Segment::Arc {
+            center,
+            end,
+            direction,
+        });
+        self
+    }
+
+    /// Add a quadratic Bézier curve from the current point to `end`
+    pub fn quadratic_bezier_to(
+        mut self,
+        control: [f64; 2],
+        end: [f64; 2],
+    ) -> Self {
+        self.segments
+            .push(Segment::QuadraticBezier { control, end });
+        self
+    }
+
+    /// Add a cubic Bézier curve from the current point to `end`
+    pub fn cubic_bezier_to(
+        mut self,
+        controls: [[f64; 2]; 2],
+        end: [f64; 2],
+    ) -> Self {
+        self.segments.push(Segment::CubicBezier { controls, end });
+        self
+    }
+
+    /// Add a Catmull-Rom spline from the current point through `points`
+    ///
+    /// The spline passes through all of the points, and ends at the last one.
+    /// At its start and end, the spline points towards its neighboring point.
+    pub fn catmull_rom_through(
+        mut self,
+        points: impl IntoIterator<Item = [f64; 2]>,
+    ) -> Self {
+        let mut points: Vec<_> =
+            iter::once(self.current_point()).chain(points).collect();
+        if points.len() < 2 {
+            return self;
+        }
+
+        // The spline's tangents at its start and end are defined by points
+        // beyond them. Mirroring the neighboring points provides those.
+        let first = sub(scale(points[0], 2.), points[1]);
+        let last = sub(
+            scale(points[points.len() - 1], 2.),
+            points[points.len() - 2],
+        );
+        points.insert(0, first);
+        points.push(last);
+
+        for points in points.windows(4) {
+            self.segments.push(Segment::CatmullRom {
+                previous: points[0],
+                end: points[2],
+                next: points[3],
+            });
+        }
+
+        self
+    }
+
+    /// Consume the builder and return the [`SegmentChain`]
+    pub fn into_chain(self) -> SegmentChain {
+        SegmentChain {
+            start: self.start,
+            segments: self.segments.into(),
+        }
+    }
+
+    fn current_point(&self) -> [f64; 2] {
+        self.segments
+            .last()
+            .map(|segment| segment.end())
+            .unwrap_or(self.start)
+    }
+}
+
+impl From<SegmentChainBuilder> for SegmentChain {
+    fn from(builder: SegmentChainBuilder) -> Self {
+        builder.into_chain()
+    }
+}
+
+/// A segment of a [`SegmentChain`]
+///
+/// A segment starts where the previous segment of the chain ended, or at the
+/// start point of the chain, if it's the first segment.
+#[derive(Clone, Copy, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum Segment {
+    /// A straight line
+    Line {
+        /// The end point of the line
+        end: [f64; 2],
+    },
+
+    /// A circular arc
+    ///
+    /// The radius of the arc is the distance between its start point and
+    /// `center`.
+    Arc {
+        /// The center of the circle that the arc is a part of
+        center: [f64; 2],
+
+        /// The end point of the arc
+        end: [f64; 2],
+
+        /// The direction in which the arc goes around its center
+        direction: ArcDirection,
+    },
+
+    /// A quadratic Bézier curve
+    QuadraticBezier {
+        /// The control point between the start and end points of the curve
+        control: [f64; 2],
+
+        /// The end point of the curve
+        end: [f64; 2],
+    },
+
+    /// A cubic Bézier curve
+    CubicBezier {
+        /// The control points between the start and end points of the curve
+        controls: [[f64; 2]; 2],
+
+        /// The end point of the curve
+        end: [f64; 2],
+    },
+
+    /// A span of a uniform Catmull-Rom spline
+    ///
+    /// The span passes through its start and end points. Its tangents there are
+    /// defined by the points before and after them.
+    CatmullRom {
+        /// The point before the start point of the span
+        previous: [f64; 2],
+
+        /// The end point of the span
+        end: [f64; 2],
+
+        /// The point after the end point of the span
+        next: [f64; 2],
+    },
+}
+
+impl Segment {
+    /// Access the end point of the segment
+    pub fn end(&self) -> [f64; 2] {
+        match self {
+            Self::Line { end } => *end,
+            Self::Arc { end, .. } => *end,
+            Self::QuadraticBezier { end, .. } => *end,
+            Self::CubicBezier { end, .. } => *end,
+            Self::CatmullRom { end, .. } => *end,
+        }
+    }
+
+    /// Compute the unit tangent at the end point of the segment
+    fn tangent_at_end(&self, start: [f64; 2]) -> [f64; 2] {
+        let tangent = match self {
+            Self::Line { end } => sub(*end, start),
+            Self::Arc {
+                center,
+                end,
+                direction,
+            } => {
+                let [x, y] = sub(*end, *center);
+                match direction {
+                    ArcDirection::Counterclockwise => [-y, x],
+                    ArcDirection::Clockwise => [y, -x],
+                }
+            }
+            Self::QuadraticBezier { control, end } => sub(*end, *control),
+            Self::CubicBezier { controls, end } => sub(*end, controls[1]),
+            Self::CatmullRom { next, .. } => sub(*next, start),
+        };
+
+        let length = dot(tangent, tangent).sqrt();
+        [tangent[0] / length, tangent[1] / length]
+    }
+}
+
+/// The direction in which an arc goes around its center
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum ArcDirection {
+    /// The arc goes around its center counterclockwise
+    Counterclockwise,
+
+    /// The arc goes around its center clockwise
+    Clockwise,
+}
+
+fn sub(a: [f64; 2], b: [f64; 2]) -> [f64; 2] {
+    [a[0] - b[0], a[1] - b[1]]
+}
+
+fn scale(a: [f64; 2], s: f64) -> [f64; 2] {
+    [a[0] * s, a[1] * s]
+}
+
+fn dot(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[0] + a[1] * b[1]
+}
+
+fn cross(a: [f64; 2], b: [f64; 2]) -> f64 {
+    a[0] * b[1] - a[1] * b[0]
+}
+
+fn distance(a: [f64; 2], b: [f64; 2]) -> f64 {
+    let d = sub(a, b);
+    dot(d, d).sqrt()
+}
+
 impl From<Sketch> for Shape {
     fn from(shape: Sketch) -> Self {
         Self::Shape2d(shape.into())
@@ -362,6 +982,187 @@ mod tests {
         // rc is deallocated after the last drop, so we can't assert that it's 0
     }
 
+    #[test]
+    fn test_segment_chain_arcs() {
+        let chain = SegmentChain::build([0., 0.])
+            .line_to([2., 0.])
+            .arc_through([3., 1.], [2., 2.])
+            .line_to([0., 2.])
+            .tangent_arc_to([0., 0.])
+            .into_chain();
+
+        assert_eq!(chain.start(), [0., 0.]);
+        assert_eq!(
+            chain.segments(),
+            [
+                Segment::Line { end: [2., 0.] },
+                Segment::Arc {
+                    center: [2., 1.],
+                    end: [2., 2.],
+                    direction: ArcDirection::Counterclockwise,
+                },
+                Segment::Line { end: [0., 2.] },
+                Segment::Arc {
+                    center: [0., 1.],
+                    end: [0., 0.],
+                    direction: ArcDirection::Counterclockwise,
+                },
+            ]
+        );
+
+        let chain = SegmentChain::build([0., 0.])
+            .line_to([1., 0.])
+            .tangent_arc_to([1., -2.])
+            .into_chain();
+        assert_eq!(
+            chain.segments()[1],
+            Segment::Arc {
+                center: [1., -1.],
+                end: [1., -2.],
+                direction: ArcDirection::Clockwise,
+            }
+        );
+    }
+
+    #[test]
+    fn test_segment_chain_catmull_rom() {
+        let chain = SegmentChain::build([0., 0.])
+            .catmull_rom_through([[1., 1.], [2., 0.]])
+            .tangent_arc_to([0., 0.])
+            .into_chain();
+
+        assert_eq!(
+            &chain.segments()[..2],
+            [
+                Segment::CatmullRom {
+                    previous: [-1., -1.],
+                    end: [1., 1.],
+                    next: [2., 0.],
+                },
+                Segment::CatmullRom {
+                    previous: [0., 0.],
+                    end: [2., 0.],
+                    next: [3., -1.],
+                },
+            ]
+        );
+
+        // The tangent at the end of the spline points towards the mirrored
+        // point.
+        match chain.segments()[2] {
+            Segment::Arc { center, .. } => {
+                assert!(distance(center, [1., -1.]) < 1e-12);
+            }
+            segment => panic!("Expected arc, got {segment:?}"),
+        }
+    }
+
+    #[test]
+    fn test_sketch_regular_polygon() {
+        let sketch = Sketch::regular_polygon(4, 2.);
+
+        let points = match sketch.chain() {
+            Chain::PolyChain(poly_chain) => poly_chain.to_points(),
+            chain => panic!("Expected polygonal chain, got {chain:?}"),
+        };
+        let expected = [[2., 0.], [0., 2.], [-2., 0.], [0., -2.]];
+
+        assert_eq!(points.len(), expected.len());
+        for (point, expected) in points.into_iter().zip(expected) {
+            assert!(distance(point, expected) < 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_sketch_rectangle() {
+        let sketch = Sketch::rectangle(4., 2.);
+
+        match sketch.chain() {
+            Chain::PolyChain(poly_chain) => assert_eq!(
+                poly_chain.to_points(),
+                [[-2., -1.], [2., -1.], [2., 1.], [-2., 1.]]
+            ),
+            chain => panic!("Expected polygonal chain, got {chain:?}"),
+        }
+    }
+
+    #[test]
+    fn test_sketch_rounded_rectangle() {
+        let sketch = Sketch::rounded_rectangle(4., 2., 0.5);
+
+        let chain = match sketch.chain() {
+            Chain::SegmentChain(chain) => chain,
+            chain => panic!("Expected segment chain, got {chain:?}"),
+        };
+        assert_eq!(chain.start(), [2., -0.5]);
+        assert_eq!(
+            &chain.segments()[..2],
+            [
+                Segment::Line { end: [2., 0.5] },
+                Segment::Arc {
+                    center: [1.5, 0.5],
+                    end: [1.5, 1.],
+                    direction: ArcDirection::Counterclockwise,
+                },
+            ]
+        );
+        assert_eq!(chain.segments().len(), 8);
+
+        // The chain is closed by its last arc.
+        assert_eq!(chain.segments()[7].end(), chain.start());
+    }
+
+    #[test]
+    fn test_sketch_slot() {
+        let sketch = Sketch::slot(4., 2.);
+
+        let chain = match sketch.chain() {
+            Chain::SegmentChain(chain) => chain,
+            chain => panic!("Expected segment chain, got {chain:?}"),
+        };
+
+        // The ends of the slot have no straight sides.
+        let num_lines = chain
+            .segments()
+            .iter()
+            .filter(|segment| matches!(segment, Segment::Line { .. }))
+            .count();
+        assert_eq!(num_lines, 2);
+        assert_eq!(chain.segments().len(), 6);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_sketch_rounded_rectangle_radius_too_large() {
+        Sketch::rounded_rectangle(4., 2., 1.5);
+    }
+
+    #[test]
+    #[should_panic]
+    fn test_segment_chain_arc_around_off_circle() {
+        SegmentChain::build([1., 0.]).arc_around(
+            [0., 0.],
+            [0., 2.],
+            ArcDirection::Counterclockwise,
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_segment_chain_serialize_loopback() {
+        use serde_json::{from_str, to_string};
+
+        let chain = SegmentChain::build([1., 0.])
+            .arc_around([0., 0.], [-1., 0.], ArcDirection::Counterclockwise)
+            .into_chain();
+
+        let json = to_string(&chain).expect("failed to serialize chain");
+        let chain_de: SegmentChain =
+            from_str(&json).expect("failed to deserialize chain");
+
+        assert_eq!(chain, chain_de);
+    }
+
     #[cfg(feature = "serde")]
     #[test]
     fn test_poly_chain_serialize_loopback() {
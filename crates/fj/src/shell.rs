@@ -0,0 +1,75 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::{FaceSelector, Shape};
+
+/// A hollowed-out 3-dimensional shape
+///
+/// All faces of the shape are offset inwards by the thickness, forming the
+/// walls of a cavity inside of the shape. The selected open faces are removed,
+/// opening the cavity towards them.
+///
+/// # Limitations
+///
+/// Only shapes whose faces are planar or cylindrical are supported. The
+/// thickness must be small enough, that none of the offset faces vanishes.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Shell {
+    /// The shape that is hollowed out
+    shape: Shape,
+
+    /// The thickness of the walls
+    thickness: f64,
+
+    /// The faces that are removed
+    open: FaceSelector,
+}
+
+impl Shell {
+    /// Create a `Shell` of a shape, with walls of the given thickness
+    ///
+    /// The resulting shape is closed. Use [`Shell::with_open_faces`] to change
+    /// that.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `thickness` is not positive.
+    pub fn from_shape(shape: Shape, thickness: f64) -> Self {
+        assert!(thickness > 0., "Wall thickness must be positive");
+
+        Self {
+            shape,
+            thickness,
+            open: FaceSelector::None,
+        }
+    }
+
+    /// Remove the selected faces, opening the cavity towards them
+    pub fn with_open_faces(mut self, open: FaceSelector) -> Self {
+        self.open = open;
+        self
+    }
+
+    /// Access the shape that is hollowed out
+    pub fn shape(&self) -> &Shape {
+        &self.shape
+    }
+
+    /// Access the thickness of the walls
+    pub fn thickness(&self) -> f64 {
+        self.thickness
+    }
+
+    /// Access the faces that are removed
+    pub fn open_faces(&self) -> FaceSelector {
+        self.open
+    }
+}
+
+impl From<Shell> for Shape {
+    fn from(shape: Shell) -> Self {
+        Self::Shell(Box::new(shape))
+    }
+}
@@ -1,9 +1,13 @@
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
-use crate::{Shape, Shape2d};
+use crate::{Angle, Shape, Shape2d};
 
 /// A sweep of a 2-dimensional shape along straight path
+///
+/// See [`PathSweep`] for sweeps along other paths.
+///
+/// [`PathSweep`]: crate::PathSweep
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(C)]
@@ -13,12 +17,33 @@ pub struct Sweep {
 
     /// The length and direction of the sweep
     path: [f64; 3],
+
+    /// The angle by which the walls of the sweep are tapered
+    draft: Angle,
 }
 
 impl Sweep {
     /// Create a `Sweep` along a straight path
     pub fn from_path(shape: Shape2d, path: [f64; 3]) -> Self {
-        Self { shape, path }
+        Self {
+            shape,
+            path,
+            draft: Angle::from_rad(0.),
+        }
+    }
+
+    /// Taper the walls of the sweep by a draft angle
+    ///
+    /// The angle is measured against the path. Positive angles taper the walls
+    /// inwards, which makes the end of the sweep a smaller offset of the
+    /// shape. Negative angles, or angles just short of a full revolution,
+    /// taper the walls outwards.
+    ///
+    /// Tapering the walls by a right angle or more is an error, which is
+    /// reported when the sweep is computed.
+    pub fn with_draft(mut self, draft: Angle) -> Self {
+        self.draft = draft;
+        self
     }
 
     /// Access the shape being swept
@@ -30,6 +55,11 @@ impl Sweep {
     pub fn path(&self) -> [f64; 3] {
         self.path
     }
+
+    /// Access the draft angle of the sweep
+    pub fn draft(&self) -> Angle {
+        self.draft
+    }
 }
 
 impl From<Sweep> for Shape {
@@ -37,3 +67,20 @@ impl From<Sweep> for Shape {
         Self::Sweep(shape)
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use crate::{Angle, Sketch};
+
+    use super::Sweep;
+
+    #[test]
+    fn draft() {
+        let shape = Sketch::from_points(vec![[0., 0.], [1., 0.], [0., 1.]]);
+        let sweep = Sweep::from_path(shape.into(), [0., 0., 1.]);
+        assert_eq!(sweep.draft(), Angle::from_rad(0.));
+
+        let sweep = sweep.with_draft(Angle::from_deg(-5.));
+        assert!((sweep.draft().deg() - 355.).abs() < 1e-9);
+    }
+}
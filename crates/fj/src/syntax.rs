@@ -3,6 +3,68 @@
 //! This model defines extension traits, which provide convenient syntax for
 //! the various operations defined in this trait.
 
+/// Convenient syntax to create an [`fj::Chamfer`]
+///
+/// [`fj::Chamfer`]: crate::Chamfer
+pub trait Chamfer {
+    /// Bevel the selected edges of `self`
+    fn chamfer(
+        &self,
+        edges: crate::EdgeSelector,
+        distance: f64,
+    ) -> crate::Chamfer;
+}
+
+impl<T> Chamfer for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn chamfer(
+        &self,
+        edges: crate::EdgeSelector,
+        distance: f64,
+    ) -> crate::Chamfer {
+        let shape = self.clone().into();
+        crate::Chamfer::from_shape(shape, edges, distance)
+    }
+}
+
+/// Convenient syntax to create an [`fj::CircularPattern`]
+///
+/// [`fj::CircularPattern`]: crate::CircularPattern
+pub trait CircularPattern {
+    /// Create `count` copies of `self` around an axis, spread over `angle`
+    fn circular_pattern(
+        &self,
+        axis_origin: [f64; 3],
+        axis_direction: [f64; 3],
+        angle: crate::Angle,
+        count: u32,
+    ) -> crate::CircularPattern;
+}
+
+impl<T> CircularPattern for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn circular_pattern(
+        &self,
+        axis_origin: [f64; 3],
+        axis_direction: [f64; 3],
+        angle: crate::Angle,
+        count: u32,
+    ) -> crate::CircularPattern {
+        let shape = self.clone().into();
+        crate::CircularPattern::from_axis(
+            shape,
+            axis_origin,
+            axis_direction,
+            angle,
+            count,
+        )
+    }
+}
+
 /// Convenient syntax to create an [`fj::Difference2d`]
 ///
 /// [`fj::Difference2d`]: crate::Difference2d
@@ -28,6 +90,49 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::Difference3d`]
+///
+/// [`fj::Difference3d`]: crate::Difference3d
+pub trait Difference3d {
+    /// Create a difference between `self` and `other`
+    fn difference_3d<Other>(&self, other: &Other) -> crate::Difference3d
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Difference3d for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn difference_3d<Other>(&self, other: &Other) -> crate::Difference3d
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Difference3d::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Fillet`]
+///
+/// [`fj::Fillet`]: crate::Fillet
+pub trait Fillet {
+    /// Round the selected edges of `self`
+    fn fillet(&self, edges: crate::EdgeSelector, radius: f64) -> crate::Fillet;
+}
+
+impl<T> Fillet for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn fillet(&self, edges: crate::EdgeSelector, radius: f64) -> crate::Fillet {
+        let shape = self.clone().into();
+        crate::Fillet::from_shape(shape, edges, radius)
+    }
+}
+
 /// Convenient syntax to create an [`fj::Group`]
 ///
 /// [`fj::Group`]: crate::Group
@@ -53,6 +158,149 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::Intersection`]
+///
+/// [`fj::Intersection`]: crate::Intersection
+pub trait Intersection {
+    /// Create an intersection of `self` and `other`
+    fn intersection<Other>(&self, other: &Other) -> crate::Intersection
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Intersection for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn intersection<Other>(&self, other: &Other) -> crate::Intersection
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Intersection::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Intersection2d`]
+///
+/// [`fj::Intersection2d`]: crate::Intersection2d
+pub trait Intersection2d {
+    /// Create an intersection of `self` and `other`
+    fn intersection_2d<Other>(&self, other: &Other) -> crate::Intersection2d
+    where
+        Other: Clone + Into<crate::Shape2d>;
+}
+
+impl<T> Intersection2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn intersection_2d<Other>(&self, other: &Other) -> crate::Intersection2d
+    where
+        Other: Clone + Into<crate::Shape2d>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Intersection2d::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::LinearPattern`]
+///
+/// [`fj::LinearPattern`]: crate::LinearPattern
+pub trait LinearPattern {
+    /// Create `count` copies of `self`, each offset from the previous one
+    fn linear_pattern(
+        &self,
+        offset: [f64; 3],
+        count: u32,
+    ) -> crate::LinearPattern;
+}
+
+impl<T> LinearPattern for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn linear_pattern(
+        &self,
+        offset: [f64; 3],
+        count: u32,
+    ) -> crate::LinearPattern {
+        let shape = self.clone().into();
+        crate::LinearPattern::from_shape(shape, offset, count)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Offset2d`]
+///
+/// [`fj::Offset2d`]: crate::Offset2d
+pub trait Offset2d {
+    /// Grow `self` by `distance`, or shrink it, if `distance` is negative
+    fn offset(&self, distance: f64) -> crate::Offset2d;
+}
+
+impl<T> Offset2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn offset(&self, distance: f64) -> crate::Offset2d {
+        let shape = self.clone().into();
+        crate::Offset2d::from_shape(shape, distance)
+    }
+}
+
+/// Convenient syntax to create an [`fj::PathSweep`]
+///
+/// [`fj::PathSweep`]: crate::PathSweep
+pub trait PathSweep {
+    /// Sweep `self` along a path
+    fn sweep_along(&self, path: crate::SweepPath) -> crate::PathSweep;
+}
+
+impl<T> PathSweep for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn sweep_along(&self, path: crate::SweepPath) -> crate::PathSweep {
+        let shape = self.clone().into();
+        crate::PathSweep::from_path(shape, path)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Revolve`]
+///
+/// [`fj::Revolve`]: crate::Revolve
+pub trait Revolve {
+    /// Revolve `self` around an axis
+    ///
+    /// The axis is defined by a point on it, `axis_origin`, and its direction,
+    /// `axis_direction`.
+    fn revolve(
+        &self,
+        axis_origin: [f64; 3],
+        axis_direction: [f64; 3],
+        angle: crate::Angle,
+    ) -> crate::Revolve;
+}
+
+impl<T> Revolve for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn revolve(
+        &self,
+        axis_origin: [f64; 3],
+        axis_direction: [f64; 3],
+        angle: crate::Angle,
+    ) -> crate::Revolve {
+        let shape = self.clone().into();
+        crate::Revolve::from_axis(shape, axis_origin, axis_direction, angle)
+    }
+}
+
 /// Convenient syntax to create an [`fj::Sketch`]
 ///
 /// [`fj::Sketch`]: crate::Sketch
@@ -73,6 +321,24 @@ where
     }
 }
 
+/// Convenient syntax to create an [`fj::Shell`]
+///
+/// [`fj::Shell`]: crate::Shell
+pub trait Shell {
+    /// Hollow out `self`, leaving walls of the given thickness
+    fn shell(&self, thickness: f64) -> crate::Shell;
+}
+
+impl<T> Shell for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn shell(&self, thickness: f64) -> crate::Shell {
+        let shape = self.clone().into();
+        crate::Shell::from_shape(shape, thickness)
+    }
+}
+
 /// Convenient syntax to create an [`fj::Sweep`]
 ///
 /// [`fj::Sweep`]: crate::Sweep
@@ -105,6 +371,24 @@ pub trait Transform {
     ///
     /// Create a translation that translates `shape` by `offset`.
     fn translate(&self, offset: [f64; 3]) -> crate::Transform;
+
+    /// Create a mirror image
+    ///
+    /// Create a mirror image of `shape` at the plane through `origin` with
+    /// `normal`.
+    fn mirror(&self, origin: [f64; 3], normal: [f64; 3]) -> crate::Transform;
+
+    /// Create a scaling
+    ///
+    /// Create a scaling that scales `shape` by `factors` along the respective
+    /// axes, relative to the origin.
+    fn scale(&self, factors: [f64; 3]) -> crate::Transform;
+
+    /// Create an arbitrary affine transform
+    ///
+    /// Create a transform that transforms `shape` by `matrix`, which is given
+    /// row by row.
+    fn transform(&self, matrix: [[f64; 4]; 4]) -> crate::Transform;
 }
 
 impl<T> Transform for T
@@ -113,21 +397,76 @@ where
 {
     fn rotate(&self, axis: [f64; 3], angle: crate::Angle) -> crate::Transform {
         let shape = self.clone().into();
-        crate::Transform {
-            shape,
-            axis,
-            angle,
-            offset: [0.; 3],
-        }
+        crate::Transform::rotation(shape, axis, angle)
     }
 
     fn translate(&self, offset: [f64; 3]) -> crate::Transform {
         let shape = self.clone().into();
-        crate::Transform {
-            shape,
-            axis: [1., 0., 0.],
-            angle: crate::Angle::from_rad(0.),
-            offset,
-        }
+        crate::Transform::translation(shape, offset)
+    }
+
+    fn mirror(&self, origin: [f64; 3], normal: [f64; 3]) -> crate::Transform {
+        let shape = self.clone().into();
+        crate::Transform::mirror(shape, origin, normal)
+    }
+
+    fn scale(&self, factors: [f64; 3]) -> crate::Transform {
+        let shape = self.clone().into();
+        crate::Transform::scaling(shape, factors)
+    }
+
+    fn transform(&self, matrix: [[f64; 4]; 4]) -> crate::Transform {
+        let shape = self.clone().into();
+        crate::Transform::from_matrix(shape, matrix)
+    }
+}
+
+/// Convenient syntax to create an [`fj::Union`]
+///
+/// [`fj::Union`]: crate::Union
+pub trait Union {
+    /// Create a union of `self` and `other`
+    fn union<Other>(&self, other: &Other) -> crate::Union
+    where
+        Other: Clone + Into<crate::Shape>;
+}
+
+impl<T> Union for T
+where
+    T: Clone + Into<crate::Shape>,
+{
+    fn union<Other>(&self, other: &Other) -> crate::Union
+    where
+        Other: Clone + Into<crate::Shape>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Union::from_shapes([a, b])
+    }
+}
+
+/// Convenient syntax to create an [`fj::Union2d`]
+///
+/// [`fj::Union2d`]: crate::Union2d
+pub trait Union2d {
+    /// Create a union of `self` and `other`
+    fn union_2d<Other>(&self, other: &Other) -> crate::Union2d
+    where
+        Other: Clone + Into<crate::Shape2d>;
+}
+
+impl<T> Union2d for T
+where
+    T: Clone + Into<crate::Shape2d>,
+{
+    fn union_2d<Other>(&self, other: &Other) -> crate::Union2d
+    where
+        Other: Clone + Into<crate::Shape2d>,
+    {
+        let a = self.clone().into();
+        let b = other.clone().into();
+
+        crate::Union2d::from_shapes([a, b])
     }
 }
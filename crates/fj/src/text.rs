@@ -0,0 +1,209 @@
+#[cfg(feature = "serde")]
+use serde::{de, ser, Deserialize, Serialize};
+
+use crate::{abi::ffi_safe, Shape, Shape2d};
+
+/// A line of text, or several, converted into 2-dimensional outlines
+///
+/// The outlines of the characters are taken from a TrueType or OpenType font.
+/// Counters (the holes in letters like "o" or "A") are cut out of the outlines.
+///
+/// The text starts at the origin, on the baseline of its first line. Further
+/// lines, separated by `\n`, continue below that.
+///
+/// # Limitations
+///
+/// Only simple layouts are supported: text is laid out left to right, using
+/// the kerning from the font's `kern` table, if it has one. Glyphs whose
+/// outlines overlap themselves are not supported.
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Text {
+    /// The text
+    #[cfg_attr(feature = "serde", serde(with = "serde_text"))]
+    text: ffi_safe::String,
+
+    /// The font size, which is the height of the font's em square
+    size: f64,
+
+    /// How lines are aligned relative to the origin
+    alignment: TextAlignment,
+
+    /// The font that the outlines are taken from
+    font: Font,
+
+    /// The color of the text in RGBA
+    color: [u8; 4],
+}
+
+impl Text {
+    /// Create a `Text` from a string, with the given font size
+    ///
+    /// Uses the bundled font and [`TextAlignment::Left`]. Use
+    /// [`Text::with_font`] and [`Text::with_alignment`] to change that.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `size` is not positive.
+    pub fn from_text(text: impl Into<String>, size: f64) -> Self {
+        assert!(size > 0., "Font size must be positive");
+
+        Self {
+            text: text.into().into(),
+            size,
+            alignment: TextAlignment::Left,
+            font: Font::Bundled,
+            color: [255, 0, 0, 255],
+        }
+    }
+
+    /// Set how lines are aligned relative to the origin
+    pub fn with_alignment(mut self, alignment: TextAlignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    /// Set the font that the outlines are taken from
+    pub fn with_font(mut self, font: Font) -> Self {
+        self.font = font;
+        self
+    }
+
+    /// Set the rendering color of the text in RGBA
+    pub fn with_color(mut self, color: [u8; 4]) -> Self {
+        self.color = color;
+        self
+    }
+
+    /// Access the text
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+
+    /// Access the font size
+    pub fn size(&self) -> f64 {
+        self.size
+    }
+
+    /// Access how lines are aligned relative to the origin
+    pub fn alignment(&self) -> TextAlignment {
+        self.alignment
+    }
+
+    /// Access the font that the outlines are taken from
+    pub fn font(&self) -> &Font {
+        &self.font
+    }
+
+    /// Get the rendering color of the text in RGBA
+    pub fn color(&self) -> [u8; 4] {
+        self.color
+    }
+}
+
+impl From<Text> for Shape {
+    fn from(shape: Text) -> Self {
+        Self::Shape2d(shape.into())
+    }
+}
+
+impl From<Text> for Shape2d {
+    fn from(shape: Text) -> Self {
+        Self::Text(Box::new(shape))
+    }
+}
+
+/// How the lines of a [`Text`] are aligned relative to the origin
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum TextAlignment {
+    /// Lines start at the origin
+    Left,
+
+    /// Lines are centered on the origin
+    Center,
+
+    /// Lines end at the origin
+    Right,
+}
+
+/// The font of a [`Text`]
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub enum Font {
+    /// The font that is bundled with Fornjot (B612 Regular)
+    Bundled,
+
+    /// A font that is loaded from TrueType or OpenType data
+    Custom(FontData),
+}
+
+impl Font {
+    /// Create a font from the contents of a TrueType or OpenType file
+    ///
+    /// The data is only parsed, once the outlines of the text are computed.
+    pub fn from_bytes(data: impl Into<Vec<u8>>) -> Self {
+        Self::Custom(FontData(data.into().into()))
+    }
+}
+
+/// The data of a [`Font::Custom`]
+#[derive(Clone, Debug, PartialEq)]
+#[repr(C)]
+pub struct FontData(ffi_safe::Vec<u8>);
+
+impl FontData {
+    /// Access the contents of the font file
+    pub fn bytes(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+#[cfg(feature = "serde")]
+impl ser::Serialize for FontData {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        self.bytes().serialize(serializer)
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> de::Deserialize<'de> for FontData {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        Vec::<u8>::deserialize(deserializer).map(|data| Self(data.into()))
+    }
+}
+
+#[cfg(feature = "serde")]
+mod serde_text {
+    use serde::{de, ser, Deserialize, Serialize};
+
+    use crate::abi::ffi_safe;
+
+    pub fn serialize<S>(
+        text: &ffi_safe::String,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error>
+    where
+        S: ser::Serializer,
+    {
+        (**text).serialize(serializer)
+    }
+
+    pub fn deserialize<'de, D>(
+        deserializer: D,
+    ) -> Result<ffi_safe::String, D::Error>
+    where
+        D: de::Deserializer<'de>,
+    {
+        String::deserialize(deserializer).map(Into::into)
+    }
+}
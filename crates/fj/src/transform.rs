@@ -5,13 +5,13 @@ use crate::{Angle, Shape};
 
 /// A transformed 3-dimensional shape
 ///
-/// # Limitations
+/// The transform is an affine transform, represented by a 4x4 matrix that
+/// operates on homogeneous coordinates. Besides rotations and translations,
+/// this covers mirroring and scaling, as well as any combination of those.
 ///
-/// Transformations are currently limited to a rotation, followed by a
-/// translation.
-///
-/// See issue:
-/// <https://github.com/hannobraun/Fornjot/issues/101>
+/// Transforms that distort circles into ellipses, like non-uniform scaling of
+/// a shape that has curved edges, are supported. The curved geometry of the
+/// shape is converted into NURBS for that.
 #[derive(Clone, Debug, PartialEq)]
 #[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
 #[repr(C)]
@@ -19,14 +19,128 @@ pub struct Transform {
     /// The shape being transformed
     pub shape: Shape,
 
-    /// The axis of the rotation
-    pub axis: [f64; 3],
+    /// The matrix of the transform, row by row
+    ///
+    /// The last row must be `[0., 0., 0., 1.]`.
+    pub matrix: [[f64; 4]; 4],
+}
+
+impl Transform {
+    /// Create a rotation around an axis through the origin
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `axis` has a length of zero.
+    pub fn rotation(shape: Shape, axis: [f64; 3], angle: Angle) -> Self {
+        let [x, y, z] = normalize(axis);
+        let (sin, cos) = angle.rad().sin_cos();
+        let t = 1. - cos;
+
+        Self {
+            shape,
+            matrix: [
+                [
+                    t * x * x + cos,
+                    t * x * y - sin * z,
+                    t * x * z + sin * y,
+                    0.,
+                ],
+                [
+                    t * x * y + sin * z,
+                    t * y * y + cos,
+                    t * y * z - sin * x,
+                    0.,
+                ],
+                [
+                    t * x * z - sin * y,
+                    t * y * z + sin * x,
+                    t * z * z + cos,
+                    0.,
+                ],
+                [0., 0., 0., 1.],
+            ],
+        }
+    }
+
+    /// Create a translation
+    pub fn translation(shape: Shape, offset: [f64; 3]) -> Self {
+        let [x, y, z] = offset;
+
+        Self {
+            shape,
+            matrix: [
+                [1., 0., 0., x],
+                [0., 1., 0., y],
+                [0., 0., 1., z],
+                [0., 0., 0., 1.],
+            ],
+        }
+    }
+
+    /// Create a mirror image at the plane through `origin` with `normal`
+    ///
+    /// # Panics
+    ///
+    /// Panics, if `normal` has a length of zero.
+    pub fn mirror(shape: Shape, origin: [f64; 3], normal: [f64; 3]) -> Self {
+        let n = normalize(normal);
+        let distance = dot(origin, n);
 
-    /// The angle of the rotation
-    pub angle: Angle,
+        let mut matrix = [[0.; 4]; 4];
+        for (i, row) in matrix.iter_mut().take(3).enumerate() {
+            for (j, value) in row.iter_mut().take(3).enumerate() {
+                let identity = if i == j { 1. } else { 0. };
+                *value = identity - 2. * n[i] * n[j];
+            }
+            row[3] = 2. * distance * n[i];
+        }
+        matrix[3][3] = 1.;
 
-    /// The offset of the translation
-    pub offset: [f64; 3],
+        Self { shape, matrix }
+    }
+
+    /// Create a scaling, relative to the origin
+    ///
+    /// Each component of `factors` is the factor along the respective axis.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if any of the factors is zero.
+    pub fn scaling(shape: Shape, factors: [f64; 3]) -> Self {
+        assert!(
+            factors.iter().all(|&factor| factor != 0.),
+            "Scaling factors must not be zero"
+        );
+
+        let [x, y, z] = factors;
+
+        Self {
+            shape,
+            matrix: [
+                [x, 0., 0., 0.],
+                [0., y, 0., 0.],
+                [0., 0., z, 0.],
+                [0., 0., 0., 1.],
+            ],
+        }
+    }
+
+    /// Create a transform from an arbitrary affine matrix
+    ///
+    /// The matrix is given row by row.
+    ///
+    /// # Panics
+    ///
+    /// Panics, if the last row of the matrix is not `[0., 0., 0., 1.]`.
+    pub fn from_matrix(shape: Shape, matrix: [[f64; 4]; 4]) -> Self {
+        assert_eq!(
+            matrix[3],
+            [0., 0., 0., 1.],
+            "Matrix must represent an affine transform"
+        );
+
+        Self { shape, matrix }
+    }
 }
 
 impl From<Transform> for Shape {
@@ -34,3 +148,58 @@ impl From<Transform> for Shape {
         Self::Transform(Box::new(shape))
     }
 }
+
+fn normalize(vector: [f64; 3]) -> [f64; 3] {
+    let length = dot(vector, vector).sqrt();
+    assert!(length != 0., "Can't normalize vector of length zero");
+
+    vector.map(|component| component / length)
+}
+
+fn dot(a: [f64; 3], b: [f64; 3]) -> f64 {
+    a[0] * b[0] + a[1] * b[1] + a[2] * b[2]
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{Angle, Circle, Shape, Sketch};
+
+    use super::Transform;
+
+    #[test]
+    fn rotation() {
+        let transform =
+            Transform::rotation(shape(), [0., 0., 2.], Angle::from_deg(90.));
+
+        let expected = [
+            [0., -1., 0., 0.],
+            [1., 0., 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 0., 1.],
+        ];
+        assert_matrix_eq(transform.matrix, expected);
+    }
+
+    #[test]
+    fn mirror() {
+        let transform = Transform::mirror(shape(), [1., 0., 0.], [-2., 0., 0.]);
+
+        let expected = [
+            [-1., 0., 0., 2.],
+            [0., 1., 0., 0.],
+            [0., 0., 1., 0.],
+            [0., 0., 0., 1.],
+        ];
+        assert_matrix_eq(transform.matrix, expected);
+    }
+
+    fn shape() -> Shape {
+        Sketch::from_circle(Circle::from_radius(1.)).into()
+    }
+
+    fn assert_matrix_eq(a: [[f64; 4]; 4], b: [[f64; 4]; 4]) {
+        for (a, b) in a.iter().flatten().zip(b.iter().flatten()) {
+            assert!((a - b).abs() < 1e-12, "{a:?} != {b:?}");
+        }
+    }
+}
@@ -0,0 +1,41 @@
+#[cfg(feature = "serde")]
+use serde::{Deserialize, Serialize};
+
+use crate::Shape;
+
+/// A union of two 3-dimensional shapes
+///
+/// Unlike a [`Group`], the shapes of a union are allowed to touch or overlap.
+/// The result is a single shape that covers the space occupied by either of
+/// them.
+///
+/// # Limitations
+///
+/// Only shapes bounded by planar faces are fully supported. Curved faces are
+/// approximated, according to the tolerance used for the model.
+///
+/// [`Group`]: crate::Group
+#[derive(Clone, Debug, PartialEq)]
+#[cfg_attr(feature = "serde", derive(Serialize, Deserialize))]
+#[repr(C)]
+pub struct Union {
+    shapes: [Shape; 2],
+}
+
+impl Union {
+    /// Create a `Union` from two shapes
+    pub fn from_shapes(shapes: [Shape; 2]) -> Self {
+        Self { shapes }
+    }
+
+    /// Access the shapes that make up the union
+    pub fn shapes(&self) -> &[Shape; 2] {
+        &self.shapes
+    }
+}
+
+impl From<Union> for Shape {
+    fn from(shape: Union) -> Self {
+        Self::Union(Box::new(shape))
+    }
+}